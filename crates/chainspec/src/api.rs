@@ -63,6 +63,12 @@ pub trait EthChainSpec: Send + Sync + Unpin + Debug {
     /// Returns the final total difficulty if the Paris hardfork is known.
     fn final_paris_total_difficulty(&self) -> Option<U256>;
 
+    /// Returns the maximum contract code size, if the chain raises or lowers the
+    /// [EIP-170](https://eips.ethereum.org/EIPS/eip-170) limit.
+    fn max_code_size(&self) -> Option<usize> {
+        None
+    }
+
     /// See [`calc_next_block_base_fee`].
     fn next_block_base_fee(&self, parent: &Self::Header, target_timestamp: u64) -> Option<u64> {
         Some(calc_next_block_base_fee(
@@ -72,6 +78,15 @@ pub trait EthChainSpec: Send + Sync + Unpin + Debug {
             self.base_fee_params_at_timestamp(target_timestamp),
         ))
     }
+
+    /// Returns the projected base fee of the block following `parent`, using the EIP-1559
+    /// parameters active at the parent's timestamp.
+    ///
+    /// This is the base fee the transaction pool expects for the pending block. Returns `None` if
+    /// the parent has no base fee.
+    fn next_block_base_fee_after(&self, parent: &Self::Header) -> Option<u64> {
+        self.next_block_base_fee(parent, parent.timestamp())
+    }
 }
 
 impl<H: BlockHeader> EthChainSpec for ChainSpec<H> {
@@ -134,4 +149,8 @@ impl<H: BlockHeader> EthChainSpec for ChainSpec<H> {
     fn final_paris_total_difficulty(&self) -> Option<U256> {
         self.paris_block_and_final_difficulty.map(|(_, final_difficulty)| final_difficulty)
     }
+
+    fn max_code_size(&self) -> Option<usize> {
+        self.max_code_size
+    }
 }
@@ -175,4 +175,31 @@ mod tests {
         let got = spec.next_block_base_fee(&parent, next_timestamp).unwrap_or_default();
         assert_eq!(expected, got, "Base fee calculation does not match expected value");
     }
+
+    #[test]
+    fn test_next_block_base_fee_transition() {
+        use crate::{EthChainSpec, MAINNET};
+        use alloy_consensus::Header;
+
+        let parent = Header {
+            gas_used: 30_000_000,
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(1_000_000_000),
+            ..Default::default()
+        };
+        // a full block raises the base fee by 1/8
+        assert_eq!(MAINNET.next_block_base_fee_after(&parent), Some(1_125_000_000));
+
+        // an empty block lowers it by 1/8
+        let parent = Header { gas_used: 0, ..parent };
+        assert_eq!(MAINNET.next_block_base_fee_after(&parent), Some(875_000_000));
+
+        // a block at the gas target keeps it
+        let parent = Header { gas_used: 15_000_000, ..parent };
+        assert_eq!(MAINNET.next_block_base_fee_after(&parent), Some(1_000_000_000));
+
+        // no projection before London
+        let parent = Header { base_fee_per_gas: None, ..parent };
+        assert_eq!(MAINNET.next_block_base_fee_after(&parent), None);
+    }
 }
@@ -117,6 +117,7 @@ pub static MAINNET: LazyLock<Arc<ChainSpec>> = LazyLock::new(|| {
             (mainnet::MAINNET_BPO1_TIMESTAMP, BlobParams::bpo1()),
             (mainnet::MAINNET_BPO2_TIMESTAMP, BlobParams::bpo2()),
         ]),
+        max_code_size: None,
     };
     spec.genesis.config.dao_fork_support = true;
     spec.into()
@@ -152,6 +153,7 @@ pub static SEPOLIA: LazyLock<Arc<ChainSpec>> = LazyLock::new(|| {
             (sepolia::SEPOLIA_BPO1_TIMESTAMP, BlobParams::bpo1()),
             (sepolia::SEPOLIA_BPO2_TIMESTAMP, BlobParams::bpo2()),
         ]),
+        max_code_size: None,
     };
     spec.genesis.config.dao_fork_support = true;
     spec.into()
@@ -182,6 +184,7 @@ pub static HOLESKY: LazyLock<Arc<ChainSpec>> = LazyLock::new(|| {
             (holesky::HOLESKY_BPO1_TIMESTAMP, BlobParams::bpo1()),
             (holesky::HOLESKY_BPO2_TIMESTAMP, BlobParams::bpo2()),
         ]),
+        max_code_size: None,
     };
     spec.genesis.config.dao_fork_support = true;
     spec.into()
@@ -214,6 +217,7 @@ pub static HOODI: LazyLock<Arc<ChainSpec>> = LazyLock::new(|| {
             (hoodi::HOODI_BPO1_TIMESTAMP, BlobParams::bpo1()),
             (hoodi::HOODI_BPO2_TIMESTAMP, BlobParams::bpo2()),
         ]),
+        max_code_size: None,
     };
     spec.genesis.config.dao_fork_support = true;
     spec.into()
@@ -318,6 +322,10 @@ pub struct ChainSpec<H: BlockHeader = Header> {
 
     /// The settings passed for blob configurations for specific hardforks.
     pub blob_params: BlobScheduleBlobParams,
+
+    /// The maximum contract code size, if it differs from the
+    /// [EIP-170](https://eips.ethereum.org/EIPS/eip-170) limit.
+    pub max_code_size: Option<usize>,
 }
 
 impl<H: BlockHeader> Default for ChainSpec<H> {
@@ -332,6 +340,7 @@ impl<H: BlockHeader> Default for ChainSpec<H> {
             base_fee_params: BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()),
             prune_delete_limit: MAINNET_PRUNE_DELETE_LIMIT,
             blob_params: Default::default(),
+            max_code_size: None,
         }
     }
 }
@@ -677,6 +686,7 @@ impl<H: BlockHeader> ChainSpec<H> {
             base_fee_params,
             prune_delete_limit,
             blob_params,
+            max_code_size,
         } = self;
         ChainSpec {
             chain,
@@ -688,6 +698,7 @@ impl<H: BlockHeader> ChainSpec<H> {
             base_fee_params,
             prune_delete_limit,
             blob_params,
+            max_code_size,
         }
     }
 }
@@ -812,6 +823,7 @@ impl From<Genesis> for ChainSpec {
         });
 
         let hardforks = ChainHardforks::new(ordered_hardforks);
+        let max_code_size = genesis_max_code_size(&genesis);
 
         Self {
             chain: genesis.config.chain_id.into(),
@@ -821,11 +833,18 @@ impl From<Genesis> for ChainSpec {
             paris_block_and_final_difficulty,
             deposit_contract,
             blob_params,
+            max_code_size,
             ..Default::default()
         }
     }
 }
 
+/// Returns the maximum contract code size configured in the `maxCodeSize` field of the genesis
+/// chain config, if any.
+fn genesis_max_code_size(genesis: &Genesis) -> Option<usize> {
+    genesis.config.extra_fields.get_deserialized::<usize>("maxCodeSize").and_then(Result::ok)
+}
+
 impl<H: BlockHeader> Hardforks for ChainSpec<H> {
     fn fork<HF: Hardfork>(&self, fork: HF) -> ForkCondition {
         self.hardforks.fork(fork)
@@ -870,6 +889,7 @@ pub struct ChainSpecBuilder {
     chain: Option<Chain>,
     genesis: Option<Genesis>,
     hardforks: ChainHardforks,
+    max_code_size: Option<usize>,
 }
 
 impl ChainSpecBuilder {
@@ -879,6 +899,7 @@ impl ChainSpecBuilder {
             chain: Some(MAINNET.chain),
             genesis: Some(MAINNET.genesis.clone()),
             hardforks: MAINNET.hardforks.clone(),
+            max_code_size: MAINNET.max_code_size,
         }
     }
 }
@@ -902,6 +923,13 @@ impl ChainSpecBuilder {
         self
     }
 
+    /// Set the maximum contract code size, overriding the
+    /// [EIP-170](https://eips.ethereum.org/EIPS/eip-170) limit.
+    pub const fn max_code_size(mut self, max_code_size: usize) -> Self {
+        self.max_code_size = Some(max_code_size);
+        self
+    }
+
     /// Add the given fork with the given activation condition to the spec.
     pub fn with_fork<H: Hardfork>(mut self, fork: H, condition: ForkCondition) -> Self {
         self.hardforks.insert(fork, condition);
@@ -1070,6 +1098,7 @@ impl ChainSpecBuilder {
             })
         };
         let genesis = self.genesis.expect("The genesis is required");
+        let max_code_size = self.max_code_size.or_else(|| genesis_max_code_size(&genesis));
         ChainSpec {
             chain: self.chain.expect("The chain is required"),
             genesis_header: SealedHeader::new_unhashed(make_genesis_header(
@@ -1080,6 +1109,7 @@ impl ChainSpecBuilder {
             hardforks: self.hardforks,
             paris_block_and_final_difficulty,
             deposit_contract: None,
+            max_code_size,
             ..Default::default()
         }
     }
@@ -1091,6 +1121,7 @@ impl From<&Arc<ChainSpec>> for ChainSpecBuilder {
             chain: Some(value.chain),
             genesis: Some(value.genesis.clone()),
             hardforks: value.hardforks.clone(),
+            max_code_size: value.max_code_size,
         }
     }
 }
@@ -2402,6 +2433,20 @@ Post-merge hard forks (timestamp based):
         assert_eq!(genesis.base_fee_per_gas, Some(0x1337));
     }
 
+    #[test]
+    fn test_max_code_size_from_genesis() {
+        let s = r#"{"config":{"chainId":1337,"londonBlock":0,"maxCodeSize":49152},"alloc":{}}"#;
+        let genesis: Genesis = serde_json::from_str(s).unwrap();
+        let chainspec = ChainSpec::from_genesis(genesis.clone());
+        assert_eq!(chainspec.max_code_size(), Some(49152));
+        assert_eq!(
+            ChainSpecBuilder::default().chain(Chain::dev()).genesis(genesis).build().max_code_size,
+            Some(49152)
+        );
+
+        assert_eq!(MAINNET.max_code_size(), None);
+    }
+
     #[test]
     fn test_parse_cancun_genesis_json() {
         let s = r#"{"config":{"ethash":{},"chainId":1337,"homesteadBlock":0,"eip150Block":0,"eip155Block":0,"eip158Block":0,"byzantiumBlock":0,"constantinopleBlock":0,"petersburgBlock":0,"istanbulBlock":0,"berlinBlock":0,"londonBlock":0,"terminalTotalDifficulty":0,"terminalTotalDifficultyPassed":true,"shanghaiTime":0,"cancunTime":4661},"nonce":"0x0","timestamp":"0x0","extraData":"0x","gasLimit":"0x4c4b40","difficulty":"0x1","mixHash":"0x0000000000000000000000000000000000000000000000000000000000000000","coinbase":"0x0000000000000000000000000000000000000000","alloc":{"658bdf435d810c91414ec09147daa6db62406379":{"balance":"0x487a9a304539440000"},"aa00000000000000000000000000000000000000":{"code":"0x6042","storage":{"0x0000000000000000000000000000000000000000000000000000000000000000":"0x0000000000000000000000000000000000000000000000000000000000000000","0x0100000000000000000000000000000000000000000000000000000000000000":"0x0100000000000000000000000000000000000000000000000000000000000000","0x0200000000000000000000000000000000000000000000000000000000000000":"0x0200000000000000000000000000000000000000000000000000000000000000","0x0300000000000000000000000000000000000000000000000000000000000000":"0x0000000000000000000000000000000000000000000000000000000000000303"},"balance":"0x1","nonce":"0x1"},"bb00000000000000000000000000000000000000":{"code":"0x600154600354","storage":{"0x0000000000000000000000000000000000000000000000000000000000000000":"0x0000000000000000000000000000000000000000000000000000000000000000","0x0100000000000000000000000000000000000000000000000000000000000000":"0x0100000000000000000000000000000000000000000000000000000000000000","0x0200000000000000000000000000000000000000000000000000000000000000":"0x0200000000000000000000000000000000000000000000000000000000000000","0x0300000000000000000000000000000000000000000000000000000000000000":"0x0000000000000000000000000000000000000000000000000000000000000303"},"balance":"0x2","nonce":"0x1"}},"number":"0x0","gasUsed":"0x0","parentHash":"0x0000000000000000000000000000000000000000000000000000000000000000","baseFeePerGas":"0x3b9aca00"}"#;
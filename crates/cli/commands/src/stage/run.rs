@@ -263,6 +263,7 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + Hardforks + EthereumHardforks>
                 StageEnum::Senders => (
                     Box::new(SenderRecoveryStage::new(SenderRecoveryConfig {
                         commit_threshold: batch_size,
+                        ..config.stages.sender_recovery
                     })),
                     None,
                 ),
@@ -255,11 +255,15 @@ impl Default for BodiesConfig {
 pub struct SenderRecoveryConfig {
     /// The maximum number of transactions to process before committing progress to the database.
     pub commit_threshold: u64,
+    /// The number of threads used to recover transaction senders.
+    ///
+    /// Defaults to the available parallelism if not set.
+    pub concurrency: Option<usize>,
 }
 
 impl Default for SenderRecoveryConfig {
     fn default() -> Self {
-        Self { commit_threshold: 5_000_000 }
+        Self { commit_threshold: 5_000_000, concurrency: None }
     }
 }
 
@@ -1,6 +1,6 @@
 //! Collection of methods for block validation.
 
-use alloy_consensus::{BlockHeader as _, Transaction, EMPTY_OMMER_ROOT_HASH};
+use alloy_consensus::{BlockHeader as _, Sealable, Transaction, EMPTY_OMMER_ROOT_HASH};
 use alloy_eips::{eip4844::DATA_GAS_PER_BLOB, eip7840::BlobParams};
 use reth_chainspec::{EthChainSpec, EthereumHardfork, EthereumHardforks};
 use reth_consensus::{ConsensusError, TxGasLimitTooHighErr};
@@ -135,8 +135,24 @@ where
     Ok(())
 }
 
+/// Validates that the claimed hash of the [`SealedBlock`] matches its header.
+///
+/// The hash is recomputed from the header the same way [`SealedHeader::seal_slow`] does and
+/// compared against the hash the block was sealed with, which may originate from an untrusted
+/// source such as a payload.
+pub fn validate_block_hash<B: Block>(block: &SealedBlock<B>) -> Result<(), ConsensusError> {
+    let hash = block.header().hash_slow();
+    if hash != block.hash() {
+        return Err(ConsensusError::BlockHashMismatch(
+            GotExpected { got: block.hash(), expected: hash }.into(),
+        ))
+    }
+    Ok(())
+}
+
 /// Validate a block without regard for state:
 ///
+/// - Compares the claimed block hash to the hash of the block header
 /// - Compares the ommer hash in the block header to the block body
 /// - Compares the transactions root in the block header to the block body
 /// - Pre-execution transaction validation
@@ -148,6 +164,8 @@ where
     B: Block,
     ChainSpec: EthereumHardforks,
 {
+    validate_block_hash(block)?;
+
     post_merge_hardfork_fields(block, chain_spec)?;
 
     // Check transaction root
@@ -270,6 +288,25 @@ pub fn validate_header_extra_data<H: BlockHeader>(
     }
 }
 
+/// Validates that the header's timestamp is not further in the future than the allowed drift.
+///
+/// Timestamps up to `allowed_future_drift` seconds ahead of `present_timestamp` are accepted to
+/// tolerate clock skew between the node and the block producer.
+#[inline]
+pub fn validate_header_timestamp_not_in_future<H: BlockHeader>(
+    header: &H,
+    present_timestamp: u64,
+    allowed_future_drift: u64,
+) -> Result<(), ConsensusError> {
+    if header.timestamp() > present_timestamp.saturating_add(allowed_future_drift) {
+        return Err(ConsensusError::TimestampIsInFuture {
+            timestamp: header.timestamp(),
+            present_timestamp,
+        })
+    }
+    Ok(())
+}
+
 /// Validates against the parent hash and number.
 ///
 /// This function ensures that the header block number is sequential and that the hash of the parent
@@ -359,9 +396,10 @@ pub fn validate_against_parent_gas_limit<
     let parent_gas_limit = if !chain_spec.is_london_active_at_block(parent.number()) &&
         chain_spec.is_london_active_at_block(header.number())
     {
-        parent.gas_limit() *
+        parent.gas_limit().saturating_mul(
             chain_spec.base_fee_params_at_timestamp(header.timestamp()).elasticity_multiplier
-                as u64
+                as u64,
+        )
     } else {
         parent.gas_limit()
     };
@@ -435,7 +473,7 @@ mod tests {
     use super::*;
     use alloy_consensus::{BlockBody, Header, TxEip4844};
     use alloy_eips::eip4895::Withdrawals;
-    use alloy_primitives::{Address, Bytes, Signature, U256};
+    use alloy_primitives::{Address, Bytes, Signature, B256, U256};
     use rand::Rng;
     use reth_chainspec::ChainSpecBuilder;
     use reth_ethereum_primitives::{Transaction, TransactionSigned};
@@ -517,4 +555,60 @@ mod tests {
         // Test with custom larger limit - should pass
         assert!(validate_header_extra_data(&header_33, 64).is_ok());
     }
+
+    #[test]
+    fn validate_header_timestamp_future_drift() {
+        let present_timestamp = 1_700_000_000;
+        let header = |timestamp| Header { timestamp, ..Default::default() };
+
+        assert!(validate_header_timestamp_not_in_future(
+            &header(present_timestamp),
+            present_timestamp,
+            0
+        )
+        .is_ok());
+        assert!(validate_header_timestamp_not_in_future(
+            &header(present_timestamp + 15),
+            present_timestamp,
+            15
+        )
+        .is_ok());
+        assert_eq!(
+            validate_header_timestamp_not_in_future(
+                &header(present_timestamp + 16),
+                present_timestamp,
+                15
+            ),
+            Err(ConsensusError::TimestampIsInFuture {
+                timestamp: present_timestamp + 16,
+                present_timestamp
+            })
+        );
+
+        // a larger drift accepts the same header
+        assert!(validate_header_timestamp_not_in_future(
+            &header(present_timestamp + 16),
+            present_timestamp,
+            30
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn validate_block_hash_tampered() {
+        let header = Header { number: 1, gas_limit: 30_000_000, ..Default::default() };
+        let block = alloy_consensus::Block::<TransactionSigned>::new(header, Default::default());
+
+        let sealed = SealedBlock::seal_slow(block.clone());
+        assert!(validate_block_hash(&sealed).is_ok());
+
+        let tampered_hash = B256::with_last_byte(1);
+        let tampered = SealedBlock::new_unchecked(block, tampered_hash);
+        assert_eq!(
+            validate_block_hash(&tampered),
+            Err(ConsensusError::BlockHashMismatch(
+                GotExpected { got: tampered_hash, expected: sealed.hash() }.into()
+            ))
+        );
+    }
 }
@@ -70,6 +70,27 @@ pub trait Consensus<B: Block>: HeaderValidator<B::Header> {
     ///
     /// Note: validating blocks does not include other validations of the Consensus
     fn validate_block_pre_execution(&self, block: &SealedBlock<B>) -> Result<(), Self::Error>;
+
+    /// Validates each of the given blocks on its own, see [`HeaderValidator::validate_header`] and
+    /// [`Self::validate_block_pre_execution`].
+    ///
+    /// Returns the outcome for every block, in the given order. Unlike
+    /// [`HeaderValidator::validate_header_range`] this doesn't stop at the first invalid block,
+    /// so that callers checking competing blocks at the same height can pick the first valid one.
+    ///
+    /// **This should not be called for the genesis block**.
+    fn validate_blocks(&self, blocks: &[SealedBlock<B>]) -> Vec<Result<(), Self::Error>>
+    where
+        Self::Error: From<ConsensusError>,
+    {
+        blocks
+            .iter()
+            .map(|block| {
+                self.validate_header(block.sealed_header())?;
+                self.validate_block_pre_execution(block)
+            })
+            .collect()
+    }
 }
 
 /// `HeaderValidator` is a protocol that validates headers and their relationships.
@@ -161,6 +182,10 @@ pub enum ConsensusError {
     #[error("mismatched block state root: {0}")]
     BodyStateRootDiff(GotExpectedBoxed<B256>),
 
+    /// Error when the hash of the block is different from the hash computed from its header.
+    #[error("mismatched block hash: {0}")]
+    BlockHashMismatch(GotExpectedBoxed<B256>),
+
     /// Error when the transaction root in the block is different from the expected transaction
     /// root.
     #[error("mismatched block transaction root: {0}")]
@@ -417,6 +442,77 @@ impl ConsensusError {
     pub const fn is_state_root_error(&self) -> bool {
         matches!(self, Self::BodyStateRootDiff(_))
     }
+
+    /// Returns the [`ConsensusErrorCategory`] of this error, i.e. whether the error was caused by
+    /// the block itself or by the environment the block was validated in.
+    pub const fn category(&self) -> ConsensusErrorCategory {
+        match self {
+            Self::ParentUnknown { .. } |
+            Self::BlockKnown { .. } |
+            Self::TimestampIsInFuture { .. } => ConsensusErrorCategory::Environmental,
+            Self::ParentHashMismatch(_) | Self::Other(_) => ConsensusErrorCategory::Indeterminate,
+            Self::HeaderGasUsedExceedsGasLimit { .. } |
+            Self::HeaderGasLimitExceedsMax { .. } |
+            Self::BlockGasUsed { .. } |
+            Self::BodyOmmersHashDiff(_) |
+            Self::BodyStateRootDiff(_) |
+            Self::BlockHashMismatch(_) |
+            Self::BodyTransactionRootDiff(_) |
+            Self::BodyReceiptRootDiff(_) |
+            Self::BodyBloomLogDiff(_) |
+            Self::BodyWithdrawalsRootDiff(_) |
+            Self::BodyRequestsHashDiff(_) |
+            Self::ParentBlockNumberMismatch { .. } |
+            Self::BaseFeeMissing |
+            Self::TransactionSignerRecoveryError |
+            Self::ExtraDataExceedsMax { .. } |
+            Self::TheMergeDifficultyIsNotZero |
+            Self::TheMergeNonceIsNotZero |
+            Self::TheMergeOmmerRootIsNotEmpty |
+            Self::WithdrawalsRootMissing |
+            Self::RequestsHashMissing |
+            Self::WithdrawalsRootUnexpected |
+            Self::RequestsHashUnexpected |
+            Self::BodyWithdrawalsMissing |
+            Self::BodyRequestsMissing |
+            Self::BlobGasUsedMissing |
+            Self::BlobGasUsedUnexpected |
+            Self::ExcessBlobGasMissing |
+            Self::ExcessBlobGasUnexpected |
+            Self::ParentBeaconBlockRootMissing |
+            Self::ParentBeaconBlockRootUnexpected |
+            Self::BlobGasUsedExceedsMaxBlobGasPerBlock { .. } |
+            Self::BlobGasUsedNotMultipleOfBlobGasPerBlob { .. } |
+            Self::BlobGasUsedDiff(_) |
+            Self::InvalidTransaction(_) |
+            Self::BaseFeeDiff(_) |
+            Self::ExcessBlobGasDiff { .. } |
+            Self::GasLimitInvalidIncrease { .. } |
+            Self::GasLimitInvalidMinimum { .. } |
+            Self::GasLimitInvalidBlockMaximum { .. } |
+            Self::GasLimitInvalidDecrease { .. } |
+            Self::TimestampIsInPast { .. } |
+            Self::BlockTooLarge { .. } |
+            Self::TransactionGasLimitTooHigh(_) => ConsensusErrorCategory::BuilderFault,
+        }
+    }
+
+    /// Returns `true` if the error was caused by the block itself.
+    pub const fn is_builder_fault(&self) -> bool {
+        matches!(self.category(), ConsensusErrorCategory::BuilderFault)
+    }
+}
+
+/// Attribution of a [`ConsensusError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsensusErrorCategory {
+    /// The block is invalid, e.g. a header or body field is wrong.
+    BuilderFault,
+    /// The block could not be validated because of the local environment, e.g. the parent block is
+    /// not known.
+    Environmental,
+    /// The error can not be attributed to either the block or the environment.
+    Indeterminate,
 }
 
 impl From<InvalidTransactionError> for ConsensusError {
@@ -447,3 +543,174 @@ pub struct TxGasLimitTooHighErr {
     /// The maximum allowed gas limit
     pub max_allowed: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::ToString, vec};
+    use alloy_primitives::Bloom;
+
+    /// Rejects headers that use more gas than their gas limit.
+    #[derive(Debug)]
+    struct GasUsedConsensus;
+
+    impl HeaderValidator for GasUsedConsensus {
+        fn validate_header(&self, header: &SealedHeader) -> Result<(), ConsensusError> {
+            if header.gas_used > header.gas_limit {
+                return Err(ConsensusError::HeaderGasUsedExceedsGasLimit {
+                    gas_used: header.gas_used,
+                    gas_limit: header.gas_limit,
+                })
+            }
+            Ok(())
+        }
+
+        fn validate_header_against_parent(
+            &self,
+            _header: &SealedHeader,
+            _parent: &SealedHeader,
+        ) -> Result<(), ConsensusError> {
+            Ok(())
+        }
+    }
+
+    impl Consensus<alloy_consensus::Block<alloy_consensus::TxEnvelope>> for GasUsedConsensus {
+        type Error = ConsensusError;
+
+        fn validate_body_against_header(
+            &self,
+            _body: &alloy_consensus::BlockBody<alloy_consensus::TxEnvelope>,
+            _header: &SealedHeader,
+        ) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn validate_block_pre_execution(
+            &self,
+            block: &SealedBlock<alloy_consensus::Block<alloy_consensus::TxEnvelope>>,
+        ) -> Result<(), Self::Error> {
+            if block.header().extra_data.len() > 32 {
+                return Err(ConsensusError::ExtraDataExceedsMax {
+                    len: block.header().extra_data.len(),
+                })
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn validate_blocks_returns_outcome_per_block() {
+        let block = |header| {
+            SealedBlock::seal_slow(alloy_consensus::Block::<alloy_consensus::TxEnvelope>::new(
+                header,
+                Default::default(),
+            ))
+        };
+        let valid = Header { number: 1, gas_limit: 100, gas_used: 100, ..Default::default() };
+        let blocks = [
+            block(Header { gas_used: 101, ..valid.clone() }),
+            block(valid.clone()),
+            block(Header { extra_data: vec![0; 33].into(), ..valid }),
+        ];
+
+        assert_eq!(
+            GasUsedConsensus.validate_blocks(&blocks),
+            vec![
+                Err(ConsensusError::HeaderGasUsedExceedsGasLimit { gas_used: 101, gas_limit: 100 }),
+                Ok(()),
+                Err(ConsensusError::ExtraDataExceedsMax { len: 33 }),
+            ]
+        );
+        assert!(GasUsedConsensus.validate_blocks(&[]).is_empty());
+    }
+
+    #[test]
+    fn consensus_error_categories() {
+        let hash = GotExpected { got: B256::ZERO, expected: B256::with_last_byte(1) };
+        let number = GotExpected { got: 1u64, expected: 2u64 };
+
+        let environmental = vec![
+            ConsensusError::ParentUnknown { hash: B256::ZERO },
+            ConsensusError::BlockKnown { hash: B256::ZERO, number: 1 },
+            ConsensusError::TimestampIsInFuture { timestamp: 2, present_timestamp: 1 },
+        ];
+        let indeterminate = vec![
+            ConsensusError::ParentHashMismatch(hash.clone().into()),
+            ConsensusError::Other("l2 error".to_string()),
+        ];
+        let builder_fault = vec![
+            ConsensusError::HeaderGasUsedExceedsGasLimit { gas_used: 2, gas_limit: 1 },
+            ConsensusError::HeaderGasLimitExceedsMax { gas_limit: u64::MAX },
+            ConsensusError::BlockGasUsed { gas: number.clone(), gas_spent_by_tx: vec![] },
+            ConsensusError::BodyOmmersHashDiff(hash.clone().into()),
+            ConsensusError::BodyStateRootDiff(hash.clone().into()),
+            ConsensusError::BlockHashMismatch(hash.clone().into()),
+            ConsensusError::BodyTransactionRootDiff(hash.clone().into()),
+            ConsensusError::BodyReceiptRootDiff(hash.clone().into()),
+            ConsensusError::BodyBloomLogDiff(
+                GotExpected { got: Bloom::ZERO, expected: Bloom::repeat_byte(1) }.into(),
+            ),
+            ConsensusError::BodyWithdrawalsRootDiff(hash.clone().into()),
+            ConsensusError::BodyRequestsHashDiff(hash.into()),
+            ConsensusError::ParentBlockNumberMismatch { parent_block_number: 1, block_number: 3 },
+            ConsensusError::BaseFeeMissing,
+            ConsensusError::TransactionSignerRecoveryError,
+            ConsensusError::ExtraDataExceedsMax { len: 33 },
+            ConsensusError::TheMergeDifficultyIsNotZero,
+            ConsensusError::TheMergeNonceIsNotZero,
+            ConsensusError::TheMergeOmmerRootIsNotEmpty,
+            ConsensusError::WithdrawalsRootMissing,
+            ConsensusError::RequestsHashMissing,
+            ConsensusError::WithdrawalsRootUnexpected,
+            ConsensusError::RequestsHashUnexpected,
+            ConsensusError::BodyWithdrawalsMissing,
+            ConsensusError::BodyRequestsMissing,
+            ConsensusError::BlobGasUsedMissing,
+            ConsensusError::BlobGasUsedUnexpected,
+            ConsensusError::ExcessBlobGasMissing,
+            ConsensusError::ExcessBlobGasUnexpected,
+            ConsensusError::ParentBeaconBlockRootMissing,
+            ConsensusError::ParentBeaconBlockRootUnexpected,
+            ConsensusError::BlobGasUsedExceedsMaxBlobGasPerBlock {
+                blob_gas_used: 2,
+                max_blob_gas_per_block: 1,
+            },
+            ConsensusError::BlobGasUsedNotMultipleOfBlobGasPerBlob {
+                blob_gas_used: 1,
+                blob_gas_per_blob: 2,
+            },
+            ConsensusError::BlobGasUsedDiff(number.clone()),
+            ConsensusError::InvalidTransaction(InvalidTransactionError::SignerAccountHasBytecode),
+            ConsensusError::BaseFeeDiff(number.clone()),
+            ConsensusError::ExcessBlobGasDiff {
+                diff: number,
+                parent_excess_blob_gas: 0,
+                parent_blob_gas_used: 0,
+            },
+            ConsensusError::GasLimitInvalidIncrease { parent_gas_limit: 1, child_gas_limit: 2 },
+            ConsensusError::GasLimitInvalidMinimum { child_gas_limit: 1 },
+            ConsensusError::GasLimitInvalidBlockMaximum { block_gas_limit: u64::MAX },
+            ConsensusError::GasLimitInvalidDecrease { parent_gas_limit: 2, child_gas_limit: 1 },
+            ConsensusError::TimestampIsInPast { parent_timestamp: 2, timestamp: 1 },
+            ConsensusError::BlockTooLarge { rlp_length: 2, max_rlp_length: 1 },
+            ConsensusError::TransactionGasLimitTooHigh(Box::new(TxGasLimitTooHighErr {
+                tx_hash: B256::ZERO,
+                gas_limit: 2,
+                max_allowed: 1,
+            })),
+        ];
+
+        for err in environmental {
+            assert_eq!(err.category(), ConsensusErrorCategory::Environmental, "{err:?}");
+            assert!(!err.is_builder_fault());
+        }
+        for err in indeterminate {
+            assert_eq!(err.category(), ConsensusErrorCategory::Indeterminate, "{err:?}");
+            assert!(!err.is_builder_fault());
+        }
+        for err in builder_fault {
+            assert_eq!(err.category(), ConsensusErrorCategory::BuilderFault, "{err:?}");
+            assert!(err.is_builder_fault());
+        }
+    }
+}
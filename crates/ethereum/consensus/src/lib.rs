@@ -13,7 +13,7 @@ extern crate alloc;
 
 use alloc::{fmt::Debug, sync::Arc};
 use alloy_consensus::{constants::MAXIMUM_EXTRA_DATA_SIZE, EMPTY_OMMER_ROOT_HASH};
-use alloy_eips::eip7840::BlobParams;
+use alloy_eips::{eip7840::BlobParams, merge::ALLOWED_FUTURE_BLOCK_TIME_SECONDS};
 use reth_chainspec::{EthChainSpec, EthereumHardforks};
 use reth_consensus::{Consensus, ConsensusError, FullConsensus, HeaderValidator};
 use reth_consensus_common::validation::{
@@ -23,6 +23,8 @@ use reth_consensus_common::validation::{
     validate_block_pre_execution, validate_body_against_header, validate_header_base_fee,
     validate_header_extra_data, validate_header_gas,
 };
+#[cfg(feature = "std")]
+use reth_consensus_common::validation::validate_header_timestamp_not_in_future;
 use reth_execution_types::BlockExecutionResult;
 use reth_primitives_traits::{
     Block, BlockHeader, NodePrimitives, RecoveredBlock, SealedBlock, SealedHeader,
@@ -40,12 +42,18 @@ pub struct EthBeaconConsensus<ChainSpec> {
     chain_spec: Arc<ChainSpec>,
     /// Maximum allowed extra data size in bytes
     max_extra_data_size: usize,
+    /// Number of seconds a pre-merge header's timestamp may be ahead of the present time
+    allowed_future_block_time: u64,
 }
 
 impl<ChainSpec: EthChainSpec + EthereumHardforks> EthBeaconConsensus<ChainSpec> {
     /// Create a new instance of [`EthBeaconConsensus`]
     pub const fn new(chain_spec: Arc<ChainSpec>) -> Self {
-        Self { chain_spec, max_extra_data_size: MAXIMUM_EXTRA_DATA_SIZE }
+        Self {
+            chain_spec,
+            max_extra_data_size: MAXIMUM_EXTRA_DATA_SIZE,
+            allowed_future_block_time: ALLOWED_FUTURE_BLOCK_TIME_SECONDS,
+        }
     }
 
     /// Returns the maximum allowed extra data size.
@@ -59,6 +67,20 @@ impl<ChainSpec: EthChainSpec + EthereumHardforks> EthBeaconConsensus<ChainSpec>
         self
     }
 
+    /// Returns the number of seconds a header's timestamp may be ahead of the present time.
+    pub const fn allowed_future_block_time(&self) -> u64 {
+        self.allowed_future_block_time
+    }
+
+    /// Sets the number of seconds a header's timestamp may be ahead of the present time and returns
+    /// the updated instance.
+    ///
+    /// Defaults to [`ALLOWED_FUTURE_BLOCK_TIME_SECONDS`].
+    pub const fn with_allowed_future_block_time(mut self, seconds: u64) -> Self {
+        self.allowed_future_block_time = seconds;
+        self
+    }
+
     /// Returns the chain spec associated with this consensus engine.
     pub const fn chain_spec(&self) -> &Arc<ChainSpec> {
         &self.chain_spec
@@ -128,14 +150,11 @@ where
                     .unwrap()
                     .as_secs();
 
-                if header.timestamp() >
-                    present_timestamp + alloy_eips::merge::ALLOWED_FUTURE_BLOCK_TIME_SECONDS
-                {
-                    return Err(ConsensusError::TimestampIsInFuture {
-                        timestamp: header.timestamp(),
-                        present_timestamp,
-                    });
-                }
+                validate_header_timestamp_not_in_future(
+                    header,
+                    present_timestamp,
+                    self.allowed_future_block_time,
+                )?;
             }
         }
         validate_header_extra_data(header, self.max_extra_data_size)?;
@@ -211,7 +230,9 @@ mod tests {
     use super::*;
     use alloy_consensus::Header;
     use alloy_primitives::B256;
-    use reth_chainspec::{ChainSpec, ChainSpecBuilder};
+    use reth_chainspec::{
+        Chain, ChainSpec, ChainSpecBuilder, EthereumHardfork, ForkCondition, MAINNET,
+    };
     use reth_consensus_common::validation::validate_against_parent_gas_limit;
     use reth_primitives_traits::{
         constants::{GAS_LIMIT_BOUND_DIVISOR, MINIMUM_GAS_LIMIT},
@@ -288,6 +309,115 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_gas_limit_increase_at_bound() {
+        let parent = header_with_gas_limit(GAS_LIMIT_BOUND_DIVISOR * 10);
+        let max_increase = parent.gas_limit / GAS_LIMIT_BOUND_DIVISOR - 1;
+
+        let child = header_with_gas_limit(parent.gas_limit + max_increase);
+        assert_eq!(
+            validate_against_parent_gas_limit(&child, &parent, &ChainSpec::<Header>::default()),
+            Ok(())
+        );
+
+        let child = header_with_gas_limit(parent.gas_limit + max_increase + 1);
+        assert_eq!(
+            validate_against_parent_gas_limit(&child, &parent, &ChainSpec::<Header>::default()),
+            Err(ConsensusError::GasLimitInvalidIncrease {
+                parent_gas_limit: parent.gas_limit,
+                child_gas_limit: child.gas_limit,
+            })
+        );
+    }
+
+    #[test]
+    fn test_gas_limit_decrease_at_bound() {
+        let parent = header_with_gas_limit(GAS_LIMIT_BOUND_DIVISOR * 10);
+        let max_decrease = parent.gas_limit / GAS_LIMIT_BOUND_DIVISOR - 1;
+
+        let child = header_with_gas_limit(parent.gas_limit - max_decrease);
+        assert_eq!(
+            validate_against_parent_gas_limit(&child, &parent, &ChainSpec::<Header>::default()),
+            Ok(())
+        );
+
+        let child = header_with_gas_limit(parent.gas_limit - max_decrease - 1);
+        assert_eq!(
+            validate_against_parent_gas_limit(&child, &parent, &ChainSpec::<Header>::default()),
+            Err(ConsensusError::GasLimitInvalidDecrease {
+                parent_gas_limit: parent.gas_limit,
+                child_gas_limit: child.gas_limit,
+            })
+        );
+    }
+
+    #[test]
+    fn test_gas_limit_london_transition() {
+        let chain_spec = ChainSpecBuilder::mainnet()
+            .berlin_activated()
+            .with_fork(EthereumHardfork::London, ForkCondition::Block(10))
+            .build();
+        let elasticity_multiplier =
+            chain_spec.base_fee_params_at_timestamp(0).elasticity_multiplier as u64;
+
+        let parent = SealedHeader::new(
+            reth_primitives_traits::Header {
+                number: 9,
+                gas_limit: GAS_LIMIT_BOUND_DIVISOR * 10,
+                ..Default::default()
+            },
+            B256::ZERO,
+        );
+        let child_with_gas_limit = |gas_limit| {
+            SealedHeader::new(
+                reth_primitives_traits::Header { number: 10, gas_limit, ..Default::default() },
+                B256::ZERO,
+            )
+        };
+
+        // on the fork block the bounds are derived from the elastic parent gas limit
+        let parent_gas_limit = parent.gas_limit * elasticity_multiplier;
+        let max_delta = parent_gas_limit / GAS_LIMIT_BOUND_DIVISOR - 1;
+
+        for gas_limit in
+            [parent_gas_limit, parent_gas_limit + max_delta, parent_gas_limit - max_delta]
+        {
+            let child = child_with_gas_limit(gas_limit);
+            assert_eq!(validate_against_parent_gas_limit(&child, &parent, &chain_spec), Ok(()));
+        }
+
+        let child = child_with_gas_limit(parent_gas_limit + max_delta + 1);
+        assert_eq!(
+            validate_against_parent_gas_limit(&child, &parent, &chain_spec),
+            Err(ConsensusError::GasLimitInvalidIncrease {
+                parent_gas_limit,
+                child_gas_limit: child.gas_limit,
+            })
+        );
+
+        // keeping the pre-london gas limit is a decrease relative to the elastic parent gas limit
+        let child = child_with_gas_limit(parent.gas_limit);
+        assert_eq!(
+            validate_against_parent_gas_limit(&child, &parent, &chain_spec),
+            Err(ConsensusError::GasLimitInvalidDecrease {
+                parent_gas_limit,
+                child_gas_limit: child.gas_limit,
+            })
+        );
+
+        // after the fork block the parent gas limit is used as is
+        let parent = child_with_gas_limit(parent_gas_limit);
+        let child = SealedHeader::new(
+            reth_primitives_traits::Header {
+                number: 11,
+                gas_limit: parent_gas_limit + max_delta,
+                ..Default::default()
+            },
+            B256::ZERO,
+        );
+        assert_eq!(validate_against_parent_gas_limit(&child, &parent, &chain_spec), Ok(()));
+    }
+
     #[test]
     fn shanghai_block_zero_withdrawals() {
         // ensures that if shanghai is activated, and we include a block with a withdrawals root,
@@ -305,4 +435,40 @@ mod tests {
             Ok(())
         );
     }
+
+    #[test]
+    fn test_timestamp_in_future_tolerance() {
+        let chain_spec = Arc::new(
+            ChainSpecBuilder::default()
+                .chain(Chain::mainnet())
+                .genesis(MAINNET.genesis.clone())
+                .frontier_activated()
+                .build(),
+        );
+        let present_timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let header = |timestamp| {
+            SealedHeader::new(
+                reth_primitives_traits::Header { number: 1, timestamp, ..Default::default() },
+                B256::ZERO,
+            )
+        };
+
+        let consensus = EthBeaconConsensus::new(chain_spec);
+        assert_eq!(consensus.allowed_future_block_time(), ALLOWED_FUTURE_BLOCK_TIME_SECONDS);
+        assert_eq!(consensus.validate_header(&header(present_timestamp + 5)), Ok(()));
+        assert!(matches!(
+            consensus.validate_header(&header(present_timestamp + 60)),
+            Err(ConsensusError::TimestampIsInFuture { .. })
+        ));
+
+        let consensus = consensus.with_allowed_future_block_time(120);
+        assert_eq!(consensus.validate_header(&header(present_timestamp + 60)), Ok(()));
+        assert!(matches!(
+            consensus.validate_header(&header(present_timestamp + 600)),
+            Err(ConsensusError::TimestampIsInFuture { .. })
+        ));
+    }
 }
@@ -10,7 +10,7 @@ use alloy_eips::{
 };
 use alloy_evm::block::BlockValidationError;
 use alloy_primitives::{b256, fixed_bytes, keccak256, Bytes, TxKind, B256, U256};
-use reth_chainspec::{ChainSpecBuilder, EthereumHardfork, ForkCondition, MAINNET};
+use reth_chainspec::{ChainSpec, ChainSpecBuilder, EthereumHardfork, ForkCondition, MAINNET};
 use reth_ethereum_primitives::{Block, BlockBody, Transaction};
 use reth_evm::{
     execute::{BasicBlockExecutor, Executor},
@@ -24,7 +24,7 @@ use reth_primitives_traits::{
 use reth_testing_utils::generators::{self, sign_tx_with_key_pair};
 use revm::{
     database::{CacheDB, EmptyDB, TransitionState},
-    primitives::address,
+    primitives::{address, eip170::MAX_CODE_SIZE},
     state::{AccountInfo, Bytecode, EvmState},
     Database,
 };
@@ -826,3 +826,57 @@ fn test_balance_increment_not_duplicated() {
         );
     }
 }
+
+#[test]
+fn max_code_size_from_chain_spec() {
+    // init code returning `MAX_CODE_SIZE + 1` zero bytes: PUSH3 size, PUSH1 0, RETURN
+    let code_size = (MAX_CODE_SIZE + 1) as u32;
+    let init_code: Bytes =
+        [&[0x62][..], &code_size.to_be_bytes()[1..], &[0x60, 0x00, 0xf3]].concat().into();
+
+    let deploy = |chain_spec: ChainSpec| {
+        let chain_spec = Arc::new(chain_spec);
+
+        let sender_key_pair = generators::generate_key(&mut generators::rng());
+        let sender_address = public_key_to_address(sender_key_pair.public_key());
+        let mut db = CacheDB::new(EmptyDB::default());
+        db.insert_account_info(
+            sender_address,
+            AccountInfo { balance: U256::from(ETH_TO_WEI), ..Default::default() },
+        );
+
+        let mut header = chain_spec.genesis_header().clone();
+        header.gas_limit = 30_000_000;
+        let tx = sign_tx_with_key_pair(
+            sender_key_pair,
+            Transaction::Legacy(TxLegacy {
+                chain_id: Some(chain_spec.chain.id()),
+                nonce: 0,
+                gas_price: header.base_fee_per_gas.unwrap().into(),
+                gas_limit: 10_000_000,
+                to: TxKind::Create,
+                value: U256::ZERO,
+                input: init_code.clone(),
+            }),
+        );
+
+        let evm_config = EthEvmConfig::new(chain_spec);
+        let mut executor = evm_config.batch_executor(db);
+        let result = executor
+            .execute_one(
+                &Block { header, body: BlockBody { transactions: vec![tx], ..Default::default() } }
+                    .try_into_recovered()
+                    .unwrap(),
+            )
+            .unwrap();
+        result.receipts[0].success
+    };
+
+    // one byte over the EIP-170 limit fails on mainnet
+    assert!(!deploy(ChainSpecBuilder::mainnet().shanghai_activated().build()));
+
+    // but succeeds on a chain with a raised limit
+    assert!(deploy(
+        ChainSpecBuilder::mainnet().shanghai_activated().max_code_size(2 * MAX_CODE_SIZE).build()
+    ));
+}
@@ -38,7 +38,7 @@ use reth_payload_primitives::PayloadTypes;
 use reth_provider::{providers::ProviderFactoryBuilder, EthStorage};
 use reth_rpc::{
     eth::core::{EthApiFor, EthRpcConverterFor},
-    ValidationApi,
+    ValidationApi, ValidationApiConfig,
 };
 use reth_rpc_api::servers::BlockSubmissionValidationApiServer;
 use reth_rpc_builder::{config::RethRpcServerConfig, middleware::RethRpcMiddleware};
@@ -294,7 +294,10 @@ where
             ctx.node.provider().clone(),
             Arc::new(ctx.node.consensus().clone()),
             ctx.node.evm_config().clone(),
-            ctx.config.rpc.flashbots_config(),
+            ValidationApiConfig {
+                kzg_settings: ctx.kzg_settings.clone(),
+                ..ctx.config.rpc.flashbots_config()
+            },
             Box::new(ctx.node.task_executor().clone()),
             Arc::new(EthereumEngineValidator::new(ctx.config.chain.clone())),
         );
@@ -484,6 +487,10 @@ where
             .with_local_transactions_config(pool_config.local_transactions_config.clone())
             .set_tx_fee_cap(ctx.config().rpc.rpc_tx_fee_cap)
             .with_max_tx_gas_limit(ctx.config().txpool.max_tx_gas_limit)
+            .with_max_access_list_entries(ctx.config().txpool.max_access_list_entries)
+            .with_reject_unprotected_transactions(
+                ctx.config().txpool.reject_unprotected_transactions,
+            )
             .with_minimum_priority_fee(ctx.config().txpool.minimum_priority_fee)
             .with_additional_tasks(ctx.config().txpool.additional_validation_tasks)
             .build_with_tasks(ctx.task_executor().clone(), blob_store.clone());
@@ -295,6 +295,84 @@ async fn test_flashbots_validate_v4() -> eyre::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_flashbots_validate_v4_with_parent() -> eyre::Result<()> {
+    reth_tracing::init_test_tracing();
+
+    let chain_spec = Arc::new(
+        ChainSpecBuilder::default()
+            .chain(MAINNET.chain)
+            .genesis(serde_json::from_str(include_str!("../assets/genesis.json")).unwrap())
+            .prague_activated()
+            .build(),
+    );
+
+    let (mut nodes, _tasks, wallet) = setup_engine::<EthereumNode>(
+        1,
+        chain_spec.clone(),
+        false,
+        Default::default(),
+        eth_payload_attributes,
+    )
+    .await?;
+    let mut node = nodes.pop().unwrap();
+    let provider = ProviderBuilder::new()
+        .wallet(EthereumWallet::new(wallet.wallet_gen().swap_remove(0)))
+        .connect_http(node.rpc_url());
+
+    let _ = provider.send_transaction(TransactionRequest::default().to(Address::ZERO)).await?;
+    let payload = node.new_payload().await?;
+    let parent_hash = payload.block().parent_hash;
+
+    let request = BuilderBlockValidationRequestV4 {
+        request: SignedBidSubmissionV4 {
+            message: BidTrace {
+                parent_hash,
+                block_hash: payload.block().hash(),
+                gas_used: payload.block().gas_used,
+                gas_limit: payload.block().gas_limit,
+                ..Default::default()
+            },
+            execution_payload: ExecutionPayloadV3::from_block_unchecked(
+                payload.block().hash(),
+                &payload.block().clone().into_block(),
+            ),
+            blobs_bundle: BlobsBundleV1::new([]),
+            execution_requests: payload.requests().unwrap().try_into().unwrap(),
+            signature: Default::default(),
+        },
+        parent_beacon_block_root: payload.block().parent_beacon_block_root.unwrap(),
+        registered_gas_limit: payload.block().gas_limit,
+    };
+
+    // the parent of the submission is no longer the latest block
+    node.advance_block().await?;
+
+    let err = provider
+        .raw_request::<_, ()>("flashbots_validateBuilderSubmissionV4".into(), (&request,))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("block is too old"), "{err}");
+
+    provider
+        .raw_request::<_, ()>(
+            "flashbots_validateBuilderSubmissionV4WithParent".into(),
+            (&request, parent_hash),
+        )
+        .await
+        .expect("request should validate against its parent");
+
+    let err = provider
+        .raw_request::<_, ()>(
+            "flashbots_validateBuilderSubmissionV4WithParent".into(),
+            (&request, B256::ZERO),
+        )
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("block parent hash mismatch"), "{err}");
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_eth_config() -> eyre::Result<()> {
     reth_tracing::init_test_tracing();
@@ -830,6 +830,96 @@ mod tests {
         let withdrawals_root = calculate_withdrawals_root(withdrawals);
         assert_eq!(block.withdrawals_root, Some(withdrawals_root));
     }
+
+    #[test]
+    fn roundtrip_block_withdrawals_section() {
+        use alloy_consensus::{BlockBody, Header, EMPTY_ROOT_HASH};
+        use alloy_rlp::Encodable;
+        use reth_primitives_traits::BlockBody as _;
+
+        // pre-Shanghai block without a withdrawals section
+        let block: Block = Block {
+            header: Header { number: 1, ..Default::default() },
+            body: BlockBody { withdrawals: None, ..Default::default() },
+        };
+        let mut encoded = Vec::new();
+        block.encode(&mut encoded);
+        let decoded = Block::decode(&mut encoded.as_slice()).unwrap();
+        assert!(!decoded.body.has_withdrawals_section());
+        assert_eq!(decoded, block);
+        let mut reencoded = Vec::new();
+        decoded.encode(&mut reencoded);
+        assert_eq!(reencoded, encoded);
+
+        // post-Shanghai block with an empty withdrawals section
+        let block: Block = Block {
+            header: Header {
+                number: 1,
+                base_fee_per_gas: Some(7),
+                withdrawals_root: Some(EMPTY_ROOT_HASH),
+                ..Default::default()
+            },
+            body: BlockBody { withdrawals: Some(Default::default()), ..Default::default() },
+        };
+        let mut encoded = Vec::new();
+        block.encode(&mut encoded);
+        let decoded = Block::decode(&mut encoded.as_slice()).unwrap();
+        assert!(decoded.body.has_withdrawals_section());
+        assert!(decoded.body.withdrawals.as_ref().unwrap().is_empty());
+        assert_eq!(decoded, block);
+        let mut reencoded = Vec::new();
+        decoded.encode(&mut reencoded);
+        assert_eq!(reencoded, encoded);
+    }
+
+    #[test]
+    fn body_into_block_roots_match_header() {
+        use alloy_consensus::{BlockBody, Header, SignableTransaction, TxLegacy};
+        use alloy_eips::eip4895::{Withdrawal, Withdrawals};
+        use alloy_primitives::{Signature, TxKind, U256};
+        use reth_primitives_traits::BlockBody as _;
+
+        let transactions: Vec<TransactionSigned> = (0..3u64)
+            .map(|nonce| {
+                TxLegacy {
+                    nonce,
+                    gas_price: 1,
+                    gas_limit: 21_000,
+                    to: TxKind::Call(Address::with_last_byte(1)),
+                    value: U256::from(nonce),
+                    ..Default::default()
+                }
+                .into_signed(Signature::test_signature())
+                .into()
+            })
+            .collect();
+        let withdrawals = Withdrawals::new(vec![Withdrawal {
+            index: 0,
+            validator_index: 1,
+            address: Address::with_last_byte(2),
+            amount: 3,
+        }]);
+        let body: BlockBody<TransactionSigned> =
+            BlockBody { transactions, ommers: Vec::new(), withdrawals: Some(withdrawals) };
+
+        let header = Header {
+            number: 1,
+            base_fee_per_gas: Some(7),
+            transactions_root: body.calculate_tx_root(),
+            ommers_hash: body.calculate_ommers_root(),
+            withdrawals_root: body.calculate_withdrawals_root(),
+            ..Default::default()
+        };
+
+        // the body is moved into the block without cloning its transactions
+        let block: Block = body.into_block(header.clone());
+        assert_eq!(block.header, header);
+        assert_eq!(block.body.transactions.len(), 3);
+        assert_eq!(block.body.calculate_tx_root(), block.header.transactions_root);
+        assert_eq!(block.body.calculate_ommers_root(), block.header.ommers_hash);
+        assert_eq!(block.body.calculate_withdrawals_root(), block.header.withdrawals_root);
+    }
+
     #[test]
     fn check_receipt_root_optimism() {
         use alloy_consensus::ReceiptWithBloom;
@@ -675,11 +675,74 @@ impl SignedTransaction for TransactionSigned {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_consensus::EthereumTxEnvelope;
+    use crate::PooledTransactionVariant;
+    use alloy_consensus::{EthereumTxEnvelope, TxEip4844WithSidecar};
+    use alloy_eips::{eip4844::BlobTransactionSidecar, eip7594::BlobTransactionSidecarVariant};
     use proptest::proptest;
     use proptest_arbitrary_interop::arb;
     use reth_codecs::Compact;
 
+    /// Returns the consensus and the network encoding of the same blob transaction.
+    fn blob_transaction_encodings() -> (Vec<u8>, Vec<u8>) {
+        let sidecar = BlobTransactionSidecar {
+            blobs: vec![Default::default()],
+            commitments: vec![Default::default()],
+            proofs: vec![Default::default()],
+        };
+        let tx = TxEip4844 {
+            chain_id: 1,
+            gas_limit: 21_000,
+            max_fee_per_blob_gas: 1,
+            blob_versioned_hashes: sidecar.versioned_hashes().collect(),
+            ..Default::default()
+        };
+        let signature = Signature::test_signature();
+
+        let consensus =
+            EthereumTxEnvelope::<TxEip4844>::from(Signed::new_unhashed(tx.clone(), signature));
+        let pooled = PooledTransactionVariant::from(Signed::new_unhashed(
+            TxEip4844WithSidecar::from_tx_and_sidecar(
+                tx,
+                BlobTransactionSidecarVariant::Eip4844(sidecar),
+            ),
+            signature,
+        ));
+        assert_eq!(consensus.tx_hash(), pooled.tx_hash());
+
+        (consensus.encoded_2718(), pooled.encoded_2718())
+    }
+
+    #[test]
+    fn decode_blob_transaction_consensus_encoding() {
+        let (consensus, network) = blob_transaction_encodings();
+        assert!(network.len() > consensus.len());
+
+        let tx = EthereumTxEnvelope::<TxEip4844>::decode_2718(&mut consensus.as_slice()).unwrap();
+        assert!(tx.is_eip4844());
+        let legacy = TransactionSigned::decode_2718(&mut consensus.as_slice()).unwrap();
+        assert_eq!(EthereumTxEnvelope::<TxEip4844>::from(legacy), tx);
+
+        // the consensus encoding lacks the sidecar the network encoding requires
+        assert!(PooledTransactionVariant::decode_2718(&mut consensus.as_slice()).is_err());
+    }
+
+    #[test]
+    fn decode_blob_transaction_network_encoding() {
+        let (consensus, network) = blob_transaction_encodings();
+
+        let pooled = PooledTransactionVariant::decode_2718(&mut network.as_slice()).unwrap();
+        let sidecar = pooled.as_eip4844().unwrap().tx().sidecar.as_eip4844().unwrap();
+        assert_eq!(sidecar.blobs.len(), 1);
+
+        // a block must not contain a blob transaction with its sidecar
+        assert!(EthereumTxEnvelope::<TxEip4844>::decode_2718(&mut network.as_slice()).is_err());
+        assert!(TransactionSigned::decode_2718(&mut network.as_slice()).is_err());
+
+        // stripping the sidecar yields the consensus encoding
+        let tx = EthereumTxEnvelope::<TxEip4844>::from(pooled);
+        assert_eq!(tx.encoded_2718(), consensus);
+    }
+
     proptest! {
         #[test]
         fn test_roundtrip_compact_encode_envelope(reth_tx in arb::<TransactionSigned>()) {
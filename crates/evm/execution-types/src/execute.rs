@@ -1,3 +1,4 @@
+use alloy_primitives::{Address, U256};
 use revm::database::BundleState;
 
 pub use alloy_evm::block::BlockExecutionResult;
@@ -23,3 +24,64 @@ pub struct BlockExecutionOutput<T> {
     /// The changed state of the block after execution.
     pub state: BundleState,
 }
+
+impl<T> BlockExecutionOutput<T> {
+    /// Returns the balance of the given account before and after the execution of the block.
+    ///
+    /// This is read directly from the [`BundleState`], which tracks the state of every changed
+    /// account before the block and after its last transaction, so all balance changes of the
+    /// block are accounted for, even if the account was touched by multiple transactions.
+    ///
+    /// Returns `None` if the account was not changed by the block.
+    pub fn balance_change(&self, address: &Address) -> Option<(U256, U256)> {
+        let account = self.state.account(address)?;
+        let before = account.original_info.as_ref().map(|info| info.balance).unwrap_or_default();
+        let after = account.info.as_ref().map(|info| info.balance).unwrap_or_default();
+        Some((before, after))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::map::HashMap;
+    use revm::{
+        database::{states::bundle_state::BundleRetention, CacheDB, EmptyDB, State},
+        state::{Account, AccountInfo},
+        DatabaseCommit,
+    };
+
+    #[test]
+    fn balance_change_across_multiple_transactions() {
+        let fee_recipient = Address::with_last_byte(1);
+        let mut db = CacheDB::new(EmptyDB::default());
+        db.insert_account_info(
+            fee_recipient,
+            AccountInfo { balance: U256::from(10), ..Default::default() },
+        );
+        let mut state = State::builder().with_database(db).with_bundle_update().build();
+        state.load_cache_account(fee_recipient).unwrap();
+
+        // the fee recipient receives a tip in two separate transactions
+        for balance in [15, 22] {
+            let mut account =
+                Account::from(AccountInfo { balance: U256::from(balance), ..Default::default() });
+            account.mark_touch();
+            state.commit(HashMap::from_iter([(fee_recipient, account)]));
+        }
+        state.merge_transitions(BundleRetention::Reverts);
+
+        let output = BlockExecutionOutput::<()> {
+            result: BlockExecutionResult {
+                receipts: vec![],
+                requests: Default::default(),
+                gas_used: 0,
+                blob_gas_used: 0,
+            },
+            state: state.take_bundle(),
+        };
+
+        assert_eq!(output.balance_change(&fee_recipient), Some((U256::from(10), U256::from(22))));
+        assert_eq!(output.balance_change(&Address::with_last_byte(2)), None);
+    }
+}
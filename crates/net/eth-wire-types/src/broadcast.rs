@@ -2,6 +2,8 @@
 
 use crate::{EthMessage, EthVersion, NetworkPrimitives};
 use alloc::{sync::Arc, vec::Vec};
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockNumHash;
 use alloy_primitives::{
     map::{HashMap, HashSet},
     Bytes, TxHash, B256, U128,
@@ -64,6 +66,90 @@ impl From<NewBlockHashes> for Vec<BlockHashNumber> {
     }
 }
 
+/// The roots committing to the contents of a block body, as found in the block header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, RlpEncodable, RlpDecodable, Default)]
+#[rlp(trailing)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
+pub struct BlockBodyRoots {
+    /// The root of the transactions trie of the block.
+    pub transactions_root: B256,
+    /// The hash of the ommers list of the block.
+    pub ommers_hash: B256,
+    /// The root of the withdrawals trie of the block, if any.
+    pub withdrawals_root: Option<B256>,
+}
+
+impl BlockBodyRoots {
+    /// Returns the body roots committed to by the given header.
+    pub fn from_header<H: BlockHeader>(header: &H) -> Self {
+        Self {
+            transactions_root: header.transactions_root(),
+            ommers_hash: header.ommers_hash(),
+            withdrawals_root: header.withdrawals_root(),
+        }
+    }
+}
+
+/// A lightweight announcement of a new block, carrying the block number and hash together with
+/// the roots of its body.
+///
+/// This allows peers to verify a block body response against the announced roots before
+/// downloading the full block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
+pub struct NewBlockAnnouncement {
+    /// The number and hash of the announced block.
+    pub block: BlockNumHash,
+    /// The body roots of the announced block.
+    pub roots: BlockBodyRoots,
+}
+
+impl NewBlockAnnouncement {
+    fn rlp_payload_length(&self) -> usize {
+        self.block.number.length() + self.block.hash.length() + self.roots.length()
+    }
+}
+
+impl Encodable for NewBlockAnnouncement {
+    fn encode(&self, out: &mut dyn alloy_rlp::BufMut) {
+        alloy_rlp::Header { list: true, payload_length: self.rlp_payload_length() }.encode(out);
+        self.block.number.encode(out);
+        self.block.hash.encode(out);
+        self.roots.encode(out);
+    }
+
+    fn length(&self) -> usize {
+        let payload_length = self.rlp_payload_length();
+        payload_length + alloy_rlp::length_of_length(payload_length)
+    }
+}
+
+impl Decodable for NewBlockAnnouncement {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let header = alloy_rlp::Header::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString)
+        }
+        let started_len = buf.len();
+        let this = Self {
+            block: BlockNumHash { number: Decodable::decode(buf)?, hash: Decodable::decode(buf)? },
+            roots: Decodable::decode(buf)?,
+        };
+        let consumed = started_len - buf.len();
+        if consumed != header.payload_length {
+            return Err(alloy_rlp::Error::ListLengthMismatch {
+                expected: header.payload_length,
+                got: consumed,
+            })
+        }
+        Ok(this)
+    }
+}
+
 /// A trait for block payloads transmitted through p2p.
 pub trait NewBlockPayload:
     Encodable + Decodable + Clone + Eq + Debug + Send + Sync + Unpin + 'static
@@ -1112,4 +1198,31 @@ mod tests {
         assert_eq!(tx_hashes.sizes[1], tx.encode_2718_len());
         assert_eq!(tx_hashes.hashes[1], *tx.tx_hash());
     }
+
+    #[test]
+    fn new_block_announcement_roundtrip() {
+        let header = alloy_consensus::Header {
+            number: 42,
+            transactions_root: B256::repeat_byte(1),
+            ommers_hash: B256::repeat_byte(2),
+            ..Default::default()
+        };
+        let roots = BlockBodyRoots::from_header(&header);
+        assert_eq!(roots.withdrawals_root, None);
+
+        for roots in
+            [roots, BlockBodyRoots { withdrawals_root: Some(B256::repeat_byte(3)), ..roots }]
+        {
+            let announcement = NewBlockAnnouncement {
+                block: BlockNumHash::new(header.number, header.hash_slow()),
+                roots,
+            };
+            let mut encoded = Vec::new();
+            announcement.encode(&mut encoded);
+            assert_eq!(encoded.len(), announcement.length());
+
+            let decoded = NewBlockAnnouncement::decode(&mut &encoded[..]).unwrap();
+            assert_eq!(decoded, announcement);
+        }
+    }
 }
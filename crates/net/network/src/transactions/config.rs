@@ -30,6 +30,9 @@ pub struct TransactionsManagerConfig {
     /// Which peers we accept incoming transactions or announcements from.
     #[cfg_attr(feature = "serde", serde(default))]
     pub ingress_policy: TransactionIngressPolicy,
+    /// Which transaction types are propagated to peers.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub propagated_tx_types: PropagatedTransactionTypes,
 }
 
 impl Default for TransactionsManagerConfig {
@@ -39,6 +42,7 @@ impl Default for TransactionsManagerConfig {
             max_transactions_seen_by_peer_history: DEFAULT_MAX_COUNT_TRANSACTIONS_SEEN_BY_PEER,
             propagation_mode: TransactionPropagationMode::default(),
             ingress_policy: TransactionIngressPolicy::default(),
+            propagated_tx_types: PropagatedTransactionTypes::default(),
         }
     }
 }
@@ -226,6 +230,91 @@ impl FromStr for TransactionIngressPolicy {
     }
 }
 
+/// Determines which transaction types are propagated to peers.
+///
+/// Transactions of other types are still accepted into the pool, they are just never announced or
+/// sent to peers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PropagatedTransactionTypes {
+    /// Bitmask of the propagated [EIP-2718](https://eips.ethereum.org/EIPS/eip-2718) types, a
+    /// type is propagated if the bit at its position is set.
+    mask: u128,
+}
+
+impl PropagatedTransactionTypes {
+    /// Propagate transactions of all types.
+    pub const ALL: Self = Self { mask: u128::MAX };
+
+    /// Propagate only transactions of the given types.
+    ///
+    /// Types outside of the EIP-2718 range `0x00..=0x7f` are ignored.
+    pub fn only(types: impl IntoIterator<Item = u8>) -> Self {
+        let mask = types
+            .into_iter()
+            .filter(|ty| *ty <= MAX_TRANSACTION_TYPE)
+            .fold(0, |mask, ty| mask | (1 << ty));
+        Self { mask }
+    }
+
+    /// Returns true if transactions of the given type are propagated.
+    pub const fn propagates(&self, ty: u8) -> bool {
+        ty <= MAX_TRANSACTION_TYPE && self.mask & (1 << ty) != 0
+    }
+
+    /// Returns true if transactions of all types are propagated.
+    pub const fn propagates_all(&self) -> bool {
+        self.mask == Self::ALL.mask
+    }
+}
+
+impl Default for PropagatedTransactionTypes {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl fmt::Display for PropagatedTransactionTypes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.propagates_all() {
+            return f.write_str("all")
+        }
+        let types = (0..=MAX_TRANSACTION_TYPE)
+            .filter(|ty| self.propagates(*ty))
+            .map(|ty| ty.to_string())
+            .collect::<Vec<_>>();
+        f.write_str(&types.join(","))
+    }
+}
+
+impl FromStr for PropagatedTransactionTypes {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(Self::ALL)
+        }
+        let types = s
+            .split(',')
+            .map(|ty| {
+                let ty = ty.trim();
+                let parsed = match ty.strip_prefix("0x") {
+                    Some(hex) => u8::from_str_radix(hex, 16),
+                    None => ty.parse(),
+                };
+                parsed
+                    .ok()
+                    .filter(|ty| *ty <= MAX_TRANSACTION_TYPE)
+                    .ok_or_else(|| format!("Invalid transaction type: {ty}"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::only(types))
+    }
+}
+
+/// The highest transaction type allowed by EIP-2718.
+const MAX_TRANSACTION_TYPE: u8 = 0x7f;
+
 /// Defines the outcome of evaluating a transaction against an `AnnouncementFilteringPolicy`.
 ///
 /// Dictates how the `TransactionManager` should proceed on an announced transaction.
@@ -359,4 +448,24 @@ mod tests {
         assert!(TransactionPropagationMode::from_str("max").is_err());
         assert!(TransactionPropagationMode::from_str("").is_err());
     }
+
+    #[test]
+    fn test_propagated_transaction_types_from_str() {
+        let all = PropagatedTransactionTypes::from_str("all").unwrap();
+        assert_eq!(all, PropagatedTransactionTypes::ALL);
+        assert!(all.propagates(0) && all.propagates(3) && all.propagates(0x7e));
+        assert_eq!(all.to_string(), "all");
+
+        let types = PropagatedTransactionTypes::from_str("0,1, 2,0x04").unwrap();
+        assert_eq!(types, PropagatedTransactionTypes::only([0, 1, 2, 4]));
+        assert!(types.propagates(2));
+        assert!(!types.propagates(3));
+        assert!(!types.propagates(0x80));
+        assert_eq!(types.to_string(), "0,1,2,4");
+        assert_eq!(types.to_string().parse::<PropagatedTransactionTypes>().unwrap(), types);
+
+        assert!(PropagatedTransactionTypes::from_str("").is_err());
+        assert!(PropagatedTransactionTypes::from_str("1,blob").is_err());
+        assert!(PropagatedTransactionTypes::from_str("128").is_err());
+    }
 }
@@ -17,8 +17,9 @@ pub use self::constants::{
 };
 use config::AnnouncementAcceptance;
 pub use config::{
-    AnnouncementFilteringPolicy, TransactionFetcherConfig, TransactionIngressPolicy,
-    TransactionPropagationMode, TransactionPropagationPolicy, TransactionsManagerConfig,
+    AnnouncementFilteringPolicy, PropagatedTransactionTypes, TransactionFetcherConfig,
+    TransactionIngressPolicy, TransactionPropagationMode, TransactionPropagationPolicy,
+    TransactionsManagerConfig,
 };
 use policy::NetworkPolicies;
 
@@ -850,6 +851,18 @@ where
         self.propagate_all(hashes);
     }
 
+    /// Returns the pooled transactions with the given hashes whose type is propagated to peers.
+    ///
+    /// See [`TransactionsManagerConfig::propagated_tx_types`].
+    fn get_propagated_transactions(
+        &self,
+        hashes: Vec<TxHash>,
+    ) -> Vec<Arc<ValidPoolTransaction<Pool::Transaction>>> {
+        let mut transactions = self.pool.get_all(hashes);
+        transactions.retain(|tx| self.config.propagated_tx_types.propagates(tx.tx_type()));
+        transactions
+    }
+
     /// Propagate the full transactions to a specific peer.
     ///
     /// Returns the propagated transactions.
@@ -861,14 +874,15 @@ where
     ) -> Option<PropagatedTransactions> {
         trace!(target: "net::tx", ?peer_id, "Propagating transactions to peer");
 
+        let to_propagate =
+            self.get_propagated_transactions(txs).into_iter().map(PropagateTransaction::pool_tx);
+
         let peer = self.peers.get_mut(&peer_id)?;
         let mut propagated = PropagatedTransactions::default();
 
         // filter all transactions unknown to the peer
         let mut full_transactions = FullTransactionsBuilder::new(peer.version);
 
-        let to_propagate = self.pool.get_all(txs).into_iter().map(PropagateTransaction::pool_tx);
-
         if propagation_mode.is_forced() {
             // skip cache check if forced
             full_transactions.extend(to_propagate);
@@ -934,18 +948,17 @@ where
         // This fetches a transactions from the pool, including the blob transactions, which are
         // only ever sent as hashes.
         let propagated = {
-            let Some(peer) = self.peers.get_mut(&peer_id) else {
-                // no such peer
-                return
-            };
-
             let to_propagate = self
-                .pool
-                .get_all(hashes)
+                .get_propagated_transactions(hashes)
                 .into_iter()
                 .map(PropagateTransaction::pool_tx)
                 .collect::<Vec<_>>();
 
+            let Some(peer) = self.peers.get_mut(&peer_id) else {
+                // no such peer
+                return
+            };
+
             let mut propagated = PropagatedTransactions::default();
 
             // check if transaction is known to peer
@@ -1096,7 +1109,10 @@ where
             return
         }
         let propagated = self.propagate_transactions(
-            self.pool.get_all(hashes).into_iter().map(PropagateTransaction::pool_tx).collect(),
+            self.get_propagated_transactions(hashes)
+                .into_iter()
+                .map(PropagateTransaction::pool_tx)
+                .collect(),
             PropagationMode::Basic,
         );
 
@@ -1214,9 +1230,10 @@ where
         }
 
         // Get transactions to broadcast
-        let pooled_txs = self.pool.pooled_transactions_max(
+        let mut pooled_txs = self.pool.pooled_transactions_max(
             SOFT_LIMIT_COUNT_HASHES_IN_NEW_POOLED_TRANSACTIONS_BROADCAST_MESSAGE,
         );
+        pooled_txs.retain(|tx| self.config.propagated_tx_types.propagates(tx.tx_type()));
         if pooled_txs.is_empty() {
             trace!(target: "net::tx", ?peer_id, "No transactions in the pool to broadcast");
             return;
@@ -1370,6 +1387,19 @@ where
         //    reallocations
         let mut new_txs = Vec::with_capacity(transactions.len());
         for tx in transactions {
+            // reject malformed signatures before the more expensive recovery
+            if let Err(err) = tx.verify_signature() {
+                trace!(target: "net::tx",
+                    peer_id=format!("{peer_id:#}"),
+                    hash=%tx.tx_hash(),
+                    client_version=%peer.client_version,
+                    %err,
+                    "invalid signature for transaction"
+                );
+                has_bad_transactions = true;
+                continue
+            }
+
             // recover transaction
             let tx = match tx.try_into_recovered() {
                 Ok(tx) => tx,
@@ -6,7 +6,8 @@ use reth_ethereum_primitives::TransactionSigned;
 use reth_network::{
     test_utils::{NetworkEventStream, Testnet},
     transactions::config::{
-        TransactionIngressPolicy, TransactionPropagationKind, TransactionsManagerConfig,
+        PropagatedTransactionTypes, TransactionIngressPolicy, TransactionPropagationKind,
+        TransactionsManagerConfig,
     },
     NetworkEvent, NetworkEventListenerProvider, Peers,
 };
@@ -15,7 +16,7 @@ use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
 use reth_transaction_pool::{
     test_utils::TransactionGenerator, AddedTransactionOutcome, PoolTransaction, TransactionPool,
 };
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use tokio::join;
 
 #[tokio::test(flavor = "multi_thread")]
@@ -192,6 +193,67 @@ async fn test_tx_ingress_policy_trusted_only() {
     assert!(buff.contains(&outcome_1.hash));
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tx_propagation_filtered_by_type() {
+    reth_tracing::init_test_tracing();
+
+    let provider = MockEthProvider::default();
+
+    // propagate all types except EIP-1559 transactions
+    let tx_manager_config = TransactionsManagerConfig {
+        propagated_tx_types: PropagatedTransactionTypes::only([0, 1, 3, 4]),
+        ..Default::default()
+    };
+
+    let net = Testnet::create_with(2, provider.clone()).await;
+    let net = net.with_eth_pool_config(tx_manager_config);
+
+    let handle = net.spawn();
+
+    // connect all the peers
+    handle.connect_peers().await;
+
+    let peer_0_handle = &handle.peers()[0];
+    let peer_1_handle = &handle.peers()[1];
+
+    let mut peer0_tx_listener = peer_0_handle.pool().unwrap().pending_transactions_listener();
+    let mut peer1_tx_listener = peer_1_handle.pool().unwrap().new_transactions_listener();
+
+    let mut tx_gen = TransactionGenerator::new(rand::rng());
+    let tx = tx_gen.gen_eip1559_pooled();
+
+    // ensure the sender has balance
+    let sender = tx.sender();
+    provider.add_account(sender, ExtendedAccount::new(0, U256::from(100_000_000)));
+
+    // insert the tx in peer0's pool, it's pooled as usual
+    let outcome = peer_0_handle.pool().unwrap().add_external_transaction(tx).await.unwrap();
+    let inserted = peer0_tx_listener.recv().await.unwrap();
+    assert_eq!(inserted, outcome.hash);
+    assert!(peer_0_handle.pool().unwrap().contains(&outcome.hash));
+
+    // ensure tx is not announced to peer1
+    tokio::time::timeout(Duration::from_millis(500), peer1_tx_listener.recv())
+        .await
+        .expect_err("transaction should not be propagated");
+    assert!(!peer_1_handle.pool().unwrap().contains(&outcome.hash));
+
+    // ensure the tx isn't announced to newly connected peers either
+    let mut event_stream_0 = NetworkEventStream::new(peer_0_handle.network().event_listener());
+    let mut event_stream_1 = NetworkEventStream::new(peer_1_handle.network().event_listener());
+
+    peer_0_handle.network().remove_peer(*peer_1_handle.peer_id(), PeerKind::Static);
+    join!(event_stream_0.next_session_closed(), event_stream_1.next_session_closed());
+
+    peer_0_handle.network().add_peer(*peer_1_handle.peer_id(), peer_1_handle.local_addr());
+    join!(event_stream_0.next_session_established(), event_stream_1.next_session_established());
+
+    tokio::time::timeout(Duration::from_millis(500), peer1_tx_listener.recv())
+        .await
+        .expect_err("transaction should not be propagated");
+    assert!(!peer_1_handle.pool().unwrap().contains(&outcome.hash));
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_4844_tx_gossip_penalization() {
     reth_tracing::init_test_tracing();
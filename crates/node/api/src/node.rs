@@ -1,6 +1,7 @@
 //! Traits for configuring a node.
 
 use crate::PayloadTypes;
+use alloy_eips::eip4844::env_settings::EnvKzgSettings;
 use alloy_rpc_types_engine::JwtSecret;
 use reth_basic_payload_builder::PayloadBuilder;
 use reth_consensus::{ConsensusError, FullConsensus};
@@ -118,6 +119,8 @@ pub struct AddOnsContext<'a, N: FullNodeComponents> {
     pub engine_events: EventSender<ConsensusEngineEvent<<N::Types as NodeTypes>::Primitives>>,
     /// JWT secret for the node.
     pub jwt_secret: JwtSecret,
+    /// KZG settings loaded from the configured trusted setup, shared with the transaction pool.
+    pub kzg_settings: EnvKzgSettings,
 }
 
 /// Customizable node add-on types.
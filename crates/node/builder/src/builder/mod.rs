@@ -710,6 +710,8 @@ pub struct BuilderContext<Node: FullNodeTypes> {
     pub(crate) executor: TaskExecutor,
     /// Config container
     pub(crate) config_container: WithConfigs<<Node::Types as NodeTypes>::ChainSpec>,
+    /// KZG settings loaded from the configured trusted setup.
+    pub(crate) kzg_settings: EnvKzgSettings,
 }
 
 impl<Node: FullNodeTypes> BuilderContext<Node> {
@@ -719,8 +721,9 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
         provider: Node::Provider,
         executor: TaskExecutor,
         config_container: WithConfigs<<Node::Types as NodeTypes>::ChainSpec>,
+        kzg_settings: EnvKzgSettings,
     ) -> Self {
-        Self { head, provider, executor, config_container }
+        Self { head, provider, executor, config_container, kzg_settings }
     }
 
     /// Returns the configured provider to interact with the blockchain.
@@ -770,9 +773,10 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
         self.config().txpool.pool_config()
     }
 
-    /// Loads `EnvKzgSettings::Default`.
-    pub const fn kzg_settings(&self) -> eyre::Result<EnvKzgSettings> {
-        Ok(EnvKzgSettings::Default)
+    /// Returns the [`EnvKzgSettings`] loaded from the configured trusted setup file, or
+    /// `EnvKzgSettings::Default` if none is configured.
+    pub fn kzg_settings(&self) -> eyre::Result<EnvKzgSettings> {
+        Ok(self.kzg_settings.clone())
     }
 
     /// Returns the config for payload building.
@@ -245,6 +245,7 @@ where
             ctx.task_executor().clone(),
             reth_transaction_pool::maintain::MaintainPoolConfig {
                 max_tx_lifetime: pool_config.max_queued_lifetime,
+                max_pooled_tx_lifetime: pool_config.max_tx_lifetime,
                 no_local_exemptions: pool_config.local_transactions_config.no_exemptions,
                 ..Default::default()
             },
@@ -34,7 +34,7 @@ use crate::{
     hooks::OnComponentInitializedHook,
     BuilderContext, ExExLauncher, NodeAdapter, PrimitivesTy,
 };
-use alloy_eips::eip2124::Head;
+use alloy_eips::{eip2124::Head, eip4844::env_settings::EnvKzgSettings};
 use alloy_primitives::{BlockNumber, B256};
 use eyre::Context;
 use rayon::ThreadPoolBuilder;
@@ -168,6 +168,9 @@ impl LaunchContext {
         // Merge static file CLI arguments with config file, giving priority to CLI
         toml_config.static_files = config.static_files.merge_with_config(toml_config.static_files);
 
+        // Merge stages CLI arguments with config file, giving priority to CLI
+        toml_config.stages = config.stages.merge_with_config(toml_config.stages);
+
         Ok(toml_config)
     }
 
@@ -766,11 +769,15 @@ where
         // fetch the head block from the database
         let head = self.lookup_head()?;
 
+        // load the trusted setup once, it's shared by the pool and the add-ons
+        let kzg_settings = self.node_config().txpool.kzg_settings()?;
+
         let builder_ctx = BuilderContext::new(
             head,
             self.blockchain_db().clone(),
             self.task_executor().clone(),
             self.configs().clone(),
+            kzg_settings.clone(),
         );
 
         debug!(target: "reth::cli", "creating components");
@@ -794,6 +801,7 @@ where
             },
             node_adapter,
             head,
+            kzg_settings,
         };
 
         let ctx = LaunchContextWith {
@@ -856,6 +864,11 @@ where
         &mut self.right_mut().node_adapter
     }
 
+    /// Returns the [`EnvKzgSettings`] that were loaded for the components.
+    pub const fn kzg_settings(&self) -> &EnvKzgSettings {
+        &self.right().kzg_settings
+    }
+
     /// Returns a reference to the blockchain provider.
     pub const fn blockchain_db(&self) -> &T::Provider {
         &self.node_adapter().provider
@@ -1140,6 +1153,7 @@ where
     db_provider_container: WithMeteredProvider<NodeTypesWithDBAdapter<T::Types, T::DB>>,
     node_adapter: NodeAdapter<T, CB::Components>,
     head: Head,
+    kzg_settings: EnvKzgSettings,
 }
 
 #[cfg(test)]
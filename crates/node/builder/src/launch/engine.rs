@@ -197,6 +197,7 @@ impl EngineNodeLauncher {
             beacon_engine_handle: beacon_engine_handle.clone(),
             jwt_secret,
             engine_events: event_sender.clone(),
+            kzg_settings: ctx.kzg_settings().clone(),
         };
         let validator_builder = add_ons.engine_validator_builder();
 
@@ -971,7 +971,14 @@ where
         let Self { eth_api_builder, engine_api_builder, hooks, .. } = self;
 
         let engine_api = engine_api_builder.build_engine_api(&ctx).await?;
-        let AddOnsContext { node, config, beacon_engine_handle, jwt_secret, engine_events } = ctx;
+        let AddOnsContext {
+            node,
+            config,
+            beacon_engine_handle,
+            jwt_secret,
+            engine_events,
+            kzg_settings,
+        } = ctx;
 
         info!(target: "reth::cli", "Engine API handler initialized");
 
@@ -1000,7 +1007,10 @@ where
         let eth_api = eth_api_builder.build_eth_api(ctx).await?;
 
         let auth_config = config.rpc.auth_server_config(jwt_secret)?;
-        let module_config = config.rpc.transport_rpc_module_config();
+        let mut module_config = config.rpc.transport_rpc_module_config();
+        if let Some(module_config) = module_config.config_mut() {
+            module_config.flashbots_mut().kzg_settings = kzg_settings;
+        }
         debug!(target: "reth::cli", http=?module_config.http(), ws=?module_config.ws(), "Using RPC module config");
 
         let (mut modules, mut auth_module, registry) = RpcModuleBuilder::default()
@@ -1182,6 +1192,8 @@ impl<'a, N: FullNodeComponents<Types: NodeTypes<ChainSpec: Hardforks + EthereumH
             .pending_block_kind(self.config.pending_block_kind)
             .raw_tx_forwarder(self.config.raw_tx_forwarder)
             .evm_memory_limit(self.config.rpc_evm_memory_limit)
+            .transient_retry(self.config.transient_retry)
+            .max_trace_response_size(self.config.max_trace_response_size)
     }
 }
 
@@ -1394,6 +1406,7 @@ where
             EngineCapabilities::default(),
             engine_validator,
             ctx.config.engine.accept_execution_requests_hash,
+            ctx.config.engine.max_concurrent_new_payloads,
         ))
     }
 }
@@ -1,6 +1,6 @@
 //! clap [Args](clap::Args) for engine purposes
 
-use clap::Args;
+use clap::{builder::RangedU64ValueParser, Args};
 use reth_engine_primitives::{TreeConfig, DEFAULT_MULTIPROOF_TASK_CHUNK_SIZE};
 
 use crate::node_config::{
@@ -68,6 +68,13 @@ pub struct EngineArgs {
     #[arg(long = "engine.accept-execution-requests-hash")]
     pub accept_execution_requests_hash: bool,
 
+    /// Maximum number of `engine_newPayload` validations that may run concurrently.
+    ///
+    /// Payloads received while the limit is reached are answered with `SYNCING` instead of being
+    /// queued. Unlimited if not specified.
+    #[arg(long = "engine.max-concurrent-new-payloads", value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    pub max_concurrent_new_payloads: Option<usize>,
+
     /// Whether multiproof task should chunk proof targets.
     #[arg(long = "engine.multiproof-chunking", default_value = "true")]
     pub multiproof_chunking_enabled: bool,
@@ -136,6 +143,7 @@ impl Default for EngineArgs {
             state_provider_metrics: false,
             cross_block_cache_size: DEFAULT_CROSS_BLOCK_CACHE_SIZE_MB,
             accept_execution_requests_hash: false,
+            max_concurrent_new_payloads: None,
             multiproof_chunking_enabled: true,
             multiproof_chunk_size: DEFAULT_MULTIPROOF_TASK_CHUNK_SIZE,
             reserved_cpu_cores: DEFAULT_RESERVED_CPU_CORES,
@@ -202,4 +210,22 @@ mod tests {
         let args = CommandParser::<EngineArgs>::parse_from(["reth"]).args;
         assert_eq!(args, default_args);
     }
+
+    #[test]
+    fn test_parse_max_concurrent_new_payloads() {
+        let args = CommandParser::<EngineArgs>::parse_from([
+            "reth",
+            "--engine.max-concurrent-new-payloads",
+            "4",
+        ])
+        .args;
+        assert_eq!(args.max_concurrent_new_payloads, Some(4));
+
+        assert!(CommandParser::<EngineArgs>::try_parse_from([
+            "reth",
+            "--engine.max-concurrent-new-payloads",
+            "0",
+        ])
+        .is_err());
+    }
 }
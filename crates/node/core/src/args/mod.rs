@@ -80,5 +80,9 @@ pub use era::{DefaultEraHost, EraArgs, EraSourceArgs};
 mod static_files;
 pub use static_files::StaticFilesArgs;
 
+/// `StagesArgs` for configuring pipeline stages.
+mod stages;
+pub use stages::StagesArgs;
+
 mod error;
 pub mod types;
@@ -22,7 +22,9 @@ use reth_net_banlist::IpFilter;
 use reth_net_nat::{NatResolver, DEFAULT_NET_IF_NAME};
 use reth_network::{
     transactions::{
-        config::{TransactionIngressPolicy, TransactionPropagationKind},
+        config::{
+            PropagatedTransactionTypes, TransactionIngressPolicy, TransactionPropagationKind,
+        },
         constants::{
             tx_fetcher::{
                 DEFAULT_MAX_CAPACITY_CACHE_PENDING_FETCH, DEFAULT_MAX_COUNT_CONCURRENT_REQUESTS,
@@ -179,6 +181,13 @@ pub struct NetworkArgs {
     #[arg(long = "tx-ingress-policy", default_value_t = TransactionIngressPolicy::All)]
     pub tx_ingress_policy: TransactionIngressPolicy,
 
+    /// Transaction types that are propagated to peers
+    ///
+    /// Comma separated list of EIP-2718 transaction types, e.g. `0,1,2,4` to not propagate
+    /// EIP-4844 blob transactions. Transactions of other types are still accepted into the pool.
+    #[arg(long = "tx-propagation-types", value_name = "TYPES", default_value_t = PropagatedTransactionTypes::ALL)]
+    pub tx_propagation_types: PropagatedTransactionTypes,
+
     /// Disable transaction pool gossip
     ///
     /// Disables gossiping of transactions in the mempool to peers. This can be omitted for
@@ -258,6 +267,7 @@ impl NetworkArgs {
             max_transactions_seen_by_peer_history: self.max_seen_tx_history,
             propagation_mode: self.propagation_mode,
             ingress_policy: self.tx_ingress_policy,
+            propagated_tx_types: self.tx_propagation_types,
         }
     }
 
@@ -445,6 +455,7 @@ impl Default for NetworkArgs {
             net_if: None,
             tx_propagation_policy: TransactionPropagationKind::default(),
             tx_ingress_policy: TransactionIngressPolicy::default(),
+            tx_propagation_types: PropagatedTransactionTypes::default(),
             disable_tx_gossip: false,
             propagation_mode: TransactionPropagationMode::Sqrt,
             required_block_hashes: vec![],
@@ -758,6 +769,15 @@ mod tests {
         assert!(args.disable_tx_gossip);
     }
 
+    #[test]
+    fn parse_tx_propagation_types_args() {
+        let args =
+            CommandParser::<NetworkArgs>::parse_from(["reth", "--tx-propagation-types", "0,1,2,4"])
+                .args;
+        assert_eq!(args.tx_propagation_types, PropagatedTransactionTypes::only([0, 1, 2, 4]));
+        assert!(!args.transactions_manager_config().propagated_tx_types.propagates(3));
+    }
+
     #[test]
     fn network_args_default_sanity_test() {
         let default_args = NetworkArgs::default();
@@ -13,7 +13,7 @@ use clap::{
 use rand::Rng;
 use reth_cli_util::{parse_duration_from_secs_or_ms, parse_ether_value};
 use reth_rpc_eth_types::builder::config::PendingBlockKind;
-use reth_rpc_server_types::{constants, RethRpcModule, RpcModuleSelection};
+use reth_rpc_server_types::{constants, RethRpcModule, RpcMethodAllowList, RpcModuleSelection};
 use std::{
     collections::HashSet,
     ffi::OsStr,
@@ -63,6 +63,13 @@ pub struct RpcServerArgs {
     #[arg(long = "http.api", value_parser = RpcModuleSelectionValueParser::default())]
     pub http_api: Option<RpcModuleSelection>,
 
+    /// Comma separated list of RPC methods or namespaces callable over HTTP.
+    ///
+    /// Entries are either full method names (`debug_traceTransaction`) or namespaces (`eth`).
+    /// If not set, all methods of the configured modules are callable.
+    #[arg(long = "http.methods")]
+    pub http_methods: Option<RpcMethodAllowList>,
+
     /// Http Corsdomain to allow request from
     #[arg(long = "http.corsdomain")]
     pub http_corsdomain: Option<String>,
@@ -87,6 +94,13 @@ pub struct RpcServerArgs {
     #[arg(long = "ws.api", value_parser = RpcModuleSelectionValueParser::default())]
     pub ws_api: Option<RpcModuleSelection>,
 
+    /// Comma separated list of RPC methods or namespaces callable over WS.
+    ///
+    /// Entries are either full method names (`debug_traceTransaction`) or namespaces (`eth`).
+    /// If not set, all methods of the configured modules are callable.
+    #[arg(long = "ws.methods")]
+    pub ws_methods: Option<RpcMethodAllowList>,
+
     /// Disable the IPC-RPC server
     #[arg(long)]
     pub ipcdisable: bool,
@@ -101,6 +115,13 @@ pub struct RpcServerArgs {
     #[arg(long = "ipc.permissions")]
     pub ipc_socket_permissions: Option<String>,
 
+    /// Comma separated list of RPC methods or namespaces callable over IPC.
+    ///
+    /// Entries are either full method names (`debug_traceTransaction`) or namespaces (`eth`).
+    /// If not set, all methods of the configured modules are callable.
+    #[arg(long = "ipc.methods")]
+    pub ipc_methods: Option<RpcMethodAllowList>,
+
     /// Auth server address to listen on
     #[arg(long = "authrpc.addr", default_value_t = IpAddr::V4(Ipv4Addr::LOCALHOST))]
     pub auth_addr: IpAddr,
@@ -230,6 +251,15 @@ pub struct RpcServerArgs {
     #[arg(long = "rpc.proof-permits", alias = "rpc-proof-permits", value_name = "COUNT", default_value_t = constants::DEFAULT_PROOF_PERMITS)]
     pub rpc_proof_permits: usize,
 
+    /// Maximum number of retries of eth read requests that failed because the database was
+    /// busy (0 = no retries).
+    #[arg(long = "rpc.transient-retries", value_name = "COUNT", default_value_t = 0)]
+    pub rpc_transient_retries: u32,
+
+    /// Maximum size in bytes of the output of a single trace request. (0 = no limit)
+    #[arg(long = "rpc.max-trace-response-size", value_name = "BYTES", default_value_t = ZeroAsNoneU64(None))]
+    pub rpc_max_trace_response_size: ZeroAsNoneU64,
+
     /// Configures the pending block behavior for RPC responses.
     ///
     /// Options: full (include all transactions), empty (header only), none (disable pending
@@ -393,15 +423,18 @@ impl Default for RpcServerArgs {
             http_port: constants::DEFAULT_HTTP_RPC_PORT,
             http_disable_compression: false,
             http_api: None,
+            http_methods: None,
             http_corsdomain: None,
             ws: false,
             ws_addr: Ipv4Addr::LOCALHOST.into(),
             ws_port: constants::DEFAULT_WS_RPC_PORT,
             ws_allowed_origins: None,
             ws_api: None,
+            ws_methods: None,
             ipcdisable: false,
             ipcpath: constants::DEFAULT_IPC_ENDPOINT.to_string(),
             ipc_socket_permissions: None,
+            ipc_methods: None,
             auth_addr: Ipv4Addr::LOCALHOST.into(),
             auth_port: constants::DEFAULT_AUTH_PORT,
             auth_jwtsecret: None,
@@ -426,6 +459,8 @@ impl Default for RpcServerArgs {
             gas_price_oracle: GasPriceOracleArgs::default(),
             rpc_state_cache: RpcStateCacheArgs::default(),
             rpc_proof_permits: constants::DEFAULT_PROOF_PERMITS,
+            rpc_transient_retries: 0,
+            rpc_max_trace_response_size: ZeroAsNoneU64(None),
             rpc_forwarder: None,
             builder_disallow: Default::default(),
             rpc_send_raw_transaction_sync_timeout:
@@ -542,4 +577,29 @@ mod tests {
         let expected = 1_000_000_000_000_000_000u128;
         assert_eq!(args.rpc_tx_fee_cap, expected); // 1 ETH default cap
     }
+
+    #[test]
+    fn test_rpc_transient_retries() {
+        let args = CommandParser::<RpcServerArgs>::parse_from(["reth"]).args;
+        assert_eq!(args.rpc_transient_retries, 0);
+
+        let args =
+            CommandParser::<RpcServerArgs>::parse_from(["reth", "--rpc.transient-retries", "3"])
+                .args;
+        assert_eq!(args.rpc_transient_retries, 3);
+    }
+
+    #[test]
+    fn test_rpc_max_trace_response_size() {
+        let args = CommandParser::<RpcServerArgs>::parse_from(["reth"]).args;
+        assert_eq!(args.rpc_max_trace_response_size, ZeroAsNoneU64(None));
+
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--rpc.max-trace-response-size",
+            "1000000",
+        ])
+        .args;
+        assert_eq!(args.rpc_max_trace_response_size, ZeroAsNoneU64::new(1_000_000));
+    }
 }
@@ -1,7 +1,7 @@
 use clap::Args;
 use reth_rpc_server_types::constants::cache::{
-    DEFAULT_BLOCK_CACHE_MAX_LEN, DEFAULT_CONCURRENT_DB_REQUESTS, DEFAULT_HEADER_CACHE_MAX_LEN,
-    DEFAULT_RECEIPT_CACHE_MAX_LEN,
+    DEFAULT_BLOCK_CACHE_MAX_LEN, DEFAULT_BYTECODE_CACHE_MAX_BYTES, DEFAULT_CONCURRENT_DB_REQUESTS,
+    DEFAULT_HEADER_CACHE_MAX_LEN, DEFAULT_RECEIPT_CACHE_MAX_LEN,
 };
 
 /// Parameters to configure RPC state cache.
@@ -36,6 +36,13 @@ pub struct RpcStateCacheArgs {
         default_value_t = DEFAULT_CONCURRENT_DB_REQUESTS,
     )]
     pub max_concurrent_db_requests: usize,
+
+    /// Max total size of cached contract bytecode in bytes.
+    #[arg(
+        long = "rpc-cache.max-bytecode-bytes",
+        default_value_t = DEFAULT_BYTECODE_CACHE_MAX_BYTES,
+    )]
+    pub max_bytecode_bytes: usize,
 }
 
 impl RpcStateCacheArgs {
@@ -44,6 +51,7 @@ impl RpcStateCacheArgs {
         self.max_blocks = 0;
         self.max_receipts = 0;
         self.max_headers = 0;
+        self.max_bytecode_bytes = 0;
     }
 }
 
@@ -54,6 +62,7 @@ impl Default for RpcStateCacheArgs {
             max_receipts: DEFAULT_RECEIPT_CACHE_MAX_LEN,
             max_headers: DEFAULT_HEADER_CACHE_MAX_LEN,
             max_concurrent_db_requests: DEFAULT_CONCURRENT_DB_REQUESTS,
+            max_bytecode_bytes: DEFAULT_BYTECODE_CACHE_MAX_BYTES,
         }
     }
 }
@@ -0,0 +1,59 @@
+//! clap [Args](clap::Args) for pipeline stages configuration
+
+use clap::{builder::RangedU64ValueParser, Args};
+use reth_config::config::StageConfig;
+
+/// Parameters for pipeline stages configuration
+#[derive(Debug, Args, PartialEq, Eq, Default, Clone, Copy)]
+#[command(next_help_heading = "Stages")]
+pub struct StagesArgs {
+    /// Number of threads used to recover transaction senders in the senders stage.
+    ///
+    /// Defaults to the available parallelism.
+    #[arg(long = "stages.senders.concurrency", value_name = "THREADS", value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    pub senders_concurrency: Option<usize>,
+}
+
+impl StagesArgs {
+    /// Merges the CLI arguments with an existing [`StageConfig`], giving priority to CLI args.
+    pub fn merge_with_config(&self, mut config: StageConfig) -> StageConfig {
+        config.sender_recovery.concurrency =
+            self.senders_concurrency.or(config.sender_recovery.concurrency);
+        config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[command(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn parse_senders_concurrency() {
+        let args = CommandParser::<StagesArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, StagesArgs::default());
+
+        let args =
+            CommandParser::<StagesArgs>::parse_from(["reth", "--stages.senders.concurrency", "4"])
+                .args;
+        assert_eq!(args.senders_concurrency, Some(4));
+        assert_eq!(
+            args.merge_with_config(StageConfig::default()).sender_recovery.concurrency,
+            Some(4)
+        );
+
+        assert!(CommandParser::<StagesArgs>::try_parse_from([
+            "reth",
+            "--stages.senders.concurrency",
+            "0"
+        ])
+        .is_err());
+    }
+}
@@ -1,7 +1,10 @@
 //! Transaction pool arguments
 
 use crate::cli::config::RethTransactionPoolConfig;
-use alloy_eips::eip1559::{ETHEREUM_BLOCK_GAS_LIMIT_30M, MIN_PROTOCOL_BASE_FEE};
+use alloy_eips::{
+    eip1559::{ETHEREUM_BLOCK_GAS_LIMIT_30M, MIN_PROTOCOL_BASE_FEE},
+    eip4844::env_settings::EnvKzgSettings,
+};
 use alloy_primitives::Address;
 use clap::Args;
 use reth_cli_util::parse_duration_from_secs_or_ms;
@@ -12,10 +15,11 @@ use reth_transaction_pool::{
     validate::DEFAULT_MAX_TX_INPUT_BYTES,
     LocalTransactionConfig, PoolConfig, PriceBumpConfig, SubPoolLimit, DEFAULT_PRICE_BUMP,
     DEFAULT_TXPOOL_ADDITIONAL_VALIDATION_TASKS, MAX_NEW_PENDING_TXS_NOTIFICATIONS,
-    REPLACE_BLOB_PRICE_BUMP, TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
-    TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT, TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
+    REPLACE_BLOB_PRICE_BUMP, TXPOOL_BLOBPOOL_MAX_SIZE_MB_DEFAULT,
+    TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER, TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT,
+    TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
 };
-use std::time::Duration;
+use std::{path::PathBuf, time::Duration};
 
 /// Parameters for debugging purposes
 #[derive(Debug, Clone, Args, PartialEq, Eq)]
@@ -43,10 +47,10 @@ pub struct TxPoolArgs {
     pub queued_max_size: usize,
 
     /// Max number of transaction in the blobpool
-    #[arg(long = "txpool.blobpool-max-count", alias = "txpool.blobpool_max_count", default_value_t = TXPOOL_SUBPOOL_MAX_TXS_DEFAULT)]
+    #[arg(long = "txpool.blobpool-max-count", aliases = ["txpool.blobpool_max_count", "blobpool.max-count", "blobpool.max_count"], default_value_t = TXPOOL_SUBPOOL_MAX_TXS_DEFAULT)]
     pub blobpool_max_count: usize,
-    /// Max size of the blobpool in megabytes.
-    #[arg(long = "txpool.blobpool-max-size", alias = "txpool.blobpool_max_size", default_value_t = TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT)]
+    /// Max size of the blobpool in megabytes, including the blob sidecars kept in the blob store.
+    #[arg(long = "txpool.blobpool-max-size", aliases = ["txpool.blobpool_max_size", "blobpool.max-size", "blobpool.max_size"], default_value_t = TXPOOL_BLOBPOOL_MAX_SIZE_MB_DEFAULT)]
     pub blobpool_max_size: usize,
 
     /// Max number of entries for the in memory cache of the blob store.
@@ -54,7 +58,7 @@ pub struct TxPoolArgs {
     pub blob_cache_size: Option<u32>,
 
     /// Disable EIP-4844 blob transaction support
-    #[arg(long = "txpool.disable-blobs-support", alias = "txpool.disable_blobs_support", default_value_t = false, conflicts_with_all = ["blobpool_max_count", "blobpool_max_size", "blob_cache_size", "blob_transaction_price_bump"])]
+    #[arg(long = "txpool.disable-blobs-support", alias = "txpool.disable_blobs_support", default_value_t = false, conflicts_with_all = ["blobpool_max_count", "blobpool_max_size", "blob_cache_size", "blob_transaction_price_bump", "kzg_trusted_setup"])]
     pub disable_blobs_support: bool,
 
     /// Max number of executable transaction slots guaranteed per account
@@ -83,6 +87,15 @@ pub struct TxPoolArgs {
     #[arg(long = "txpool.max-tx-gas")]
     pub max_tx_gas_limit: Option<u64>,
 
+    /// Maximum number of access list entries (addresses and storage keys) for individual
+    /// transactions. Transactions exceeding this limit will be rejected by the transaction pool
+    #[arg(long = "txpool.max-access-list-entries")]
+    pub max_access_list_entries: Option<usize>,
+
+    /// Reject legacy transactions without EIP-155 replay protection
+    #[arg(long = "txpool.reject-unprotected")]
+    pub reject_unprotected_transactions: bool,
+
     /// Price bump percentage to replace an already existing blob transaction
     #[arg(long = "blobpool.pricebump", default_value_t = REPLACE_BLOB_PRICE_BUMP)]
     pub blob_transaction_price_bump: u128,
@@ -125,6 +138,11 @@ pub struct TxPoolArgs {
     #[arg(long = "txpool.lifetime", value_parser = parse_duration_from_secs_or_ms, default_value = "10800", value_name = "DURATION")]
     pub max_queued_lifetime: Duration,
 
+    /// Maximum amount of time any non-local transaction is kept in the pool, regardless of its
+    /// subpool. Disabled by default.
+    #[arg(long = "txpool.tx-lifetime", alias = "txpool.tx_lifetime", value_parser = parse_tx_lifetime, value_name = "DURATION")]
+    pub max_tx_lifetime: Option<Duration>,
+
     /// Path to store the local transaction backup at, to survive node restarts.
     #[arg(long = "txpool.transactions-backup", alias = "txpool.journal", value_name = "PATH")]
     pub transactions_backup_path: Option<std::path::PathBuf>,
@@ -140,6 +158,12 @@ pub struct TxPoolArgs {
     /// Max batch size for transaction pool insertions
     #[arg(long = "txpool.max-batch-size", default_value_t = 1)]
     pub max_batch_size: usize,
+
+    /// Path to the KZG trusted setup file used to verify blob sidecars.
+    ///
+    /// If not set, the embedded mainnet trusted setup is used.
+    #[arg(long = "txpool.kzg-trusted-setup", value_name = "PATH")]
+    pub kzg_trusted_setup: Option<PathBuf>,
 }
 
 impl TxPoolArgs {
@@ -158,6 +182,17 @@ impl TxPoolArgs {
         self.minimal_protocol_basefee = protocol_base_fee;
         self
     }
+
+    /// Returns the [`EnvKzgSettings`] used to verify blob sidecars.
+    ///
+    /// Loads the configured trusted setup file, or falls back to the embedded mainnet trusted
+    /// setup if none is configured.
+    pub fn kzg_settings(&self) -> eyre::Result<EnvKzgSettings> {
+        let Some(path) = &self.kzg_trusted_setup else { return Ok(EnvKzgSettings::Default) };
+        EnvKzgSettings::load_from_trusted_setup_file(path).map_err(|err| {
+            eyre::eyre!("failed to load KZG trusted setup from {}: {err}", path.display())
+        })
+    }
 }
 
 impl Default for TxPoolArgs {
@@ -170,7 +205,7 @@ impl Default for TxPoolArgs {
             queued_max_count: TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
             queued_max_size: TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT,
             blobpool_max_count: TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
-            blobpool_max_size: TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT,
+            blobpool_max_size: TXPOOL_BLOBPOOL_MAX_SIZE_MB_DEFAULT,
             blob_cache_size: None,
             disable_blobs_support: false,
             max_account_slots: TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
@@ -179,6 +214,8 @@ impl Default for TxPoolArgs {
             minimum_priority_fee: None,
             enforced_gas_limit: ETHEREUM_BLOCK_GAS_LIMIT_30M,
             max_tx_gas_limit: None,
+            max_access_list_entries: None,
+            reject_unprotected_transactions: false,
             blob_transaction_price_bump: REPLACE_BLOB_PRICE_BUMP,
             max_tx_input_bytes: DEFAULT_MAX_TX_INPUT_BYTES,
             max_cached_entries: DEFAULT_MAX_CACHED_BLOBS,
@@ -190,9 +227,11 @@ impl Default for TxPoolArgs {
             new_tx_listener_buffer_size: NEW_TX_LISTENER_BUFFER_SIZE,
             max_new_pending_txs_notifications: MAX_NEW_PENDING_TXS_NOTIFICATIONS,
             max_queued_lifetime: MAX_QUEUED_TRANSACTION_LIFETIME,
+            max_tx_lifetime: None,
             transactions_backup_path: None,
             disable_transactions_backup: false,
             max_batch_size: 1,
+            kzg_trusted_setup: None,
         }
     }
 }
@@ -205,6 +244,7 @@ impl RethTransactionPoolConfig for TxPoolArgs {
                 no_exemptions: self.no_locals,
                 local_addresses: self.locals.clone().into_iter().collect(),
                 propagate_local_transactions: !self.no_local_transactions_propagation,
+                balance_reserves: Default::default(),
             },
             pending_limit: SubPoolLimit {
                 max_txs: self.pending_max_count,
@@ -235,6 +275,7 @@ impl RethTransactionPoolConfig for TxPoolArgs {
             new_tx_listener_buffer_size: self.new_tx_listener_buffer_size,
             max_new_pending_txs_notifications: self.max_new_pending_txs_notifications,
             max_queued_lifetime: self.max_queued_lifetime,
+            max_tx_lifetime: self.max_tx_lifetime,
             ..Default::default()
         }
     }
@@ -245,6 +286,16 @@ impl RethTransactionPoolConfig for TxPoolArgs {
     }
 }
 
+/// Parses the `--txpool.tx-lifetime` duration, which must not be zero because the eviction
+/// interval is derived from it.
+fn parse_tx_lifetime(arg: &str) -> eyre::Result<Duration> {
+    let lifetime = parse_duration_from_secs_or_ms(arg)?;
+    if lifetime.is_zero() {
+        eyre::bail!("transaction lifetime must be greater than zero")
+    }
+    Ok(lifetime)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -275,6 +326,86 @@ mod tests {
         assert_eq!(args.locals, vec![Address::ZERO]);
     }
 
+    #[test]
+    fn txpool_parse_blobpool_limit() {
+        let args = CommandParser::<TxPoolArgs>::parse_from([
+            "reth",
+            "--blobpool.max_count",
+            "100",
+            "--blobpool.max_size",
+            "512",
+        ])
+        .args;
+        assert_eq!(
+            args.pool_config().blob_limit,
+            SubPoolLimit { max_txs: 100, max_size: 512 * 1024 * 1024 }
+        );
+        // non blob subpools keep their default limits
+        assert_eq!(args.pool_config().pending_limit, SubPoolLimit::default());
+    }
+
+    #[test]
+    fn txpool_kzg_trusted_setup() {
+        let args = CommandParser::<TxPoolArgs>::parse_from(["reth"]).args;
+        assert_eq!(args.kzg_settings().unwrap(), EnvKzgSettings::Default);
+
+        let args = CommandParser::<TxPoolArgs>::parse_from([
+            "reth",
+            "--txpool.kzg-trusted-setup",
+            "/path/to/trusted_setup.txt",
+        ])
+        .args;
+        assert_eq!(args.kzg_trusted_setup, Some(PathBuf::from("/path/to/trusted_setup.txt")));
+    }
+
+    #[test]
+    fn txpool_load_kzg_trusted_setup() {
+        // compressed generators of G1, its negation and G2
+        const G1: &str = "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+        const NEG_G1: &str = "b7f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+        const G2: &str = "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+
+        // a minimal setup that passes the loader's checks: valid points of the expected counts,
+        // with the Lagrange points alternating in sign so they aren't taken for monomial form
+        let mut setup = String::from("4096\n65\n");
+        for i in 0..4096 {
+            setup.push_str(if i % 2 == 0 { G1 } else { NEG_G1 });
+            setup.push('\n');
+        }
+        for _ in 0..65 {
+            setup.push_str(G2);
+            setup.push('\n');
+        }
+        for _ in 0..4096 {
+            setup.push_str(G1);
+            setup.push('\n');
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("trusted_setup.txt");
+        std::fs::write(&path, setup).unwrap();
+
+        let args = TxPoolArgs { kzg_trusted_setup: Some(path), ..Default::default() };
+        assert!(matches!(args.kzg_settings().unwrap(), EnvKzgSettings::Custom(_)));
+    }
+
+    #[test]
+    fn txpool_malformed_kzg_trusted_setup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("trusted_setup.txt");
+        std::fs::write(&path, "4096\n65\nnot a point\n").unwrap();
+
+        let args = TxPoolArgs { kzg_trusted_setup: Some(path), ..Default::default() };
+        let err = args.kzg_settings().unwrap_err();
+        assert!(err.to_string().contains("failed to load KZG trusted setup"));
+
+        let args = TxPoolArgs {
+            kzg_trusted_setup: Some(dir.path().join("missing.txt")),
+            ..Default::default()
+        };
+        assert!(args.kzg_settings().is_err());
+    }
+
     #[test]
     fn txpool_parse_max_tx_lifetime() {
         // Test with a custom duration
@@ -287,6 +418,23 @@ mod tests {
         assert_eq!(args.max_queued_lifetime, Duration::from_secs(3 * 60 * 60)); // Default is 3h
     }
 
+    #[test]
+    fn txpool_parse_tx_lifetime() {
+        let args = CommandParser::<TxPoolArgs>::parse_from(["reth"]).args;
+        assert_eq!(args.max_tx_lifetime, None);
+
+        let args =
+            CommandParser::<TxPoolArgs>::parse_from(["reth", "--txpool.tx_lifetime", "600"]).args;
+        assert_eq!(args.max_tx_lifetime, Some(Duration::from_secs(600)));
+        assert_eq!(args.pool_config().max_tx_lifetime, Some(Duration::from_secs(600)));
+
+        for zero in ["0", "0s", "0ms"] {
+            let result =
+                CommandParser::<TxPoolArgs>::try_parse_from(["reth", "--txpool.tx-lifetime", zero]);
+            assert!(result.is_err(), "expected an error for {zero}");
+        }
+    }
+
     #[test]
     fn txpool_parse_max_tx_lifetime_invalid() {
         let result =
@@ -292,6 +292,10 @@ impl EthChainSpec for OpChainSpec {
         self.inner.final_paris_total_difficulty()
     }
 
+    fn max_code_size(&self) -> Option<usize> {
+        self.inner.max_code_size()
+    }
+
     fn next_block_base_fee(&self, parent: &Header, target_timestamp: u64) -> Option<u64> {
         if self.is_jovian_active_at_timestamp(parent.timestamp()) {
             compute_jovian_base_fee(self, parent, target_timestamp).ok()
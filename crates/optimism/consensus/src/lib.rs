@@ -21,8 +21,8 @@ use reth_chainspec::EthChainSpec;
 use reth_consensus::{Consensus, ConsensusError, FullConsensus, HeaderValidator};
 use reth_consensus_common::validation::{
     validate_against_parent_eip1559_base_fee, validate_against_parent_hash_number,
-    validate_against_parent_timestamp, validate_cancun_gas, validate_header_base_fee,
-    validate_header_extra_data, validate_header_gas,
+    validate_against_parent_timestamp, validate_block_hash, validate_cancun_gas,
+    validate_header_base_fee, validate_header_extra_data, validate_header_gas,
 };
 use reth_execution_types::BlockExecutionResult;
 use reth_optimism_forks::OpHardforks;
@@ -100,6 +100,8 @@ where
     }
 
     fn validate_block_pre_execution(&self, block: &SealedBlock<B>) -> Result<(), ConsensusError> {
+        validate_block_hash(block)?;
+
         // Check ommers hash
         let ommers_hash = block.body().calculate_ommers_root();
         if Some(block.ommers_hash()) != ommers_hash {
@@ -241,7 +243,7 @@ mod tests {
 
     use alloy_consensus::{BlockBody, Eip658Value, Header, Receipt, TxEip7702, TxReceipt};
     use alloy_eips::{eip4895::Withdrawals, eip7685::Requests};
-    use alloy_primitives::{Address, Bytes, Signature, U256};
+    use alloy_primitives::{Address, Bytes, Signature, B256, U256};
     use op_alloy_consensus::{
         encode_holocene_extra_data, encode_jovian_extra_data, OpTypedTransaction,
     };
@@ -310,6 +312,42 @@ mod tests {
         assert!(pre_execution.is_ok());
     }
 
+    #[test]
+    fn test_block_hash_validation() {
+        let chain_spec = OpChainSpecBuilder::default()
+            .isthmus_activated()
+            .genesis(OP_MAINNET.genesis.clone())
+            .chain(OP_MAINNET.chain)
+            .build();
+        let beacon_consensus = OpBeaconConsensus::new(Arc::new(chain_spec));
+
+        let header = Header {
+            base_fee_per_gas: Some(1337),
+            withdrawals_root: Some(proofs::calculate_withdrawals_root(&[])),
+            blob_gas_used: Some(0),
+            timestamp: u64::MAX,
+            ..Default::default()
+        };
+        let body = BlockBody::<OpTransactionSigned> {
+            withdrawals: Some(Withdrawals::default()),
+            ..Default::default()
+        };
+        let block = alloy_consensus::Block { header, body };
+
+        let sealed = SealedBlock::seal_slow(block.clone());
+        assert!(beacon_consensus.validate_block_pre_execution(&sealed).is_ok());
+
+        // a block claiming a hash that isn't the hash of its header
+        let tampered_hash = B256::with_last_byte(1);
+        let tampered = SealedBlock::new_unchecked(block, tampered_hash);
+        assert_eq!(
+            beacon_consensus.validate_block_pre_execution(&tampered),
+            Err(ConsensusError::BlockHashMismatch(
+                GotExpected { got: tampered_hash, expected: sealed.hash() }.into()
+            ))
+        );
+    }
+
     #[test]
     fn test_block_blob_gas_used_validation_failure_isthmus() {
         let chain_spec = OpChainSpecBuilder::default()
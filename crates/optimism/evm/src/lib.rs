@@ -154,7 +154,10 @@ where
     }
 
     fn evm_env(&self, header: &Header) -> Result<EvmEnv<OpSpecId>, Self::Error> {
-        Ok(EvmEnv::for_op_block(header, self.chain_spec(), self.chain_spec().chain().id()))
+        let mut evm_env =
+            EvmEnv::for_op_block(header, self.chain_spec(), self.chain_spec().chain().id());
+        evm_env.cfg_env.limit_contract_code_size = self.chain_spec().max_code_size();
+        Ok(evm_env)
     }
 
     fn next_evm_env(
@@ -162,7 +165,7 @@ where
         parent: &Header,
         attributes: &Self::NextBlockEnvCtx,
     ) -> Result<EvmEnv<OpSpecId>, Self::Error> {
-        Ok(EvmEnv::for_op_next_block(
+        let mut evm_env = EvmEnv::for_op_next_block(
             parent,
             NextEvmEnvAttributes {
                 timestamp: attributes.timestamp,
@@ -173,7 +176,9 @@ where
             self.chain_spec().next_block_base_fee(parent, attributes.timestamp).unwrap_or_default(),
             self.chain_spec(),
             self.chain_spec().chain().id(),
-        ))
+        );
+        evm_env.cfg_env.limit_contract_code_size = self.chain_spec().max_code_size();
+        Ok(evm_env)
     }
 
     fn context_for_block(
@@ -223,7 +228,9 @@ where
 
         let spec = revm_spec_by_timestamp_after_bedrock(self.chain_spec(), timestamp);
 
-        let cfg_env = CfgEnv::new().with_chain_id(self.chain_spec().chain().id()).with_spec(spec);
+        let mut cfg_env =
+            CfgEnv::new().with_chain_id(self.chain_spec().chain().id()).with_spec(spec);
+        cfg_env.limit_contract_code_size = self.chain_spec().max_code_size();
 
         let blob_excess_gas_and_price = spec
             .into_eth_spec()
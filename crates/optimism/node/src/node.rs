@@ -988,6 +988,10 @@ where
             .kzg_settings(ctx.kzg_settings()?)
             .set_tx_fee_cap(ctx.config().rpc.rpc_tx_fee_cap)
             .with_max_tx_gas_limit(ctx.config().txpool.max_tx_gas_limit)
+            .with_max_access_list_entries(ctx.config().txpool.max_access_list_entries)
+            .with_reject_unprotected_transactions(
+                ctx.config().txpool.reject_unprotected_transactions,
+            )
             .with_minimum_priority_fee(ctx.config().txpool.minimum_priority_fee)
             .with_additional_tasks(
                 pool_config_overrides
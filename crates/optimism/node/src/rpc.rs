@@ -146,6 +146,7 @@ where
             EngineCapabilities::new(OP_ENGINE_CAPABILITIES.iter().copied()),
             engine_validator,
             ctx.config.engine.accept_execution_requests_hash,
+            ctx.config.engine.max_concurrent_new_payloads,
         );
 
         Ok(OpEngineApi::new(inner))
@@ -158,6 +158,10 @@ impl SignedTransaction for OpTransactionSigned {
     fn recalculate_hash(&self) -> B256 {
         keccak256(self.encoded_2718())
     }
+
+    fn is_deposit_transaction(&self) -> bool {
+        self.is_deposit()
+    }
 }
 
 macro_rules! impl_from_signed {
@@ -38,6 +38,7 @@ use reth_rpc_eth_api::{
     RpcNodeCoreExt, RpcTypes,
 };
 use reth_rpc_eth_types::{EthStateCache, FeeHistoryCache, GasPriceOracle, PendingBlock};
+use reth_rpc_server_types::result::RetryConfig;
 use reth_storage_api::{BlockReaderIdExt, ProviderHeader};
 use reth_tasks::{
     pool::{BlockingTaskGuard, BlockingTaskPool},
@@ -251,6 +252,11 @@ where
     fn starting_block(&self) -> U256 {
         self.inner.eth_api.starting_block()
     }
+
+    #[inline]
+    fn transient_retry(&self) -> RetryConfig {
+        self.inner.eth_api.transient_retry()
+    }
 }
 
 impl<N, Rpc> SpawnBlocking for OpEthApi<N, Rpc>
@@ -334,6 +340,10 @@ where
     OpEthApiError: FromEvmError<N::Evm>,
     Rpc: RpcConvert<Primitives = N::Primitives, Error = OpEthApiError, Evm = N::Evm>,
 {
+    #[inline]
+    fn max_trace_response_size(&self) -> Option<usize> {
+        self.inner.eth_api.max_trace_response_size()
+    }
 }
 
 impl<N: RpcNodeCore, Rpc: RpcConvert> fmt::Debug for OpEthApi<N, Rpc> {
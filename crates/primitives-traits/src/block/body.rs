@@ -1,8 +1,9 @@
 //! Block body abstraction.
 
 use crate::{
-    transaction::signed::RecoveryError, BlockHeader, FullSignedTx, InMemorySize, MaybeSerde,
-    MaybeSerdeBincodeCompat, SignedTransaction,
+    proofs::{calculate_transaction_proof, TransactionIndexOutOfBounds},
+    transaction::signed::RecoveryError,
+    BlockHeader, FullSignedTx, InMemorySize, MaybeSerde, MaybeSerdeBincodeCompat, SignedTransaction,
 };
 use alloc::{fmt, vec::Vec};
 use alloy_consensus::{
@@ -107,9 +108,23 @@ pub trait BlockBody:
         alloy_consensus::proofs::calculate_transaction_root(self.transactions())
     }
 
+    /// Calculates the merkle proof that the transaction at `index` is included in the block's
+    /// transactions trie, see [`calculate_transaction_proof`].
+    fn transaction_proof(&self, index: usize) -> Result<Vec<Bytes>, TransactionIndexOutOfBounds> {
+        calculate_transaction_proof(self.transactions(), index)
+    }
+
     /// Returns block withdrawals if any.
     fn withdrawals(&self) -> Option<&Withdrawals>;
 
+    /// Returns `true` if the block body has a withdrawals section, even if it is empty.
+    ///
+    /// Pre-Shanghai blocks don't have a withdrawals section at all, which must be distinguished
+    /// from a post-Shanghai block without any withdrawals to re-encode the block faithfully.
+    fn has_withdrawals_section(&self) -> bool {
+        self.withdrawals().is_some()
+    }
+
     /// Calculate the withdrawals root for the block body.
     ///
     /// Returns `Some(root)` if withdrawals are present, otherwise `None`.
@@ -3,12 +3,12 @@
 use crate::{
     block::{error::SealedBlockRecoveryError, SealedBlock},
     transaction::signed::{RecoveryError, SignedTransaction},
-    Block, BlockBody, InMemorySize, SealedHeader,
+    Block, BlockBody, GotExpected, InMemorySize, SealedHeader,
 };
 use alloc::vec::Vec;
 use alloy_consensus::{
     transaction::{Recovered, TransactionMeta},
-    BlockHeader,
+    BlockHeader, Transaction, TxReceipt,
 };
 use alloy_eips::{eip1898::BlockWithParent, BlockNumHash, Encodable2718};
 use alloy_primitives::{
@@ -366,6 +366,35 @@ impl<B: Block> RecoveredBlock<B> {
     pub fn into_transactions(self) -> Vec<<B::Body as BlockBody>::Transaction> {
         self.block.split().0.into_body().into_transactions()
     }
+
+    /// Returns the sum of the priority fees paid by the transactions of this block, which is each
+    /// transaction's effective tip per gas at the given base fee multiplied by the gas it used.
+    ///
+    /// The gas used by a transaction is derived from the cumulative gas used of the given
+    /// receipts, which are expected to be the receipts of this block in transaction order.
+    /// Transactions that don't pay a tip, such as OP deposit transactions, contribute zero.
+    ///
+    /// Returns `Err(error)` with the number of receipts and transactions if they don't match.
+    pub fn total_priority_fees<R: TxReceipt>(
+        &self,
+        receipts: &[R],
+        base_fee: u64,
+    ) -> Result<U256, GotExpected<usize>> {
+        let transaction_count = self.body().transaction_count();
+        if receipts.len() != transaction_count {
+            return Err(GotExpected { got: receipts.len(), expected: transaction_count })
+        }
+
+        let mut cumulative_gas_used = 0;
+        let fees =
+            self.body().transactions_iter().zip(receipts).fold(U256::ZERO, |fees, (tx, receipt)| {
+                let gas_used = receipt.cumulative_gas_used().saturating_sub(cumulative_gas_used);
+                cumulative_gas_used = receipt.cumulative_gas_used();
+                let tip = tx.effective_tip_per_gas(base_fee).unwrap_or_default();
+                fees.saturating_add(U256::from(tip).saturating_mul(U256::from(gas_used)))
+            });
+        Ok(fees)
+    }
 }
 
 impl<B: Block> BlockHeader for RecoveredBlock<B> {
@@ -998,4 +1027,126 @@ mod tests {
         assert_eq!(recovered_block.senders()[0], sender);
         assert_eq!(recovered_block.body().transactions().count(), 1);
     }
+
+    #[cfg(feature = "op")]
+    #[test]
+    fn total_priority_fees_of_mixed_transactions() {
+        use alloy_consensus::{Receipt, Signed, TxEip1559};
+        use op_alloy_consensus::{OpTxEnvelope, TxDeposit};
+
+        let base_fee = 10;
+        let signature = Signature::new(U256::from(1), U256::from(2), false);
+        let transactions = vec![
+            // pays the whole gas price above the base fee as tip
+            OpTxEnvelope::Legacy(Signed::new_unchecked(
+                TxLegacy { gas_price: 15, gas_limit: 21_000, ..Default::default() },
+                signature,
+                B256::ZERO,
+            )),
+            OpTxEnvelope::Deposit(Sealed::new(TxDeposit {
+                gas_limit: 50_000,
+                ..Default::default()
+            })),
+            // tip capped by the max fee
+            OpTxEnvelope::Eip1559(Signed::new_unchecked(
+                TxEip1559 {
+                    max_fee_per_gas: 12,
+                    max_priority_fee_per_gas: 5,
+                    gas_limit: 60_000,
+                    ..Default::default()
+                },
+                signature,
+                B256::ZERO,
+            )),
+            // tip not capped
+            OpTxEnvelope::Eip1559(Signed::new_unchecked(
+                TxEip1559 {
+                    max_fee_per_gas: 100,
+                    max_priority_fee_per_gas: 3,
+                    gas_limit: 60_000,
+                    ..Default::default()
+                },
+                signature,
+                B256::ZERO,
+            )),
+        ];
+        let senders = vec![Address::ZERO; transactions.len()];
+        let block = alloy_consensus::Block::<OpTxEnvelope>::new(
+            Header::default(),
+            alloy_consensus::BlockBody { transactions, ommers: vec![], withdrawals: None },
+        );
+        let block = RecoveredBlock::new_unhashed(block, senders);
+
+        let receipts = [21_000u64, 61_000, 101_000, 151_000]
+            .map(|cumulative_gas_used| Receipt::<alloy_primitives::Log> {
+                status: true.into(),
+                cumulative_gas_used,
+                logs: vec![],
+            })
+            .to_vec();
+
+        assert_eq!(
+            block.total_priority_fees(&receipts, base_fee),
+            Ok(U256::from(21_000 * 5 + 40_000 * 2 + 50_000 * 3))
+        );
+        assert_eq!(
+            block.total_priority_fees::<Receipt>(&[], base_fee),
+            Err(GotExpected { got: 0, expected: 4 })
+        );
+    }
+
+    #[test]
+    fn total_priority_fees_of_ethereum_transactions() {
+        use alloy_consensus::{Receipt, Signed, TxEip1559, TxEnvelope};
+
+        let base_fee = 1_000;
+        let signature = Signature::new(U256::from(1), U256::from(2), false);
+        let transactions = vec![
+            TxEnvelope::Legacy(Signed::new_unchecked(
+                TxLegacy { gas_price: 1_500, gas_limit: 21_000, ..Default::default() },
+                signature,
+                B256::ZERO,
+            )),
+            TxEnvelope::Eip1559(Signed::new_unchecked(
+                TxEip1559 {
+                    max_fee_per_gas: 2_000,
+                    max_priority_fee_per_gas: 100,
+                    gas_limit: 100_000,
+                    ..Default::default()
+                },
+                signature,
+                B256::ZERO,
+            )),
+        ];
+        let senders = vec![Address::ZERO; transactions.len()];
+        let block = alloy_consensus::Block::<TxEnvelope>::new(
+            Header::default(),
+            alloy_consensus::BlockBody { transactions, ommers: vec![], withdrawals: None },
+        );
+        let block = RecoveredBlock::new_unhashed(block, senders);
+
+        let receipts = |cumulative_gas_used: [u64; 2]| {
+            cumulative_gas_used
+                .map(|cumulative_gas_used| Receipt::<alloy_primitives::Log> {
+                    status: true.into(),
+                    cumulative_gas_used,
+                    logs: vec![],
+                })
+                .to_vec()
+        };
+
+        assert_eq!(
+            block.total_priority_fees(&receipts([21_000, 71_000]), base_fee),
+            Ok(U256::from(21_000 * 500 + 50_000 * 100))
+        );
+        // a decreasing cumulative gas used doesn't underflow
+        assert_eq!(
+            block.total_priority_fees(&receipts([21_000, 20_000]), base_fee),
+            Ok(U256::from(21_000 * 500))
+        );
+        assert_eq!(
+            block.total_priority_fees(&receipts([21_000, 71_000])[..1], base_fee),
+            Err(GotExpected { got: 1, expected: 2 })
+        );
+    }
 }
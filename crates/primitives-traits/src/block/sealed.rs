@@ -474,6 +474,7 @@ pub(super) mod serde_bincode_compat {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_consensus::Transaction as _;
     use alloy_rlp::{Decodable, Encodable};
 
     #[test]
@@ -550,4 +551,56 @@ mod tests {
         assert_eq!(sealed_block.header().state_root, decoded.header().state_root);
         assert_eq!(sealed_block.body().transactions.len(), decoded.body().transactions.len());
     }
+
+    #[test]
+    fn test_blob_versioned_hashes_iter() {
+        let blob_tx = |hashes: Vec<B256>| {
+            let tx = alloy_consensus::TxEip4844 {
+                chain_id: 1,
+                blob_versioned_hashes: hashes,
+                max_fee_per_blob_gas: 1,
+                ..Default::default()
+            };
+            alloy_consensus::TxEnvelope::Eip4844(alloy_consensus::Signed::new_unchecked(
+                tx.into(),
+                alloy_primitives::Signature::test_signature(),
+                B256::ZERO,
+            ))
+        };
+        let legacy_tx =
+            alloy_consensus::TxEnvelope::Legacy(alloy_consensus::Signed::new_unchecked(
+                alloy_consensus::TxLegacy::default(),
+                alloy_primitives::Signature::test_signature(),
+                B256::ZERO,
+            ));
+
+        let body = alloy_consensus::BlockBody {
+            transactions: vec![
+                blob_tx(vec![B256::with_last_byte(1), B256::with_last_byte(2)]),
+                legacy_tx,
+                blob_tx(vec![]),
+                blob_tx(vec![B256::with_last_byte(3)]),
+            ],
+            ommers: vec![],
+            withdrawals: None,
+        };
+        let block = SealedBlock::seal_slow(alloy_consensus::Block::new(
+            alloy_consensus::Header::default(),
+            body,
+        ));
+
+        let mut expected = Vec::new();
+        for tx in &block.body().transactions {
+            if let Some(hashes) = tx.blob_versioned_hashes() {
+                expected.extend_from_slice(hashes);
+            }
+        }
+
+        let hashes = block.blob_versioned_hashes_iter().copied().collect::<Vec<_>>();
+        assert_eq!(hashes, expected);
+        assert_eq!(
+            hashes,
+            vec![B256::with_last_byte(1), B256::with_last_byte(2), B256::with_last_byte(3)]
+        );
+    }
 }
@@ -173,6 +173,9 @@ where
     B: SignedTransaction + IsTyped2718 + TxHashRef,
     T: SignedTransaction + TxHashRef,
 {
+    fn is_deposit_transaction(&self) -> bool {
+        delegate!(self => tx.is_deposit_transaction())
+    }
 }
 
 impl<B, T> Typed2718 for Extended<B, T>
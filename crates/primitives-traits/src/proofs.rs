@@ -1,4 +1,9 @@
 //! Helper function for calculating Merkle proofs and hashes.
+use alloc::vec::Vec;
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::Bytes;
+use alloy_trie::{proof::ProofRetainer, root::adjust_index_for_rlp, HashBuilder, Nibbles};
+
 pub use alloy_trie::root::ordered_trie_root_with_encoder;
 
 pub use alloy_consensus::proofs::calculate_receipt_root;
@@ -17,15 +22,96 @@ pub use alloy_consensus::proofs::calculate_withdrawals_root;
 #[doc(inline)]
 pub use alloy_consensus::proofs::calculate_ommers_root;
 
+/// The requested transaction index is out of bounds for the block's transactions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("transaction index {index} is out of bounds for {len} transactions")]
+pub struct TransactionIndexOutOfBounds {
+    /// The requested transaction index.
+    pub index: usize,
+    /// The number of transactions in the block.
+    pub len: usize,
+}
+
+/// Calculates the merkle proof that the transaction at `index` is included in the transactions
+/// trie, verifiable against the root computed by [`calculate_transaction_root`].
+///
+/// The key of the transaction in the trie is `rlp(index)` and the value is its EIP-2718 encoding.
+/// The returned trie nodes are ordered from the root node to the leaf of the transaction.
+pub fn calculate_transaction_proof<T: Encodable2718>(
+    transactions: &[T],
+    index: usize,
+) -> Result<Vec<Bytes>, TransactionIndexOutOfBounds> {
+    let len = transactions.len();
+    if index >= len {
+        return Err(TransactionIndexOutOfBounds { index, len })
+    }
+
+    let target = Nibbles::unpack(alloy_rlp::encode_fixed_size(&index));
+    let mut hb =
+        HashBuilder::default().with_proof_retainer(ProofRetainer::new(alloc::vec![target]));
+
+    let mut value_buffer = Vec::new();
+    for i in 0..len {
+        let index = adjust_index_for_rlp(i, len);
+
+        value_buffer.clear();
+        transactions[index].encode_2718(&mut value_buffer);
+
+        hb.add_leaf(Nibbles::unpack(alloy_rlp::encode_fixed_size(&index)), &value_buffer);
+    }
+    hb.root();
+
+    Ok(hb
+        .take_proof_nodes()
+        .matching_nodes_sorted(&target)
+        .into_iter()
+        .map(|(_, node)| node)
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
-    use alloy_consensus::EMPTY_ROOT_HASH;
+    use super::*;
+    use alloy_consensus::{Signed, TxLegacy, EMPTY_ROOT_HASH};
     use alloy_genesis::GenesisAccount;
-    use alloy_primitives::{b256, hex_literal::hex, Address, B256, U256};
-    use alloy_trie::root::{state_root_ref_unhashed, state_root_unhashed};
+    use alloy_primitives::{b256, hex_literal::hex, Address, Signature, B256, U256};
+    use alloy_trie::{
+        proof::verify_proof,
+        root::{state_root_ref_unhashed, state_root_unhashed},
+    };
     use reth_chainspec::{HOLESKY, MAINNET, SEPOLIA};
     use std::collections::HashMap;
 
+    #[test]
+    fn transaction_proof_verifies_against_root() {
+        let transactions = (0..200u64)
+            .map(|nonce| {
+                let tx = TxLegacy { nonce, gas_limit: 21_000, ..Default::default() };
+                Signed::new_unhashed(tx, Signature::test_signature())
+            })
+            .collect::<Vec<_>>();
+        let root = calculate_transaction_root(&transactions);
+
+        for index in [0, 1, 127, 128, 199] {
+            let proof = calculate_transaction_proof(&transactions, index).unwrap();
+            let key = Nibbles::unpack(alloy_rlp::encode_fixed_size(&index));
+            let value = transactions[index].encoded_2718();
+            verify_proof(root, key, Some(value), &proof).unwrap();
+        }
+    }
+
+    #[test]
+    fn transaction_proof_out_of_bounds() {
+        let transactions = vec![Signed::new_unhashed(
+            TxLegacy::default(),
+            Signature::test_signature(),
+        )];
+        assert_eq!(
+            calculate_transaction_proof(&transactions, 1),
+            Err(TransactionIndexOutOfBounds { index: 1, len: 1 })
+        );
+    }
+
     #[test]
     fn check_empty_state_root() {
         let genesis_alloc = HashMap::<Address, GenesisAccount>::new();
@@ -3,7 +3,7 @@ use alloy_consensus::{
     transaction::TxEip4844Sidecar, EthereumTxEnvelope, Header, TxEip1559, TxEip2930, TxEip4844,
     TxEip4844Variant, TxEip4844WithSidecar, TxEip7702, TxLegacy, TxType,
 };
-use alloy_eips::eip4895::Withdrawals;
+use alloy_eips::eip4895::{Withdrawal, Withdrawals};
 use alloy_primitives::{LogData, Signature, TxHash, B256};
 use revm_primitives::Log;
 
@@ -102,15 +102,28 @@ impl<T: InMemorySize> InMemorySize for EthereumTxEnvelope<T> {
     }
 }
 
+/// Calculates a heuristic for the in-memory size of a block body with the given contents.
+///
+/// Absent withdrawals are accounted the same as an empty withdrawals list, and only the
+/// withdrawals themselves count towards the size, not any additional capacity.
+#[inline]
+pub fn block_body_size<T: InMemorySize, H: InMemorySize>(
+    transactions: &[T],
+    ommers: &[H],
+    withdrawals: Option<&Withdrawals>,
+) -> usize {
+    transactions.iter().map(T::size).sum::<usize>() +
+        ommers.iter().map(H::size).sum::<usize>() +
+        core::mem::size_of::<Option<Withdrawals>>() +
+        withdrawals
+            .map_or(0, |withdrawals| withdrawals.len() * core::mem::size_of::<Withdrawal>())
+}
+
 impl<T: InMemorySize, H: InMemorySize> InMemorySize for alloy_consensus::BlockBody<T, H> {
     /// Calculates a heuristic for the in-memory size of the block body
     #[inline]
     fn size(&self) -> usize {
-        self.transactions.iter().map(T::size).sum::<usize>() +
-            self.ommers.iter().map(H::size).sum::<usize>() +
-            self.withdrawals
-                .as_ref()
-                .map_or(core::mem::size_of::<Option<Withdrawals>>(), Withdrawals::total_size)
+        block_body_size(&self.transactions, &self.ommers, self.withdrawals.as_ref())
     }
 }
 
@@ -195,9 +208,12 @@ mod op {
         }
     }
 }
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::SealedBlock;
+    use alloy_consensus::TxEnvelope;
 
     // ensures we don't have any recursion in the `InMemorySize` impls
     #[test]
@@ -212,4 +228,26 @@ mod tests {
         assert_no_recursion::<TxEip7702>();
         assert_no_recursion::<TxEip4844>();
     }
+
+    #[test]
+    fn empty_withdrawals_size() {
+        let body = alloy_consensus::BlockBody::<TxLegacy> {
+            withdrawals: Some(Withdrawals::new(Vec::with_capacity(16))),
+            ..Default::default()
+        };
+        assert_eq!(body.size(), alloy_consensus::BlockBody::<TxLegacy>::default().size());
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn block_size_consistency(
+            block in proptest_arbitrary_interop::arb::<alloy_consensus::Block<TxEnvelope>>()
+        ) {
+            proptest::prop_assert_eq!(block.header.size() + block.body.size(), block.size());
+
+            // the sealed block additionally accounts for the cached block hash
+            let sealed = SealedBlock::seal_slow(block.clone());
+            proptest::prop_assert_eq!(sealed.size(), block.size() + core::mem::size_of::<B256>());
+        }
+    }
 }
@@ -3,10 +3,39 @@
 /// Re-exported signature type
 pub use alloy_primitives::Signature;
 
+use alloy_consensus::crypto::{InvalidSignatureS, RecoveryError};
+use alloy_primitives::{uint, U256};
+
+/// The order of the secp256k1 curve, `r` and `s` values must be less than this.
+pub(crate) const SECP256K1N_ORDER: U256 =
+    uint!(0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141_U256);
+
+/// Checks that the signature is well-formed, without recovering the signer.
+///
+/// `r` and `s` must be valid non-zero scalars and `s` must be in the lower half of the curve order
+/// as required by [EIP-2](https://eips.ethereum.org/EIPS/eip-2), which rejects malleable
+/// signatures. The y-parity is a single bit and therefore always valid.
+///
+/// This is much cheaper than recovering the signer, but doesn't check that `r` is the
+/// x-coordinate of a point on the curve: recovering the signer of a well-formed signature can
+/// still fail.
+pub fn verify_signature(signature: &Signature) -> Result<(), RecoveryError> {
+    let (r, s) = (signature.r(), signature.s());
+    if r.is_zero() || r >= SECP256K1N_ORDER || s.is_zero() {
+        return Err(RecoveryError::new())
+    }
+    if signature.normalize_s().is_some() {
+        return Err(RecoveryError::from_source(InvalidSignatureS))
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::crypto::secp256k1::recover_signer;
-    use alloy_primitives::{address, b256, Signature, U256};
+    use alloy_consensus::crypto::SECP256K1N_HALF;
+    use alloy_primitives::{address, b256};
     use std::str::FromStr;
 
     #[test]
@@ -27,4 +56,21 @@ mod tests {
         let expected = address!("0x9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f");
         assert_eq!(expected, signer);
     }
+
+    #[test]
+    fn test_verify_signature() {
+        let signature = Signature::test_signature();
+        assert!(verify_signature(&signature).is_ok());
+
+        // the malleable counterpart of a valid signature has a high `s` value
+        let malleable =
+            Signature::new(signature.r(), SECP256K1N_ORDER - signature.s(), !signature.v());
+        assert!(malleable.s() > SECP256K1N_HALF);
+        assert!(verify_signature(&malleable).is_err());
+
+        let (s, v) = (signature.s(), signature.v());
+        assert!(verify_signature(&Signature::new(U256::ZERO, s, v)).is_err());
+        assert!(verify_signature(&Signature::new(SECP256K1N_ORDER, s, v)).is_err());
+        assert!(verify_signature(&Signature::new(signature.r(), U256::ZERO, v)).is_err());
+    }
 }
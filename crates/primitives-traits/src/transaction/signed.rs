@@ -1,6 +1,9 @@
 //! API of a signed transaction.
 
-use crate::{InMemorySize, MaybeCompact, MaybeSerde, MaybeSerdeBincodeCompat};
+use crate::{
+    transaction::signature::verify_signature, InMemorySize, MaybeCompact, MaybeSerde,
+    MaybeSerdeBincodeCompat,
+};
 use alloc::fmt;
 use alloy_consensus::{
     transaction::{Recovered, RlpEcdsaEncodableTx, SignerRecoverable, TxHashRef},
@@ -58,6 +61,35 @@ pub trait SignedTransaction:
         !self.is_eip4844()
     }
 
+    /// Returns whether the transaction is replay protected as defined in [EIP-155](https://eips.ethereum.org/EIPS/eip-155).
+    ///
+    /// This is the case if the signature commits to a chain id. Typed transactions always include
+    /// a chain id, legacy transactions only if they were signed with one.
+    fn is_replay_protected(&self) -> bool {
+        self.chain_id().is_some()
+    }
+
+    /// Returns whether this is a deposit transaction, like the OP deposit transactions that are
+    /// derived from L1 and neither signed nor paid for like regular transactions.
+    ///
+    /// The default implementation returns `false`.
+    fn is_deposit_transaction(&self) -> bool {
+        false
+    }
+
+    /// Checks that the transaction's signature is well-formed, without recovering the signer.
+    ///
+    /// This is a cheap way to reject transactions with malformed or malleable (high `s`, see
+    /// [EIP-2](https://eips.ethereum.org/EIPS/eip-2)) signatures before the expensive
+    /// [`Self::try_recover`]. Recovery can still fail for a transaction that passes this check, see
+    /// [`verify_signature`].
+    ///
+    /// Transactions without a signature, like deposits, always pass. The default implementation
+    /// doesn't check anything.
+    fn verify_signature(&self) -> Result<(), RecoveryError> {
+        Ok(())
+    }
+
     /// Recover signer from signature and hash.
     ///
     /// Returns an error if the transaction's signature is invalid.
@@ -135,6 +167,9 @@ where
     T: RlpEcdsaEncodableTx + SignableTransaction<Signature> + Unpin,
     Self: Clone + PartialEq + Eq + Decodable + Decodable2718 + MaybeSerde + InMemorySize,
 {
+    fn verify_signature(&self) -> Result<(), RecoveryError> {
+        verify_signature(self.signature())
+    }
 }
 
 #[cfg(feature = "op")]
@@ -142,7 +177,60 @@ mod op {
     use super::*;
     use op_alloy_consensus::{OpPooledTransaction, OpTxEnvelope};
 
-    impl SignedTransaction for OpPooledTransaction {}
+    impl SignedTransaction for OpPooledTransaction {
+        fn verify_signature(&self) -> Result<(), RecoveryError> {
+            verify_signature(self.signature())
+        }
+    }
+
+    impl SignedTransaction for OpTxEnvelope {
+        fn verify_signature(&self) -> Result<(), RecoveryError> {
+            // deposit transactions don't have a signature
+            self.signature().map_or(Ok(()), verify_signature)
+        }
+
+        fn is_deposit_transaction(&self) -> bool {
+            matches!(self, Self::Deposit(_))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::signature::SECP256K1N_ORDER;
+    use alloy_consensus::{TxEip4844, TxLegacy};
+
+    #[test]
+    fn legacy_replay_protection() {
+        let legacy = |chain_id| {
+            EthereumTxEnvelope::<TxEip4844>::Legacy(
+                TxLegacy { chain_id, ..Default::default() }
+                    .into_signed(Signature::test_signature()),
+            )
+        };
+
+        // EIP-155 protected
+        assert!(legacy(Some(1)).is_replay_protected());
+        // pre EIP-155, unprotected
+        assert!(!legacy(None).is_replay_protected());
+    }
 
-    impl SignedTransaction for OpTxEnvelope {}
+    #[test]
+    fn verify_malleable_signature() {
+        let tx = TxLegacy { chain_id: Some(1), ..Default::default() };
+        let signature = Signature::test_signature();
+        let signed = EthereumTxEnvelope::<TxEip4844>::Legacy(tx.clone().into_signed(signature));
+        assert!(signed.verify_signature().is_ok());
+        let signer = signed.try_recover().unwrap();
+
+        // flipping `s` to `n - s` yields another valid signature for the same signer, which is
+        // rejected by EIP-2
+        let malleable =
+            Signature::new(signature.r(), SECP256K1N_ORDER - signature.s(), !signature.v());
+        let malleable = EthereumTxEnvelope::<TxEip4844>::Legacy(tx.into_signed(malleable));
+        assert!(malleable.verify_signature().is_err());
+        assert!(malleable.try_recover().is_err());
+        assert_eq!(malleable.try_recover_unchecked().unwrap(), signer);
+    }
 }
@@ -169,3 +169,119 @@ impl<DB: EvmStateProvider> DatabaseRef for StateProviderDatabase<DB> {
         Ok(self.0.block_hash(number)?.unwrap_or_default())
     }
 }
+
+/// A [`StateProviderDatabase`] that returns an error for unknown block hashes.
+///
+/// [`StateProviderDatabase`] falls back to a zero hash if the block hash of the requested number
+/// is unavailable. This wrapper returns [`ProviderError::HeaderNotFound`] instead, which is
+/// preferable when replaying historical state, e.g. for tracing, where a silent zero hash for the
+/// `BLOCKHASH` opcode would produce diverging results.
+///
+/// Everything else is delegated to the wrapped [`StateProviderDatabase`].
+#[derive(Clone)]
+pub struct StrictStateProviderDatabase<DB>(pub StateProviderDatabase<DB>);
+
+impl<DB> StrictStateProviderDatabase<DB> {
+    /// Create new State with generic `StateProvider`.
+    pub const fn new(db: DB) -> Self {
+        Self(StateProviderDatabase::new(db))
+    }
+
+    /// Consume State and return inner `StateProvider`.
+    pub fn into_inner(self) -> DB {
+        self.0.into_inner()
+    }
+}
+
+impl<DB> core::fmt::Debug for StrictStateProviderDatabase<DB> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("StrictStateProviderDatabase").field(&self.0).finish()
+    }
+}
+
+impl<DB> Deref for StrictStateProviderDatabase<DB> {
+    type Target = StateProviderDatabase<DB>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<DB> DerefMut for StrictStateProviderDatabase<DB> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<DB> From<StateProviderDatabase<DB>> for StrictStateProviderDatabase<DB> {
+    fn from(db: StateProviderDatabase<DB>) -> Self {
+        Self(db)
+    }
+}
+
+impl<DB: EvmStateProvider> Database for StrictStateProviderDatabase<DB> {
+    type Error = ProviderError;
+
+    fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        self.0.basic(address)
+    }
+
+    fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        self.0.code_by_hash(code_hash)
+    }
+
+    fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        self.0.storage(address, index)
+    }
+
+    /// Retrieves the block hash for a given block number.
+    ///
+    /// Returns an error if the block hash is not found.
+    fn block_hash(&mut self, number: u64) -> Result<B256, Self::Error> {
+        self.block_hash_ref(number)
+    }
+}
+
+impl<DB: EvmStateProvider> DatabaseRef for StrictStateProviderDatabase<DB> {
+    type Error = <Self as Database>::Error;
+
+    fn basic_ref(&self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        self.0.basic_ref(address)
+    }
+
+    fn code_by_hash_ref(&self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        self.0.code_by_hash_ref(code_hash)
+    }
+
+    fn storage_ref(&self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        self.0.storage_ref(address, index)
+    }
+
+    /// Retrieves the block hash for a given block number.
+    ///
+    /// Returns an error if the block hash is not found.
+    fn block_hash_ref(&self, number: u64) -> Result<B256, Self::Error> {
+        self.0 .0.block_hash(number)?.ok_or_else(|| ProviderError::HeaderNotFound(number.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::StateProviderTest;
+
+    #[test]
+    fn strict_block_hash() {
+        let hash = B256::with_last_byte(1);
+        let mut provider = StateProviderTest::default();
+        provider.insert_block_hash(1, hash);
+
+        let db = StateProviderDatabase::new(provider.clone());
+        assert_eq!(db.block_hash_ref(1).unwrap(), hash);
+        assert_eq!(db.block_hash_ref(2).unwrap(), B256::ZERO);
+
+        let db = StrictStateProviderDatabase::new(provider);
+        assert_eq!(db.block_hash_ref(1).unwrap(), hash);
+        assert!(matches!(db.block_hash_ref(2), Err(ProviderError::HeaderNotFound(_))));
+    }
+}
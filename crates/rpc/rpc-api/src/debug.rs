@@ -1,5 +1,6 @@
 use alloy_eips::{BlockId, BlockNumberOrTag};
 use alloy_genesis::ChainConfig;
+use alloy_json_abi::JsonAbi;
 use alloy_json_rpc::RpcObject;
 use alloy_primitives::{Address, Bytes, B256};
 use alloy_rpc_types_debug::ExecutionWitness;
@@ -8,7 +9,12 @@ use alloy_rpc_types_trace::geth::{
     BlockTraceResult, GethDebugTracingCallOptions, GethDebugTracingOptions, GethTrace, TraceResult,
 };
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_rpc_eth_types::trace::{
+    BlockReward, DecodedCallLogFrame, InternalTransfer, LocalizedCreatedContract,
+    LocalizedSelfDestruct, OpcodeGas,
+};
 use reth_trie_common::{updates::TrieUpdates, HashedPostState};
+use std::collections::BTreeMap;
 
 /// Debug rpc interface.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "debug"))]
@@ -156,6 +162,81 @@ pub trait DebugApi<TxReq: RpcObject> {
         hash: B256,
     ) -> RpcResult<ExecutionWitness>;
 
+    /// Returns all contracts created by the transactions of the given block, in execution order.
+    ///
+    /// Each entry contains the address of the contract, its deployer and the hash of its init code.
+    /// Failed creations are included and flagged as such.
+    ///
+    /// Returns `None` if the block is not found.
+    #[method(name = "getCreatedContracts")]
+    async fn debug_get_created_contracts(
+        &self,
+        block_id: BlockId,
+    ) -> RpcResult<Option<Vec<LocalizedCreatedContract>>>;
+
+    /// Returns all selfdestructs executed by the transactions of the given block, in execution
+    /// order.
+    ///
+    /// Each entry contains the address of the contract, the refund address and the transferred
+    /// balance. Since Cancun, selfdestructs of contracts that weren't created in the same
+    /// transaction only transfer the balance and are flagged as not destroyed.
+    ///
+    /// Returns `None` if the block is not found.
+    #[method(name = "getSelfDestructs")]
+    async fn debug_get_self_destructs(
+        &self,
+        block_id: BlockId,
+    ) -> RpcResult<Option<Vec<LocalizedSelfDestruct>>>;
+
+    /// Returns the value transfers made by the subcalls of the given transaction, also known as
+    /// internal transactions, in execution order.
+    ///
+    /// Each entry contains the sender, the recipient, the value and the type of the call. The
+    /// top-level call and failed subcalls are not included.
+    ///
+    /// Returns `None` if the transaction is not found.
+    #[method(name = "getInternalTransactions")]
+    async fn debug_get_internal_transactions(
+        &self,
+        tx_hash: B256,
+    ) -> RpcResult<Option<Vec<InternalTransfer>>>;
+
+    /// Returns how often each opcode was executed by the given transaction and the combined gas
+    /// cost of those executions, keyed by opcode name.
+    ///
+    /// Opcodes of all calls are included, also those of failed calls.
+    ///
+    /// Returns `None` if the transaction is not found.
+    #[method(name = "getOpcodeGas")]
+    async fn debug_get_opcode_gas(
+        &self,
+        tx_hash: B256,
+    ) -> RpcResult<Option<BTreeMap<String, OpcodeGas>>>;
+
+    /// Returns all logs emitted by the given transaction and its subcalls in execution order,
+    /// decoded with the events of the given ABI.
+    ///
+    /// Logs of events that are not in the ABI are returned raw. This is a separate method instead
+    /// of an option of the `callTracer` config, because the call tracer's config and log frames
+    /// are geth's types and have no room for the ABI or the decoded events.
+    ///
+    /// Returns `None` if the transaction is not found.
+    #[method(name = "getDecodedLogs")]
+    async fn debug_get_decoded_logs(
+        &self,
+        tx_hash: B256,
+        abi: JsonAbi,
+    ) -> RpcResult<Option<Vec<DecodedCallLogFrame>>>;
+
+    /// Returns the fees and payments received by the fee recipient of the given block.
+    ///
+    /// This includes the total and burnt transaction fees, the priority fees and the balance change
+    /// of the fee recipient, from which direct payments such as MEV payments are approximated.
+    ///
+    /// Returns `None` if the block is not found.
+    #[method(name = "blockReward")]
+    async fn debug_block_reward(&self, block_id: BlockId) -> RpcResult<Option<BlockReward>>;
+
     /// Sets the logging backtrace location. When a backtrace location is set and a log message is
     /// emitted at that location, the stack of the goroutine executing the log statement will
     /// be printed to stderr.
@@ -1,6 +1,7 @@
 use alloy_eips::BlockId;
 use alloy_primitives::{Address, U256};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_rpc_eth_types::ForkchoiceStateInfo;
 use std::collections::HashMap;
 
 // Required for the subscription attribute below
@@ -17,6 +18,11 @@ pub trait RethApi {
         block_id: BlockId,
     ) -> RpcResult<HashMap<Address, U256>>;
 
+    /// Returns the current fork choice state of the node: the head, safe and finalized block
+    /// hashes and their block numbers.
+    #[method(name = "getForkchoiceState")]
+    async fn reth_get_forkchoice_state(&self) -> RpcResult<ForkchoiceStateInfo>;
+
     /// Subscribe to json `ChainNotifications`
     #[subscription(
         name = "subscribeChainNotifications",
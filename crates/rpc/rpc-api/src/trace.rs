@@ -7,6 +7,7 @@ use alloy_rpc_types_trace::{
     parity::*,
 };
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_rpc_eth_types::trace::{CallKind, ExtendedTransactionTrace};
 
 /// Ethereum trace API
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "trace"))]
@@ -70,8 +71,15 @@ pub trait TraceApi<TxReq> {
     /// Returns traces matching given filter.
     ///
     /// This is similar to `eth_getLogs` but for traces.
+    ///
+    /// If a call kind is given, only call and create traces of that kind are returned, e.g. only
+    /// `DELEGATECALL`s or only `CREATE2`s.
     #[method(name = "filter")]
-    async fn trace_filter(&self, filter: TraceFilter) -> RpcResult<Vec<LocalizedTransactionTrace>>;
+    async fn trace_filter(
+        &self,
+        filter: TraceFilter,
+        call_kind: Option<CallKind>,
+    ) -> RpcResult<Vec<LocalizedTransactionTrace>>;
 
     /// Returns transaction trace at given index.
     ///
@@ -87,12 +95,25 @@ pub trait TraceApi<TxReq> {
     ) -> RpcResult<Option<LocalizedTransactionTrace>>;
 
     /// Returns all traces of given transaction.
+    ///
+    /// If `only_failed` is set, subtraces that succeeded and don't lead to a failed subtrace are
+    /// omitted.
     #[method(name = "transaction")]
     async fn trace_transaction(
         &self,
         hash: B256,
+        only_failed: Option<bool>,
     ) -> RpcResult<Option<Vec<LocalizedTransactionTrace>>>;
 
+    /// Returns all traces of given transaction, optionally extended with the number of calls and
+    /// combined gas usage per precompile called by the transaction.
+    #[method(name = "transactionExtended")]
+    async fn trace_transaction_extended(
+        &self,
+        hash: B256,
+        include_precompile_calls: Option<bool>,
+    ) -> RpcResult<Option<ExtendedTransactionTrace>>;
+
     /// Returns all opcodes with their count and combined gas usage for the given transaction in no
     /// particular order.
     #[method(name = "transactionOpcodeGas")]
@@ -1,5 +1,6 @@
 //! API for block submission validation.
 
+use alloy_primitives::B256;
 use alloy_rpc_types_beacon::relay::{
     BuilderBlockValidationRequest, BuilderBlockValidationRequestV2,
     BuilderBlockValidationRequestV3, BuilderBlockValidationRequestV4,
@@ -45,4 +46,26 @@ pub trait BlockSubmissionValidationApi {
         &self,
         request: BuilderBlockValidationRequestV5,
     ) -> jsonrpsee::core::RpcResult<()>;
+
+    /// A Request to validate a block submission on top of the given parent block.
+    ///
+    /// Unlike `validateBuilderSubmissionV4`, the parent does not need to be within the validation
+    /// window of the latest block, only its state needs to be available.
+    #[method(name = "validateBuilderSubmissionV4WithParent")]
+    async fn validate_builder_submission_v4_with_parent(
+        &self,
+        request: BuilderBlockValidationRequestV4,
+        parent_block_hash: B256,
+    ) -> jsonrpsee::core::RpcResult<()>;
+
+    /// A Request to validate a block submission on top of the given parent block.
+    ///
+    /// Unlike `validateBuilderSubmissionV5`, the parent does not need to be within the validation
+    /// window of the latest block, only its state needs to be available.
+    #[method(name = "validateBuilderSubmissionV5WithParent")]
+    async fn validate_builder_submission_v5_with_parent(
+        &self,
+        request: BuilderBlockValidationRequestV5,
+        parent_block_hash: B256,
+    ) -> jsonrpsee::core::RpcResult<()>;
 }
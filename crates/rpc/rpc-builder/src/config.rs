@@ -3,7 +3,7 @@ use reth_node_core::{args::RpcServerArgs, utils::get_or_create_jwt_secret_from_p
 use reth_rpc::ValidationApiConfig;
 use reth_rpc_eth_types::{EthConfig, EthStateCacheConfig, GasPriceOracleConfig};
 use reth_rpc_layer::{JwtError, JwtSecret};
-use reth_rpc_server_types::RpcModuleSelection;
+use reth_rpc_server_types::{result::RetryConfig, RpcModuleSelection};
 use std::{net::SocketAddr, path::PathBuf};
 use tower::layer::util::Identity;
 use tracing::{debug, warn};
@@ -106,12 +106,15 @@ impl RethRpcServerConfig for RpcServerArgs {
             .pending_block_kind(self.rpc_pending_block)
             .raw_tx_forwarder(self.rpc_forwarder.clone())
             .rpc_evm_memory_limit(self.rpc_evm_memory_limit)
+            .transient_retry(RetryConfig::default().with_max_retries(self.rpc_transient_retries))
+            .max_trace_response_size(self.rpc_max_trace_response_size.0.map(|size| size as usize))
     }
 
     fn flashbots_config(&self) -> ValidationApiConfig {
         ValidationApiConfig {
             disallow: self.builder_disallow.clone().unwrap_or_default(),
             validation_window: self.rpc_eth_proof_window,
+            kzg_settings: Default::default(),
         }
     }
 
@@ -121,6 +124,7 @@ impl RethRpcServerConfig for RpcServerArgs {
             max_receipts: self.rpc_state_cache.max_receipts,
             max_headers: self.rpc_state_cache.max_headers,
             max_concurrent_db_requests: self.rpc_state_cache.max_concurrent_db_requests,
+            max_bytecode_bytes: self.rpc_state_cache.max_bytecode_bytes,
         }
     }
 
@@ -160,6 +164,16 @@ impl RethRpcServerConfig for RpcServerArgs {
             config = config.with_ipc(RpcModuleSelection::default_ipc_modules());
         }
 
+        if let Some(methods) = self.http_methods.clone() {
+            config = config.with_http_allowed_methods(methods);
+        }
+        if let Some(methods) = self.ws_methods.clone() {
+            config = config.with_ws_allowed_methods(methods);
+        }
+        if let Some(methods) = self.ipc_methods.clone() {
+            config = config.with_ipc_allowed_methods(methods);
+        }
+
         config
     }
 
@@ -1,4 +1,4 @@
-use crate::{cors::CorsDomainError, RethRpcModule};
+use crate::{cors::CorsDomainError, RethRpcModule, RpcMethodAllowList};
 use reth_ipc::server::IpcServerStartError;
 use std::{
     collections::HashSet,
@@ -127,6 +127,17 @@ pub enum WsHttpSamePortError {
     /// Ws and http server configured on same port but with different modules.
     #[error("{0}")]
     ConflictingModules(Box<ConflictingModules>),
+    /// Ws and http server configured on same port but with different allowed methods.
+    #[error(
+        "allowed methods for HTTP and WS are different, but they are on the same port: \
+         HTTP: {http_allowed_methods:?}, WS: {ws_allowed_methods:?}"
+    )]
+    ConflictingAllowedMethods {
+        /// Methods allowed on http.
+        http_allowed_methods: Option<RpcMethodAllowList>,
+        /// Methods allowed on ws.
+        ws_allowed_methods: Option<RpcMethodAllowList>,
+    },
 }
 
 #[cfg(test)]
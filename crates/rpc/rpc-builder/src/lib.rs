@@ -74,7 +74,7 @@ use jsonrpsee::server::ServerConfigBuilder;
 pub use reth_ipc::server::{
     Builder as IpcServerBuilder, RpcServiceBuilder as IpcRpcServiceBuilder,
 };
-pub use reth_rpc_server_types::{constants, RethRpcModule, RpcModuleSelection};
+pub use reth_rpc_server_types::{constants, RethRpcModule, RpcMethodAllowList, RpcModuleSelection};
 pub use tower::layer::util::{Identity, Stack};
 
 /// Auth server utilities.
@@ -380,7 +380,7 @@ where
         let Self { provider, pool, network, executor, consensus, evm_config, .. } = self;
 
         if !module_config.is_empty() {
-            let TransportRpcModuleConfig { http, ws, ipc, config } = module_config.clone();
+            let TransportRpcModuleConfig { http, ws, ipc, config, .. } = module_config.clone();
 
             let mut registry = RpcRegistryInner::new(
                 provider,
@@ -440,6 +440,16 @@ impl RpcModuleConfig {
     pub const fn eth_mut(&mut self) -> &mut EthConfig {
         &mut self.eth
     }
+
+    /// Get a reference to the flashbots namespace config
+    pub const fn flashbots(&self) -> &ValidationApiConfig {
+        &self.flashbots
+    }
+
+    /// Get a mutable reference to the flashbots namespace config
+    pub const fn flashbots_mut(&mut self) -> &mut ValidationApiConfig {
+        &mut self.flashbots
+    }
 }
 
 /// Configures [`RpcModuleConfig`]
@@ -1275,6 +1285,10 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         let mut ws_handle = None;
         let mut ipc_handle = None;
 
+        // only serve the methods that are allowed on the respective transport
+        let mut modules = modules.clone();
+        modules.retain_allowed_methods();
+
         let http_socket_addr = self.http_addr.unwrap_or(SocketAddr::V4(SocketAddrV4::new(
             Ipv4Addr::LOCALHOST,
             constants::DEFAULT_HTTP_RPC_PORT,
@@ -1458,6 +1472,12 @@ pub struct TransportRpcModuleConfig {
     ws: Option<RpcModuleSelection>,
     /// ipc module configuration
     ipc: Option<RpcModuleSelection>,
+    /// Methods allowed on the http transport, all methods of the modules if not set
+    http_allowed_methods: Option<RpcMethodAllowList>,
+    /// Methods allowed on the ws transport, all methods of the modules if not set
+    ws_allowed_methods: Option<RpcMethodAllowList>,
+    /// Methods allowed on the ipc transport, all methods of the modules if not set
+    ipc_allowed_methods: Option<RpcMethodAllowList>,
     /// Config for the modules
     config: Option<RpcModuleConfig>,
 }
@@ -1504,6 +1524,24 @@ impl TransportRpcModuleConfig {
         self
     }
 
+    /// Restricts the http transport to the given methods of its configured modules.
+    pub fn with_http_allowed_methods(mut self, methods: impl Into<RpcMethodAllowList>) -> Self {
+        self.http_allowed_methods = Some(methods.into());
+        self
+    }
+
+    /// Restricts the ws transport to the given methods of its configured modules.
+    pub fn with_ws_allowed_methods(mut self, methods: impl Into<RpcMethodAllowList>) -> Self {
+        self.ws_allowed_methods = Some(methods.into());
+        self
+    }
+
+    /// Restricts the ipc transport to the given methods of its configured modules.
+    pub fn with_ipc_allowed_methods(mut self, methods: impl Into<RpcMethodAllowList>) -> Self {
+        self.ipc_allowed_methods = Some(methods.into());
+        self
+    }
+
     /// Get a mutable reference to the
     pub const fn http_mut(&mut self) -> &mut Option<RpcModuleSelection> {
         &mut self.http
@@ -1549,6 +1587,21 @@ impl TransportRpcModuleConfig {
         self.config.as_ref()
     }
 
+    /// Returns the [`RpcMethodAllowList`] for the http transport
+    pub const fn http_allowed_methods(&self) -> Option<&RpcMethodAllowList> {
+        self.http_allowed_methods.as_ref()
+    }
+
+    /// Returns the [`RpcMethodAllowList`] for the ws transport
+    pub const fn ws_allowed_methods(&self) -> Option<&RpcMethodAllowList> {
+        self.ws_allowed_methods.as_ref()
+    }
+
+    /// Returns the [`RpcMethodAllowList`] for the ipc transport
+    pub const fn ipc_allowed_methods(&self) -> Option<&RpcMethodAllowList> {
+        self.ipc_allowed_methods.as_ref()
+    }
+
     /// Returns true if the given module is configured for any transport.
     pub fn contains_any(&self, module: &RethRpcModule) -> bool {
         self.contains_http(module) || self.contains_ws(module) || self.contains_ipc(module)
@@ -1573,6 +1626,12 @@ impl TransportRpcModuleConfig {
     /// port.
     fn ensure_ws_http_identical(&self) -> Result<(), WsHttpSamePortError> {
         if RpcModuleSelection::are_identical(self.http.as_ref(), self.ws.as_ref()) {
+            if self.http_allowed_methods != self.ws_allowed_methods {
+                return Err(WsHttpSamePortError::ConflictingAllowedMethods {
+                    http_allowed_methods: self.http_allowed_methods.clone(),
+                    ws_allowed_methods: self.ws_allowed_methods.clone(),
+                })
+            }
             Ok(())
         } else {
             let http_modules =
@@ -1843,6 +1902,31 @@ impl TransportRpcModules {
         }
     }
 
+    /// Removes all methods that are not allowed on their transport, see
+    /// [`TransportRpcModuleConfig::with_http_allowed_methods`].
+    ///
+    /// Transports without an allow-list are left untouched.
+    pub fn retain_allowed_methods(&mut self) {
+        let TransportRpcModuleConfig {
+            http_allowed_methods,
+            ws_allowed_methods,
+            ipc_allowed_methods,
+            ..
+        } = &self.config;
+        for (module, allowed) in [
+            (&mut self.http, http_allowed_methods),
+            (&mut self.ws, ws_allowed_methods),
+            (&mut self.ipc, ipc_allowed_methods),
+        ] {
+            let (Some(module), Some(allowed)) = (module, allowed) else { continue };
+            let disallowed =
+                module.method_names().filter(|name| !allowed.is_allowed(name)).collect::<Vec<_>>();
+            for name in disallowed {
+                module.remove_method(name);
+            }
+        }
+    }
+
     /// Removes the method with the given name from all configured transports.
     ///
     /// Returns `true` if the method was found and removed, `false` otherwise.
@@ -2337,6 +2421,9 @@ mod tests {
                 )),
                 ws: None,
                 ipc: None,
+                http_allowed_methods: None,
+                ws_allowed_methods: None,
+                ipc_allowed_methods: None,
                 config: None,
             }
         )
@@ -2351,6 +2438,9 @@ mod tests {
                 http: Some(RpcModuleSelection::Selection(Default::default())),
                 ws: None,
                 ipc: None,
+                http_allowed_methods: None,
+                ws_allowed_methods: None,
+                ipc_allowed_methods: None,
                 config: None,
             }
         )
@@ -2362,6 +2452,33 @@ mod tests {
         module
     }
 
+    #[test]
+    fn test_retain_allowed_methods() {
+        let mut module = create_test_module();
+        module.register_method("eth_call", |_, _, _| "succeed").unwrap();
+        module.register_method("trace_call", |_, _, _| "succeed").unwrap();
+        let mut modules = TransportRpcModules {
+            config: TransportRpcModuleConfig::default()
+                .with_http_allowed_methods(["eth", "anything"])
+                .with_ws_allowed_methods(["eth_call"]),
+            http: Some(module.clone()),
+            ws: Some(module.clone()),
+            ipc: Some(module),
+        };
+
+        modules.retain_allowed_methods();
+
+        let names = |module: &Option<RpcModule<()>>| {
+            let mut names = module.as_ref().unwrap().method_names().collect::<Vec<_>>();
+            names.sort_unstable();
+            names
+        };
+        assert_eq!(names(&modules.http), ["anything", "eth_call"]);
+        assert_eq!(names(&modules.ws), ["eth_call"]);
+        // no allow-list configured for ipc
+        assert_eq!(names(&modules.ipc), ["anything", "eth_call", "trace_call"]);
+    }
+
     #[test]
     fn test_remove_http_method() {
         let mut modules =
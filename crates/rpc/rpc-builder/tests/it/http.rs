@@ -28,7 +28,7 @@ use reth_rpc_api::{
 use reth_rpc_server_types::RethRpcModule;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 fn is_unimplemented(err: jsonrpsee::core::client::Error) -> bool {
     match err {
@@ -128,6 +128,7 @@ where
     let id = EthFilterApiClient::<Transaction>::new_block_filter(client).await.unwrap();
     EthFilterApiClient::<Transaction>::filter_changes(client, id.clone()).await.unwrap();
     EthFilterApiClient::<Transaction>::logs(client, Filter::default()).await.unwrap();
+    EthFilterApiClient::<Transaction>::logs_with_blooms(client, Filter::default()).await.unwrap();
     let id =
         EthFilterApiClient::<Transaction>::new_filter(client, Filter::default()).await.unwrap();
     EthFilterApiClient::<Transaction>::filter_logs(client, id.clone()).await.unwrap();
@@ -233,6 +234,13 @@ where
     )
     .await
     .unwrap();
+    EthApiClient::<TransactionRequest, Transaction, Block, Receipt, Header, TransactionSigned>::storage_values(
+        client,
+        HashMap::from([(address, vec![U256::default().into()])]),
+        None,
+    )
+    .await
+    .unwrap();
     EthApiClient::<TransactionRequest, Transaction, Block, Receipt, Header, TransactionSigned>::block_by_hash(
         client, hash, false,
     )
@@ -294,6 +302,11 @@ where
     )
     .await
     .unwrap();
+    EthApiClient::<TransactionRequest, Transaction, Block, Receipt, Header, TransactionSigned>::transaction_status(
+        client, tx_hash,
+    )
+    .await
+    .unwrap();
     EthApiClient::<TransactionRequest, Transaction, Block, Receipt, Header, TransactionSigned>::transaction_by_block_hash_and_index(
         client, hash, index,
     )
@@ -331,6 +344,22 @@ where
     )
     .await
     .unwrap_err();
+    EthApiClient::<TransactionRequest, Transaction, Block, Receipt, Header, TransactionSigned>::call_with_access_list(
+        client,
+        call_request.clone(),
+        Some(block_number.into()),
+        None,
+        None,
+    )
+    .await
+    .unwrap_err();
+    EthApiClient::<TransactionRequest, Transaction, Block, Receipt, Header, TransactionSigned>::call_batch(
+        client,
+        vec![call_request.clone()],
+        Some(block_number.into()),
+    )
+    .await
+    .unwrap_err();
     EthApiClient::<TransactionRequest, Transaction, Block, Receipt, Header, TransactionSigned>::syncing(client)
         .await
         .unwrap();
@@ -362,6 +391,9 @@ where
     EthApiClient::<TransactionRequest, Transaction, Block, Receipt, Header, TransactionSigned>::max_priority_fee_per_gas(client)
         .await
         .unwrap_err();
+    EthApiClient::<TransactionRequest, Transaction, Block, Receipt, Header, TransactionSigned>::next_base_fee_per_gas(client)
+        .await
+        .unwrap_err();
     EthApiClient::<TransactionRequest, Transaction, Block, Receipt, Header, TransactionSigned>::get_proof(
         client,
         address,
@@ -446,6 +478,17 @@ where
     DebugApiClient::<TransactionRequest>::raw_transaction(client, B256::default()).await.unwrap();
     DebugApiClient::<TransactionRequest>::raw_receipts(client, block_id).await.unwrap();
     DebugApiClient::<TransactionRequest>::bad_blocks(client).await.unwrap();
+    DebugApiClient::<TransactionRequest>::debug_get_created_contracts(client, block_id)
+        .await
+        .unwrap_err();
+    DebugApiClient::<TransactionRequest>::debug_get_self_destructs(client, block_id).await.unwrap();
+    DebugApiClient::<TransactionRequest>::debug_get_internal_transactions(client, B256::default())
+        .await
+        .unwrap();
+    DebugApiClient::<TransactionRequest>::debug_get_opcode_gas(client, B256::default())
+        .await
+        .unwrap();
+    DebugApiClient::<TransactionRequest>::debug_block_reward(client, block_id).await.unwrap();
 }
 
 async fn test_basic_net_calls<C>(client: &C)
@@ -504,7 +547,17 @@ where
     .await
     .unwrap_err();
 
-    TraceApiClient::<TransactionRequest>::trace_filter(client, trace_filter).await.unwrap();
+    TraceApiClient::<TransactionRequest>::trace_filter(client, trace_filter, None).await.unwrap();
+    TraceApiClient::<TransactionRequest>::trace_transaction(client, B256::default(), Some(true))
+        .await
+        .unwrap();
+    TraceApiClient::<TransactionRequest>::trace_transaction_extended(
+        client,
+        B256::default(),
+        Some(true),
+    )
+    .await
+    .unwrap();
 }
 
 async fn test_basic_web3_calls<C>(client: &C)
@@ -2,11 +2,16 @@
 
 use std::io;
 
+use jsonrpsee::{
+    core::client::{ClientT, Error as ClientError},
+    rpc_params,
+    types::error::ErrorCode,
+};
 use reth_rpc_builder::{
     error::{RpcError, ServerKind, WsHttpSamePortError},
     RpcServerConfig, TransportRpcModuleConfig,
 };
-use reth_rpc_server_types::RethRpcModule;
+use reth_rpc_server_types::{RethRpcModule, RpcMethodAllowList};
 
 use crate::utils::{
     launch_http, launch_http_ws_same_port, launch_ws, test_address, test_rpc_builder,
@@ -124,3 +129,66 @@ async fn test_launch_same_port_different_cors() {
         RpcError::WsHttpSamePortError(WsHttpSamePortError::ConflictingCorsDomains { .. })
     ));
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_launch_same_port_different_allowed_methods() {
+    let builder = test_rpc_builder();
+    let eth_api = builder.bootstrap_eth_api();
+    let server = builder.build(
+        TransportRpcModuleConfig::set_ws(vec![RethRpcModule::Eth])
+            .with_http(vec![RethRpcModule::Eth])
+            .with_http_allowed_methods(["eth_chainId"]),
+        eth_api,
+    );
+    let addr = test_address();
+    let res = RpcServerConfig::ws(Default::default())
+        .with_ws_address(addr)
+        .with_http(Default::default())
+        .with_http_address(addr)
+        .start(&server)
+        .await;
+    let err = res.unwrap_err();
+    assert!(matches!(
+        err,
+        RpcError::WsHttpSamePortError(WsHttpSamePortError::ConflictingAllowedMethods { .. })
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_disallowed_method_not_found_on_restricted_transport() {
+    let builder = test_rpc_builder();
+    let eth_api = builder.bootstrap_eth_api();
+    let modules = vec![RethRpcModule::Web3, RethRpcModule::Trace];
+    let server = builder.build(
+        TransportRpcModuleConfig::set_ws(modules.clone())
+            .with_http(modules)
+            .with_http_allowed_methods("web3".parse::<RpcMethodAllowList>().unwrap()),
+        eth_api,
+    );
+    let http_handle = RpcServerConfig::http(Default::default())
+        .with_http_address(test_address())
+        .start(&server)
+        .await
+        .unwrap();
+    let ws_handle = RpcServerConfig::ws(Default::default())
+        .with_ws_address(test_address())
+        .start(&server)
+        .await
+        .unwrap();
+
+    let is_method_not_found = |err: &ClientError| matches!(err, ClientError::Call(err) if err.code() == ErrorCode::MethodNotFound.code());
+
+    let http = http_handle.http_client().unwrap();
+    let _: String = http.request("web3_clientVersion", rpc_params![]).await.unwrap();
+    let err = http
+        .request::<serde_json::Value, _>("trace_block", rpc_params!["latest"])
+        .await
+        .unwrap_err();
+    assert!(is_method_not_found(&err), "{err:?}");
+
+    let ws = ws_handle.ws_client().await.unwrap();
+    if let Err(err) = ws.request::<serde_json::Value, _>("trace_block", rpc_params!["latest"]).await
+    {
+        assert!(!is_method_not_found(&err), "{err:?}");
+    }
+}
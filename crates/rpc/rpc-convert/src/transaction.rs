@@ -3,7 +3,8 @@ use crate::{
     RpcHeader, RpcReceipt, RpcTransaction, RpcTxReq, RpcTypes, SignableTxRequest, TryIntoTxEnv,
 };
 use alloy_consensus::{
-    error::ValueError, transaction::Recovered, EthereumTxEnvelope, Sealable, TxEip4844,
+    error::ValueError, transaction::Recovered, EthereumTxEnvelope, Sealable, Transaction as _,
+    TxEip4844,
 };
 use alloy_network::Network;
 use alloy_primitives::{Address, U256};
@@ -33,6 +34,16 @@ pub struct ConvertReceiptInput<'a, N: NodePrimitives> {
     pub meta: TransactionMeta,
 }
 
+impl<N: NodePrimitives> ConvertReceiptInput<'_, N> {
+    /// Returns the price per unit of gas the transaction paid in its block.
+    ///
+    /// For legacy and EIP-2930 transactions this is the gas price, for dynamic fee transactions
+    /// it's `min(max_fee_per_gas, base_fee + max_priority_fee_per_gas)`.
+    pub fn effective_gas_price(&self) -> u128 {
+        self.tx.effective_gas_price(self.meta.base_fee)
+    }
+}
+
 /// A type that knows how to convert primitive receipts to RPC representations.
 pub trait ReceiptConverter<N: NodePrimitives>: Debug + 'static {
     /// RPC representation.
@@ -974,9 +985,12 @@ impl TryFromTransactionResponse<op_alloy_network::Optimism>
 #[cfg(test)]
 mod transaction_response_tests {
     use super::*;
-    use alloy_consensus::{transaction::Recovered, EthereumTxEnvelope, Signed, TxLegacy};
+    use alloy_consensus::{
+        transaction::Recovered, EthereumTxEnvelope, Signed, TxEip7702, TxEnvelope, TxLegacy,
+    };
+    use alloy_eips::eip7702::{Authorization, SignedAuthorization};
     use alloy_network::Ethereum;
-    use alloy_primitives::{Address, Signature, B256, U256};
+    use alloy_primitives::{address, Address, Signature, B256, U256};
     use alloy_rpc_types_eth::Transaction;
 
     #[test]
@@ -1002,6 +1016,77 @@ mod transaction_response_tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_eip7702_transaction_authorization_list_roundtrip() {
+        let authorization = SignedAuthorization::new_unchecked(
+            Authorization {
+                chain_id: U256::from(1),
+                address: address!("0x000000000000000000000000000000000000aaaa"),
+                nonce: 7,
+            },
+            1,
+            U256::from(2),
+            U256::from(3),
+        );
+        let tx = TxEip7702 {
+            chain_id: 1,
+            nonce: 1,
+            gas_limit: 100_000,
+            max_fee_per_gas: 2,
+            max_priority_fee_per_gas: 1,
+            to: address!("0x000000000000000000000000000000000000bbbb"),
+            authorization_list: vec![authorization],
+            ..Default::default()
+        };
+        let envelope = TxEnvelope::Eip7702(Signed::new_unchecked(
+            tx,
+            Signature::new(U256::ONE, U256::ONE, false),
+            B256::ZERO,
+        ));
+
+        let tx_response = Transaction::<TxEnvelope>::from_consensus_tx(
+            envelope,
+            Address::ZERO,
+            TransactionInfo::default(),
+        )
+        .unwrap();
+
+        let json = serde_json::to_value(&tx_response).unwrap();
+        assert_eq!(
+            json["authorizationList"],
+            serde_json::json!([{
+                "chainId": "0x1",
+                "address": "0x000000000000000000000000000000000000aaaa",
+                "nonce": "0x7",
+                "yParity": "0x1",
+                "r": "0x2",
+                "s": "0x3"
+            }])
+        );
+
+        let decoded: Transaction<TxEnvelope> = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, tx_response);
+    }
+
+    #[test]
+    fn test_non_eip7702_transaction_omits_authorization_list() {
+        let envelope = TxEnvelope::Legacy(Signed::new_unchecked(
+            TxLegacy::default(),
+            Signature::new(U256::ONE, U256::ONE, false),
+            B256::ZERO,
+        ));
+
+        let tx_response = Transaction::<TxEnvelope>::from_consensus_tx(
+            envelope,
+            Address::ZERO,
+            TransactionInfo::default(),
+        )
+        .unwrap();
+
+        let json = serde_json::to_value(&tx_response).unwrap();
+        assert!(json.get("authorizationList").is_none());
+    }
+
     #[cfg(feature = "op")]
     mod op {
         use super::*;
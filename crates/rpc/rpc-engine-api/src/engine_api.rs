@@ -12,7 +12,7 @@ use alloy_rpc_types_engine::{
     CancunPayloadFields, ClientVersionV1, ExecutionData, ExecutionPayloadBodiesV1,
     ExecutionPayloadBodyV1, ExecutionPayloadInputV2, ExecutionPayloadSidecar, ExecutionPayloadV1,
     ExecutionPayloadV3, ForkchoiceState, ForkchoiceUpdated, PayloadId, PayloadStatus,
-    PraguePayloadFields,
+    PayloadStatusEnum, PraguePayloadFields,
 };
 use async_trait::async_trait;
 use jsonrpsee_core::{server::RpcModule, RpcResult};
@@ -32,7 +32,7 @@ use std::{
     sync::Arc,
     time::{Instant, SystemTime},
 };
-use tokio::sync::oneshot;
+use tokio::sync::{oneshot, Semaphore};
 use tracing::{debug, trace, warn};
 
 /// The Engine API response sender.
@@ -94,6 +94,7 @@ where
         capabilities: EngineCapabilities,
         validator: Validator,
         accept_execution_requests_hash: bool,
+        max_concurrent_new_payloads: Option<usize>,
     ) -> Self {
         let inner = Arc::new(EngineApiInner {
             provider,
@@ -107,10 +108,34 @@ where
             tx_pool,
             validator,
             accept_execution_requests_hash,
+            new_payload_permits: max_concurrent_new_payloads.map(Semaphore::new),
         });
         Self { inner }
     }
 
+    /// Forwards the payload to the consensus engine for validation.
+    ///
+    /// If a limit of concurrent payload validations is configured and reached, this responds with
+    /// [`PayloadStatusEnum::Syncing`] instead of queueing the payload.
+    async fn forward_new_payload(
+        &self,
+        payload: PayloadT::ExecutionData,
+    ) -> EngineApiResult<PayloadStatus> {
+        let _permit = match &self.inner.new_payload_permits {
+            Some(permits) => match permits.try_acquire() {
+                Ok(permit) => Some(permit),
+                Err(_) => {
+                    debug!(target: "rpc::engine", "Too many concurrent new payload validations, responding with SYNCING");
+                    self.inner.metrics.new_payload.throttled.increment(1);
+                    return Ok(PayloadStatus::from_status(PayloadStatusEnum::Syncing))
+                }
+            },
+            None => None,
+        };
+
+        Ok(self.inner.beacon_consensus.new_payload(payload).await?)
+    }
+
     /// Fetches the client version.
     pub fn get_client_version_v1(
         &self,
@@ -145,7 +170,7 @@ where
             .validator
             .validate_version_specific_fields(EngineApiMessageVersion::V1, payload_or_attrs)?;
 
-        Ok(self.inner.beacon_consensus.new_payload(payload).await?)
+        self.forward_new_payload(payload).await
     }
 
     /// Metered version of `new_payload_v1`.
@@ -173,7 +198,7 @@ where
         self.inner
             .validator
             .validate_version_specific_fields(EngineApiMessageVersion::V2, payload_or_attrs)?;
-        Ok(self.inner.beacon_consensus.new_payload(payload).await?)
+        self.forward_new_payload(payload).await
     }
 
     /// Metered version of `new_payload_v2`.
@@ -202,7 +227,7 @@ where
             .validator
             .validate_version_specific_fields(EngineApiMessageVersion::V3, payload_or_attrs)?;
 
-        Ok(self.inner.beacon_consensus.new_payload(payload).await?)
+        self.forward_new_payload(payload).await
     }
 
     /// Metrics version of `new_payload_v3`
@@ -232,7 +257,7 @@ where
             .validator
             .validate_version_specific_fields(EngineApiMessageVersion::V4, payload_or_attrs)?;
 
-        Ok(self.inner.beacon_consensus.new_payload(payload).await?)
+        self.forward_new_payload(payload).await
     }
 
     /// Metrics version of `new_payload_v4`
@@ -1155,6 +1180,8 @@ struct EngineApiInner<Provider, PayloadT: PayloadTypes, Pool, Validator, ChainSp
     /// Engine validator.
     validator: Validator,
     accept_execution_requests_hash: bool,
+    /// Limits the number of concurrent `engine_newPayload` validations, if configured.
+    new_payload_permits: Option<Semaphore>,
 }
 
 #[cfg(test)]
@@ -1173,16 +1200,21 @@ mod tests {
     use reth_transaction_pool::noop::NoopTransactionPool;
     use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
 
-    fn setup_engine_api() -> (
-        EngineApiTestHandle,
-        EngineApi<
-            Arc<MockEthProvider>,
-            EthEngineTypes,
-            NoopTransactionPool,
-            EthereumEngineValidator,
-            ChainSpec,
-        >,
-    ) {
+    type TestEngineApi = EngineApi<
+        Arc<MockEthProvider>,
+        EthEngineTypes,
+        NoopTransactionPool,
+        EthereumEngineValidator,
+        ChainSpec,
+    >;
+
+    fn setup_engine_api() -> (EngineApiTestHandle, TestEngineApi) {
+        setup_engine_api_with_new_payload_limit(None)
+    }
+
+    fn setup_engine_api_with_new_payload_limit(
+        max_concurrent_new_payloads: Option<usize>,
+    ) -> (EngineApiTestHandle, TestEngineApi) {
         let client = ClientVersionV1 {
             code: ClientCode::RH,
             name: "Reth".to_string(),
@@ -1206,6 +1238,7 @@ mod tests {
             EngineCapabilities::default(),
             EthereumEngineValidator::new(chain_spec.clone()),
             false,
+            max_concurrent_new_payloads,
         );
         let handle = EngineApiTestHandle { chain_spec, provider, from_api: engine_rx };
         (handle, api)
@@ -1247,6 +1280,55 @@ mod tests {
         assert_matches!(handle.from_api.recv().await, Some(BeaconEngineMessage::NewPayload { .. }));
     }
 
+    #[tokio::test]
+    async fn throttles_concurrent_new_payloads() {
+        let (mut handle, api) = setup_engine_api_with_new_payload_limit(Some(2));
+        let execution_data = || ExecutionData {
+            payload: ExecutionPayloadV1::from_block_slow(&Block::default()).into(),
+            sidecar: ExecutionPayloadSidecar::none(),
+        };
+
+        // occupy all permits with validations the engine hasn't responded to yet
+        let mut pending = Vec::new();
+        let mut responders = Vec::new();
+        for _ in 0..2 {
+            let api = api.clone();
+            let payload = execution_data();
+            pending.push(tokio::spawn(async move { api.new_payload_v1(payload).await }));
+            let Some(BeaconEngineMessage::NewPayload { tx, .. }) = handle.from_api.recv().await
+            else {
+                panic!("expected new payload message")
+            };
+            responders.push(tx);
+        }
+
+        // the next payload is not forwarded to the engine
+        let status = api.new_payload_v1(execution_data()).await.unwrap();
+        assert_eq!(status.status, PayloadStatusEnum::Syncing);
+        assert!(handle.from_api.try_recv().is_err());
+
+        // forkchoice updates are not affected by the limit
+        let api_clone = api.clone();
+        tokio::spawn(async move {
+            api_clone.fork_choice_updated_v1(ForkchoiceState::default(), None).await
+        });
+        assert_matches!(
+            handle.from_api.recv().await,
+            Some(BeaconEngineMessage::ForkchoiceUpdated { .. })
+        );
+
+        // permits are released once the engine responds
+        for tx in responders {
+            tx.send(Ok(PayloadStatus::from_status(PayloadStatusEnum::Valid))).unwrap();
+        }
+        for task in pending {
+            assert_eq!(task.await.unwrap().unwrap().status, PayloadStatusEnum::Valid);
+        }
+        let api_clone = api.clone();
+        tokio::spawn(async move { api_clone.new_payload_v1(execution_data()).await });
+        assert_matches!(handle.from_api.recv().await, Some(BeaconEngineMessage::NewPayload { .. }));
+    }
+
     // tests covering `engine_getPayloadBodiesByRange` and `engine_getPayloadBodiesByHash`
     mod get_payload_bodies {
         use super::*;
@@ -1280,6 +1362,30 @@ mod tests {
             assert_matches!(res, Err(EngineApiError::PayloadRequestTooLarge { .. }));
         }
 
+        #[tokio::test]
+        async fn accepts_max_count() {
+            let mut rng = generators::rng();
+            let (handle, api) = setup_engine_api();
+
+            let blocks = random_block_range(
+                &mut rng,
+                1..=3,
+                BlockRangeParams { tx_count: 0..2, ..Default::default() },
+            );
+            handle
+                .provider
+                .extend_blocks(blocks.iter().cloned().map(|b| (b.hash(), b.into_block())));
+
+            // the range is truncated to the best block, so there are no trailing `None`s
+            let expected = blocks
+                .into_iter()
+                .map(|b| Some(ExecutionPayloadBodyV1::from_block(b.into_block())))
+                .collect::<Vec<_>>();
+            let res =
+                api.get_payload_bodies_by_range_v1(1, MAX_PAYLOAD_BODIES_LIMIT).await.unwrap();
+            assert_eq!(res, expected);
+        }
+
         #[tokio::test]
         async fn returns_payload_bodies() {
             let mut rng = generators::rng();
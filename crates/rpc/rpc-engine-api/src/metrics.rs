@@ -8,6 +8,8 @@ pub(crate) struct EngineApiMetrics {
     pub(crate) latency: EngineApiLatencyMetrics,
     /// Blob-related metrics
     pub(crate) blob_metrics: BlobMetrics,
+    /// `engine_newPayload` throttling metrics
+    pub(crate) new_payload: NewPayloadMetrics,
 }
 
 /// Beacon consensus engine latency metrics.
@@ -48,6 +50,14 @@ pub(crate) struct EngineApiLatencyMetrics {
     pub(crate) get_blobs_v2: Histogram,
 }
 
+/// Metrics for throttled `engine_newPayload` calls.
+#[derive(Metrics)]
+#[metrics(scope = "engine.rpc.new_payload")]
+pub(crate) struct NewPayloadMetrics {
+    /// Number of payloads answered with `SYNCING` because too many validations were in flight
+    pub(crate) throttled: Counter,
+}
+
 #[derive(Metrics)]
 #[metrics(scope = "engine.rpc.blobs")]
 pub(crate) struct BlobMetrics {
@@ -2,7 +2,7 @@
 //! the `eth_` namespace.
 use crate::{
     helpers::{EthApiSpec, EthBlocks, EthCall, EthFees, EthState, EthTransactions, FullEthApi},
-    RpcBlock, RpcHeader, RpcReceipt, RpcTransaction,
+    AsEthApiError, RpcBlock, RpcHeader, RpcReceipt, RpcTransaction,
 };
 use alloy_dyn_abi::TypedData;
 use alloy_eips::{eip2930::AccessListResult, BlockId, BlockNumberOrTag};
@@ -18,8 +18,11 @@ use alloy_serde::JsonStorageKey;
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use reth_primitives_traits::TxTy;
 use reth_rpc_convert::RpcTxReq;
-use reth_rpc_eth_types::FillTransaction;
+use reth_rpc_eth_types::{
+    utils::retry_transient, CallAccessListResult, FillTransaction, TransactionStatus,
+};
 use reth_rpc_server_types::{result::internal_rpc_err, ToRpcResult};
+use std::collections::HashMap;
 use tracing::trace;
 
 /// Helper trait, unifies functionality that must be supported to implement all RPC methods for
@@ -188,6 +191,12 @@ pub trait EthApi<
     #[method(name = "getTransactionReceipt")]
     async fn transaction_receipt(&self, hash: B256) -> RpcResult<Option<R>>;
 
+    /// Returns whether a transaction is mined, pending or queued in the pool, or unknown.
+    ///
+    /// This is a non-standard method.
+    #[method(name = "getTransactionStatus")]
+    async fn transaction_status(&self, hash: B256) -> RpcResult<TransactionStatus>;
+
     /// Returns the balance of the account of given address.
     #[method(name = "getBalance")]
     async fn balance(&self, address: Address, block_number: Option<BlockId>) -> RpcResult<U256>;
@@ -201,6 +210,19 @@ pub trait EthApi<
         block_number: Option<BlockId>,
     ) -> RpcResult<B256>;
 
+    /// Returns the values from multiple storage positions of multiple addresses, all read from the
+    /// state at the same block.
+    ///
+    /// The values of each address are in the order of the requested positions.
+    ///
+    /// This is a non-standard method.
+    #[method(name = "getStorageValues")]
+    async fn storage_values(
+        &self,
+        requests: HashMap<Address, Vec<JsonStorageKey>>,
+        block_number: Option<BlockId>,
+    ) -> RpcResult<HashMap<Address, Vec<B256>>>;
+
     /// Returns the number of transactions sent from an address at given block number.
     #[method(name = "getTransactionCount")]
     async fn transaction_count(
@@ -240,6 +262,31 @@ pub trait EthApi<
         block_overrides: Option<Box<BlockOverrides>>,
     ) -> RpcResult<Bytes>;
 
+    /// Executes a new message call like `eth_call` and additionally returns the accounts and
+    /// storage slots accessed by the call.
+    ///
+    /// This is a non-standard method.
+    #[method(name = "callWithAccessList")]
+    async fn call_with_access_list(
+        &self,
+        request: TxReq,
+        block_number: Option<BlockId>,
+        state_overrides: Option<StateOverride>,
+        block_overrides: Option<Box<BlockOverrides>>,
+    ) -> RpcResult<CallAccessListResult>;
+
+    /// Executes a batch of message calls against the same block state.
+    ///
+    /// Each call is executed independently, state changes are not carried over between calls.
+    ///
+    /// This is a non-standard method.
+    #[method(name = "callBatch")]
+    async fn call_batch(
+        &self,
+        requests: Vec<TxReq>,
+        block_number: Option<BlockId>,
+    ) -> RpcResult<Vec<EthCallResponse>>;
+
     /// Fills the defaults on a given unsigned transaction.
     #[method(name = "fillTransaction")]
     async fn fill_transaction(&self, request: TxReq) -> RpcResult<FillTransaction<RawTx>>;
@@ -306,6 +353,14 @@ pub trait EthApi<
     #[method(name = "blobBaseFee")]
     async fn blob_base_fee(&self) -> RpcResult<U256>;
 
+    /// Returns the projected base fee per gas of the next block, computed from the latest block.
+    ///
+    /// Returns `null` if the latest block has no base fee.
+    ///
+    /// This is a non-standard method.
+    #[method(name = "nextBaseFeePerGas")]
+    async fn next_base_fee_per_gas(&self) -> RpcResult<Option<U256>>;
+
     /// Returns the Transaction fee history
     ///
     /// Introduced in EIP-1559 for getting information on the appropriate priority fee to use.
@@ -454,7 +509,12 @@ where
         full: bool,
     ) -> RpcResult<Option<RpcBlock<T::NetworkTypes>>> {
         trace!(target: "rpc::eth", ?hash, ?full, "Serving eth_getBlockByHash");
-        Ok(EthBlocks::rpc_block(self, hash.into(), full).await?)
+        Ok(retry_transient(
+            self.transient_retry(),
+            || EthBlocks::rpc_block(self, hash.into(), full),
+            T::Error::is_transient,
+        )
+        .await?)
     }
 
     /// Handler for: `eth_getBlockByNumber`
@@ -464,7 +524,12 @@ where
         full: bool,
     ) -> RpcResult<Option<RpcBlock<T::NetworkTypes>>> {
         trace!(target: "rpc::eth", ?number, ?full, "Serving eth_getBlockByNumber");
-        Ok(EthBlocks::rpc_block(self, number.into(), full).await?)
+        Ok(retry_transient(
+            self.transient_retry(),
+            || EthBlocks::rpc_block(self, number.into(), full),
+            T::Error::is_transient,
+        )
+        .await?)
     }
 
     /// Handler for: `eth_getBlockTransactionCountByHash`
@@ -513,7 +578,12 @@ where
         block_id: BlockId,
     ) -> RpcResult<Option<Vec<RpcReceipt<T::NetworkTypes>>>> {
         trace!(target: "rpc::eth", ?block_id, "Serving eth_getBlockReceipts");
-        Ok(EthBlocks::block_receipts(self, block_id).await?)
+        Ok(retry_transient(
+            self.transient_retry(),
+            || EthBlocks::block_receipts(self, block_id),
+            T::Error::is_transient,
+        )
+        .await?)
     }
 
     /// Handler for: `eth_getUncleByBlockHashAndIndex`
@@ -548,11 +618,15 @@ where
         hash: B256,
     ) -> RpcResult<Option<RpcTransaction<T::NetworkTypes>>> {
         trace!(target: "rpc::eth", ?hash, "Serving eth_getTransactionByHash");
-        Ok(EthTransactions::transaction_by_hash(self, hash)
-            .await?
-            .map(|tx| tx.into_transaction(self.converter()))
-            .transpose()
-            .map_err(T::Error::from)?)
+        Ok(retry_transient(
+            self.transient_retry(),
+            || EthTransactions::transaction_by_hash(self, hash),
+            T::Error::is_transient,
+        )
+        .await?
+        .map(|tx| tx.into_transaction(self.converter()))
+        .transpose()
+        .map_err(T::Error::from)?)
     }
 
     /// Handler for: `eth_getRawTransactionByBlockHashAndIndex`
@@ -620,13 +694,29 @@ where
         hash: B256,
     ) -> RpcResult<Option<RpcReceipt<T::NetworkTypes>>> {
         trace!(target: "rpc::eth", ?hash, "Serving eth_getTransactionReceipt");
-        Ok(EthTransactions::transaction_receipt(self, hash).await?)
+        Ok(retry_transient(
+            self.transient_retry(),
+            || EthTransactions::transaction_receipt(self, hash),
+            T::Error::is_transient,
+        )
+        .await?)
+    }
+
+    /// Handler for: `eth_getTransactionStatus`
+    async fn transaction_status(&self, hash: B256) -> RpcResult<TransactionStatus> {
+        trace!(target: "rpc::eth", ?hash, "Serving eth_getTransactionStatus");
+        Ok(EthTransactions::transaction_status(self, hash).await?)
     }
 
     /// Handler for: `eth_getBalance`
     async fn balance(&self, address: Address, block_number: Option<BlockId>) -> RpcResult<U256> {
         trace!(target: "rpc::eth", ?address, ?block_number, "Serving eth_getBalance");
-        Ok(EthState::balance(self, address, block_number).await?)
+        Ok(retry_transient(
+            self.transient_retry(),
+            || EthState::balance(self, address, block_number),
+            T::Error::is_transient,
+        )
+        .await?)
     }
 
     /// Handler for: `eth_getStorageAt`
@@ -637,7 +727,22 @@ where
         block_number: Option<BlockId>,
     ) -> RpcResult<B256> {
         trace!(target: "rpc::eth", ?address, ?block_number, "Serving eth_getStorageAt");
-        Ok(EthState::storage_at(self, address, index, block_number).await?)
+        Ok(retry_transient(
+            self.transient_retry(),
+            || EthState::storage_at(self, address, index, block_number),
+            T::Error::is_transient,
+        )
+        .await?)
+    }
+
+    /// Handler for: `eth_getStorageValues`
+    async fn storage_values(
+        &self,
+        requests: HashMap<Address, Vec<JsonStorageKey>>,
+        block_number: Option<BlockId>,
+    ) -> RpcResult<HashMap<Address, Vec<B256>>> {
+        trace!(target: "rpc::eth", addresses = requests.len(), ?block_number, "Serving eth_getStorageValues");
+        Ok(EthState::storage_values(self, requests, block_number).await?)
     }
 
     /// Handler for: `eth_getTransactionCount`
@@ -647,13 +752,23 @@ where
         block_number: Option<BlockId>,
     ) -> RpcResult<U256> {
         trace!(target: "rpc::eth", ?address, ?block_number, "Serving eth_getTransactionCount");
-        Ok(EthState::transaction_count(self, address, block_number).await?)
+        Ok(retry_transient(
+            self.transient_retry(),
+            || EthState::transaction_count(self, address, block_number),
+            T::Error::is_transient,
+        )
+        .await?)
     }
 
     /// Handler for: `eth_getCode`
     async fn get_code(&self, address: Address, block_number: Option<BlockId>) -> RpcResult<Bytes> {
         trace!(target: "rpc::eth", ?address, ?block_number, "Serving eth_getCode");
-        Ok(EthState::get_code(self, address, block_number).await?)
+        Ok(retry_transient(
+            self.transient_retry(),
+            || EthState::get_code(self, address, block_number),
+            T::Error::is_transient,
+        )
+        .await?)
     }
 
     /// Handler for: `eth_getHeaderByNumber`
@@ -662,13 +777,23 @@ where
         block_number: BlockNumberOrTag,
     ) -> RpcResult<Option<RpcHeader<T::NetworkTypes>>> {
         trace!(target: "rpc::eth", ?block_number, "Serving eth_getHeaderByNumber");
-        Ok(EthBlocks::rpc_block_header(self, block_number.into()).await?)
+        Ok(retry_transient(
+            self.transient_retry(),
+            || EthBlocks::rpc_block_header(self, block_number.into()),
+            T::Error::is_transient,
+        )
+        .await?)
     }
 
     /// Handler for: `eth_getHeaderByHash`
     async fn header_by_hash(&self, hash: B256) -> RpcResult<Option<RpcHeader<T::NetworkTypes>>> {
         trace!(target: "rpc::eth", ?hash, "Serving eth_getHeaderByHash");
-        Ok(EthBlocks::rpc_block_header(self, hash.into()).await?)
+        Ok(retry_transient(
+            self.transient_retry(),
+            || EthBlocks::rpc_block_header(self, hash.into()),
+            T::Error::is_transient,
+        )
+        .await?)
     }
 
     /// Handler for: `eth_simulateV1`
@@ -700,6 +825,34 @@ where
         .await?)
     }
 
+    /// Handler for: `eth_callWithAccessList`
+    async fn call_with_access_list(
+        &self,
+        request: RpcTxReq<T::NetworkTypes>,
+        block_number: Option<BlockId>,
+        state_overrides: Option<StateOverride>,
+        block_overrides: Option<Box<BlockOverrides>>,
+    ) -> RpcResult<CallAccessListResult> {
+        trace!(target: "rpc::eth", ?request, ?block_number, ?state_overrides, ?block_overrides, "Serving eth_callWithAccessList");
+        Ok(EthCall::call_with_access_list(
+            self,
+            request,
+            block_number,
+            EvmOverrides::new(state_overrides, block_overrides),
+        )
+        .await?)
+    }
+
+    /// Handler for: `eth_callBatch`
+    async fn call_batch(
+        &self,
+        requests: Vec<RpcTxReq<T::NetworkTypes>>,
+        block_number: Option<BlockId>,
+    ) -> RpcResult<Vec<EthCallResponse>> {
+        trace!(target: "rpc::eth", ?requests, ?block_number, "Serving eth_callBatch");
+        Ok(EthCall::call_batch(self, requests, block_number).await?)
+    }
+
     /// Handler for: `eth_fillTransaction`
     async fn fill_transaction(
         &self,
@@ -776,6 +929,12 @@ where
         Ok(EthFees::blob_base_fee(self).await?)
     }
 
+    /// Handler for: `eth_nextBaseFeePerGas`
+    async fn next_base_fee_per_gas(&self) -> RpcResult<Option<U256>> {
+        trace!(target: "rpc::eth", "Serving eth_nextBaseFeePerGas");
+        Ok(EthFees::next_block_base_fee(self).await?)
+    }
+
     // FeeHistory is calculated based on lazy evaluation of fees for historical blocks, and further
     // caching of it in the LRU cache.
     // When new RPC call is executed, the cache gets locked, we check it for the historical fees
@@ -3,6 +3,7 @@
 use alloy_json_rpc::RpcObject;
 use alloy_rpc_types_eth::{Filter, FilterChanges, FilterId, Log, PendingTransactionFilterKind};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_rpc_eth_types::logs_utils::LogsWithBlooms;
 use std::future::Future;
 
 /// Rpc Interface for poll-based ethereum filter API.
@@ -39,6 +40,13 @@ pub trait EthFilterApi<T: RpcObject> {
     /// Returns logs matching given filter object.
     #[method(name = "getLogs")]
     async fn logs(&self, filter: Filter) -> RpcResult<Vec<Log>>;
+
+    /// Returns logs matching given filter object, together with the `logsBloom` of every block
+    /// that contains a matching log.
+    ///
+    /// This is a non-standard companion of `eth_getLogs` and does not change its response.
+    #[method(name = "getLogsWithBlooms")]
+    async fn logs_with_blooms(&self, filter: Filter) -> RpcResult<LogsWithBlooms>;
 }
 
 /// Limits for logs queries
@@ -10,10 +10,14 @@ use alloy_eips::BlockId;
 use alloy_rlp::Encodable;
 use alloy_rpc_types_eth::{Block, BlockTransactions, Index};
 use futures::Future;
+use reth_errors::ProviderError;
 use reth_node_api::BlockBody;
 use reth_primitives_traits::{AlloyBlockHeader, RecoveredBlock, SealedHeader, TransactionMeta};
 use reth_rpc_convert::{transaction::ConvertReceiptInput, RpcConvert, RpcHeader};
-use reth_storage_api::{BlockIdReader, BlockReader, ProviderHeader, ProviderReceipt, ProviderTx};
+use reth_storage_api::{
+    BlockBodyIndicesProvider, BlockIdReader, BlockReader, ProviderHeader, ProviderReceipt,
+    ProviderTx,
+};
 use reth_transaction_pool::{PoolTransaction, TransactionPool};
 use std::sync::Arc;
 
@@ -32,6 +36,9 @@ pub type BlockAndReceiptsResult<Eth> = Result<
 /// `eth_` namespace.
 pub trait EthBlocks: LoadBlock<RpcConvert: RpcConvert<Primitives = Self::Primitives>> {
     /// Returns the block header for the given block id.
+    ///
+    /// The `size` of the returned header is the size of the encoded block, so the block is loaded
+    /// through the block cache, but only its header is converted.
     fn rpc_block_header(
         &self,
         block_id: BlockId,
@@ -39,13 +46,22 @@ pub trait EthBlocks: LoadBlock<RpcConvert: RpcConvert<Primitives = Self::Primiti
     where
         Self: FullEthApiTypes,
     {
-        async move { Ok(self.rpc_block(block_id, false).await?.map(|block| block.header)) }
+        async move {
+            let Some(block) = self.recovered_block(block_id).await? else { return Ok(None) };
+            let header =
+                self.converter().convert_header(block.clone_sealed_header(), block.rlp_length())?;
+
+            Ok(Some(header))
+        }
     }
 
     /// Returns the populated rpc block object for the given block id.
     ///
     /// If `full` is true, the block object will contain all transaction objects, otherwise it will
     /// only contain the transaction hashes.
+    ///
+    /// The transactions of the `pending` block are not included in a block yet, so like pending
+    /// pool transactions their block hash, block number and index are not set.
     fn rpc_block(
         &self,
         block_id: BlockId,
@@ -57,9 +73,17 @@ pub trait EthBlocks: LoadBlock<RpcConvert: RpcConvert<Primitives = Self::Primiti
         async move {
             let Some(block) = self.recovered_block(block_id).await? else { return Ok(None) };
 
+            let pending = block_id.is_pending();
             let block = block.clone_into_rpc_block(
                 full.into(),
-                |tx, tx_info| self.converter().fill(tx, tx_info),
+                |tx, mut tx_info| {
+                    if pending {
+                        tx_info.block_hash = None;
+                        tx_info.block_number = None;
+                        tx_info.index = None;
+                    }
+                    self.converter().fill(tx, tx_info)
+                },
                 |header, size| self.converter().convert_header(header, size),
             )?;
             Ok(Some(block))
@@ -68,6 +92,10 @@ pub trait EthBlocks: LoadBlock<RpcConvert: RpcConvert<Primitives = Self::Primiti
 
     /// Returns the number transactions in the given block.
     ///
+    /// For the `pending` tag this is the number of transactions in the pending block, either
+    /// provided by the provider or the locally built one. For canonical blocks requested by number
+    /// only the stored body indices are read, without loading the block's transactions.
+    ///
     /// Returns `None` if the block does not exist
     fn block_transaction_count(
         &self,
@@ -75,21 +103,32 @@ pub trait EthBlocks: LoadBlock<RpcConvert: RpcConvert<Primitives = Self::Primiti
     ) -> impl Future<Output = Result<Option<usize>, Self::Error>> + Send {
         async move {
             if block_id.is_pending() {
-                // Pending block can be fetched directly without need for caching
                 return Ok(self
-                    .provider()
-                    .pending_block()
-                    .map_err(Self::Error::from_eth_err)?
+                    .recovered_block(block_id)
+                    .await?
                     .map(|block| block.body().transaction_count()));
             }
 
-            let block_hash = match self
-                .provider()
-                .block_hash_for_id(block_id)
-                .map_err(Self::Error::from_eth_err)?
+            // canonical blocks requested by number can be counted from the stored body indices
+            if let BlockId::Number(number) = block_id &&
+                let Some(block_number) = self
+                    .provider()
+                    .convert_block_number(number)
+                    .map_err(Self::Error::from_eth_err)?
             {
-                Some(block_hash) => block_hash,
-                None => return Ok(None),
+                match self.provider().block_body_indices(block_number) {
+                    Ok(Some(indices)) => return Ok(Some(indices.tx_count as usize)),
+                    // fall back to the block if the provider doesn't store body indices
+                    Ok(None) | Err(ProviderError::UnsupportedProvider) => {}
+                    Err(err) => return Err(Self::Error::from_eth_err(err)),
+                }
+            }
+
+            // blocks requested by hash are resolved by hash, they might not be canonical
+            let Some(block_hash) =
+                self.provider().block_hash_for_id(block_id).map_err(Self::Error::from_eth_err)?
+            else {
+                return Ok(None)
             };
 
             Ok(self
@@ -25,13 +25,16 @@ use reth_evm::{
 };
 use reth_node_api::BlockBody;
 use reth_primitives_traits::Recovered;
-use reth_revm::{database::StateProviderDatabase, db::State};
+use reth_revm::{
+    database::{StateProviderDatabase, StrictStateProviderDatabase},
+    db::State,
+};
 use reth_rpc_convert::{RpcConvert, RpcTxReq};
 use reth_rpc_eth_types::{
     cache::db::StateProviderTraitObjWrapper,
     error::FromEthApiError,
     simulate::{self, EthSimulateError},
-    EthApiError, StateCacheDb,
+    CallAccessListResult, EthApiError, StateCacheDb, StrictStateCacheDb,
 };
 use reth_storage_api::{BlockIdReader, ProviderTx, StateProvider};
 use revm::{
@@ -219,6 +222,77 @@ pub trait EthCall: EstimateCall + Call + LoadPendingBlock + LoadBlock + FullEthA
         }
     }
 
+    /// Executes the call request like [`Self::call`] and additionally returns the accounts and
+    /// storage slots accessed by the call.
+    ///
+    /// Unlike `eth_createAccessList`, the call is executed exactly once and the observed accesses
+    /// are reported as is. The caller, the callee and precompiles are only included if storage of
+    /// them was accessed.
+    fn call_with_access_list(
+        &self,
+        request: RpcTxReq<<Self::RpcConvert as RpcConvert>::Network>,
+        block_number: Option<BlockId>,
+        overrides: EvmOverrides,
+    ) -> impl Future<Output = Result<CallAccessListResult, Self::Error>> + Send {
+        async move {
+            let this = self.clone();
+            let (res, access_list) = self
+                .spawn_with_call_at(
+                    request,
+                    block_number.unwrap_or_default(),
+                    overrides,
+                    move |db, evm_env, tx_env| {
+                        let mut inspector = AccessListInspector::new(Default::default());
+                        let res =
+                            this.transact_with_inspector(db, evm_env, tx_env, &mut inspector)?;
+                        Ok((res, inspector.into_access_list()))
+                    },
+                )
+                .await?;
+
+            let gas_used = U256::from(res.result.gas_used());
+            let output = Self::Error::ensure_success(res.result)?;
+
+            Ok(CallAccessListResult { output, access_list, gas_used })
+        }
+    }
+
+    /// Executes a batch of call requests against the same state snapshot and returns the output
+    /// of each call.
+    ///
+    /// The state for the given block is resolved once and shared by all calls, but state changes
+    /// of a call are not visible to subsequent calls: each call behaves like an individual
+    /// `eth_call` at the same block.
+    fn call_batch(
+        &self,
+        requests: Vec<RpcTxReq<<Self::RpcConvert as RpcConvert>::Network>>,
+        block_number: Option<BlockId>,
+    ) -> impl Future<Output = Result<Vec<EthCallResponse>, Self::Error>> + Send {
+        async move {
+            if requests.is_empty() {
+                return Err(EthApiError::InvalidParams(String::from("calls are empty.")).into());
+            }
+
+            let (evm_env, at) = self.evm_env_at(block_number.unwrap_or_default()).await?;
+            self.spawn_with_state_at_block(at, move |this, mut db| {
+                let mut results = Vec::with_capacity(requests.len());
+                for request in requests {
+                    let res = this
+                        .prepare_call_env(evm_env.clone(), request, &mut db, Default::default())
+                        .and_then(|(evm_env, tx_env)| this.transact(&mut db, evm_env, tx_env))
+                        .and_then(|res| Self::Error::ensure_success(res.result));
+
+                    results.push(match res {
+                        Ok(output) => EthCallResponse { value: Some(output), error: None },
+                        Err(err) => EthCallResponse { value: None, error: Some(err.to_string()) },
+                    });
+                }
+                Ok(results)
+            })
+            .await
+        }
+    }
+
     /// Simulate arbitrary number of transactions at an arbitrary blockchain index, with the
     /// optionality of state overrides
     fn call_many(
@@ -561,6 +635,32 @@ pub trait Call:
         })
     }
 
+    /// Same as [`spawn_with_state_at_block`](Self::spawn_with_state_at_block) but the database
+    /// returns an error for unknown block hashes instead of a zero hash.
+    ///
+    /// This should be used when replaying historical blocks, so that the `BLOCKHASH` opcode can't
+    /// silently diverge.
+    fn spawn_with_strict_state_at_block<F, R>(
+        &self,
+        at: impl Into<BlockId>,
+        f: F,
+    ) -> impl Future<Output = Result<R, Self::Error>> + Send
+    where
+        F: FnOnce(Self, StrictStateCacheDb) -> Result<R, Self::Error> + Send + 'static,
+        R: Send + 'static,
+    {
+        let at = at.into();
+        self.spawn_blocking_io_fut(move |this| async move {
+            let state = this.state_at_block_id(at).await?;
+            let db = State::builder()
+                .with_database(StrictStateProviderDatabase::new(StateProviderTraitObjWrapper(
+                    state,
+                )))
+                .build();
+            f(this, db)
+        })
+    }
+
     /// Prepares the state and env for the given [`RpcTxReq`] at the given [`BlockId`] and
     /// executes the closure on a new task returning the result of the closure.
     ///
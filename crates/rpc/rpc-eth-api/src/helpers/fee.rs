@@ -49,6 +49,28 @@ pub trait EthFees:
         LoadFee::suggested_priority_fee(self)
     }
 
+    /// Returns the projected base fee per gas of the block following the latest block.
+    ///
+    /// This is the same projection the transaction pool uses for the pending block, see
+    /// [`EthChainSpec::next_block_base_fee_after`]. Returns `None` if the latest block has no
+    /// base fee.
+    fn next_block_base_fee(
+        &self,
+    ) -> impl Future<Output = Result<Option<U256>, Self::Error>> + Send {
+        async move {
+            let header = self
+                .provider()
+                .latest_header()
+                .map_err(Self::Error::from_eth_err)?
+                .ok_or(EthApiError::HeaderNotFound(BlockNumberOrTag::Latest.into()))?;
+            Ok(self
+                .provider()
+                .chain_spec()
+                .next_block_base_fee_after(header.header())
+                .map(U256::from))
+        }
+    }
+
     /// Reports the fee history, for the given amount of blocks, up until the given newest block.
     ///
     /// If `reward_percentiles` are provided the [`FeeHistory`] will include the _approximated_
@@ -219,9 +241,8 @@ pub trait EthFees:
                 // The unwrap is safe since we checked earlier that we got at least 1 header.
                 let last_header = headers.last().expect("is present");
                 base_fee_per_gas.push(
-                    chain_spec
-                        .next_block_base_fee(last_header.header(), last_header.timestamp())
-                        .unwrap_or_default() as u128,
+                    chain_spec.next_block_base_fee_after(last_header.header()).unwrap_or_default()
+                        as u128,
                 );
                 // Same goes for the `base_fee_per_blob_gas`:
                 // > "[..] includes the next block after the newest of the returned range, because this value can be derived from the newest block.
@@ -3,10 +3,11 @@
 use alloy_primitives::{U256, U64};
 use alloy_rpc_types_eth::{Stage, SyncInfo, SyncStatus};
 use futures::Future;
-use reth_chainspec::ChainInfo;
+use reth_chainspec::{ChainInfo, ChainSpecProvider, EthChainSpec};
 use reth_errors::{RethError, RethResult};
 use reth_network_api::NetworkInfo;
 use reth_rpc_convert::RpcTxReq;
+use reth_rpc_server_types::result::RetryConfig;
 use reth_storage_api::{BlockNumReader, StageCheckpointReader, TransactionsProvider};
 
 use crate::{helpers::EthSigner, EthApiTypes, RpcNodeCore};
@@ -27,9 +28,14 @@ pub trait EthApiSpec: RpcNodeCore + EthApiTypes {
         }
     }
 
-    /// Returns the chain id
+    /// Returns the config for retrying reads that failed with a transient provider error.
+    fn transient_retry(&self) -> RetryConfig {
+        RetryConfig::default()
+    }
+
+    /// Returns the chain id of the configured chain spec.
     fn chain_id(&self) -> U64 {
-        U64::from(self.network().chain_id())
+        U64::from(self.provider().chain_spec().chain().id())
     }
 
     /// Returns provider chain info
@@ -13,12 +13,14 @@ use reth_errors::RethError;
 use reth_evm::{ConfigureEvm, EvmEnvFor};
 use reth_rpc_convert::RpcConvert;
 use reth_rpc_eth_types::{
-    error::FromEvmError, EthApiError, PendingBlockEnv, RpcInvalidTransactionError,
+    error::FromEvmError, CachedBytecodeStateProvider, EthApiError, PendingBlockEnv,
+    RpcInvalidTransactionError,
 };
 use reth_storage_api::{
     BlockIdReader, BlockNumReader, StateProvider, StateProviderBox, StateProviderFactory,
 };
 use reth_transaction_pool::TransactionPool;
+use std::collections::HashMap;
 
 /// Helper methods for `eth_` methods relating to state (accounts).
 pub trait EthState: LoadState + SpawnBlocking {
@@ -81,6 +83,37 @@ pub trait EthState: LoadState + SpawnBlocking {
         })
     }
 
+    /// Returns the values of the given storage positions of multiple accounts, all read from the
+    /// same state at the given blocknumber.
+    ///
+    /// The values of each account are in the order of the requested positions, unset positions
+    /// and missing accounts read as zero.
+    fn storage_values(
+        &self,
+        requests: HashMap<Address, Vec<JsonStorageKey>>,
+        block_id: Option<BlockId>,
+    ) -> impl Future<Output = Result<HashMap<Address, Vec<B256>>, Self::Error>> + Send {
+        self.spawn_blocking_io_fut(move |this| async move {
+            let state = this.state_at_block_id_or_latest(block_id).await?;
+            requests
+                .into_iter()
+                .map(|(address, keys)| {
+                    let values = keys
+                        .into_iter()
+                        .map(|key| {
+                            let value = state
+                                .storage(address, key.as_b256())
+                                .map_err(Self::Error::from_eth_err)?
+                                .unwrap_or_default();
+                            Ok(B256::new(value.to_be_bytes()))
+                        })
+                        .collect::<Result<Vec<_>, Self::Error>>()?;
+                    Ok((address, values))
+                })
+                .collect()
+        })
+    }
+
     /// Returns values stored of given account, with Merkle-proof, at given blocknumber.
     fn get_proof(
         &self,
@@ -204,9 +237,18 @@ pub trait LoadState:
         RpcConvert: RpcConvert<Network = Self::NetworkTypes>,
     > + RpcNodeCoreExt
 {
+    /// Wraps the given state with the shared [`BytecodeCache`](reth_rpc_eth_types::BytecodeCache)
+    /// so that bytecode lookups by code hash are served from memory when possible.
+    fn with_bytecode_cache(&self, state: StateProviderBox) -> StateProviderBox {
+        Box::new(CachedBytecodeStateProvider::new(state, self.cache().bytecode_cache().clone()))
+    }
+
     /// Returns the state at the given block number
     fn state_at_hash(&self, block_hash: B256) -> Result<StateProviderBox, Self::Error> {
-        self.provider().history_by_block_hash(block_hash).map_err(Self::Error::from_eth_err)
+        self.provider()
+            .history_by_block_hash(block_hash)
+            .map(|state| self.with_bytecode_cache(state))
+            .map_err(Self::Error::from_eth_err)
     }
 
     /// Returns the state at the given [`BlockId`] enum.
@@ -224,16 +266,22 @@ pub trait LoadState:
             if at.is_pending() &&
                 let Ok(Some(state)) = self.local_pending_state().await
             {
-                return Ok(state)
+                return Ok(self.with_bytecode_cache(state))
             }
 
-            self.provider().state_by_block_id(at).map_err(Self::Error::from_eth_err)
+            self.provider()
+                .state_by_block_id(at)
+                .map(|state| self.with_bytecode_cache(state))
+                .map_err(Self::Error::from_eth_err)
         }
     }
 
     /// Returns the _latest_ state
     fn latest_state(&self) -> Result<StateProviderBox, Self::Error> {
-        self.provider().latest().map_err(Self::Error::from_eth_err)
+        self.provider()
+            .latest()
+            .map(|state| self.with_bytecode_cache(state))
+            .map_err(Self::Error::from_eth_err)
     }
 
     /// Returns the state at the given [`BlockId`] enum or the latest.
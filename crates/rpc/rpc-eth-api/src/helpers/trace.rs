@@ -22,6 +22,13 @@ use std::sync::Arc;
 
 /// Executes CPU heavy tasks.
 pub trait Trace: LoadState<Error: FromEvmError<Self::Evm>> + Call {
+    /// Returns the maximum size in bytes of the serialized output of a single trace request.
+    ///
+    /// Unlimited if `None`.
+    fn max_trace_response_size(&self) -> Option<usize> {
+        None
+    }
+
     /// Executes the [`TxEnvFor`] with [`reth_evm::EvmEnv`] against the given [Database] without
     /// committing state changes.
     fn inspect<DB, I>(
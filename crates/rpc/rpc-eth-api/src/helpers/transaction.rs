@@ -24,14 +24,15 @@ use reth_rpc_convert::{transaction::RpcConvert, RpcTxReq};
 use reth_rpc_eth_types::{
     utils::{binary_search, recover_raw_transaction},
     EthApiError::{self, TransactionConfirmationTimeout},
-    FillTransaction, SignError, TransactionSource,
+    FillTransaction, SignError, TransactionSource, TransactionStatus,
 };
 use reth_storage_api::{
     BlockNumReader, BlockReaderIdExt, ProviderBlock, ProviderReceipt, ProviderTx, ReceiptProvider,
     TransactionsProvider,
 };
 use reth_transaction_pool::{
-    AddedTransactionOutcome, PoolPooledTx, PoolTransaction, TransactionOrigin, TransactionPool,
+    AddedTransactionOutcome, AddedTransactionState, PoolPooledTx, PoolTransaction,
+    TransactionOrigin, TransactionPool,
 };
 use std::{sync::Arc, time::Duration};
 
@@ -299,6 +300,38 @@ pub trait EthTransactions: LoadTransaction<Provider: BlockReaderIdExt> {
         }
     }
 
+    /// Returns the inclusion status of the transaction with the given hash.
+    ///
+    /// Checks the canonical chain first and falls back to the state of the transaction in the
+    /// pool.
+    fn transaction_status(
+        &self,
+        hash: B256,
+    ) -> impl Future<Output = Result<TransactionStatus, Self::Error>> + Send {
+        async move {
+            if let Some((_, meta)) = self
+                .spawn_blocking_io(move |this| {
+                    this.provider()
+                        .transaction_by_hash_with_meta(hash)
+                        .map_err(Self::Error::from_eth_err)
+                })
+                .await?
+            {
+                return Ok(TransactionStatus::Mined {
+                    block_hash: meta.block_hash,
+                    block_number: meta.block_number,
+                    index: meta.index,
+                })
+            }
+
+            Ok(match RpcNodeCore::pool(self).transaction_state(&hash) {
+                Some(AddedTransactionState::Pending) => TransactionStatus::Pending,
+                Some(AddedTransactionState::Queued(reason)) => TransactionStatus::Queued { reason },
+                None => TransactionStatus::Unknown,
+            })
+        }
+    }
+
     /// Find a transaction by sender's address and nonce.
     fn get_transaction_by_sender_and_nonce(
         &self,
@@ -609,20 +642,41 @@ pub trait LoadTransaction: SpawnBlocking + FullEthApiTypes + RpcNodeCoreExt {
     > + Send {
         async move {
             // Try to find the transaction on disk
-            if let Some((tx, meta)) = self
+            if let Some((tx, meta, sender)) = self
                 .spawn_blocking_io(move |this| {
-                    this.provider()
+                    let provider = this.provider();
+                    let Some((tx, meta)) = provider
                         .transaction_by_hash_with_meta(hash)
-                        .map_err(Self::Error::from_eth_err)
+                        .map_err(Self::Error::from_eth_err)?
+                    else {
+                        return Ok(None)
+                    };
+
+                    // mined transactions have their sender stored, which is cheaper to look up
+                    // than recovering it from the signature
+                    let sender = match provider
+                        .transaction_id(hash)
+                        .map_err(Self::Error::from_eth_err)?
+                    {
+                        Some(id) => {
+                            provider.transaction_sender(id).map_err(Self::Error::from_eth_err)?
+                        }
+                        None => None,
+                    };
+
+                    Ok(Some((tx, meta, sender)))
                 })
                 .await?
             {
-                // Note: we assume this transaction is valid, because it's mined (or
-                // part of pending block) and already. We don't need to
-                // check for pre EIP-2 because this transaction could be pre-EIP-2.
-                let transaction = tx
-                    .try_into_recovered_unchecked()
-                    .map_err(|_| EthApiError::InvalidTransactionSignature)?;
+                let transaction = match sender {
+                    Some(sender) => Recovered::new_unchecked(tx, sender),
+                    // Note: we assume this transaction is valid, because it's mined (or
+                    // part of pending block) and already. We don't need to
+                    // check for pre EIP-2 because this transaction could be pre-EIP-2.
+                    None => tx
+                        .try_into_recovered_unchecked()
+                        .map_err(|_| EthApiError::InvalidTransactionSignature)?,
+                };
 
                 return Ok(Some(TransactionSource::Block {
                     transaction,
@@ -7,10 +7,14 @@ use crate::{
     RPC_DEFAULT_GAS_CAP,
 };
 use reqwest::Url;
-use reth_rpc_server_types::constants::{
-    default_max_tracing_requests, DEFAULT_ETH_PROOF_WINDOW, DEFAULT_MAX_BLOCKS_PER_FILTER,
-    DEFAULT_MAX_LOGS_PER_RESPONSE, DEFAULT_MAX_SIMULATE_BLOCKS, DEFAULT_MAX_TRACE_FILTER_BLOCKS,
-    DEFAULT_PROOF_PERMITS, RPC_DEFAULT_SEND_RAW_TX_SYNC_TIMEOUT_SECS,
+use reth_rpc_server_types::{
+    constants::{
+        default_max_tracing_requests, DEFAULT_ETH_PROOF_WINDOW, DEFAULT_MAX_BLOCKS_PER_FILTER,
+        DEFAULT_MAX_LOGS_PER_RESPONSE, DEFAULT_MAX_SIMULATE_BLOCKS,
+        DEFAULT_MAX_TRACE_FILTER_BLOCKS, DEFAULT_PROOF_PERMITS,
+        RPC_DEFAULT_SEND_RAW_TX_SYNC_TIMEOUT_SECS,
+    },
+    result::RetryConfig,
 };
 use serde::{Deserialize, Serialize};
 
@@ -97,6 +101,12 @@ pub struct EthConfig {
     pub send_raw_transaction_sync_timeout: Duration,
     /// Maximum memory the EVM can allocate per RPC request.
     pub rpc_evm_memory_limit: u64,
+    /// Retries of reads that failed with a transient provider error.
+    pub transient_retry: RetryConfig,
+    /// Maximum size in bytes of the serialized output of a single trace request.
+    ///
+    /// Unlimited if `None`.
+    pub max_trace_response_size: Option<usize>,
 }
 
 impl EthConfig {
@@ -129,6 +139,8 @@ impl Default for EthConfig {
             raw_tx_forwarder: ForwardConfig::default(),
             send_raw_transaction_sync_timeout: RPC_DEFAULT_SEND_RAW_TX_SYNC_TIMEOUT_SECS,
             rpc_evm_memory_limit: (1 << 32) - 1,
+            transient_retry: RetryConfig::default(),
+            max_trace_response_size: None,
         }
     }
 }
@@ -225,6 +237,18 @@ impl EthConfig {
         self.rpc_evm_memory_limit = memory_limit;
         self
     }
+
+    /// Configures how reads that failed with a transient provider error are retried.
+    pub const fn transient_retry(mut self, transient_retry: RetryConfig) -> Self {
+        self.transient_retry = transient_retry;
+        self
+    }
+
+    /// Configures the maximum size in bytes of the serialized output of a single trace request.
+    pub const fn max_trace_response_size(mut self, max_trace_response_size: Option<usize>) -> Self {
+        self.max_trace_response_size = max_trace_response_size;
+        self
+    }
 }
 
 /// Config for the filter
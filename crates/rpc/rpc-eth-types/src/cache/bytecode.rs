@@ -0,0 +1,379 @@
+//! Shared bytecode cache keyed by code hash.
+
+use super::metrics::CacheMetrics;
+use alloy_consensus::constants::KECCAK_EMPTY;
+use alloy_primitives::{Address, BlockNumber, Bytes, StorageKey, StorageValue, B256, U256};
+use parking_lot::Mutex;
+use reth_errors::ProviderResult;
+use reth_primitives_traits::{Account, Bytecode};
+use reth_storage_api::{
+    AccountReader, BlockHashReader, BytecodeReader, HashedPostStateProvider, StateProofProvider,
+    StateProvider, StateRootProvider, StorageRootProvider,
+};
+use reth_trie::{
+    updates::TrieUpdates, AccountProof, HashedPostState, HashedStorage, MultiProof,
+    MultiProofTargets, StorageMultiProof, StorageProof, TrieInput,
+};
+use revm::database::BundleState;
+use schnellru::{Limiter, LruMap};
+use std::{fmt, sync::Arc};
+
+/// Fixed per-entry overhead accounted for every cached bytecode, covering the key and the
+/// bookkeeping of the [`Bytecode`] itself.
+const BYTECODE_ENTRY_OVERHEAD: usize = size_of::<B256>() + size_of::<Bytecode>();
+
+/// Returns the number of bytes a cached [`Bytecode`] is accounted for.
+fn bytecode_weight(bytecode: &Bytecode) -> usize {
+    BYTECODE_ENTRY_OVERHEAD + bytecode.original_byte_slice().len()
+}
+
+/// A [`Limiter`] that bounds the total size of the cached bytecodes in bytes.
+#[derive(Debug, Clone, Copy)]
+pub struct ByBytecodeSize {
+    /// Max total size of all cached bytecodes.
+    max_bytes: usize,
+    /// Current total size of all cached bytecodes.
+    used_bytes: usize,
+}
+
+impl ByBytecodeSize {
+    /// Creates a new limiter that allows at most `max_bytes` of bytecode.
+    pub const fn new(max_bytes: usize) -> Self {
+        Self { max_bytes, used_bytes: 0 }
+    }
+
+    /// Returns the total size of all cached bytecodes.
+    pub const fn used_bytes(&self) -> usize {
+        self.used_bytes
+    }
+}
+
+impl Limiter<B256, Bytecode> for ByBytecodeSize {
+    type KeyToInsert<'a> = B256;
+    type LinkType = u32;
+
+    fn is_over_the_limit(&self, _length: usize) -> bool {
+        self.used_bytes > self.max_bytes
+    }
+
+    fn on_insert(
+        &mut self,
+        _length: usize,
+        key: Self::KeyToInsert<'_>,
+        value: Bytecode,
+    ) -> Option<(B256, Bytecode)> {
+        let weight = bytecode_weight(&value);
+        if weight > self.max_bytes {
+            return None;
+        }
+        self.used_bytes += weight;
+        Some((key, value))
+    }
+
+    fn on_replace(
+        &mut self,
+        _length: usize,
+        _old_key: &mut B256,
+        _new_key: Self::KeyToInsert<'_>,
+        old_value: &mut Bytecode,
+        new_value: &mut Bytecode,
+    ) -> bool {
+        let new_weight = bytecode_weight(new_value);
+        if new_weight > self.max_bytes {
+            return false;
+        }
+        self.used_bytes = self.used_bytes - bytecode_weight(old_value) + new_weight;
+        true
+    }
+
+    fn on_removed(&mut self, _key: &mut B256, value: &mut Bytecode) {
+        self.used_bytes -= bytecode_weight(value);
+    }
+
+    fn on_cleared(&mut self) {
+        self.used_bytes = 0;
+    }
+
+    fn on_grow(&mut self, _new_memory_usage: usize) -> bool {
+        true
+    }
+}
+
+/// An LRU cache for contract bytecode keyed by code hash.
+///
+/// Bytecode is content addressed, so the same cache can safely be shared by all requests
+/// regardless of the block they're executed at. The cache is bounded by the total size of the
+/// cached bytecodes.
+#[derive(Clone)]
+pub struct BytecodeCache {
+    inner: Arc<BytecodeCacheInner>,
+}
+
+/// Shared state of a [`BytecodeCache`].
+struct BytecodeCacheInner {
+    cache: Mutex<LruMap<B256, Bytecode, ByBytecodeSize>>,
+    metrics: CacheMetrics,
+}
+
+impl BytecodeCache {
+    /// Creates a new cache that holds at most `max_bytes` of bytecode.
+    pub fn new(max_bytes: usize) -> Self {
+        Self {
+            inner: Arc::new(BytecodeCacheInner {
+                cache: Mutex::new(LruMap::new(ByBytecodeSize::new(max_bytes))),
+                metrics: CacheMetrics::new_with_labels(&[("cache", "bytecode")]),
+            }),
+        }
+    }
+
+    /// Returns the number of cached bytecodes.
+    pub fn len(&self) -> usize {
+        self.inner.cache.lock().len()
+    }
+
+    /// Returns `true` if no bytecode is cached.
+    pub fn is_empty(&self) -> bool {
+        self.inner.cache.lock().is_empty()
+    }
+
+    /// Returns the cached bytecode for the given code hash or fetches it with the given closure.
+    ///
+    /// [`KECCAK_EMPTY`] resolves to empty bytecode without calling the closure. Bytecode that
+    /// doesn't exist is not cached.
+    pub fn get_or_fetch<F>(&self, code_hash: B256, fetch: F) -> ProviderResult<Option<Bytecode>>
+    where
+        F: FnOnce(&B256) -> ProviderResult<Option<Bytecode>>,
+    {
+        if code_hash == KECCAK_EMPTY {
+            return Ok(Some(Bytecode::default()));
+        }
+
+        if let Some(bytecode) = self.inner.cache.lock().get(&code_hash) {
+            self.inner.metrics.hits_total.increment(1);
+            return Ok(Some(bytecode.clone()));
+        }
+        self.inner.metrics.misses_total.increment(1);
+
+        let bytecode = fetch(&code_hash)?;
+        if let Some(bytecode) = &bytecode {
+            let mut cache = self.inner.cache.lock();
+            cache.insert(code_hash, bytecode.clone());
+            self.inner.metrics.cached_count.set(cache.len() as f64);
+            self.inner.metrics.memory_usage.set(cache.limiter().used_bytes() as f64);
+        }
+
+        Ok(bytecode)
+    }
+}
+
+impl fmt::Debug for BytecodeCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cache = self.inner.cache.lock();
+        f.debug_struct("BytecodeCache")
+            .field("len", &cache.len())
+            .field("used_bytes", &cache.limiter().used_bytes())
+            .finish()
+    }
+}
+
+/// A state provider that serves [`BytecodeReader::bytecode_by_hash`] lookups from a shared
+/// [`BytecodeCache`] and delegates everything else to the wrapped provider.
+pub struct CachedBytecodeStateProvider<S> {
+    state_provider: S,
+    cache: BytecodeCache,
+}
+
+impl<S> CachedBytecodeStateProvider<S> {
+    /// Creates a new provider that wraps the given state provider with the given cache.
+    pub const fn new(state_provider: S, cache: BytecodeCache) -> Self {
+        Self { state_provider, cache }
+    }
+
+    /// Returns the wrapped state provider.
+    pub fn into_inner(self) -> S {
+        self.state_provider
+    }
+}
+
+impl<S> fmt::Debug for CachedBytecodeStateProvider<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedBytecodeStateProvider")
+            .field("cache", &self.cache)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: BytecodeReader> BytecodeReader for CachedBytecodeStateProvider<S> {
+    fn bytecode_by_hash(&self, code_hash: &B256) -> ProviderResult<Option<Bytecode>> {
+        self.cache.get_or_fetch(*code_hash, |hash| self.state_provider.bytecode_by_hash(hash))
+    }
+}
+
+impl<S: AccountReader> AccountReader for CachedBytecodeStateProvider<S> {
+    fn basic_account(&self, address: &Address) -> ProviderResult<Option<Account>> {
+        self.state_provider.basic_account(address)
+    }
+}
+
+impl<S: StateProvider> StateProvider for CachedBytecodeStateProvider<S> {
+    fn storage(
+        &self,
+        account: Address,
+        storage_key: StorageKey,
+    ) -> ProviderResult<Option<StorageValue>> {
+        self.state_provider.storage(account, storage_key)
+    }
+
+    fn account_balance(&self, addr: &Address) -> ProviderResult<Option<U256>> {
+        self.state_provider.account_balance(addr)
+    }
+
+    fn account_nonce(&self, addr: &Address) -> ProviderResult<Option<u64>> {
+        self.state_provider.account_nonce(addr)
+    }
+}
+
+impl<S: StateRootProvider> StateRootProvider for CachedBytecodeStateProvider<S> {
+    fn state_root(&self, hashed_state: HashedPostState) -> ProviderResult<B256> {
+        self.state_provider.state_root(hashed_state)
+    }
+
+    fn state_root_from_nodes(&self, input: TrieInput) -> ProviderResult<B256> {
+        self.state_provider.state_root_from_nodes(input)
+    }
+
+    fn state_root_with_updates(
+        &self,
+        hashed_state: HashedPostState,
+    ) -> ProviderResult<(B256, TrieUpdates)> {
+        self.state_provider.state_root_with_updates(hashed_state)
+    }
+
+    fn state_root_from_nodes_with_updates(
+        &self,
+        input: TrieInput,
+    ) -> ProviderResult<(B256, TrieUpdates)> {
+        self.state_provider.state_root_from_nodes_with_updates(input)
+    }
+}
+
+impl<S: StorageRootProvider> StorageRootProvider for CachedBytecodeStateProvider<S> {
+    fn storage_root(
+        &self,
+        address: Address,
+        hashed_storage: HashedStorage,
+    ) -> ProviderResult<B256> {
+        self.state_provider.storage_root(address, hashed_storage)
+    }
+
+    fn storage_proof(
+        &self,
+        address: Address,
+        slot: B256,
+        hashed_storage: HashedStorage,
+    ) -> ProviderResult<StorageProof> {
+        self.state_provider.storage_proof(address, slot, hashed_storage)
+    }
+
+    fn storage_multiproof(
+        &self,
+        address: Address,
+        slots: &[B256],
+        hashed_storage: HashedStorage,
+    ) -> ProviderResult<StorageMultiProof> {
+        self.state_provider.storage_multiproof(address, slots, hashed_storage)
+    }
+}
+
+impl<S: StateProofProvider> StateProofProvider for CachedBytecodeStateProvider<S> {
+    fn proof(
+        &self,
+        input: TrieInput,
+        address: Address,
+        slots: &[B256],
+    ) -> ProviderResult<AccountProof> {
+        self.state_provider.proof(input, address, slots)
+    }
+
+    fn multiproof(
+        &self,
+        input: TrieInput,
+        targets: MultiProofTargets,
+    ) -> ProviderResult<MultiProof> {
+        self.state_provider.multiproof(input, targets)
+    }
+
+    fn witness(&self, input: TrieInput, target: HashedPostState) -> ProviderResult<Vec<Bytes>> {
+        self.state_provider.witness(input, target)
+    }
+}
+
+impl<S: BlockHashReader> BlockHashReader for CachedBytecodeStateProvider<S> {
+    fn block_hash(&self, number: BlockNumber) -> ProviderResult<Option<B256>> {
+        self.state_provider.block_hash(number)
+    }
+
+    fn canonical_hashes_range(
+        &self,
+        start: BlockNumber,
+        end: BlockNumber,
+    ) -> ProviderResult<Vec<B256>> {
+        self.state_provider.canonical_hashes_range(start, end)
+    }
+}
+
+impl<S: HashedPostStateProvider> HashedPostStateProvider for CachedBytecodeStateProvider<S> {
+    fn hashed_post_state(&self, bundle_state: &BundleState) -> HashedPostState {
+        self.state_provider.hashed_post_state(bundle_state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn empty_code_hash_skips_fetch() {
+        let cache = BytecodeCache::new(1024);
+        let code = cache
+            .get_or_fetch(KECCAK_EMPTY, |_| unreachable!("empty code must not be fetched"))
+            .unwrap();
+        assert_eq!(code, Some(Bytecode::default()));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn caches_fetched_bytecode() {
+        let cache = BytecodeCache::new(1024);
+        let bytecode = Bytecode::new_raw(Bytes::from_static(&[0x60, 0x00]));
+        let fetches = Cell::new(0);
+        let fetch = |_: &B256| {
+            fetches.set(fetches.get() + 1);
+            Ok(Some(bytecode.clone()))
+        };
+
+        let hash = B256::with_last_byte(1);
+        assert_eq!(cache.get_or_fetch(hash, fetch).unwrap(), Some(bytecode.clone()));
+        assert_eq!(cache.get_or_fetch(hash, fetch).unwrap(), Some(bytecode.clone()));
+        assert_eq!(fetches.get(), 1);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn evicts_by_total_bytecode_size() {
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![0u8; 100]));
+        let weight = bytecode_weight(&bytecode);
+        let cache = BytecodeCache::new(weight * 2);
+
+        for i in 1..=3 {
+            cache.get_or_fetch(B256::with_last_byte(i), |_| Ok(Some(bytecode.clone()))).unwrap();
+        }
+        assert_eq!(cache.len(), 2);
+
+        // bytecode larger than the entire cache is never cached
+        let large = Bytecode::new_raw(Bytes::from(vec![0u8; weight * 2]));
+        cache.get_or_fetch(B256::with_last_byte(4), |_| Ok(Some(large.clone()))).unwrap();
+        assert_eq!(cache.len(), 2);
+    }
+}
@@ -4,7 +4,7 @@
 
 use alloy_primitives::{Address, B256, U256};
 use reth_errors::ProviderResult;
-use reth_revm::database::StateProviderDatabase;
+use reth_revm::database::{StateProviderDatabase, StrictStateProviderDatabase};
 use reth_storage_api::{BytecodeReader, HashedPostStateProvider, StateProvider, StateProviderBox};
 use reth_trie::{HashedStorage, MultiProofTargets};
 use revm::database::{BundleState, State};
@@ -12,6 +12,12 @@ use revm::database::{BundleState, State};
 /// Helper alias type for the state's [`State`]
 pub type StateCacheDb = State<StateProviderDatabase<StateProviderTraitObjWrapper>>;
 
+/// Helper alias type for the state's [`State`] that errors on unknown block hashes instead of
+/// returning a zero hash, see [`StrictStateProviderDatabase`].
+///
+/// This is used when replaying historical blocks for tracing.
+pub type StrictStateCacheDb = State<StrictStateProviderDatabase<StateProviderTraitObjWrapper>>;
+
 /// Hack to get around 'higher-ranked lifetime error', see
 /// <https://github.com/rust-lang/rust/issues/100013>
 ///
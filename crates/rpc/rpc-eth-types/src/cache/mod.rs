@@ -1,6 +1,6 @@
 //! Async caching support for eth RPC
 
-use super::{EthStateCacheConfig, MultiConsumerLruCache};
+use super::{BytecodeCache, EthStateCacheConfig, MultiConsumerLruCache};
 use alloy_consensus::BlockHeader;
 use alloy_eips::BlockHashOrNumber;
 use alloy_primitives::B256;
@@ -24,6 +24,7 @@ use tokio::sync::{
 };
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
+pub mod bytecode;
 pub mod config;
 pub mod db;
 pub mod metrics;
@@ -72,11 +73,13 @@ type HeaderLruCache<H, L> = MultiConsumerLruCache<B256, H, L, HeaderResponseSend
 #[derive(Debug)]
 pub struct EthStateCache<N: NodePrimitives> {
     to_service: UnboundedSender<CacheAction<N::Block, N::Receipt>>,
+    /// Contract bytecode cache shared by all requests.
+    bytecode_cache: BytecodeCache,
 }
 
 impl<N: NodePrimitives> Clone for EthStateCache<N> {
     fn clone(&self) -> Self {
-        Self { to_service: self.to_service.clone() }
+        Self { to_service: self.to_service.clone(), bytecode_cache: self.bytecode_cache.clone() }
     }
 }
 
@@ -89,6 +92,7 @@ impl<N: NodePrimitives> EthStateCache<N> {
         max_receipts: u32,
         max_headers: u32,
         max_concurrent_db_operations: usize,
+        max_bytecode_bytes: usize,
     ) -> (Self, EthStateCacheService<Provider, Tasks>)
     where
         Provider: BlockReader<Block = N::Block, Receipt = N::Receipt>,
@@ -104,7 +108,7 @@ impl<N: NodePrimitives> EthStateCache<N> {
             action_task_spawner,
             rate_limiter: Arc::new(Semaphore::new(max_concurrent_db_operations)),
         };
-        let cache = Self { to_service };
+        let cache = Self { to_service, bytecode_cache: BytecodeCache::new(max_bytecode_bytes) };
         (cache, service)
     }
 
@@ -137,6 +141,7 @@ impl<N: NodePrimitives> EthStateCache<N> {
             max_receipts,
             max_headers,
             max_concurrent_db_requests,
+            max_bytecode_bytes,
         } = config;
         let (this, service) = Self::create(
             provider,
@@ -145,11 +150,17 @@ impl<N: NodePrimitives> EthStateCache<N> {
             max_receipts,
             max_headers,
             max_concurrent_db_requests,
+            max_bytecode_bytes,
         );
         executor.spawn_critical("eth state cache", Box::pin(service));
         this
     }
 
+    /// Returns the shared contract bytecode cache.
+    pub const fn bytecode_cache(&self) -> &BytecodeCache {
+        &self.bytecode_cache
+    }
+
     /// Requests the  [`RecoveredBlock`] for the block hash
     ///
     /// Returns `None` if the block does not exist.
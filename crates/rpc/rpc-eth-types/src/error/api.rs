@@ -5,6 +5,7 @@ use crate::{EthApiError, RevertError};
 use alloy_primitives::Bytes;
 use reth_errors::ProviderError;
 use reth_evm::{ConfigureEvm, EvmErrorFor, HaltReasonFor};
+use reth_rpc_server_types::result::TransientError;
 use revm::{context::result::ExecutionResult, context_interface::result::HaltReason};
 
 use super::RpcInvalidTransactionError;
@@ -74,6 +75,12 @@ pub trait AsEthApiError {
 
         false
     }
+
+    /// Returns `true` if error is a [`TransientError`], so that the failed read may succeed when
+    /// retried.
+    fn is_transient(&self) -> bool {
+        self.as_err().is_some_and(TransientError::is_transient)
+    }
 }
 
 impl AsEthApiError for EthApiError {
@@ -14,6 +14,7 @@ use reth_primitives_traits::transaction::{error::InvalidTransactionError, signed
 use reth_rpc_convert::{CallFeesError, EthTxEnvError, TransactionConversionError};
 use reth_rpc_server_types::result::{
     block_id_to_str, internal_rpc_err, invalid_params_rpc_err, rpc_err, rpc_error_with_code,
+    TransientError,
 };
 use reth_transaction_pool::error::{
     Eip4844PoolTransactionError, Eip7702PoolTransactionError, InvalidPoolTransactionError,
@@ -147,6 +148,12 @@ pub enum EthApiError {
     /// Error thrown when a (tracing) call exceeds the configured timeout
     #[error("execution aborted (timeout = {0:?})")]
     ExecutionTimedOut(Duration),
+    /// Error thrown when the serialized output of a trace exceeds the configured size limit
+    #[error("trace too large (limit = {limit} bytes)")]
+    TraceTooLarge {
+        /// The configured limit in bytes.
+        limit: usize,
+    },
     /// Internal Error thrown by the javascript tracer
     #[error("{0}")]
     InternalJsTracerError(String),
@@ -261,6 +268,15 @@ impl EthApiError {
     }
 }
 
+impl TransientError for EthApiError {
+    fn is_transient(&self) -> bool {
+        match self {
+            Self::Internal(err) => err.is_transient(),
+            _ => false,
+        }
+    }
+}
+
 impl From<EthApiError> for jsonrpsee_types::error::ErrorObject<'static> {
     fn from(error: EthApiError) -> Self {
         match error {
@@ -307,10 +323,12 @@ impl From<EthApiError> for jsonrpsee_types::error::ErrorObject<'static> {
             EthApiError::Unsupported(msg) => internal_rpc_err(msg),
             EthApiError::InternalJsTracerError(msg) => internal_rpc_err(msg),
             EthApiError::InvalidParams(msg) => invalid_params_rpc_err(msg),
-            err @ EthApiError::ExecutionTimedOut(_) => rpc_error_with_code(
-                jsonrpsee_types::error::CALL_EXECUTION_FAILED_CODE,
-                err.to_string(),
-            ),
+            err @ (EthApiError::ExecutionTimedOut(_) | EthApiError::TraceTooLarge { .. }) => {
+                rpc_error_with_code(
+                    jsonrpsee_types::error::CALL_EXECUTION_FAILED_CODE,
+                    err.to_string(),
+                )
+            }
             err @ (EthApiError::InternalBlockingTaskError | EthApiError::InternalEthError) => {
                 internal_rpc_err(err.to_string())
             }
@@ -920,6 +938,17 @@ pub enum RpcPoolError {
     /// When the transaction gas limit exceeds the maximum transaction gas limit
     #[error("exceeds max transaction gas limit")]
     MaxTxGasLimitExceeded,
+    /// When the transaction access list exceeds the maximum number of entries
+    #[error("access list too large: {entries} entries, limit {limit}")]
+    AccessListTooLarge {
+        /// Number of addresses and storage keys in the access list.
+        entries: usize,
+        /// Configured limit that was exceeded.
+        limit: usize,
+    },
+    /// When the transaction is not replay protected (EIP-155) and such transactions are rejected
+    #[error("only replay-protected (EIP-155) transactions allowed")]
+    UnprotectedTransaction,
     /// Thrown when a new transaction is added to the pool, but then immediately discarded to
     /// respect the tx fee exceeds the configured cap
     #[error("tx fee ({max_tx_fee_wei} wei) exceeds the configured cap ({tx_fee_cap_wei} wei)")]
@@ -979,6 +1008,8 @@ impl From<RpcPoolError> for jsonrpsee_types::error::ErrorObject<'static> {
             RpcPoolError::ReplaceUnderpriced |
             RpcPoolError::ExceedsGasLimit |
             RpcPoolError::MaxTxGasLimitExceeded |
+            RpcPoolError::AccessListTooLarge { .. } |
+            RpcPoolError::UnprotectedTransaction |
             RpcPoolError::ExceedsFeeCap { .. } |
             RpcPoolError::NegativeValue |
             RpcPoolError::OversizedData { .. } |
@@ -1016,6 +1047,10 @@ impl From<InvalidPoolTransactionError> for RpcPoolError {
             InvalidPoolTransactionError::Consensus(err) => Self::Invalid(err.into()),
             InvalidPoolTransactionError::ExceedsGasLimit(_, _) => Self::ExceedsGasLimit,
             InvalidPoolTransactionError::MaxTxGasLimitExceeded(_, _) => Self::MaxTxGasLimitExceeded,
+            InvalidPoolTransactionError::AccessListTooLarge { entries, limit } => {
+                Self::AccessListTooLarge { entries, limit }
+            }
+            InvalidPoolTransactionError::UnprotectedTransaction => Self::UnprotectedTransaction,
             InvalidPoolTransactionError::ExceedsFeeCap { max_tx_fee_wei, tx_fee_cap_wei } => {
                 Self::ExceedsFeeCap { max_tx_fee_wei, tx_fee_cap_wei }
             }
@@ -1085,6 +1120,22 @@ mod tests {
         assert_eq!(err.to_string(), "execution aborted (timeout = 10s)");
     }
 
+    #[test]
+    fn transient_provider_error() {
+        use reth_errors::{DatabaseError, ProviderError};
+
+        // MDBX_BUSY
+        let err = EthApiError::from(ProviderError::Database(DatabaseError::Read((-30778).into())));
+        assert!(TransientError::is_transient(&err));
+
+        // MDBX_CORRUPTED
+        let err = EthApiError::from(ProviderError::Database(DatabaseError::Read((-30796).into())));
+        assert!(!TransientError::is_transient(&err));
+
+        let err = EthApiError::from(ProviderError::BestBlockNotFound);
+        assert!(!TransientError::is_transient(&err));
+    }
+
     #[test]
     fn header_not_found_message() {
         let err: jsonrpsee_types::error::ErrorObject<'static> =
@@ -0,0 +1,49 @@
+//! Types for reporting the fork choice state of the node.
+
+use alloy_eips::BlockNumHash;
+use alloy_primitives::B256;
+use serde::{Deserialize, Serialize};
+
+/// The fork choice state of the node, including the block number of each block.
+///
+/// Mirrors the engine API `ForkchoiceState`: the safe and finalized block are reported as the
+/// zero hash at block `0` until the consensus layer has sent them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForkchoiceStateInfo {
+    /// Hash of the canonical head block.
+    pub head_block_hash: B256,
+    /// Number of the canonical head block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub head_block_number: u64,
+    /// Hash of the safe block.
+    pub safe_block_hash: B256,
+    /// Number of the safe block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub safe_block_number: u64,
+    /// Hash of the finalized block.
+    pub finalized_block_hash: B256,
+    /// Number of the finalized block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub finalized_block_number: u64,
+}
+
+impl ForkchoiceStateInfo {
+    /// Creates the state from the head, safe and finalized blocks.
+    pub fn new(
+        head: BlockNumHash,
+        safe: Option<BlockNumHash>,
+        finalized: Option<BlockNumHash>,
+    ) -> Self {
+        let safe = safe.unwrap_or_default();
+        let finalized = finalized.unwrap_or_default();
+        Self {
+            head_block_hash: head.hash,
+            head_block_number: head.number,
+            safe_block_hash: safe.hash,
+            safe_block_number: safe.number,
+            finalized_block_hash: finalized.hash,
+            finalized_block_number: finalized.number,
+        }
+    }
+}
@@ -8,6 +8,7 @@ use alloy_primitives::{B256, U256};
 use alloy_rpc_types_eth::BlockId;
 use derive_more::{Deref, DerefMut, From, Into};
 use itertools::Itertools;
+use reth_primitives_traits::{Block, RecoveredBlock};
 use reth_rpc_server_types::{
     constants,
     constants::gas_oracle::{
@@ -232,50 +233,9 @@ where
             return Ok(None)
         };
 
-        let base_fee_per_gas = block.base_fee_per_gas();
-        let parent_hash = block.parent_hash();
-
-        // sort the functions by ascending effective tip first
-        let sorted_transactions = block.transactions_recovered().sorted_by_cached_key(|tx| {
-            if let Some(base_fee) = base_fee_per_gas {
-                (*tx).effective_tip_per_gas(base_fee)
-            } else {
-                Some((*tx).priority_fee_or_price())
-            }
-        });
-
-        let mut prices = Vec::with_capacity(limit);
-
-        for tx in sorted_transactions {
-            let effective_tip = if let Some(base_fee) = base_fee_per_gas {
-                tx.effective_tip_per_gas(base_fee)
-            } else {
-                Some(tx.priority_fee_or_price())
-            };
-
-            // ignore transactions with a tip under the configured threshold
-            if let Some(ignore_under) = self.ignore_price &&
-                effective_tip < Some(ignore_under)
-            {
-                continue
-            }
-
-            // check if the sender was the coinbase, if so, ignore
-            if tx.signer() == block.beneficiary() {
-                continue
-            }
+        let prices = lowest_effective_tips(&block, limit, self.ignore_price)?;
 
-            // a `None` effective_gas_tip represents a transaction where the max_fee_per_gas is
-            // less than the base fee which would be invalid
-            prices.push(U256::from(effective_tip.ok_or(RpcInvalidTransactionError::FeeCapTooLow)?));
-
-            // we have enough entries
-            if prices.len() >= limit {
-                break
-            }
-        }
-
-        Ok(Some((parent_hash, prices)))
+        Ok(Some((block.parent_hash(), prices)))
     }
 
     /// Suggests a max priority fee value using a simplified and more predictable algorithm
@@ -386,6 +346,62 @@ where
         Ok(median)
     }
 }
+
+/// Returns the `limit` lowest effective tip values of the block's transactions, in ascending
+/// order.
+///
+/// Tips under the `ignore_price` threshold and transactions sent by the block's beneficiary are
+/// skipped.
+fn lowest_effective_tips<B: Block>(
+    block: &RecoveredBlock<B>,
+    limit: usize,
+    ignore_price: Option<u128>,
+) -> EthResult<Vec<U256>> {
+    let base_fee_per_gas = block.base_fee_per_gas();
+
+    // sort the functions by ascending effective tip first
+    let sorted_transactions = block.transactions_recovered().sorted_by_cached_key(|tx| {
+        if let Some(base_fee) = base_fee_per_gas {
+            (*tx).effective_tip_per_gas(base_fee)
+        } else {
+            Some((*tx).priority_fee_or_price())
+        }
+    });
+
+    let mut prices = Vec::with_capacity(limit);
+
+    for tx in sorted_transactions {
+        let effective_tip = if let Some(base_fee) = base_fee_per_gas {
+            tx.effective_tip_per_gas(base_fee)
+        } else {
+            Some(tx.priority_fee_or_price())
+        };
+
+        // ignore transactions with a tip under the configured threshold
+        if let Some(ignore_under) = ignore_price &&
+            effective_tip < Some(ignore_under)
+        {
+            continue
+        }
+
+        // check if the sender was the coinbase, if so, ignore
+        if tx.signer() == block.beneficiary() {
+            continue
+        }
+
+        // a `None` effective_gas_tip represents a transaction where the max_fee_per_gas is
+        // less than the base fee which would be invalid
+        prices.push(U256::from(effective_tip.ok_or(RpcInvalidTransactionError::FeeCapTooLow)?));
+
+        // we have enough entries
+        if prices.len() >= limit {
+            break
+        }
+    }
+
+    Ok(prices)
+}
+
 /// Container type for mutable inner state of the [`GasPriceOracle`]
 #[derive(Debug)]
 struct GasPriceOracleInner {
@@ -434,6 +450,8 @@ impl Default for GasCap {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_primitives::{Address, Signature};
+    use reth_ethereum_primitives::TransactionSigned;
 
     #[test]
     fn max_price_sanity() {
@@ -445,4 +463,39 @@ mod tests {
     fn ignore_price_sanity() {
         assert_eq!(DEFAULT_IGNORE_GAS_PRICE, U256::from(2u64));
     }
+
+    #[test]
+    fn ignore_price_excludes_low_tips() {
+        let base_fee = 7;
+        let tx = |tip: u128| {
+            let tx = alloy_consensus::TxEip1559 {
+                max_fee_per_gas: base_fee as u128 + tip,
+                max_priority_fee_per_gas: tip,
+                ..Default::default()
+            };
+            TransactionSigned::new_unhashed(tx.into(), Signature::test_signature())
+        };
+        let block = reth_ethereum_primitives::Block {
+            header: alloy_consensus::Header {
+                base_fee_per_gas: Some(base_fee),
+                beneficiary: Address::with_last_byte(0xff),
+                ..Default::default()
+            },
+            body: reth_ethereum_primitives::BlockBody {
+                transactions: vec![tx(GWEI_TO_WEI as u128), tx(0), tx(0)],
+                ..Default::default()
+            },
+        };
+        let block =
+            RecoveredBlock::new_unhashed(block, (1..=3).map(Address::with_last_byte).collect());
+
+        let tips = lowest_effective_tips(&block, SAMPLE_NUMBER, None).unwrap();
+        assert_eq!(tips, vec![U256::ZERO, U256::ZERO, U256::from(GWEI_TO_WEI)]);
+
+        // the zero tips are under the default threshold
+        let tips =
+            lowest_effective_tips(&block, SAMPLE_NUMBER, Some(DEFAULT_IGNORE_GAS_PRICE.to()))
+                .unwrap();
+        assert_eq!(tips, vec![U256::from(GWEI_TO_WEI)]);
+    }
 }
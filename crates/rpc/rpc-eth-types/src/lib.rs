@@ -13,12 +13,14 @@ pub mod builder;
 pub mod cache;
 pub mod error;
 pub mod fee_history;
+pub mod forkchoice;
 pub mod gas_oracle;
 pub mod id_provider;
 pub mod logs_utils;
 pub mod pending_block;
 pub mod receipt;
 pub mod simulate;
+pub mod trace;
 pub mod transaction;
 pub mod tx_forward;
 pub mod utils;
@@ -26,15 +28,19 @@ pub mod utils;
 pub use alloy_rpc_types_eth::FillTransaction;
 pub use builder::config::{EthConfig, EthFilterConfig};
 pub use cache::{
-    config::EthStateCacheConfig, db::StateCacheDb, multi_consumer::MultiConsumerLruCache,
+    bytecode::{BytecodeCache, CachedBytecodeStateProvider},
+    config::EthStateCacheConfig,
+    db::{StateCacheDb, StrictStateCacheDb},
+    multi_consumer::MultiConsumerLruCache,
     EthStateCache,
 };
 pub use error::{EthApiError, EthResult, RevertError, RpcInvalidTransactionError, SignError};
 pub use fee_history::{FeeHistoryCache, FeeHistoryCacheConfig, FeeHistoryEntry};
+pub use forkchoice::ForkchoiceStateInfo;
 pub use gas_oracle::{
     GasCap, GasPriceOracle, GasPriceOracleConfig, GasPriceOracleResult, RPC_DEFAULT_GAS_CAP,
 };
 pub use id_provider::EthSubscriptionIdProvider;
 pub use pending_block::{PendingBlock, PendingBlockEnv, PendingBlockEnvOrigin};
-pub use transaction::TransactionSource;
+pub use transaction::{CallAccessListResult, TransactionSource, TransactionStatus};
 pub use tx_forward::ForwardConfig;
@@ -4,14 +4,39 @@
 
 use alloy_consensus::TxReceipt;
 use alloy_eips::{eip2718::Encodable2718, BlockNumHash};
-use alloy_primitives::TxHash;
+use alloy_primitives::{BlockHash, BlockNumber, Bloom, TxHash};
 use alloy_rpc_types_eth::{Filter, Log};
+use reth_chain_state::CanonStateNotification;
 use reth_chainspec::ChainInfo;
 use reth_errors::ProviderError;
-use reth_primitives_traits::{BlockBody, RecoveredBlock, SignedTransaction};
+use reth_primitives_traits::{BlockBody, NodePrimitives, RecoveredBlock, SignedTransaction};
 use reth_storage_api::{BlockReader, ProviderBlock};
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+/// Response of `eth_getLogsWithBlooms`: the logs matching a filter, together with the `logsBloom`
+/// of every block that contains at least one matching log.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogsWithBlooms {
+    /// All logs matching the filter, same as the `eth_getLogs` response.
+    pub logs: Vec<Log>,
+    /// The blooms of the blocks that contain matching logs, in block order.
+    pub blooms: Vec<BlockLogsBloom>,
+}
+
+/// The `logsBloom` of a block, as stored in its header.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockLogsBloom {
+    /// Number of the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub block_number: BlockNumber,
+    /// Hash of the block.
+    pub block_hash: BlockHash,
+    /// The bloom filter of the block's logs.
+    pub logs_bloom: Bloom,
+}
+
 /// Returns all matching of a block's receipts when the transaction hashes are known.
 pub fn matching_block_logs_with_tx_hashes<'a, I, R>(
     filter: &Filter,
@@ -55,6 +80,33 @@ where
     all_logs
 }
 
+/// Returns all logs of the blocks in the [`CanonStateNotification`] that match the filter.
+///
+/// On reorg, the matching logs of the reverted blocks come first and are marked as `removed`,
+/// followed by the matching logs of the new canonical blocks. A log that is included in both the
+/// old and the new chain segment is therefore returned as a removal and then as an addition.
+pub fn matching_canon_state_logs<N>(
+    filter: &Filter,
+    notification: &CanonStateNotification<N>,
+) -> Vec<Log>
+where
+    N: NodePrimitives<SignedTx: Encodable2718, Receipt: TxReceipt<Log = alloy_primitives::Log>>,
+{
+    notification
+        .block_receipts()
+        .into_iter()
+        .flat_map(|(block_receipts, removed)| {
+            matching_block_logs_with_tx_hashes(
+                filter,
+                block_receipts.block,
+                block_receipts.timestamp,
+                block_receipts.tx_receipts.iter().map(|(tx, receipt)| (*tx, receipt)),
+                removed,
+            )
+        })
+        .collect()
+}
+
 /// Helper enum to fetch a transaction either from a block or from the provider.
 #[derive(Debug)]
 pub enum ProviderOrBlock<'a, P: BlockReader> {
@@ -179,6 +231,126 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_canon_state_logs_reorg() {
+        use alloy_consensus::{Header, SignableTransaction, TxLegacy};
+        use alloy_primitives::{Address, Bytes, Signature};
+        use reth_ethereum_primitives::{
+            Block, BlockBody, EthPrimitives, Receipt, TransactionSigned,
+        };
+        use reth_execution_types::{Chain, ExecutionOutcome};
+        use reth_primitives_traits::{SealedBlock, SealedHeader};
+
+        let log = alloy_primitives::Log::new_unchecked(
+            Address::with_last_byte(1),
+            vec![],
+            Bytes::default(),
+        );
+        // the same transaction with the same log is included in both chain segments
+        let chain = |timestamp| {
+            let tx: TransactionSigned =
+                TxLegacy::default().into_signed(Signature::test_signature()).into();
+            let block = SealedBlock::<Block>::from_sealed_parts(
+                SealedHeader::seal_slow(Header { number: 1, timestamp, ..Default::default() }),
+                BlockBody { transactions: vec![tx], ..Default::default() },
+            )
+            .try_recover()
+            .unwrap();
+            let receipt = Receipt { logs: vec![log.clone()], success: true, ..Default::default() };
+            let outcome = ExecutionOutcome {
+                receipts: vec![vec![receipt]],
+                first_block: 1,
+                ..Default::default()
+            };
+            Arc::new(Chain::<EthPrimitives>::new(vec![block], outcome, None))
+        };
+        let old = chain(1);
+        let new = chain(2);
+        let old_hash = old.tip().hash();
+        let new_hash = new.tip().hash();
+
+        let notification = CanonStateNotification::Reorg { old, new };
+        let logs = matching_canon_state_logs(&Filter::default(), &notification);
+
+        assert_eq!(logs.len(), 2);
+        assert!(logs[0].removed);
+        assert_eq!(logs[0].block_hash, Some(old_hash));
+        assert!(!logs[1].removed);
+        assert_eq!(logs[1].block_hash, Some(new_hash));
+        assert_eq!(logs[0].inner, logs[1].inner);
+    }
+
+    #[test]
+    fn test_topic_or_sets_and_wildcards() {
+        use alloy_primitives::{Address, Bytes, B256};
+        use reth_ethereum_primitives::Receipt;
+
+        let [a, b, c, d, x] = [1, 2, 3, 4, 5].map(B256::with_last_byte);
+        // `[A, null, [B, C]]`: topic0 is A, any topic1 and topic2 is either B or C
+        let filter: Filter = serde_json::from_value(serde_json::json!({
+            "topics": [a, null, [b, c]]
+        }))
+        .unwrap();
+
+        let log = |topics: Vec<B256>| {
+            alloy_primitives::Log::new_unchecked(Address::ZERO, topics, Bytes::default())
+        };
+        let logs = vec![
+            log(vec![a, x, b]),
+            log(vec![a, d, c]),
+            // topic2 not in the set
+            log(vec![a, x, d]),
+            // topic0 mismatch
+            log(vec![x, x, b]),
+            // no topic2
+            log(vec![a, x]),
+            // topics beyond the filter are ignored
+            log(vec![a, a, c, x]),
+        ];
+        let receipt: Receipt = Receipt { logs: logs.clone(), success: true, ..Default::default() };
+
+        let matched = matching_block_logs_with_tx_hashes(
+            &filter,
+            BlockNumHash::new(1, BlockHash::with_last_byte(1)),
+            0,
+            [(TxHash::ZERO, &receipt)],
+            false,
+        );
+        let matched_indices = matched.iter().map(|log| log.log_index.unwrap()).collect::<Vec<_>>();
+        assert_eq!(matched_indices, vec![0, 1, 5]);
+        assert!(matched.iter().all(|log| filter.matches(&log.inner)));
+
+        // the bloom pre-filter applies the same OR semantics per position
+        let bloom_of = |log: &alloy_primitives::Log| {
+            let mut bloom = Bloom::default();
+            bloom.accrue_log(log);
+            bloom
+        };
+        assert!(filter.matches_bloom(bloom_of(&logs[1])));
+        assert!(!filter.matches_bloom(bloom_of(&logs[2])));
+        assert!(!filter.matches_bloom(bloom_of(&logs[3])));
+    }
+
+    #[test]
+    fn test_logs_with_blooms_serde() {
+        let response = LogsWithBlooms {
+            logs: vec![],
+            blooms: vec![BlockLogsBloom {
+                block_number: 0x10,
+                block_hash: BlockHash::with_last_byte(1),
+                logs_bloom: Bloom::with_last_byte(2),
+            }],
+        };
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["blooms"][0]["blockNumber"], "0x10");
+        assert_eq!(json["blooms"][0]["blockHash"], BlockHash::with_last_byte(1).to_string());
+        assert_eq!(json["blooms"][0]["logsBloom"], Bloom::with_last_byte(2).to_string());
+
+        let decoded: LogsWithBlooms = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, response);
+    }
+
     #[test]
     fn test_log_range_from_and_to() {
         let from = 14000000u64;
@@ -20,6 +20,8 @@ pub fn build_receipt<N, E>(
 where
     N: NodePrimitives,
 {
+    // computed once here, consumers of the receipt read the cached field
+    let effective_gas_price = input.effective_gas_price();
     let ConvertReceiptInput { tx, meta, receipt, gas_used, next_log_index } = input;
     let from = tx.signer();
 
@@ -43,7 +45,7 @@ where
         to,
         gas_used,
         contract_address,
-        effective_gas_price: tx.effective_gas_price(meta.base_fee),
+        effective_gas_price,
         // EIP-4844 fields
         blob_gas_price,
         blob_gas_used,
@@ -116,3 +118,78 @@ where
         Ok(receipts)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{Signed, TxEip1559, TxLegacy};
+    use alloy_primitives::{Signature, B256};
+    use reth_chainspec::MAINNET;
+    use reth_ethereum_primitives::{EthPrimitives, TransactionSigned};
+    use reth_primitives_traits::Recovered;
+
+    #[test]
+    fn cached_effective_gas_price_matches_fresh_computation() {
+        let base_fee = 10;
+        let legacy: TransactionSigned = Signed::new_unhashed(
+            TxLegacy { gas_price: 15, gas_limit: 21_000, ..Default::default() },
+            Signature::test_signature(),
+        )
+        .into();
+        // priority fee bound
+        let dynamic_tip: TransactionSigned = Signed::new_unhashed(
+            TxEip1559 {
+                max_fee_per_gas: 100,
+                max_priority_fee_per_gas: 3,
+                gas_limit: 21_000,
+                ..Default::default()
+            },
+            Signature::test_signature(),
+        )
+        .into();
+        // max fee bound
+        let dynamic_cap: TransactionSigned = Signed::new_unhashed(
+            TxEip1559 {
+                max_fee_per_gas: 12,
+                max_priority_fee_per_gas: 5,
+                gas_limit: 21_000,
+                ..Default::default()
+            },
+            Signature::test_signature(),
+        )
+        .into();
+
+        let txs = [(&legacy, 15), (&dynamic_tip, 13), (&dynamic_cap, 12)];
+        let inputs = txs
+            .iter()
+            .enumerate()
+            .map(|(index, (tx, _))| ConvertReceiptInput::<EthPrimitives> {
+                receipt: Receipt::default(),
+                tx: Recovered::new_unchecked(*tx, Address::ZERO),
+                gas_used: 21_000,
+                next_log_index: 0,
+                meta: TransactionMeta {
+                    tx_hash: B256::with_last_byte(index as u8),
+                    index: index as u64,
+                    base_fee: Some(base_fee),
+                    ..Default::default()
+                },
+            })
+            .collect::<Vec<_>>();
+
+        for (input, (tx, expected)) in inputs.iter().zip(txs) {
+            assert_eq!(input.effective_gas_price(), expected);
+            assert_eq!(tx.effective_gas_price(Some(base_fee)), expected);
+        }
+
+        let receipts = ReceiptConverter::<EthPrimitives>::convert_receipts(
+            &EthReceiptConverter::new(MAINNET.clone()),
+            inputs,
+        )
+        .unwrap();
+        for (receipt, (tx, expected)) in receipts.iter().zip(txs) {
+            assert_eq!(receipt.effective_gas_price, expected);
+            assert_eq!(receipt.effective_gas_price, tx.effective_gas_price(Some(base_fee)));
+        }
+    }
+}
@@ -0,0 +1,1242 @@
+//! Types and helpers for extended `trace` namespace responses.
+
+use crate::EthApiError;
+use alloy_dyn_abi::{DynSolValue, EventExt};
+use alloy_json_abi::{Event, JsonAbi};
+use alloy_primitives::{
+    hex, keccak256,
+    map::{HashMap, HashSet},
+    Address, B256, I256, U256,
+};
+use alloy_rpc_types_trace::{
+    geth::{CallFrame, CallLogFrame},
+    parity::{Action, ActionType, CallType, CreationMethod, LocalizedTransactionTrace},
+};
+use reth_rpc_server_types::constants::gas_oracle::CALL_STIPEND_GAS;
+use revm_inspectors::tracing::{
+    types::{CallTraceNode, TraceMemberOrder},
+    CallTraceArena,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    io,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+pub use revm_inspectors::tracing::types::CallKind;
+
+/// Number of calls and combined gas usage of a single precompile.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrecompileCalls {
+    /// Address of the precompile.
+    pub address: Address,
+    /// How many times the precompile was called.
+    pub count: u64,
+    /// Combined gas used by all calls to the precompile.
+    pub gas_used: u64,
+}
+
+/// Number of executions and combined gas cost of a single opcode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpcodeGas {
+    /// How many times the opcode was executed.
+    pub count: u64,
+    /// Combined gas cost of all executions of the opcode.
+    pub gas_used: u64,
+}
+
+/// Parity traces of a transaction, optionally extended with the precompile calls it made.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtendedTransactionTrace {
+    /// The parity traces of the transaction, same as the `trace_transaction` response.
+    pub traces: Vec<LocalizedTransactionTrace>,
+    /// All precompiles called by the transaction, if requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub precompile_calls: Option<Vec<PrecompileCalls>>,
+}
+
+/// A contract creation, successful or not, made by a transaction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedContract {
+    /// Address of the created contract.
+    ///
+    /// For failed creations this is the address the contract would have been deployed to.
+    pub address: Address,
+    /// Address that deployed the contract, either the sender or a contract.
+    pub deployer: Address,
+    /// Keccak256 hash of the init code.
+    pub init_code_hash: B256,
+    /// Whether the contract was created with `CREATE2`.
+    pub create2: bool,
+    /// Whether the creation succeeded.
+    pub success: bool,
+}
+
+/// A [`CreatedContract`] with the transaction that created it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizedCreatedContract {
+    /// Hash of the transaction that created the contract.
+    pub transaction_hash: B256,
+    /// Index of the transaction in the block.
+    pub transaction_position: u64,
+    /// The created contract.
+    #[serde(flatten)]
+    pub contract: CreatedContract,
+}
+
+/// A selfdestruct executed by a transaction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfDestruct {
+    /// Address of the contract that executed the selfdestruct.
+    pub address: Address,
+    /// Address that received the balance of the contract.
+    pub refund_address: Address,
+    /// Balance transferred to the refund address.
+    pub balance: U256,
+    /// Whether the account was deleted.
+    ///
+    /// Since Cancun (EIP-6780) the account is only deleted if it was created in the same
+    /// transaction, otherwise the selfdestruct only transfers its balance.
+    pub destroyed: bool,
+}
+
+/// A [`SelfDestruct`] with the transaction that executed it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizedSelfDestruct {
+    /// Hash of the transaction that executed the selfdestruct.
+    pub transaction_hash: B256,
+    /// Index of the transaction in the block.
+    pub transaction_position: u64,
+    /// The selfdestruct.
+    #[serde(flatten)]
+    pub selfdestruct: SelfDestruct,
+}
+
+/// A value transfer made by a subcall of a transaction, also known as an internal transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InternalTransfer {
+    /// Address that sent the value.
+    pub from: Address,
+    /// Address that received the value, for creations the created contract.
+    pub to: Address,
+    /// The transferred value.
+    pub value: U256,
+    /// Type of the call, e.g. `CALL` or `CREATE2`.
+    #[serde(rename = "type")]
+    pub call_type: String,
+}
+
+/// Fee and payment summary of a block from the point of view of its fee recipient.
+///
+/// Direct payments to the fee recipient, such as MEV payments, are approximated by the balance
+/// changes of the fee recipient that are not explained by priority fees. Transactions sent by the
+/// fee recipient itself are excluded from this approximation, since their balance change is
+/// dominated by the value and gas they pay.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockReward {
+    /// Hash of the block.
+    pub block_hash: B256,
+    /// Number of the block.
+    pub block_number: u64,
+    /// The fee recipient (coinbase) of the block.
+    pub fee_recipient: Address,
+    /// Fees paid by all transactions, `gas_used * effective_gas_price`.
+    pub total_fees: U256,
+    /// Base fee burnt by all transactions, `gas_used * base_fee`.
+    pub burnt_fees: U256,
+    /// Priority fees received by the fee recipient, `total_fees - burnt_fees`.
+    pub priority_fees: U256,
+    /// Net balance change of the fee recipient caused by the transactions of the block.
+    pub fee_recipient_balance_delta: I256,
+    /// Balance change of the fee recipient not explained by priority fees, excluding
+    /// transactions sent by the fee recipient.
+    pub direct_payments: I256,
+}
+
+impl BlockReward {
+    /// Creates an empty summary for the given block.
+    pub fn new(block_hash: B256, block_number: u64, fee_recipient: Address) -> Self {
+        Self { block_hash, block_number, fee_recipient, ..Default::default() }
+    }
+
+    /// Records a transaction of the block.
+    ///
+    /// `balance_before` and `balance_after` are the balances of the fee recipient before and after
+    /// the transaction was executed.
+    pub fn record_transaction(
+        &mut self,
+        sender: Address,
+        gas_used: u64,
+        effective_gas_price: u128,
+        base_fee: u64,
+        balance_before: U256,
+        balance_after: U256,
+    ) {
+        let fees = U256::from(gas_used) * U256::from(effective_gas_price);
+        let burnt = U256::from(gas_used) * U256::from(base_fee);
+        let priority_fees = fees.saturating_sub(burnt);
+        self.total_fees += fees;
+        self.burnt_fees += burnt;
+        self.priority_fees += priority_fees;
+
+        let delta = I256::from_raw(balance_after).wrapping_sub(I256::from_raw(balance_before));
+        self.fee_recipient_balance_delta += delta;
+        if sender != self.fee_recipient {
+            self.direct_payments += delta - I256::from_raw(priority_fees);
+        }
+    }
+}
+
+/// Returns all contract creations recorded in the trace arena, in execution order.
+///
+/// Failed creations are included and flagged as such, a creation that succeeded inside a call that
+/// was reverted later on is still reported as successful.
+pub fn created_contracts(arena: &CallTraceArena) -> Vec<CreatedContract> {
+    arena
+        .nodes()
+        .iter()
+        .filter(|node| node.trace.kind.is_any_create())
+        .map(|node| CreatedContract {
+            address: node.trace.address,
+            deployer: node.trace.caller,
+            init_code_hash: keccak256(&node.trace.data),
+            create2: node.trace.kind == CallKind::Create2,
+            success: node.trace.success,
+        })
+        .collect()
+}
+
+/// Returns all selfdestructs recorded in the trace arena, in execution order.
+///
+/// Selfdestructs that were reverted, because they or one of their parent calls failed, are not
+/// included. `cancun_active` determines whether the EIP-6780 semantics apply, where only contracts
+/// created in the same transaction are deleted.
+pub fn selfdestructs(arena: &CallTraceArena, cancun_active: bool) -> Vec<SelfDestruct> {
+    let nodes = arena.nodes();
+
+    let created = nodes
+        .iter()
+        .filter(|node| node.trace.kind.is_any_create() && !is_reverted(nodes, node))
+        .map(|node| node.trace.address)
+        .collect::<HashSet<_>>();
+
+    nodes
+        .iter()
+        .filter(|node| node.is_selfdestruct() && !is_reverted(nodes, node))
+        .map(|node| {
+            let address = node.trace.selfdestruct_address.unwrap_or(node.trace.address);
+            SelfDestruct {
+                address,
+                refund_address: node.trace.selfdestruct_refund_target.unwrap_or_default(),
+                balance: node.trace.selfdestruct_transferred_value.unwrap_or_default(),
+                destroyed: !cancun_active || created.contains(&address),
+            }
+        })
+        .collect()
+}
+
+/// Returns all subcalls recorded in the trace arena that transferred value, in execution order.
+///
+/// The top-level call of the transaction is not included. Neither are failed subcalls, or subcalls
+/// of failed calls, since their transfers were reverted. Delegate calls don't transfer value and
+/// selfdestructs are not reported as internal transfers.
+pub fn internal_transfers(arena: &CallTraceArena) -> Vec<InternalTransfer> {
+    let nodes = arena.nodes();
+    nodes
+        .iter()
+        .filter(|node| {
+            node.parent.is_some() &&
+                !node.trace.value.is_zero() &&
+                !node.trace.kind.is_delegate() &&
+                !is_reverted(nodes, node)
+        })
+        .map(|node| InternalTransfer {
+            from: node.trace.caller,
+            to: node.trace.address,
+            value: node.trace.value,
+            call_type: node.trace.kind.to_string(),
+        })
+        .collect()
+}
+
+/// Returns `true` if the call or one of its parent calls failed.
+fn is_reverted<'a>(nodes: &'a [CallTraceNode], mut node: &'a CallTraceNode) -> bool {
+    loop {
+        if !node.trace.success {
+            return true
+        }
+        match node.parent {
+            Some(parent) => node = &nodes[parent],
+            None => return false,
+        }
+    }
+}
+
+/// Removes all traces that succeeded and don't lead to a failed trace, so that only the branches
+/// of the call tree that lead to a revert or error remain.
+///
+/// The top-level trace is always kept. The traces are expected in the order of
+/// [`ParityTraceBuilder`](revm_inspectors::tracing::ParityTraceBuilder), with a parent before its
+/// subtraces. The `trace_address` and `subtraces` of the remaining traces are updated to the pruned
+/// call tree.
+pub fn retain_failed_traces(traces: &mut Vec<LocalizedTransactionTrace>) {
+    // whether the subtree of a trace, keyed by its trace address, contains a failed trace
+    let mut failed = HashSet::<Vec<usize>>::default();
+    for trace in traces.iter().rev() {
+        let address = &trace.trace.trace_address;
+        if (trace.trace.error.is_some() || failed.contains(address)) && !address.is_empty() {
+            failed.insert(address[..address.len() - 1].to_vec());
+        }
+    }
+    traces.retain(|trace| {
+        let address = &trace.trace.trace_address;
+        address.is_empty() || trace.trace.error.is_some() || failed.contains(address)
+    });
+
+    // renumber the remaining traces, parents are visited before their subtraces
+    let mut new_addresses = HashMap::<&[usize], Vec<usize>>::default();
+    let mut subtraces = HashMap::<&[usize], usize>::default();
+    let mut renumbered = Vec::with_capacity(traces.len());
+    for trace in traces.iter() {
+        let address = trace.trace.trace_address.as_slice();
+        let mut new_address = Vec::new();
+        if let Some((_, parent)) = address.split_last() {
+            new_address.extend_from_slice(&new_addresses[parent]);
+            let index = subtraces.entry(parent).or_default();
+            new_address.push(*index);
+            *index += 1;
+        }
+        new_addresses.insert(address, new_address.clone());
+        renumbered.push(new_address);
+    }
+    let subtraces = traces
+        .iter()
+        .map(|trace| {
+            subtraces.get(trace.trace.trace_address.as_slice()).copied().unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    for ((trace, trace_address), subtraces) in traces.iter_mut().zip(renumbered).zip(subtraces) {
+        trace.trace.trace_address = trace_address;
+        trace.trace.subtraces = subtraces;
+    }
+}
+
+/// Aggregates all calls into precompiles recorded in the trace arena, ordered by address.
+///
+/// This relies on [`CallTrace::maybe_precompile`](revm_inspectors::tracing::types::CallTrace)
+/// which is only populated if the tracing inspector is configured to exclude precompile calls,
+/// see [`TracingInspectorConfig::set_exclude_precompile_calls`](revm_inspectors::tracing::TracingInspectorConfig::set_exclude_precompile_calls).
+/// Excluded precompile calls are still recorded in the arena, they are only not attached to their
+/// parent call.
+pub fn precompile_calls(arena: &CallTraceArena) -> Vec<PrecompileCalls> {
+    let mut calls = BTreeMap::<Address, PrecompileCalls>::new();
+    for node in arena.nodes().iter().filter(|node| node.is_precompile()) {
+        let entry = calls.entry(node.trace.address).or_insert_with(|| PrecompileCalls {
+            address: node.trace.address,
+            ..Default::default()
+        });
+        entry.count += 1;
+        entry.gas_used += node.trace.gas_used;
+    }
+    calls.into_values().collect()
+}
+
+/// Aggregates the gas cost of all opcodes executed in the trace arena, keyed by opcode name.
+///
+/// Opcodes of all calls are included, also those of failed calls. The gas cost of an opcode doesn't
+/// include the gas forwarded to subcalls, and refunds are not deducted. This requires the tracing
+/// inspector to record steps, see
+/// [`TracingInspectorConfig::steps`](revm_inspectors::tracing::TracingInspectorConfig::steps).
+pub fn opcode_gas(arena: &CallTraceArena) -> BTreeMap<String, OpcodeGas> {
+    let mut opcodes = BTreeMap::<String, OpcodeGas>::new();
+    for node in arena.nodes() {
+        // the recorded cost of a call or create step includes the gas it forwarded to the subcall
+        // that follows it
+        let mut forwarded = vec![0u64; node.trace.steps.len()];
+        let mut last_step = None;
+        for member in &node.ordering {
+            match *member {
+                TraceMemberOrder::Step(step) => last_step = Some(step),
+                TraceMemberOrder::Call(child) => {
+                    if let Some(step) = last_step {
+                        let child = &arena.nodes()[node.children[child]];
+                        forwarded[step] += forwarded_gas(child);
+                    }
+                }
+                TraceMemberOrder::Log(_) => {}
+            }
+        }
+
+        for (step, forwarded) in node.trace.steps.iter().zip(forwarded) {
+            let entry = opcodes.entry(step.op.as_str().to_string()).or_default();
+            entry.count += 1;
+            entry.gas_used += step.gas_cost.saturating_sub(forwarded);
+        }
+    }
+    opcodes
+}
+
+/// Returns the gas the caller forwarded to the given call, which excludes the stipend of calls that
+/// transfer value.
+fn forwarded_gas(node: &CallTraceNode) -> u64 {
+    let receives_stipend = matches!(node.trace.kind, CallKind::Call | CallKind::CallCode) &&
+        !node.trace.value.is_zero();
+    if receives_stipend {
+        node.trace.gas_limit.saturating_sub(CALL_STIPEND_GAS)
+    } else {
+        node.trace.gas_limit
+    }
+}
+
+/// Returns `true` if the parity trace action is a call or create of the given [`CallKind`].
+///
+/// Other actions, such as rewards and selfdestructs, never match.
+pub fn matches_call_kind(action: &Action, kind: CallKind) -> bool {
+    match action {
+        Action::Call(call) => {
+            ActionType::from(kind) == ActionType::Call && call.call_type == CallType::from(kind)
+        }
+        Action::Create(create) => {
+            ActionType::from(kind) == ActionType::Create &&
+                create.creation_method == CreationMethod::from(kind)
+        }
+        _ => false,
+    }
+}
+
+/// A node of a [`CallTraceArena`] in a flat, serializable representation.
+///
+/// The nodes keep their index in the arena and refer to their parent and children by index, so
+/// the structure of a trace can be snapshotted directly, see [`flatten_call_trace_arena`].
+#[cfg(feature = "trace-serde")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlatCallTraceNode {
+    /// Index of the node in the arena.
+    pub idx: usize,
+    /// Index of the parent node, `None` for the root call.
+    pub parent: Option<usize>,
+    /// Indices of the child nodes, in call order.
+    pub children: Vec<usize>,
+    /// Depth of the call, `0` for the root call.
+    pub depth: usize,
+    /// The kind of the call.
+    pub kind: CallKind,
+    /// The caller.
+    pub caller: Address,
+    /// The called or created address.
+    pub address: Address,
+    /// The value transferred by the call.
+    pub value: U256,
+    /// The calldata, or the init code of a create.
+    pub input: alloy_primitives::Bytes,
+    /// The return data, or the deployed code of a create.
+    pub output: alloy_primitives::Bytes,
+    /// The gas limit of the call.
+    pub gas_limit: u64,
+    /// The gas used by the call.
+    pub gas_used: u64,
+    /// Whether the call succeeded.
+    pub success: bool,
+    /// Number of logs emitted by the call itself.
+    pub logs: usize,
+}
+
+#[cfg(feature = "trace-serde")]
+impl From<&CallTraceNode> for FlatCallTraceNode {
+    fn from(node: &CallTraceNode) -> Self {
+        Self {
+            idx: node.idx,
+            parent: node.parent,
+            children: node.children.clone(),
+            depth: node.trace.depth,
+            kind: node.trace.kind,
+            caller: node.trace.caller,
+            address: node.trace.address,
+            value: node.trace.value,
+            input: node.trace.data.clone(),
+            output: node.trace.output.clone(),
+            gas_limit: node.trace.gas_limit,
+            gas_used: node.trace.gas_used,
+            success: node.trace.success,
+            logs: node.logs.len(),
+        }
+    }
+}
+
+/// Converts the call trace arena into a flat list of [`FlatCallTraceNode`]s, in arena order.
+#[cfg(feature = "trace-serde")]
+pub fn flatten_call_trace_arena(arena: &CallTraceArena) -> Vec<FlatCallTraceNode> {
+    arena.nodes().iter().map(FlatCallTraceNode::from).collect()
+}
+
+/// Registry of known events, keyed by their selector (topic0), used to decode logs.
+///
+/// Events that share a selector but differ in which parameters are indexed, such as the ERC-20 and
+/// ERC-721 `Transfer` events, are tried in insertion order. Anonymous events are ignored since they
+/// can't be identified by their topics.
+#[derive(Debug, Clone, Default)]
+pub struct EventSignatureRegistry {
+    events: HashMap<B256, Vec<Event>>,
+}
+
+impl EventSignatureRegistry {
+    /// Creates a registry with all events of the given ABI.
+    pub fn from_abi(abi: &JsonAbi) -> Self {
+        abi.events().cloned().collect()
+    }
+
+    /// Adds an event to the registry.
+    pub fn insert(&mut self, event: Event) {
+        if !event.anonymous {
+            self.events.entry(event.selector()).or_default().push(event);
+        }
+    }
+
+    /// Returns the number of registered events.
+    pub fn len(&self) -> usize {
+        self.events.values().map(Vec::len).sum()
+    }
+
+    /// Returns `true` if no events are registered.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Decodes the raw log with the first registered event that matches its topic0.
+    ///
+    /// Returns `None` if the topic0 is unknown or the log doesn't match any registered event.
+    pub fn decode_log(&self, topics: &[B256], data: &[u8]) -> Option<DecodedLog> {
+        self.events.get(topics.first()?)?.iter().find_map(|event| {
+            let decoded = event.decode_log_parts(topics.iter().copied(), data).ok()?;
+            let mut indexed = decoded.indexed.iter();
+            let mut body = decoded.body.iter();
+            let params = event
+                .inputs
+                .iter()
+                .map(|input| {
+                    let value = if input.indexed { indexed.next() } else { body.next() }?;
+                    Some(DecodedLogParam {
+                        name: input.name.clone(),
+                        ty: input.selector_type().into_owned(),
+                        indexed: input.indexed,
+                        value: sol_value_to_json(value),
+                    })
+                })
+                .collect::<Option<_>>()?;
+            Some(DecodedLog { name: event.name.clone(), signature: event.signature(), params })
+        })
+    }
+
+    /// Decodes the log of a call frame, see [`Self::decode_log`].
+    pub fn decode_call_log(&self, log: &CallLogFrame) -> Option<DecodedLog> {
+        self.decode_log(
+            log.topics.as_deref().unwrap_or_default(),
+            log.data.as_ref().map(|data| data.as_ref()).unwrap_or_default(),
+        )
+    }
+}
+
+impl FromIterator<Event> for EventSignatureRegistry {
+    fn from_iter<I: IntoIterator<Item = Event>>(iter: I) -> Self {
+        let mut registry = Self::default();
+        registry.extend(iter);
+        registry
+    }
+}
+
+impl Extend<Event> for EventSignatureRegistry {
+    fn extend<I: IntoIterator<Item = Event>>(&mut self, iter: I) {
+        for event in iter {
+            self.insert(event);
+        }
+    }
+}
+
+/// An event log decoded with its ABI.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedLog {
+    /// Name of the event.
+    pub name: String,
+    /// Signature of the event, e.g. `Transfer(address,address,uint256)`.
+    pub signature: String,
+    /// The event parameters, in declaration order.
+    pub params: Vec<DecodedLogParam>,
+}
+
+/// A decoded event parameter.
+///
+/// Indexed parameters of dynamic types are only available as the hash of their value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedLogParam {
+    /// Name of the parameter, may be empty.
+    pub name: String,
+    /// Solidity type of the parameter.
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Whether the parameter is indexed.
+    pub indexed: bool,
+    /// The decoded value.
+    pub value: serde_json::Value,
+}
+
+/// A [`CallLogFrame`] of the call tracer with its decoded event, if known.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedCallLogFrame {
+    /// The raw log.
+    #[serde(flatten)]
+    pub log: CallLogFrame,
+    /// The decoded event, `None` if the event is not in the registry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decoded: Option<DecodedLog>,
+}
+
+/// Returns all logs of the call frame and its subcalls, depth-first, decoded with the given
+/// registry.
+///
+/// Logs of unknown events are returned raw.
+pub fn decode_call_frame_logs(
+    frame: &CallFrame,
+    registry: &EventSignatureRegistry,
+) -> Vec<DecodedCallLogFrame> {
+    let mut logs = Vec::new();
+    let mut stack = vec![frame];
+    while let Some(frame) = stack.pop() {
+        logs.extend(frame.logs.iter().map(|log| DecodedCallLogFrame {
+            log: log.clone(),
+            decoded: registry.decode_call_log(log),
+        }));
+        stack.extend(frame.calls.iter().rev());
+    }
+    logs
+}
+
+/// Byte budget for the serialized output of a trace request.
+///
+/// Every converted trace output is charged with its serialized size.
+/// Serialization is aborted as soon as the accumulated size exceeds the limit, so an oversized
+/// response fails without being serialized in full.
+#[derive(Debug, Default)]
+pub struct TraceSizeBudget {
+    /// The limit in bytes, unlimited if `None`.
+    limit: Option<usize>,
+    /// Bytes charged so far.
+    used: AtomicUsize,
+}
+
+impl TraceSizeBudget {
+    /// Creates a budget with the given limit in bytes, unlimited if `None`.
+    pub const fn new(limit: Option<usize>) -> Self {
+        Self { limit, used: AtomicUsize::new(0) }
+    }
+
+    /// Returns the number of bytes charged so far.
+    ///
+    /// This is always zero for an unlimited budget.
+    pub fn used(&self) -> usize {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    /// Charges the serialized size of the given output.
+    ///
+    /// Returns [`EthApiError::TraceTooLarge`] if the accumulated size exceeds the limit.
+    pub fn charge<T: Serialize + ?Sized>(&self, output: &T) -> Result<(), EthApiError> {
+        let Some(limit) = self.limit else { return Ok(()) };
+        let mut counter = SizeCounter { remaining: limit.saturating_sub(self.used()), written: 0 };
+        if serde_json::to_writer(&mut counter, output).is_err() {
+            return Err(EthApiError::TraceTooLarge { limit })
+        }
+        let used = self.used.fetch_add(counter.written, Ordering::Relaxed) + counter.written;
+        if used > limit {
+            return Err(EthApiError::TraceTooLarge { limit })
+        }
+        Ok(())
+    }
+}
+
+/// [`io::Write`] that only counts the written bytes and fails once more than `remaining` bytes
+/// were written.
+#[derive(Debug)]
+struct SizeCounter {
+    remaining: usize,
+    written: usize,
+}
+
+impl io::Write for SizeCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.written += buf.len();
+        if self.written > self.remaining {
+            return Err(io::Error::other("trace size limit exceeded"))
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Converts a decoded value to JSON.
+///
+/// Integers are encoded as decimal strings to not lose precision, bytes as hex strings.
+fn sol_value_to_json(value: &DynSolValue) -> serde_json::Value {
+    match value {
+        DynSolValue::Bool(value) => (*value).into(),
+        DynSolValue::Int(value, _) => value.to_string().into(),
+        DynSolValue::Uint(value, _) => value.to_string().into(),
+        DynSolValue::Address(value) => value.to_string().into(),
+        DynSolValue::String(value) => value.clone().into(),
+        value => match value.as_array().or_else(|| value.as_fixed_seq()) {
+            Some(values) => values.iter().map(sol_value_to_json).collect(),
+            None => hex::encode_prefixed(value.abi_encode_packed()).into(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Bytes;
+    use alloy_rpc_types_trace::parity::{
+        CallAction, CreateAction, RewardAction, RewardType, TransactionTrace,
+    };
+    use revm_inspectors::tracing::types::{CallTrace, CallTraceNode};
+
+    #[test]
+    fn trace_size_budget_charges_serialized_size() {
+        let output = vec![B256::ZERO; 2];
+        let size = serde_json::to_vec(&output).unwrap().len();
+
+        let budget = TraceSizeBudget::new(Some(2 * size));
+        budget.charge(&output).unwrap();
+        assert_eq!(budget.used(), size);
+        budget.charge(&output).unwrap();
+        assert_eq!(budget.used(), 2 * size);
+
+        // the budget is shared across all charged outputs
+        let err = budget.charge(&output).unwrap_err();
+        assert!(matches!(err, EthApiError::TraceTooLarge { limit } if limit == 2 * size));
+
+        // serialization stops once the budget is exceeded
+        let budget = TraceSizeBudget::new(Some(size - 1));
+        assert!(budget.charge(&output).is_err());
+        assert_eq!(budget.used(), 0);
+
+        let budget = TraceSizeBudget::new(None);
+        budget.charge(&output).unwrap();
+        assert_eq!(budget.used(), 0);
+    }
+
+    #[test]
+    fn block_reward_with_fee_recipient_as_sender() {
+        let fee_recipient = Address::with_last_byte(0xfe);
+        let mut reward = BlockReward::new(B256::ZERO, 1, fee_recipient);
+
+        // 21000 gas at 3 gwei with a base fee of 1 gwei and a direct payment of 1 ether
+        let tip = U256::from(21_000u64 * 2_000_000_000);
+        let payment = U256::from(10).pow(U256::from(18));
+        let balance = U256::from(5) * payment;
+        reward.record_transaction(
+            Address::with_last_byte(1),
+            21_000,
+            3_000_000_000,
+            1_000_000_000,
+            balance,
+            balance + tip + payment,
+        );
+
+        // the fee recipient pays out 2 ether, this is not a direct payment
+        let balance = balance + tip + payment;
+        let spent = U256::from(2) * payment + U256::from(21_000u64 * 3_000_000_000);
+        reward.record_transaction(
+            fee_recipient,
+            21_000,
+            3_000_000_000,
+            1_000_000_000,
+            balance,
+            balance - spent + tip,
+        );
+
+        assert_eq!(reward.total_fees, U256::from(2 * 21_000u64 * 3_000_000_000));
+        assert_eq!(reward.burnt_fees, U256::from(2 * 21_000u64 * 1_000_000_000));
+        assert_eq!(reward.priority_fees, tip * U256::from(2));
+        assert_eq!(reward.direct_payments, I256::from_raw(payment));
+        assert_eq!(
+            reward.fee_recipient_balance_delta,
+            I256::from_raw(tip * U256::from(2) + payment) - I256::from_raw(spent)
+        );
+    }
+
+    #[test]
+    fn aggregate_precompile_calls() {
+        let ecrecover = Address::with_last_byte(1);
+        let sha256 = Address::with_last_byte(2);
+        let node = |address, gas_used, maybe_precompile| CallTraceNode {
+            trace: CallTrace { address, gas_used, maybe_precompile, ..Default::default() },
+            ..Default::default()
+        };
+
+        let mut arena = CallTraceArena::default();
+        arena.nodes_mut().clear();
+        arena.nodes_mut().extend([
+            node(Address::with_last_byte(0xaa), 50_000, Some(false)),
+            node(sha256, 72, Some(true)),
+            node(ecrecover, 3_000, Some(true)),
+            node(sha256, 84, Some(true)),
+            // not populated, not counted
+            node(ecrecover, 3_000, None),
+        ]);
+
+        assert_eq!(
+            precompile_calls(&arena),
+            vec![
+                PrecompileCalls { address: ecrecover, count: 1, gas_used: 3_000 },
+                PrecompileCalls { address: sha256, count: 2, gas_used: 156 },
+            ]
+        );
+    }
+
+    #[test]
+    fn collect_created_contracts() {
+        let sender = Address::with_last_byte(0xaa);
+        let factory = Address::with_last_byte(0xbb);
+        let init_code = Bytes::from_static(&[0x60, 0x00, 0x60, 0x00, 0xf3]);
+        let node = |caller, address, kind, success| CallTraceNode {
+            trace: CallTrace {
+                caller,
+                address,
+                kind,
+                success,
+                data: init_code.clone(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut arena = CallTraceArena::default();
+        arena.nodes_mut().clear();
+        arena.nodes_mut().extend([
+            node(sender, factory, CallKind::Call, true),
+            node(factory, Address::with_last_byte(1), CallKind::Create2, true),
+            node(factory, Address::with_last_byte(2), CallKind::Create, false),
+            node(factory, Address::with_last_byte(3), CallKind::DelegateCall, true),
+        ]);
+
+        let init_code_hash = keccak256(&init_code);
+        assert_eq!(
+            created_contracts(&arena),
+            vec![
+                CreatedContract {
+                    address: Address::with_last_byte(1),
+                    deployer: factory,
+                    init_code_hash,
+                    create2: true,
+                    success: true,
+                },
+                CreatedContract {
+                    address: Address::with_last_byte(2),
+                    deployer: factory,
+                    init_code_hash,
+                    create2: false,
+                    success: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "trace-serde")]
+    fn flatten_call_trace_arena_snapshot() {
+        let sender = Address::with_last_byte(0xaa);
+        let factory = Address::with_last_byte(0xbb);
+        let node = |idx, parent, children: &[usize], trace| CallTraceNode {
+            idx,
+            parent,
+            children: children.to_vec(),
+            trace,
+            ..Default::default()
+        };
+
+        let mut arena = CallTraceArena::default();
+        arena.nodes_mut().clear();
+        arena.nodes_mut().extend([
+            node(
+                0,
+                None,
+                &[1, 2],
+                CallTrace {
+                    caller: sender,
+                    address: factory,
+                    kind: CallKind::Call,
+                    value: U256::from(1),
+                    data: Bytes::from_static(&[0x01]),
+                    gas_limit: 100_000,
+                    gas_used: 50_000,
+                    success: true,
+                    ..Default::default()
+                },
+            ),
+            node(
+                1,
+                Some(0),
+                &[],
+                CallTrace {
+                    depth: 1,
+                    caller: factory,
+                    address: Address::with_last_byte(1),
+                    kind: CallKind::Create2,
+                    output: Bytes::from_static(&[0x02]),
+                    gas_limit: 60_000,
+                    gas_used: 30_000,
+                    success: true,
+                    ..Default::default()
+                },
+            ),
+            node(
+                2,
+                Some(0),
+                &[],
+                CallTrace {
+                    depth: 1,
+                    caller: factory,
+                    address: Address::with_last_byte(2),
+                    kind: CallKind::StaticCall,
+                    gas_limit: 10_000,
+                    gas_used: 10_000,
+                    ..Default::default()
+                },
+            ),
+        ]);
+        arena.nodes_mut()[2].logs.push(Default::default());
+
+        let flat = flatten_call_trace_arena(&arena);
+        let expected = serde_json::json!([
+            {
+                "idx": 0,
+                "parent": null,
+                "children": [1, 2],
+                "depth": 0,
+                "kind": "CALL",
+                "caller": "0x00000000000000000000000000000000000000aa",
+                "address": "0x00000000000000000000000000000000000000bb",
+                "value": "0x1",
+                "input": "0x01",
+                "output": "0x",
+                "gasLimit": 100000,
+                "gasUsed": 50000,
+                "success": true,
+                "logs": 0
+            },
+            {
+                "idx": 1,
+                "parent": 0,
+                "children": [],
+                "depth": 1,
+                "kind": "CREATE2",
+                "caller": "0x00000000000000000000000000000000000000bb",
+                "address": "0x0000000000000000000000000000000000000001",
+                "value": "0x0",
+                "input": "0x",
+                "output": "0x02",
+                "gasLimit": 60000,
+                "gasUsed": 30000,
+                "success": true,
+                "logs": 0
+            },
+            {
+                "idx": 2,
+                "parent": 0,
+                "children": [],
+                "depth": 1,
+                "kind": "STATICCALL",
+                "caller": "0x00000000000000000000000000000000000000bb",
+                "address": "0x0000000000000000000000000000000000000002",
+                "value": "0x0",
+                "input": "0x",
+                "output": "0x",
+                "gasLimit": 10000,
+                "gasUsed": 10000,
+                "success": false,
+                "logs": 1
+            }
+        ]);
+        assert_eq!(serde_json::to_value(&flat).unwrap(), expected);
+
+        // the representation roundtrips
+        let decoded: Vec<FlatCallTraceNode> = serde_json::from_value(expected).unwrap();
+        assert_eq!(decoded, flat);
+    }
+
+    #[test]
+    fn retain_only_failed_branches() {
+        let trace =
+            |trace_address: &[usize], subtraces, error: Option<&str>| LocalizedTransactionTrace {
+                trace: TransactionTrace {
+                    trace_address: trace_address.to_vec(),
+                    subtraces,
+                    error: error.map(String::from),
+                    ..Default::default()
+                },
+                block_hash: None,
+                block_number: Some(1),
+                transaction_hash: None,
+                transaction_position: Some(0),
+            };
+
+        // the second call succeeds, but one of its subcalls reverts
+        let mut traces = vec![
+            trace(&[], 3, None),
+            trace(&[0], 0, None),
+            trace(&[1], 2, None),
+            trace(&[1, 0], 0, None),
+            trace(&[1, 1], 1, Some("Reverted")),
+            trace(&[1, 1, 0], 0, None),
+            trace(&[2], 0, Some("out of gas")),
+        ];
+        retain_failed_traces(&mut traces);
+
+        assert_eq!(
+            traces,
+            vec![
+                trace(&[], 2, None),
+                trace(&[0], 1, None),
+                trace(&[0, 0], 0, Some("Reverted")),
+                trace(&[1], 0, Some("out of gas")),
+            ]
+        );
+
+        // only the top-level trace remains of a successful transaction
+        let mut traces = vec![trace(&[], 1, None), trace(&[0], 0, None)];
+        retain_failed_traces(&mut traces);
+        assert_eq!(traces, vec![trace(&[], 0, None)]);
+    }
+
+    #[test]
+    fn collect_internal_transfers() {
+        let sender = Address::with_last_byte(0xaa);
+        let splitter = Address::with_last_byte(0xbb);
+        let node = |parent, caller, address, kind, value: u64, success| CallTraceNode {
+            parent,
+            trace: CallTrace {
+                caller,
+                address,
+                kind,
+                value: U256::from(value),
+                success,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut arena = CallTraceArena::default();
+        arena.nodes_mut().clear();
+        arena.nodes_mut().extend([
+            // top-level call, not internal
+            node(None, sender, splitter, CallKind::Call, 10, true),
+            node(Some(0), splitter, Address::with_last_byte(1), CallKind::Call, 3, true),
+            // no value
+            node(Some(0), splitter, Address::with_last_byte(2), CallKind::StaticCall, 0, true),
+            // the apparent value of a delegate call is not transferred
+            node(Some(0), splitter, Address::with_last_byte(3), CallKind::DelegateCall, 10, true),
+            node(Some(0), splitter, Address::with_last_byte(4), CallKind::Create2, 2, true),
+            // failed transfer
+            node(Some(0), splitter, Address::with_last_byte(5), CallKind::Call, 1, false),
+            // transfer inside a reverted call
+            node(Some(0), splitter, Address::with_last_byte(6), CallKind::Call, 0, false),
+            node(Some(6), Address::with_last_byte(6), sender, CallKind::Call, 1, true),
+            node(Some(1), Address::with_last_byte(1), sender, CallKind::Call, 1, true),
+        ]);
+
+        let transfer = |from, to, value: u64, call_type: &str| InternalTransfer {
+            from,
+            to,
+            value: U256::from(value),
+            call_type: call_type.to_string(),
+        };
+        assert_eq!(
+            internal_transfers(&arena),
+            vec![
+                transfer(splitter, Address::with_last_byte(1), 3, "CALL"),
+                transfer(splitter, Address::with_last_byte(4), 2, "CREATE2"),
+                transfer(Address::with_last_byte(1), sender, 1, "CALL"),
+            ]
+        );
+    }
+
+    #[test]
+    fn collect_selfdestructs() {
+        let created = Address::with_last_byte(1);
+        let existing = Address::with_last_byte(2);
+        let refund_address = Address::with_last_byte(0xaa);
+        let node = |parent, address, kind, success| CallTraceNode {
+            parent,
+            trace: CallTrace { address, kind, success, ..Default::default() },
+            ..Default::default()
+        };
+        let selfdestruct = |parent, address, balance| {
+            let mut node = node(parent, address, CallKind::Call, true);
+            node.trace.selfdestruct_address = Some(address);
+            node.trace.selfdestruct_refund_target = Some(refund_address);
+            node.trace.selfdestruct_transferred_value = Some(U256::from(balance));
+            node
+        };
+
+        let mut arena = CallTraceArena::default();
+        arena.nodes_mut().clear();
+        arena.nodes_mut().extend([
+            node(None, Address::with_last_byte(0xbb), CallKind::Call, true),
+            node(Some(0), created, CallKind::Create, true),
+            selfdestruct(Some(0), created, 5),
+            selfdestruct(Some(0), existing, 7),
+            // reverted call, its selfdestruct has no effect
+            node(Some(0), Address::with_last_byte(3), CallKind::Call, false),
+            selfdestruct(Some(4), Address::with_last_byte(4), 9),
+        ]);
+
+        // only the contract created in the same transaction is deleted
+        assert_eq!(
+            selfdestructs(&arena, true),
+            vec![
+                SelfDestruct {
+                    address: created,
+                    refund_address,
+                    balance: U256::from(5),
+                    destroyed: true,
+                },
+                SelfDestruct {
+                    address: existing,
+                    refund_address,
+                    balance: U256::from(7),
+                    destroyed: false,
+                },
+            ]
+        );
+
+        // before Cancun every selfdestruct deletes the account
+        assert!(selfdestructs(&arena, false).iter().all(|selfdestruct| selfdestruct.destroyed));
+        assert_eq!(selfdestructs(&arena, false).len(), 2);
+    }
+
+    #[test]
+    fn decode_call_logs_with_registry() {
+        let abi = JsonAbi::parse([
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+            "event Transfer(address indexed from, address indexed to, uint256 indexed tokenId)",
+            "event Named(string indexed key, string value)",
+        ])
+        .unwrap();
+        let registry = EventSignatureRegistry::from_abi(&abi);
+        assert_eq!(registry.len(), 3);
+
+        let from = Address::with_last_byte(1);
+        let to = Address::with_last_byte(2);
+        let transfer = keccak256("Transfer(address,address,uint256)");
+        let erc20 = CallLogFrame {
+            topics: Some(vec![transfer, from.into_word(), to.into_word()]),
+            data: Some(alloy_primitives::U256::from(100).to_be_bytes::<32>().into()),
+            ..Default::default()
+        };
+        let erc721 = CallLogFrame {
+            topics: Some(vec![transfer, from.into_word(), to.into_word(), B256::with_last_byte(7)]),
+            data: Some(Default::default()),
+            ..Default::default()
+        };
+        let unknown = CallLogFrame {
+            topics: Some(vec![B256::with_last_byte(0xff)]),
+            data: Some(Default::default()),
+            ..Default::default()
+        };
+        let frame = CallFrame {
+            logs: vec![erc20],
+            calls: vec![CallFrame { logs: vec![erc721, unknown], ..Default::default() }],
+            ..Default::default()
+        };
+
+        let logs = decode_call_frame_logs(&frame, &registry);
+        assert_eq!(logs.len(), 3);
+
+        let decoded = logs[0].decoded.as_ref().unwrap();
+        assert_eq!(decoded.name, "Transfer");
+        assert_eq!(decoded.signature, "Transfer(address,address,uint256)");
+        assert_eq!(
+            decoded.params,
+            vec![
+                DecodedLogParam {
+                    name: "from".to_string(),
+                    ty: "address".to_string(),
+                    indexed: true,
+                    value: from.to_string().into(),
+                },
+                DecodedLogParam {
+                    name: "to".to_string(),
+                    ty: "address".to_string(),
+                    indexed: true,
+                    value: to.to_string().into(),
+                },
+                DecodedLogParam {
+                    name: "value".to_string(),
+                    ty: "uint256".to_string(),
+                    indexed: false,
+                    value: "100".into(),
+                },
+            ]
+        );
+
+        // same selector, the event with the matching indexed parameters is used
+        let decoded = logs[1].decoded.as_ref().unwrap();
+        assert_eq!(decoded.params[2].name, "tokenId");
+        assert_eq!(decoded.params[2].value, serde_json::Value::from("7"));
+
+        // unknown topic0 falls back to the raw log
+        assert_eq!(logs[2].decoded, None);
+        assert_eq!(logs[2].log.topics, Some(vec![B256::with_last_byte(0xff)]));
+        let json = serde_json::to_value(&logs[2]).unwrap();
+        assert!(json.get("decoded").is_none());
+        assert!(json.get("topics").is_some());
+    }
+
+    #[test]
+    fn filter_actions_by_call_kind() {
+        let call = |call_type| Action::Call(CallAction { call_type, ..Default::default() });
+        let create = |creation_method| {
+            Action::Create(CreateAction { creation_method, ..Default::default() })
+        };
+        let actions = [
+            call(CallType::Call),
+            call(CallType::DelegateCall),
+            create(CreationMethod::Create),
+            create(CreationMethod::Create2),
+            Action::Reward(RewardAction {
+                author: Address::ZERO,
+                reward_type: RewardType::Block,
+                value: U256::ZERO,
+            }),
+        ];
+
+        let create2 = actions
+            .iter()
+            .filter(|action| matches_call_kind(action, CallKind::Create2))
+            .collect::<Vec<_>>();
+        assert_eq!(create2, [&create(CreationMethod::Create2)]);
+
+        let delegate_calls = actions
+            .iter()
+            .filter(|action| matches_call_kind(action, CallKind::DelegateCall))
+            .collect::<Vec<_>>();
+        assert_eq!(delegate_calls, [&call(CallType::DelegateCall)]);
+    }
+}
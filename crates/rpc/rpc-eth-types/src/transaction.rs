@@ -2,11 +2,14 @@
 //!
 //! Transaction wrapper that labels transaction with its origin.
 
-use alloy_primitives::B256;
+use alloy_eips::eip2930::AccessList;
+use alloy_primitives::{Bytes, B256, U256};
 use alloy_rpc_types_eth::TransactionInfo;
 use reth_ethereum_primitives::TransactionSigned;
 use reth_primitives_traits::{NodePrimitives, Recovered, SignedTransaction};
 use reth_rpc_convert::{RpcConvert, RpcTransaction};
+use reth_transaction_pool::QueuedReason;
+use serde::{Deserialize, Serialize};
 
 /// Represents from where a transaction was fetched.
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -30,6 +33,32 @@ pub enum TransactionSource<T = TransactionSigned> {
     },
 }
 
+/// The inclusion status of a transaction, as returned by `eth_getTransactionStatus`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum TransactionStatus {
+    /// Transaction is included in a canonical block.
+    Mined {
+        /// Hash of the block.
+        block_hash: B256,
+        /// Number of the block.
+        #[serde(with = "alloy_serde::quantity")]
+        block_number: u64,
+        /// Index of the transaction in the block.
+        #[serde(with = "alloy_serde::quantity")]
+        index: u64,
+    },
+    /// Transaction is in the pool and ready to be included in the next block.
+    Pending,
+    /// Transaction is in the pool but not ready to be included in the next block.
+    Queued {
+        /// Why the transaction is not ready.
+        reason: QueuedReason,
+    },
+    /// Transaction is neither mined nor in the pool.
+    Unknown,
+}
+
 // === impl TransactionSource ===
 
 impl<T: SignedTransaction> TransactionSource<T> {
@@ -94,3 +123,48 @@ impl<T> From<TransactionSource<T>> for Recovered<T> {
         }
     }
 }
+
+/// Output of `eth_callWithAccessList`: the return data of a call and the accounts and storage
+/// slots it accessed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallAccessListResult {
+    /// The return data of the call.
+    pub output: Bytes,
+    /// The accounts and storage slots accessed by the call.
+    pub access_list: AccessList,
+    /// The gas used by the call.
+    pub gas_used: U256,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transaction_status_serde() {
+        let status =
+            TransactionStatus::Mined { block_hash: B256::ZERO, block_number: 16, index: 2 };
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "status": "mined",
+                "blockHash": B256::ZERO,
+                "blockNumber": "0x10",
+                "index": "0x2",
+            })
+        );
+        assert_eq!(serde_json::from_value::<TransactionStatus>(json).unwrap(), status);
+
+        let status = TransactionStatus::Queued { reason: QueuedReason::NonceGap };
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(json, serde_json::json!({ "status": "queued", "reason": "nonceGap" }));
+        assert_eq!(serde_json::from_value::<TransactionStatus>(json).unwrap(), status);
+
+        assert_eq!(
+            serde_json::to_value(TransactionStatus::Unknown).unwrap(),
+            serde_json::json!({ "status": "unknown" })
+        );
+    }
+}
@@ -3,6 +3,7 @@
 use super::{EthApiError, EthResult};
 use alloy_consensus::TxReceipt;
 use reth_primitives_traits::{Recovered, SignedTransaction};
+use reth_rpc_server_types::result::RetryConfig;
 use std::future::Future;
 
 /// Calculates the gas used and next log index for a transaction at the given index
@@ -40,6 +41,9 @@ pub fn recover_raw_transaction<T: SignedTransaction>(data: &[u8]) -> EthResult<R
     let transaction =
         T::decode_2718_exact(data).map_err(|_| EthApiError::FailedToDecodeSignedTransaction)?;
 
+    // reject malformed signatures before the more expensive recovery
+    transaction.verify_signature().map_err(|_| EthApiError::InvalidTransactionSignature)?;
+
     SignedTransaction::try_into_recovered(transaction)
         .or(Err(EthApiError::InvalidTransactionSignature))
 }
@@ -80,6 +84,35 @@ where
     Ok(num)
 }
 
+/// Runs `op`, retrying it according to the [`RetryConfig`] while it fails with an error for which
+/// `is_transient` returns `true`.
+///
+/// The delay before a retry starts at [`RetryConfig::initial_backoff`] and doubles for every
+/// subsequent retry. Returns the last error if all retries are exhausted.
+pub async fn retry_transient<T, E, F, Fut, P>(
+    config: RetryConfig,
+    mut op: F,
+    is_transient: P,
+) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    P: Fn(&E) -> bool,
+{
+    let mut backoff = config.initial_backoff;
+    let mut retries = 0;
+    loop {
+        match op().await {
+            Err(err) if retries < config.max_retries && is_transient(&err) => {
+                tokio::time::sleep(backoff).await;
+                backoff = backoff.saturating_mul(2);
+                retries += 1;
+            }
+            res => return res,
+        }
+    }
+}
+
 /// Calculates the blob gas used ratio for a block, accounting for the case where
 /// `max_blob_gas_per_block` is zero.
 ///
@@ -96,6 +129,26 @@ pub fn checked_blob_gas_used_ratio(blob_gas_used: u64, max_blob_gas_per_block: u
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_consensus::{crypto::SECP256K1N_HALF, SignableTransaction, TxLegacy};
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::{Signature, U256};
+    use reth_ethereum_primitives::PooledTransactionVariant;
+
+    #[test]
+    fn recover_raw_transaction_rejects_malleable_signature() {
+        let tx = TxLegacy { chain_id: Some(1), ..Default::default() };
+        let signature = Signature::test_signature();
+        let raw =
+            PooledTransactionVariant::Legacy(tx.clone().into_signed(signature)).encoded_2718();
+        assert!(recover_raw_transaction::<PooledTransactionVariant>(&raw).is_ok());
+
+        let malleable = Signature::new(signature.r(), SECP256K1N_HALF + U256::from(1), false);
+        let raw = PooledTransactionVariant::Legacy(tx.into_signed(malleable)).encoded_2718();
+        assert!(matches!(
+            recover_raw_transaction::<PooledTransactionVariant>(&raw),
+            Err(EthApiError::InvalidTransactionSignature)
+        ));
+    }
 
     #[tokio::test]
     async fn test_binary_search() {
@@ -133,4 +186,69 @@ mod tests {
         // Blob gas used is non-zero and equal to max blob gas per block
         assert_eq!(checked_blob_gas_used_ratio(100, 100), 1.0);
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_transient() {
+        let config = RetryConfig::default().with_max_retries(3);
+
+        // succeeds on the second attempt after a single backoff
+        let start = tokio::time::Instant::now();
+        let mut attempts = 0;
+        let res: Result<_, bool> = retry_transient(
+            config,
+            || {
+                attempts += 1;
+                let attempt = attempts;
+                async move { if attempt == 1 { Err(true) } else { Ok(attempt) } }
+            },
+            |transient| *transient,
+        )
+        .await;
+        assert_eq!(res, Ok(2));
+        assert_eq!(start.elapsed(), config.initial_backoff);
+
+        // permanent errors are returned on the first attempt
+        let mut attempts = 0;
+        let res: Result<(), _> = retry_transient(
+            config,
+            || {
+                attempts += 1;
+                async { Err(false) }
+            },
+            |transient| *transient,
+        )
+        .await;
+        assert_eq!(res, Err(false));
+        assert_eq!(attempts, 1);
+
+        // the last error is returned once retries are exhausted
+        let start = tokio::time::Instant::now();
+        let mut attempts = 0;
+        let res: Result<(), _> = retry_transient(
+            config,
+            || {
+                attempts += 1;
+                let attempt = attempts;
+                async move { Err(attempt) }
+            },
+            |_| true,
+        )
+        .await;
+        assert_eq!(res, Err(4));
+        assert_eq!(start.elapsed(), config.initial_backoff * 7);
+
+        // disabled by default
+        let mut attempts = 0;
+        let res: Result<(), _> = retry_transient(
+            RetryConfig::default(),
+            || {
+                attempts += 1;
+                async { Err(()) }
+            },
+            |_| true,
+        )
+        .await;
+        assert_eq!(res, Err(()));
+        assert_eq!(attempts, 1);
+    }
 }
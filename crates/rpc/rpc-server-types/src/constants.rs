@@ -118,4 +118,7 @@ pub mod cache {
 
     /// Default number of concurrent database requests.
     pub const DEFAULT_CONCURRENT_DB_REQUESTS: usize = 512;
+
+    /// Default max total size of the bytecode cache: 64MB.
+    pub const DEFAULT_BYTECODE_CACHE_MAX_BYTES: usize = 64 * 1024 * 1024;
 }
@@ -14,8 +14,8 @@ pub mod result;
 
 mod module;
 pub use module::{
-    DefaultRpcModuleValidator, LenientRpcModuleValidator, RethRpcModule, RpcModuleSelection,
-    RpcModuleValidator,
+    DefaultRpcModuleValidator, LenientRpcModuleValidator, RethRpcModule, RpcMethodAllowList,
+    RpcModuleSelection, RpcModuleValidator,
 };
 
 pub use result::ToRpcResult;
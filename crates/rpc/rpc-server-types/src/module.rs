@@ -1,4 +1,4 @@
-use std::{collections::HashSet, fmt, str::FromStr};
+use std::{collections::HashSet, convert::Infallible, fmt, str::FromStr};
 
 use serde::{Deserialize, Serialize, Serializer};
 use strum::{ParseError, VariantNames};
@@ -292,6 +292,77 @@ impl fmt::Display for RpcModuleSelection {
     }
 }
 
+/// Allow-list of the RPC methods that are served on a transport.
+///
+/// An entry is either a namespace, e.g. `eth`, which allows all methods of that namespace, or a
+/// full method name, e.g. `debug_traceTransaction`.
+///
+/// # Example
+///
+/// ```
+/// use reth_rpc_server_types::RpcMethodAllowList;
+/// let allowed: RpcMethodAllowList = "eth,debug_traceTransaction".parse().unwrap();
+/// assert!(allowed.is_allowed("eth_call"));
+/// assert!(allowed.is_allowed("debug_traceTransaction"));
+/// assert!(!allowed.is_allowed("debug_traceCall"));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct RpcMethodAllowList(HashSet<String>);
+
+impl RpcMethodAllowList {
+    /// Returns true if the given method is allowed, either by its name or by its namespace.
+    pub fn is_allowed(&self, method: &str) -> bool {
+        self.0.contains(method) ||
+            method.split_once('_').is_some_and(|(namespace, _)| self.0.contains(namespace))
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if no method is allowed.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over all entries.
+    pub fn iter(&self) -> impl Iterator<Item = &str> + '_ {
+        self.0.iter().map(String::as_str)
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for RpcMethodAllowList {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+    {
+        Self(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<S: Into<String>, const N: usize> From<[S; N]> for RpcMethodAllowList {
+    fn from(entries: [S; N]) -> Self {
+        entries.into_iter().collect()
+    }
+}
+
+impl FromStr for RpcMethodAllowList {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.split(',').map(str::trim).filter(|entry| !entry.is_empty()).collect())
+    }
+}
+
+impl fmt::Display for RpcMethodAllowList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut entries = self.iter().collect::<Vec<_>>();
+        entries.sort_unstable();
+        write!(f, "[{}]", entries.join(", "))
+    }
+}
+
 /// Represents RPC modules that are supported by reth
 #[derive(Debug, Clone, Eq, PartialEq, Hash, VariantNames, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -534,6 +605,22 @@ impl RpcModuleValidator for LenientRpcModuleValidator {
 mod test {
     use super::*;
 
+    #[test]
+    fn test_method_allow_list() {
+        let allowed: RpcMethodAllowList = " eth, debug_traceTransaction ,".parse().unwrap();
+        assert_eq!(allowed.len(), 2);
+        assert!(allowed.is_allowed("eth_call"));
+        assert!(allowed.is_allowed("eth_subscribe"));
+        assert!(allowed.is_allowed("debug_traceTransaction"));
+        assert!(!allowed.is_allowed("debug_traceCall"));
+        assert!(!allowed.is_allowed("ethereum_call"));
+        assert_eq!(allowed.to_string(), "[debug_traceTransaction, eth]");
+
+        let allowed: RpcMethodAllowList = "".parse().unwrap();
+        assert!(allowed.is_empty());
+        assert!(!allowed.is_allowed("eth_call"));
+    }
+
     #[test]
     fn test_all_modules() {
         let all_modules = RpcModuleSelection::all_modules();
@@ -1,11 +1,12 @@
 //! Additional helpers for converting errors.
 
-use std::fmt;
+use std::{fmt, time::Duration};
 
 use alloy_eips::BlockId;
 use alloy_rpc_types_engine::PayloadError;
 use jsonrpsee_core::RpcResult;
-use reth_errors::ConsensusError;
+use reth_errors::{ConsensusError, DatabaseError, ProviderError, RethError};
+use serde::{Deserialize, Serialize};
 
 /// Helper trait to easily convert various `Result` types into [`RpcResult`]
 pub trait ToRpcResult<Ok, Err>: Sized {
@@ -152,6 +153,95 @@ pub fn rpc_err(
     )
 }
 
+/// Errors that can be temporary, so that the failed operation may succeed when retried.
+pub trait TransientError {
+    /// Returns `true` if the error is temporary, e.g. caused by contention on the database.
+    fn is_transient(&self) -> bool;
+}
+
+/// MDBX error code returned if the environment is busy, see `MDBX_BUSY` in `mdbx.h`.
+const MDBX_BUSY: i32 = -30778;
+
+/// MDBX error code returned if the reader lock table is full, see `MDBX_READERS_FULL` in `mdbx.h`.
+const MDBX_READERS_FULL: i32 = -30790;
+
+impl TransientError for DatabaseError {
+    fn is_transient(&self) -> bool {
+        // only contention on the environment is temporary, any other failure to open a read
+        // transaction or cursor, or to read a value, fails again when retried
+        match self {
+            Self::InitTx(info) | Self::InitCursor(info) | Self::Read(info) => {
+                matches!(info.code, MDBX_BUSY | MDBX_READERS_FULL)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl TransientError for ProviderError {
+    fn is_transient(&self) -> bool {
+        match self {
+            Self::Database(err) => err.is_transient(),
+            _ => false,
+        }
+    }
+}
+
+impl TransientError for RethError {
+    fn is_transient(&self) -> bool {
+        match self {
+            Self::Database(err) => err.is_transient(),
+            Self::Provider(err) => err.is_transient(),
+            _ => false,
+        }
+    }
+}
+
+/// Default delay before the first retry of an operation that failed with a [`TransientError`].
+pub const DEFAULT_TRANSIENT_INITIAL_BACKOFF: Duration = Duration::from_millis(10);
+
+/// Bounded retries with exponential backoff for operations that failed with a
+/// [`TransientError`].
+///
+/// Retries are disabled by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetryConfig {
+    /// Maximum number of retries after the first attempt, `0` disables retries.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for every subsequent retry.
+    pub initial_backoff: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+impl RetryConfig {
+    /// Returns a config that never retries.
+    pub const fn disabled() -> Self {
+        Self { max_retries: 0, initial_backoff: DEFAULT_TRANSIENT_INITIAL_BACKOFF }
+    }
+
+    /// Returns `true` if failed operations are retried.
+    pub const fn is_enabled(&self) -> bool {
+        self.max_retries > 0
+    }
+
+    /// Sets the maximum number of retries.
+    pub const fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    pub const fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+}
+
 /// Formats a [`BlockId`] into an error message.
 pub fn block_id_to_str(id: BlockId) -> String {
     match id {
@@ -169,7 +259,8 @@ pub fn block_id_to_str(id: BlockId) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use reth_errors::{RethError, RethResult};
+    use reth_errors::RethResult;
+    use reth_storage_errors::db::DatabaseErrorInfo;
 
     const fn assert_rpc_result<T, E, TRR: ToRpcResult<T, E>>() {}
 
@@ -182,4 +273,40 @@ mod tests {
         let val = rpc_res.unwrap();
         assert_eq!(val, 100);
     }
+
+    fn info(code: i32) -> DatabaseErrorInfo {
+        DatabaseErrorInfo { message: "mdbx".into(), code }
+    }
+
+    fn db_error(code: i32) -> DatabaseError {
+        DatabaseError::Read(info(code))
+    }
+
+    #[test]
+    fn only_busy_database_errors_are_transient() {
+        assert!(db_error(MDBX_BUSY).is_transient());
+        assert!(db_error(MDBX_READERS_FULL).is_transient());
+        assert!(DatabaseError::InitTx(info(MDBX_BUSY)).is_transient());
+        assert!(DatabaseError::InitCursor(info(MDBX_READERS_FULL)).is_transient());
+
+        // MDBX_NOTFOUND, MDBX_CORRUPTED and MDBX_BAD_TXN
+        for code in [-30798, -30796, -30782] {
+            assert!(!db_error(code).is_transient());
+        }
+        assert!(!DatabaseError::Open(info(MDBX_BUSY)).is_transient());
+    }
+
+    #[test]
+    fn provider_errors_forward_database_classification() {
+        assert!(ProviderError::Database(db_error(MDBX_BUSY)).is_transient());
+        assert!(RethError::Provider(ProviderError::Database(db_error(MDBX_BUSY))).is_transient());
+        assert!(!ProviderError::Database(db_error(-30796)).is_transient());
+        assert!(!ProviderError::BestBlockNotFound.is_transient());
+    }
+
+    #[test]
+    fn retries_are_disabled_by_default() {
+        assert!(!RetryConfig::default().is_enabled());
+        assert!(RetryConfig::default().with_max_retries(1).is_enabled());
+    }
 }
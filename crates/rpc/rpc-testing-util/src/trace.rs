@@ -381,7 +381,7 @@ impl<T: TraceApiClient<TransactionRequest> + Sync> TraceApiExt for T {
     {
         let filter_list = filters.into_iter().collect::<Vec<_>>();
         let stream = futures::stream::iter(filter_list.into_iter().map(move |filter| async move {
-            match self.trace_filter(filter.clone()).await {
+            match self.trace_filter(filter.clone(), None).await {
                 Ok(result) => Ok((result, filter)),
                 Err(err) => Err((err, filter)),
             }
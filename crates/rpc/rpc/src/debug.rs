@@ -1,10 +1,11 @@
 use alloy_consensus::{
     transaction::{SignerRecoverable, TxHashRef},
-    BlockHeader,
+    BlockHeader, Transaction as _,
 };
 use alloy_eips::{eip2718::Encodable2718, BlockId, BlockNumberOrTag};
 use alloy_evm::env::BlockEnvironment;
 use alloy_genesis::ChainConfig;
+use alloy_json_abi::JsonAbi;
 use alloy_primitives::{hex::decode, uint, Address, Bytes, B256};
 use alloy_rlp::{Decodable, Encodable};
 use alloy_rpc_types_debug::ExecutionWitness;
@@ -19,7 +20,7 @@ use alloy_rpc_types_trace::geth::{
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
 use reth_chainspec::{ChainSpecProvider, EthChainSpec, EthereumHardforks};
-use reth_errors::RethError;
+use reth_errors::{ProviderError, RethError};
 use reth_evm::{execute::Executor, ConfigureEvm, EvmEnvFor};
 use reth_primitives_traits::{Block as _, BlockBody, ReceiptWithBloom, RecoveredBlock};
 use reth_revm::{db::State, witness::ExecutionWitnessRecord};
@@ -29,7 +30,13 @@ use reth_rpc_eth_api::{
     helpers::{EthTransactions, TraceExt},
     EthApiTypes, FromEthApiError, RpcNodeCore,
 };
-use reth_rpc_eth_types::{EthApiError, StateCacheDb};
+use reth_rpc_eth_types::{
+    trace::{
+        self, BlockReward, DecodedCallLogFrame, EventSignatureRegistry, InternalTransfer,
+        LocalizedCreatedContract, LocalizedSelfDestruct, OpcodeGas, TraceSizeBudget,
+    },
+    EthApiError,
+};
 use reth_rpc_server_types::{result::internal_rpc_err, ToRpcResult};
 use reth_storage_api::{
     BlockIdReader, BlockReaderIdExt, HeaderProvider, ProviderBlock, ReceiptProviderIdExt,
@@ -44,13 +51,13 @@ use revm::{
     },
     inspector::{JournalExt, NoOpInspector},
     interpreter::{CallInputs, CallOutcome, CreateInputs, CreateOutcome, Interpreter},
-    DatabaseCommit, DatabaseRef, Inspector,
+    Database, DatabaseCommit, DatabaseRef, Inspector,
 };
 use revm_inspectors::tracing::{
     FourByteInspector, MuxInspector, TracingInspector, TracingInspectorConfig, TransactionContext,
 };
 use revm_primitives::{Log, U256};
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 use tokio::sync::{AcquireError, OwnedSemaphorePermit};
 
 /// `debug` API implementation.
@@ -101,13 +108,14 @@ where
         opts: GethDebugTracingOptions,
     ) -> Result<Vec<TraceResult>, Eth::Error> {
         self.eth_api()
-            .spawn_with_state_at_block(block.parent_hash(), move |eth_api, mut db| {
+            .spawn_with_strict_state_at_block(block.parent_hash(), move |eth_api, mut db| {
                 let mut results = Vec::with_capacity(block.body().transactions().len());
 
                 eth_api.apply_pre_execution_changes(&block, &mut db, &evm_env)?;
 
                 let mut transactions = block.transactions_recovered().enumerate().peekable();
                 let mut inspector = DebugInspector::new(opts)?;
+                let budget = TraceSizeBudget::new(eth_api.max_trace_response_size());
                 while let Some((index, tx)) = transactions.next() {
                     let tx_hash = *tx.tx_hash();
                     let tx_env = eth_api.evm_config().tx_env(tx);
@@ -128,6 +136,7 @@ where
                         &evm_env.block_env,
                         &res,
                         &mut db,
+                        &budget,
                     )?;
 
                     results.push(TraceResult::Success { result, tx_hash: Some(tx_hash) });
@@ -223,12 +232,16 @@ where
         let (evm_env, _) = self.eth_api().evm_env_at(block.hash().into()).await?;
 
         // we need to get the state of the parent block because we're essentially replaying the
-        // block the transaction is included in
+        // block the transaction is included in.
+        //
+        // For historical blocks this is served by the historical state provider, which
+        // reconstructs account and storage values from the stored changesets, so reads return the
+        // values as of the parent block without re-executing any prior blocks.
         let state_at: BlockId = block.parent_hash().into();
         let block_hash = block.hash();
 
         self.eth_api()
-            .spawn_with_state_at_block(state_at, move |eth_api, mut db| {
+            .spawn_with_strict_state_at_block(state_at, move |eth_api, mut db| {
                 let block_txs = block.transactions_recovered();
 
                 // configure env for the target transaction
@@ -259,6 +272,7 @@ where
                     &evm_env.block_env,
                     &res,
                     &mut db,
+                    &TraceSizeBudget::new(eth_api.max_trace_response_size()),
                 )?;
 
                 Ok(trace)
@@ -307,7 +321,9 @@ where
                     tx_env.clone(),
                     &mut inspector,
                 )?;
-                let trace = inspector.get_result(None, &tx_env, &evm_env.block_env, &res, db)?;
+                let budget = TraceSizeBudget::new(this.eth_api().max_trace_response_size());
+                let trace =
+                    inspector.get_result(None, &tx_env, &evm_env.block_env, &res, db, &budget)?;
                 Ok(trace)
             })
             .await
@@ -365,8 +381,15 @@ where
                 let mut inspector = DebugInspector::new(tracing_options)?;
                 let res =
                     eth_api.inspect(&mut db, evm_env.clone(), tx_env.clone(), &mut inspector)?;
-                let trace =
-                    inspector.get_result(None, &tx_env, &evm_env.block_env, &res, &mut db)?;
+                let budget = TraceSizeBudget::new(eth_api.max_trace_response_size());
+                let trace = inspector.get_result(
+                    None,
+                    &tx_env,
+                    &evm_env.block_env,
+                    &res,
+                    &mut db,
+                    &budget,
+                )?;
 
                 Ok(trace)
             })
@@ -436,6 +459,7 @@ where
                 // Trace all bundles
                 let mut bundles = bundles.into_iter().peekable();
                 let mut inspector = DebugInspector::new(tracing_options.clone())?;
+                let budget = TraceSizeBudget::new(eth_api.max_trace_response_size());
                 while let Some(bundle) = bundles.next() {
                     let mut results = Vec::with_capacity(bundle.transactions.len());
                     let Bundle { transactions, block_override } = bundle;
@@ -463,6 +487,7 @@ where
                             &evm_env.block_env,
                             &res,
                             &mut db,
+                            &budget,
                         )?;
 
                         // If there is more transactions, commit the database
@@ -578,6 +603,183 @@ where
         Ok(exec_witness)
     }
 
+    /// Replays all transactions of the given block and returns the contracts they created.
+    ///
+    /// Returns `None` if the block is not found.
+    pub async fn debug_get_created_contracts(
+        &self,
+        block_id: BlockId,
+    ) -> Result<Option<Vec<LocalizedCreatedContract>>, Eth::Error> {
+        let contracts = self
+            .eth_api()
+            .trace_block_with(block_id, None, TracingInspectorConfig::none(), |tx_info, ctx| {
+                let transaction_hash = tx_info.hash.expect("tx hash is set");
+                let transaction_position = tx_info.index.expect("tx index is set");
+                Ok(trace::created_contracts(ctx.inspector.traces())
+                    .into_iter()
+                    .map(|contract| LocalizedCreatedContract {
+                        transaction_hash,
+                        transaction_position,
+                        contract,
+                    })
+                    .collect::<Vec<_>>())
+            })
+            .await?;
+
+        Ok(contracts.map(|contracts| contracts.into_iter().flatten().collect()))
+    }
+
+    /// Replays all transactions of the given block and returns the selfdestructs they executed.
+    ///
+    /// Returns `None` if the block is not found.
+    pub async fn debug_get_self_destructs(
+        &self,
+        block_id: BlockId,
+    ) -> Result<Option<Vec<LocalizedSelfDestruct>>, Eth::Error> {
+        let Some(block) = self.eth_api().recovered_block(block_id).await? else { return Ok(None) };
+        let cancun_active =
+            self.provider().chain_spec().is_cancun_active_at_timestamp(block.timestamp());
+
+        let selfdestructs = self
+            .eth_api()
+            .trace_block_with(
+                block_id,
+                Some(block),
+                TracingInspectorConfig::none(),
+                move |tx_info, ctx| {
+                    let transaction_hash = tx_info.hash.expect("tx hash is set");
+                    let transaction_position = tx_info.index.expect("tx index is set");
+                    Ok(trace::selfdestructs(ctx.inspector.traces(), cancun_active)
+                        .into_iter()
+                        .map(|selfdestruct| LocalizedSelfDestruct {
+                            transaction_hash,
+                            transaction_position,
+                            selfdestruct,
+                        })
+                        .collect::<Vec<_>>())
+                },
+            )
+            .await?;
+
+        Ok(selfdestructs.map(|selfdestructs| selfdestructs.into_iter().flatten().collect()))
+    }
+
+    /// Replays the given transaction and returns the value transfers made by its subcalls.
+    ///
+    /// Returns `None` if the transaction is not found.
+    pub async fn debug_get_internal_transactions(
+        &self,
+        tx_hash: B256,
+    ) -> Result<Option<Vec<InternalTransfer>>, Eth::Error> {
+        self.eth_api()
+            .spawn_trace_transaction_in_block(
+                tx_hash,
+                TracingInspectorConfig::none(),
+                move |_tx_info, inspector, _res, _db| {
+                    Ok(trace::internal_transfers(inspector.traces()))
+                },
+            )
+            .await
+    }
+
+    /// Replays the given transaction and returns the number of executions and combined gas cost
+    /// of each opcode.
+    ///
+    /// Returns `None` if the transaction is not found.
+    pub async fn debug_get_opcode_gas(
+        &self,
+        tx_hash: B256,
+    ) -> Result<Option<BTreeMap<String, OpcodeGas>>, Eth::Error> {
+        self.eth_api()
+            .spawn_trace_transaction_in_block(
+                tx_hash,
+                TracingInspectorConfig::none().steps(),
+                move |_tx_info, inspector, _res, _db| Ok(trace::opcode_gas(inspector.traces())),
+            )
+            .await
+    }
+
+    /// Replays the given transaction and returns its logs decoded with the events of the given
+    /// ABI.
+    ///
+    /// Returns `None` if the transaction is not found.
+    pub async fn debug_get_decoded_logs(
+        &self,
+        tx_hash: B256,
+        abi: JsonAbi,
+    ) -> Result<Option<Vec<DecodedCallLogFrame>>, Eth::Error> {
+        let registry = EventSignatureRegistry::from_abi(&abi);
+        let config = CallConfig::default().with_log();
+        self.eth_api()
+            .spawn_trace_transaction_in_block(
+                tx_hash,
+                TracingInspectorConfig::from_geth_call_config(&config),
+                move |_tx_info, inspector, res, _db| {
+                    let frame =
+                        inspector.geth_builder().geth_call_traces(config, res.result.gas_used());
+                    Ok(trace::decode_call_frame_logs(&frame, &registry))
+                },
+            )
+            .await
+    }
+
+    /// Replays all transactions of the given block and returns the fees and payments received by
+    /// its fee recipient.
+    ///
+    /// Returns `None` if the block is not found.
+    pub async fn debug_block_reward(
+        &self,
+        block_id: BlockId,
+    ) -> Result<Option<BlockReward>, Eth::Error> {
+        let Some(block) = self.eth_api().recovered_block(block_id).await? else { return Ok(None) };
+        let mut reward = BlockReward::new(block.hash(), block.number(), block.beneficiary());
+        let fee_recipient = reward.fee_recipient;
+
+        let transactions = self
+            .eth_api()
+            .trace_block_with(
+                block_id,
+                Some(block),
+                TracingInspectorConfig::none(),
+                move |tx_info, ctx| {
+                    let base_fee = tx_info.base_fee.unwrap_or_default();
+                    let balance_before = ctx
+                        .db
+                        .basic(fee_recipient)
+                        .map_err(Eth::Error::from_eth_err)?
+                        .map(|account| account.balance)
+                        .unwrap_or_default();
+                    let balance_after = ctx
+                        .state
+                        .get(&fee_recipient)
+                        .map_or(balance_before, |account| account.info.balance);
+                    Ok((
+                        ctx.tx.signer(),
+                        ctx.result.gas_used(),
+                        ctx.tx.effective_gas_price(Some(base_fee)),
+                        base_fee,
+                        balance_before,
+                        balance_after,
+                    ))
+                },
+            )
+            .await?
+            .unwrap_or_default();
+
+        for (sender, gas_used, gas_price, base_fee, balance_before, balance_after) in transactions {
+            reward.record_transaction(
+                sender,
+                gas_used,
+                gas_price,
+                base_fee,
+                balance_before,
+                balance_after,
+            );
+        }
+
+        Ok(Some(reward))
+    }
+
     /// Returns the code associated with a given hash at the specified block ID. If no code is
     /// found, it returns None. If no block ID is provided, it defaults to the latest block.
     pub async fn debug_code_by_hash(
@@ -790,6 +992,58 @@ where
         Self::debug_execution_witness_by_block_hash(self, hash).await.map_err(Into::into)
     }
 
+    /// Handler for `debug_blockReward`
+    async fn debug_block_reward(&self, block_id: BlockId) -> RpcResult<Option<BlockReward>> {
+        let _permit = self.acquire_trace_permit().await;
+        Self::debug_block_reward(self, block_id).await.map_err(Into::into)
+    }
+
+    /// Handler for `debug_getCreatedContracts`
+    async fn debug_get_created_contracts(
+        &self,
+        block_id: BlockId,
+    ) -> RpcResult<Option<Vec<LocalizedCreatedContract>>> {
+        let _permit = self.acquire_trace_permit().await;
+        Self::debug_get_created_contracts(self, block_id).await.map_err(Into::into)
+    }
+
+    /// Handler for `debug_getSelfDestructs`
+    async fn debug_get_self_destructs(
+        &self,
+        block_id: BlockId,
+    ) -> RpcResult<Option<Vec<LocalizedSelfDestruct>>> {
+        let _permit = self.acquire_trace_permit().await;
+        Self::debug_get_self_destructs(self, block_id).await.map_err(Into::into)
+    }
+
+    /// Handler for `debug_getInternalTransactions`
+    async fn debug_get_internal_transactions(
+        &self,
+        tx_hash: B256,
+    ) -> RpcResult<Option<Vec<InternalTransfer>>> {
+        let _permit = self.acquire_trace_permit().await;
+        Self::debug_get_internal_transactions(self, tx_hash).await.map_err(Into::into)
+    }
+
+    /// Handler for `debug_getOpcodeGas`
+    async fn debug_get_opcode_gas(
+        &self,
+        tx_hash: B256,
+    ) -> RpcResult<Option<BTreeMap<String, OpcodeGas>>> {
+        let _permit = self.acquire_trace_permit().await;
+        Self::debug_get_opcode_gas(self, tx_hash).await.map_err(Into::into)
+    }
+
+    /// Handler for `debug_getDecodedLogs`
+    async fn debug_get_decoded_logs(
+        &self,
+        tx_hash: B256,
+        abi: JsonAbi,
+    ) -> RpcResult<Option<Vec<DecodedCallLogFrame>>> {
+        let _permit = self.acquire_trace_permit().await;
+        Self::debug_get_decoded_logs(self, tx_hash, abi).await.map_err(Into::into)
+    }
+
     async fn debug_backtrace_at(&self, _location: &str) -> RpcResult<()> {
         Ok(())
     }
@@ -1174,10 +1428,10 @@ impl DebugInspector {
                 }
             }
         } else {
-            Self::Default(
-                TracingInspector::new(TracingInspectorConfig::from_geth_config(&config)),
-                config,
-            )
+            let mut inspector_config = TracingInspectorConfig::from_geth_config(&config);
+            // the per step `returnData` is only populated if returndata snapshots are recorded
+            inspector_config.record_returndata_snapshots = config.is_return_data_enabled();
+            Self::Default(TracingInspector::new(inspector_config), config)
         };
 
         Ok(this)
@@ -1210,14 +1464,21 @@ impl DebugInspector {
     }
 
     /// Should be invoked after each transaction to obtain the resulting [`GethTrace`].
-    fn get_result(
+    ///
+    /// The serialized size of the trace is charged to the given budget, this fails with
+    /// [`EthApiError::TraceTooLarge`] if the budget is exhausted.
+    fn get_result<DB>(
         &mut self,
         tx_context: Option<TransactionContext>,
         tx_env: &impl revm::context::Transaction,
         block_env: &impl revm::context::Block,
         res: &ResultAndState<impl HaltReasonTr>,
-        db: &mut StateCacheDb,
-    ) -> Result<GethTrace, EthApiError> {
+        db: &mut DB,
+        budget: &TraceSizeBudget,
+    ) -> Result<GethTrace, EthApiError>
+    where
+        DB: DatabaseRef<Error = ProviderError>,
+    {
         let tx_info = TransactionInfo {
             hash: tx_context.as_ref().map(|c| c.tx_hash).unwrap_or_default(),
             index: tx_context.as_ref().map(|c| c.tx_index.map(|i| i as u64)).unwrap_or_default(),
@@ -1265,6 +1526,7 @@ impl DebugInspector {
                 GethTrace::JS(res)
             }
         };
+        budget.charge(&res)?;
 
         Ok(res)
     }
@@ -1335,3 +1597,939 @@ where
         delegate!(self => inspector.selfdestruct(contract, target, value))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{eth::helpers::types::EthRpcConverter, EthApi};
+    use alloy_consensus::{Block, BlockBody, Header, TxEip1559, TxLegacy};
+    use alloy_eips::eip2718::Decodable2718;
+    use alloy_primitives::{bytes, TxKind, I256};
+    use alloy_rpc_types_eth::{BlockOverrides, TransactionRequest};
+    use alloy_rpc_types_trace::geth::{DiffMode, PreStateFrame, PreStateMode};
+    use reth_chainspec::{ChainSpec, ChainSpecBuilder};
+    use reth_ethereum_primitives::{Receipt, Transaction, TransactionSigned};
+    use reth_evm_ethereum::EthEvmConfig;
+    use reth_network_api::noop::NoopNetwork;
+    use reth_primitives_traits::{crypto::secp256k1::public_key_to_address, SealedBlock};
+    use reth_provider::{
+        providers::BlockchainProvider,
+        test_utils::{
+            create_test_provider_factory_with_chain_spec, ExtendedAccount, MockEthProvider,
+            MockNodeTypesWithDB,
+        },
+        BlockWriter, ExecutionOutcome,
+    };
+    use reth_revm::db::BundleState;
+    use reth_rpc_eth_api::{helpers::EthCall, node::RpcNodeCoreAdapter};
+    use reth_testing_utils::generators::{self, generate_key, sign_tx_with_key_pair};
+    use reth_transaction_pool::test_utils::{testing_pool, TestPool};
+    use revm::{bytecode::Bytecode, state::AccountInfo};
+
+    type TestEthApi = EthApi<
+        RpcNodeCoreAdapter<MockEthProvider, TestPool, NoopNetwork, EthEvmConfig>,
+        EthRpcConverter<ChainSpec>,
+    >;
+
+    fn test_provider() -> MockEthProvider {
+        MockEthProvider::default()
+            .with_chain_spec(ChainSpecBuilder::mainnet().cancun_activated().build())
+    }
+
+    fn test_header() -> Header {
+        Header {
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(1_000_000_000),
+            excess_blob_gas: Some(0),
+            blob_gas_used: Some(0),
+            parent_beacon_block_root: Some(B256::ZERO),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a genesis block with the given header, returns its hash.
+    fn add_genesis(provider: &MockEthProvider, header: Header) -> B256 {
+        let genesis = Block::new(header, BlockBody::<TransactionSigned>::default());
+        let genesis_hash = genesis.header.hash_slow();
+        provider.add_block(genesis_hash, genesis);
+        genesis_hash
+    }
+
+    /// Adds a genesis block with the given header and block 1 with the given transactions on top
+    /// of it, returns the hash of block 1.
+    fn add_block_on_genesis(
+        provider: &MockEthProvider,
+        header: Header,
+        transactions: Vec<TransactionSigned>,
+    ) -> B256 {
+        let genesis_hash = add_genesis(provider, header.clone());
+        let header = Header { number: 1, parent_hash: genesis_hash, timestamp: 12, ..header };
+        let block = Block::new(header, BlockBody { transactions, ..Default::default() });
+        let block_hash = block.header.hash_slow();
+        provider.add_block(block_hash, block);
+        block_hash
+    }
+
+    fn test_debug_api(
+        provider: MockEthProvider,
+        max_trace_response_size: Option<usize>,
+    ) -> DebugApi<TestEthApi> {
+        let evm_config = EthEvmConfig::new(provider.chain_spec());
+        let eth_api = EthApi::builder(provider, testing_pool(), NoopNetwork::default(), evm_config)
+            .max_trace_response_size(max_trace_response_size)
+            .build();
+        DebugApi::new(eth_api, BlockingTaskGuard::new(1))
+    }
+
+    #[tokio::test]
+    async fn block_reward_with_coinbase_payment() {
+        let provider = test_provider();
+
+        let mut rng = generators::rng();
+        let sender_key = generate_key(&mut rng);
+        let coinbase_key = generate_key(&mut rng);
+        let sender = public_key_to_address(sender_key.public_key());
+        let coinbase = public_key_to_address(coinbase_key.public_key());
+        let ether = U256::from(10).pow(U256::from(18));
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(10) * ether));
+        provider.add_account(coinbase, ExtendedAccount::new(0, U256::from(10) * ether));
+
+        let transfer = |to, value| {
+            Transaction::Legacy(TxLegacy {
+                chain_id: Some(1),
+                nonce: 0,
+                gas_price: 2_000_000_000,
+                gas_limit: 21_000,
+                to: TxKind::Call(to),
+                value,
+                input: Default::default(),
+            })
+        };
+        let transactions = vec![
+            // direct payment to the coinbase
+            sign_tx_with_key_pair(sender_key, transfer(coinbase, ether)),
+            // the coinbase pays someone else, this is not a payment to the coinbase
+            sign_tx_with_key_pair(coinbase_key, transfer(Address::repeat_byte(0x11), ether)),
+        ];
+
+        let header = Header { beneficiary: coinbase, ..test_header() };
+        let block_hash = add_block_on_genesis(&provider, header, transactions);
+
+        let debug_api = test_debug_api(provider, None);
+
+        let reward = debug_api.debug_block_reward(BlockId::number(1)).await.unwrap().unwrap();
+        let gas_used = U256::from(2 * 21_000);
+        let tips = gas_used * U256::from(1_000_000_000);
+        assert_eq!(reward.block_hash, block_hash);
+        assert_eq!(reward.block_number, 1);
+        assert_eq!(reward.fee_recipient, coinbase);
+        assert_eq!(reward.total_fees, gas_used * U256::from(2_000_000_000));
+        assert_eq!(reward.burnt_fees, gas_used * U256::from(1_000_000_000));
+        assert_eq!(reward.priority_fees, tips);
+        assert_eq!(reward.direct_payments, I256::from_raw(ether));
+        // received 1 ether and the tips, paid 1 ether and the gas of its own transaction
+        let own_gas = U256::from(21_000u64 * 2_000_000_000);
+        assert_eq!(
+            reward.fee_recipient_balance_delta,
+            I256::from_raw(tips) - I256::from_raw(own_gas)
+        );
+
+        assert!(debug_api.debug_block_reward(BlockId::number(2)).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn self_destructs_of_block() {
+        let provider = test_provider();
+
+        let mut rng = generators::rng();
+        let sender_key = generate_key(&mut rng);
+        let sender = public_key_to_address(sender_key.public_key());
+        let ether = U256::from(10).pow(U256::from(18));
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(10) * ether));
+
+        // selfdestructs to 0xaa..aa
+        let refund_address = Address::repeat_byte(0xaa);
+        let destructible = Address::repeat_byte(0x10);
+        provider.add_account(
+            destructible,
+            ExtendedAccount::new(0, ether)
+                .with_bytecode(bytes!("0x73aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaff")),
+        );
+
+        let transaction = sign_tx_with_key_pair(
+            sender_key,
+            Transaction::Legacy(TxLegacy {
+                chain_id: Some(1),
+                nonce: 0,
+                gas_price: 2_000_000_000,
+                gas_limit: 100_000,
+                to: TxKind::Call(destructible),
+                value: U256::from(1),
+                input: Default::default(),
+            }),
+        );
+        let transaction_hash = *transaction.tx_hash();
+
+        add_block_on_genesis(&provider, test_header(), vec![transaction]);
+
+        let debug_api = test_debug_api(provider, None);
+
+        let selfdestructs =
+            debug_api.debug_get_self_destructs(BlockId::number(1)).await.unwrap().unwrap();
+        assert_eq!(
+            selfdestructs,
+            vec![LocalizedSelfDestruct {
+                transaction_hash,
+                transaction_position: 0,
+                selfdestruct: trace::SelfDestruct {
+                    address: destructible,
+                    refund_address,
+                    balance: ether + U256::from(1),
+                    // the contract existed before the transaction, only its balance is moved
+                    destroyed: false,
+                },
+            }]
+        );
+
+        assert!(debug_api.debug_get_self_destructs(BlockId::number(2)).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn internal_transactions_of_transaction() {
+        let provider = test_provider();
+
+        let mut rng = generators::rng();
+        let sender_key = generate_key(&mut rng);
+        let sender = public_key_to_address(sender_key.public_key());
+        let ether = U256::from(10).pow(U256::from(18));
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(10) * ether));
+
+        // sends 3 wei to 0xa1, 2 wei to 0xa2 and 1 wei to 0xa3
+        let splitter = Address::repeat_byte(0x10);
+        provider.add_account(
+            splitter,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(bytes!("0x6000600060006000600360a15af1506000600060006000600260a25af1506000600060006000600160a35af15000")),
+        );
+        // 0xa3 always reverts, so its transfer fails
+        provider.add_account(
+            Address::with_last_byte(0xa3),
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(bytes!("0x60006000fd")),
+        );
+
+        let transaction = sign_tx_with_key_pair(
+            sender_key,
+            Transaction::Legacy(TxLegacy {
+                chain_id: Some(1),
+                nonce: 0,
+                gas_price: 2_000_000_000,
+                gas_limit: 200_000,
+                to: TxKind::Call(splitter),
+                value: U256::from(10),
+                input: Default::default(),
+            }),
+        );
+        let transaction_hash = *transaction.tx_hash();
+
+        add_block_on_genesis(&provider, test_header(), vec![transaction]);
+
+        let debug_api = test_debug_api(provider, None);
+
+        let transfers =
+            debug_api.debug_get_internal_transactions(transaction_hash).await.unwrap().unwrap();
+        let transfer = |to, value: u64| InternalTransfer {
+            from: splitter,
+            to: Address::with_last_byte(to),
+            value: U256::from(value),
+            call_type: "CALL".to_string(),
+        };
+        assert_eq!(transfers, vec![transfer(0xa1, 3), transfer(0xa2, 2)]);
+
+        assert!(debug_api.debug_get_internal_transactions(B256::ZERO).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn opcode_gas_of_transaction() {
+        let provider = test_provider();
+
+        let mut rng = generators::rng();
+        let sender_key = generate_key(&mut rng);
+        let sender = public_key_to_address(sender_key.public_key());
+        let ether = U256::from(10).pow(U256::from(18));
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(10) * ether));
+
+        // increments storage slot 0: PUSH1 0 SLOAD PUSH1 1 ADD PUSH1 0 SSTORE STOP
+        let counter = Address::repeat_byte(0x10);
+        provider.add_account(
+            counter,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(bytes!("0x60005460010160005500")),
+        );
+
+        let transaction = sign_tx_with_key_pair(
+            sender_key,
+            Transaction::Legacy(TxLegacy {
+                chain_id: Some(1),
+                nonce: 0,
+                gas_price: 2_000_000_000,
+                gas_limit: 100_000,
+                to: TxKind::Call(counter),
+                value: U256::ZERO,
+                input: Default::default(),
+            }),
+        );
+        let transaction_hash = *transaction.tx_hash();
+
+        add_block_on_genesis(&provider, test_header(), vec![transaction]);
+
+        let debug_api = test_debug_api(provider, None);
+
+        let opcodes = debug_api.debug_get_opcode_gas(transaction_hash).await.unwrap().unwrap();
+        let gas = |count, gas_used| OpcodeGas { count, gas_used };
+        assert_eq!(
+            opcodes,
+            BTreeMap::from([
+                ("PUSH1".to_string(), gas(3, 9)),
+                // cold slot
+                ("SLOAD".to_string(), gas(1, 2_100)),
+                ("ADD".to_string(), gas(1, 3)),
+                // warm slot set from zero
+                ("SSTORE".to_string(), gas(1, 20_000)),
+                ("STOP".to_string(), gas(1, 0)),
+            ])
+        );
+
+        assert!(debug_api.debug_get_opcode_gas(B256::ZERO).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn opcode_gas_excludes_gas_forwarded_to_calls() {
+        let provider = test_provider();
+
+        let mut rng = generators::rng();
+        let sender_key = generate_key(&mut rng);
+        let sender = public_key_to_address(sender_key.public_key());
+        let ether = U256::from(10).pow(U256::from(18));
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(10) * ether));
+
+        // increments storage slot 0: PUSH1 0 SLOAD PUSH1 1 ADD PUSH1 0 SSTORE STOP
+        let counter = Address::repeat_byte(0x10);
+        provider.add_account(
+            counter,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(bytes!("0x60005460010160005500")),
+        );
+        // calls the counter with 0xffff gas and no value or calldata:
+        // PUSH1 0 PUSH1 0 PUSH1 0 PUSH1 0 PUSH1 0 PUSH20 counter PUSH2 0xffff CALL STOP
+        let caller = Address::repeat_byte(0x20);
+        provider.add_account(
+            caller,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(bytes!(
+                "0x6000600060006000600073101010101010101010101010101010101010101061fffff100"
+            )),
+        );
+
+        let transaction = sign_tx_with_key_pair(
+            sender_key,
+            Transaction::Legacy(TxLegacy {
+                chain_id: Some(1),
+                nonce: 0,
+                gas_price: 2_000_000_000,
+                gas_limit: 200_000,
+                to: TxKind::Call(caller),
+                value: U256::ZERO,
+                input: Default::default(),
+            }),
+        );
+        let transaction_hash = *transaction.tx_hash();
+
+        add_block_on_genesis(&provider, test_header(), vec![transaction]);
+
+        let debug_api = test_debug_api(provider, None);
+
+        let opcodes = debug_api.debug_get_opcode_gas(transaction_hash).await.unwrap().unwrap();
+        let gas = |count, gas_used| OpcodeGas { count, gas_used };
+        assert_eq!(
+            opcodes,
+            BTreeMap::from([
+                ("PUSH1".to_string(), gas(8, 24)),
+                ("PUSH20".to_string(), gas(1, 3)),
+                ("PUSH2".to_string(), gas(1, 3)),
+                // cold account access, the 0xffff forwarded to the counter is not included
+                ("CALL".to_string(), gas(1, 2_600)),
+                ("SLOAD".to_string(), gas(1, 2_100)),
+                ("ADD".to_string(), gas(1, 3)),
+                ("SSTORE".to_string(), gas(1, 20_000)),
+                ("STOP".to_string(), gas(2, 0)),
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn decoded_logs_of_transaction() {
+        let provider = test_provider();
+
+        let mut rng = generators::rng();
+        let sender_key = generate_key(&mut rng);
+        let sender = public_key_to_address(sender_key.public_key());
+        let ether = U256::from(10).pow(U256::from(18));
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(10) * ether));
+
+        // emits Transfer(0x11..11, 0x22..22, 5) and a log with the unknown topic 1:
+        // PUSH1 5 PUSH1 0 MSTORE PUSH20 to PUSH20 from PUSH32 topic0 PUSH1 32 PUSH1 0 LOG3
+        // PUSH1 1 PUSH1 0 PUSH1 0 LOG1 STOP
+        let token = Address::repeat_byte(0x10);
+        provider.add_account(
+            token,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(bytes!("0x60056000527322222222222222222222222222222222222222227311111111111111111111111111111111111111117fddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef60206000a3600160006000a100")),
+        );
+
+        let transaction = sign_tx_with_key_pair(
+            sender_key,
+            Transaction::Legacy(TxLegacy {
+                chain_id: Some(1),
+                nonce: 0,
+                gas_price: 2_000_000_000,
+                gas_limit: 100_000,
+                to: TxKind::Call(token),
+                value: U256::ZERO,
+                input: Default::default(),
+            }),
+        );
+        let transaction_hash = *transaction.tx_hash();
+
+        add_block_on_genesis(&provider, test_header(), vec![transaction]);
+
+        let debug_api = test_debug_api(provider, None);
+
+        let abi = JsonAbi::parse([
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+        ])
+        .unwrap();
+        let logs =
+            debug_api.debug_get_decoded_logs(transaction_hash, abi.clone()).await.unwrap().unwrap();
+        assert_eq!(logs.len(), 2);
+
+        let transfer = &logs[0];
+        assert_eq!(transfer.log.address, Some(token));
+        let decoded = transfer.decoded.as_ref().unwrap();
+        assert_eq!(decoded.signature, "Transfer(address,address,uint256)");
+        let params = decoded
+            .params
+            .iter()
+            .map(|param| (param.name.as_str(), param.value.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            params,
+            vec![
+                ("from", Address::repeat_byte(0x11).to_string().into()),
+                ("to", Address::repeat_byte(0x22).to_string().into()),
+                ("value", "5".into()),
+            ]
+        );
+
+        // unknown events are returned raw
+        assert_eq!(logs[1].log.topics, Some(vec![B256::with_last_byte(1)]));
+        assert!(logs[1].decoded.is_none());
+
+        assert!(debug_api.debug_get_decoded_logs(B256::ZERO, abi).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn raw_transaction_decodes_to_same_transaction() {
+        let provider = test_provider();
+
+        let mut rng = generators::rng();
+        let legacy = sign_tx_with_key_pair(
+            generate_key(&mut rng),
+            Transaction::Legacy(TxLegacy {
+                chain_id: Some(1),
+                gas_price: 2_000_000_000,
+                gas_limit: 21_000,
+                to: TxKind::Call(Address::repeat_byte(0x11)),
+                value: U256::from(1),
+                ..Default::default()
+            }),
+        );
+        let eip1559 = sign_tx_with_key_pair(
+            generate_key(&mut rng),
+            Transaction::Eip1559(TxEip1559 {
+                chain_id: 1,
+                max_fee_per_gas: 2_000_000_000,
+                max_priority_fee_per_gas: 1_000_000_000,
+                gas_limit: 21_000,
+                to: TxKind::Call(Address::repeat_byte(0x11)),
+                value: U256::from(1),
+                ..Default::default()
+            }),
+        );
+
+        let block = Block::new(
+            Header::default(),
+            BlockBody { transactions: vec![legacy.clone(), eip1559.clone()], ..Default::default() },
+        );
+        provider.add_block(block.header.hash_slow(), block);
+
+        let debug_api = test_debug_api(provider, None);
+
+        // legacy transactions are encoded as a plain RLP list without a type prefix
+        let raw = debug_api.raw_transaction(*legacy.tx_hash()).await.unwrap().unwrap();
+        assert!(raw[0] >= 0xc0);
+        assert_eq!(TransactionSigned::decode_2718(&mut raw.as_ref()).unwrap(), legacy);
+
+        // typed transactions are prefixed with their type
+        let raw = debug_api.raw_transaction(*eip1559.tx_hash()).await.unwrap().unwrap();
+        assert_eq!(raw[0], 2);
+        assert_eq!(TransactionSigned::decode_2718(&mut raw.as_ref()).unwrap(), eip1559);
+
+        assert!(debug_api.raw_transaction(B256::ZERO).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn trace_call_many_carries_state_across_calls() {
+        let provider = test_provider();
+
+        // without calldata: returns the value of storage slot 0 and then increments it
+        // with calldata: increments storage slot 0 and then reverts
+        let counter = Address::repeat_byte(0x10);
+        let code =
+            bytes!("0x366016576000548060005260010160005560206000f35b60005460010160005560006000fd");
+        provider.add_account(
+            counter,
+            ExtendedAccount::new(0, U256::ZERO)
+                .with_bytecode(code)
+                .extend_storage([(B256::ZERO, U256::from(7))]),
+        );
+
+        add_genesis(&provider, test_header());
+
+        let debug_api = test_debug_api(provider, None);
+
+        let read = || TransactionRequest::default().to(counter);
+        let revert = || read().input(Bytes::from_static(&[1]).into());
+        let bundles = vec![
+            Bundle { transactions: vec![read(), revert(), read()], block_override: None },
+            Bundle { transactions: vec![read()], block_override: None },
+        ];
+
+        let traces = debug_api
+            .debug_trace_call_many(bundles, Some(StateContext::default()), None)
+            .await
+            .unwrap();
+        let frames = traces
+            .into_iter()
+            .map(|bundle| {
+                bundle
+                    .into_iter()
+                    .map(|trace| match trace {
+                        GethTrace::Default(frame) => frame,
+                        trace => panic!("unexpected trace {trace:?}"),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let counter_value = |value: u64| Bytes::from(B256::from(U256::from(value)));
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].len(), 3);
+        assert!(!frames[0][0].failed);
+        assert_eq!(frames[0][0].return_value, counter_value(7));
+        // the reverted increment is discarded
+        assert!(frames[0][1].failed);
+        assert!(!frames[0][2].failed);
+        assert_eq!(frames[0][2].return_value, counter_value(8));
+        // state is carried over to the next bundle
+        assert_eq!(frames[1].len(), 1);
+        assert_eq!(frames[1][0].return_value, counter_value(9));
+    }
+
+    #[tokio::test]
+    async fn trace_call_records_return_data_per_step() {
+        let provider = test_provider();
+
+        // returns 42 as a 32 byte word: PUSH1 42 PUSH1 0 MSTORE PUSH1 32 PUSH1 0 RETURN
+        let callee = Address::repeat_byte(0x20);
+        provider.add_account(
+            callee,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(bytes!("0x602a60005260206000f3")),
+        );
+        // calls the callee without arguments and discards the result:
+        // PUSH1 0 (x5) PUSH20 callee GAS CALL POP STOP
+        let caller = Address::repeat_byte(0x10);
+        provider.add_account(
+            caller,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(bytes!(
+                "0x600060006000600060007320202020202020202020202020202020202020205af15000"
+            )),
+        );
+
+        add_genesis(&provider, test_header());
+
+        let debug_api = test_debug_api(provider, None);
+
+        let trace = |enable_return_data| {
+            let opts = GethDebugTracingCallOptions::default().with_tracing_options(
+                GethDebugTracingOptions {
+                    config: GethDefaultTracingOptions::default()
+                        .with_enable_return_data(enable_return_data),
+                    ..Default::default()
+                },
+            );
+            debug_api.debug_trace_call(
+                TransactionRequest::default().to(caller),
+                Some(BlockId::latest()),
+                opts,
+            )
+        };
+
+        let frame = trace(true).await.unwrap().try_into_default_frame().unwrap();
+        let steps = frame
+            .struct_logs
+            .iter()
+            .map(|log| (log.op.as_ref(), log.depth, log.return_data.clone().unwrap_or_default()))
+            .collect::<Vec<_>>();
+        // geth reports the return data of the last call, which is only set once the callee
+        // returned
+        let answer = Bytes::from(B256::from(U256::from(42)));
+        let mut expected = vec![("PUSH1", 1, Bytes::new()); 5];
+        expected.extend([("PUSH20", 1, Bytes::new()), ("GAS", 1, Bytes::new())]);
+        expected.push(("CALL", 1, Bytes::new()));
+        expected.extend(
+            ["PUSH1", "PUSH1", "MSTORE", "PUSH1", "PUSH1", "RETURN"]
+                .map(|op| (op, 2, Bytes::new())),
+        );
+        expected.extend([("POP", 1, answer.clone()), ("STOP", 1, answer)]);
+        assert_eq!(steps, expected);
+
+        // return data is opt-in
+        let frame = trace(false).await.unwrap().try_into_default_frame().unwrap();
+        assert_eq!(frame.struct_logs.len(), expected.len());
+        assert!(frame.struct_logs.iter().all(|log| log.return_data.is_none()));
+    }
+
+    #[tokio::test]
+    async fn trace_call_aborts_once_output_exceeds_size_limit() {
+        let provider = test_provider();
+
+        // counts down from 256 in a loop:
+        // PUSH2 256 JUMPDEST PUSH1 1 SWAP1 SUB DUP1 PUSH1 3 JUMPI STOP
+        let looper = Address::repeat_byte(0x10);
+        provider.add_account(
+            looper,
+            ExtendedAccount::new(0, U256::ZERO)
+                .with_bytecode(bytes!("0x6101005b600190038060035700")),
+        );
+
+        add_genesis(&provider, test_header());
+
+        let trace = |limit, tracer: Option<GethDebugTracerType>| {
+            let debug_api = test_debug_api(provider.clone(), limit);
+            let opts = GethDebugTracingCallOptions::default()
+                .with_tracing_options(GethDebugTracingOptions { tracer, ..Default::default() });
+            let request = TransactionRequest::default().to(looper);
+            async move { debug_api.debug_trace_call(request, Some(BlockId::latest()), opts).await }
+        };
+
+        let limit = 10_000;
+        let trace_size = serde_json::to_vec(&trace(None, None).await.unwrap()).unwrap().len();
+        assert!(trace_size > limit);
+
+        let err = trace(Some(limit), None).await.unwrap_err();
+        assert!(matches!(err, EthApiError::TraceTooLarge { limit: 10_000 }));
+
+        // the same execution as a single call frame fits into the limit
+        let call_tracer = GethDebugBuiltInTracerType::CallTracer.into();
+        assert!(trace(Some(limit), Some(call_tracer)).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn prestate_tracer_reports_accessed_accounts_and_diffs() {
+        let provider = test_provider();
+
+        // only read by the contract
+        let bystander = Address::repeat_byte(0x30);
+        provider.add_account(bystander, ExtendedAccount::new(1, U256::from(7)));
+        // reads the balance of the bystander and stores 1 in slot 0:
+        // PUSH20 bystander BALANCE POP PUSH1 1 PUSH1 0 SSTORE STOP
+        let contract = Address::repeat_byte(0x10);
+        let code = bytes!("0x7330303030303030303030303030303030303030303150600160005500");
+        provider.add_account(
+            contract,
+            ExtendedAccount::new(0, U256::ZERO)
+                .with_bytecode(code.clone())
+                .extend_storage([(B256::ZERO, U256::from(5))]),
+        );
+
+        add_genesis(&provider, test_header());
+
+        let debug_api = test_debug_api(provider, None);
+
+        let trace = |diff_mode| {
+            let opts = GethDebugTracingCallOptions::default().with_tracing_options(
+                GethDebugTracingOptions::default()
+                    .with_tracer(GethDebugBuiltInTracerType::PreStateTracer.into())
+                    .with_prestate_config(PreStateConfig {
+                        diff_mode: Some(diff_mode),
+                        ..Default::default()
+                    }),
+            );
+            debug_api.debug_trace_call(
+                TransactionRequest::default().to(contract),
+                Some(BlockId::latest()),
+                opts,
+            )
+        };
+
+        let GethTrace::PreStateTracer(PreStateFrame::Default(PreStateMode(prestate))) =
+            trace(false).await.unwrap()
+        else {
+            panic!("expected prestate frame")
+        };
+        // the bystander is included even though it is unchanged
+        let account = &prestate[&bystander];
+        assert_eq!((account.balance, account.nonce), (Some(U256::from(7)), Some(1)));
+        assert!(account.storage.is_empty());
+        let account = &prestate[&contract];
+        assert_eq!(account.code, Some(code));
+        assert_eq!(account.storage, BTreeMap::from([(B256::ZERO, B256::with_last_byte(5))]));
+
+        let GethTrace::PreStateTracer(PreStateFrame::Diff(DiffMode { pre, post })) =
+            trace(true).await.unwrap()
+        else {
+            panic!("expected prestate diff frame")
+        };
+        // unchanged accounts are omitted in diff mode
+        assert!(!pre.contains_key(&bystander));
+        assert!(!post.contains_key(&bystander));
+        assert_eq!(pre[&contract].storage, BTreeMap::from([(B256::ZERO, B256::with_last_byte(5))]));
+        assert_eq!(
+            post[&contract].storage,
+            BTreeMap::from([(B256::ZERO, B256::with_last_byte(1))])
+        );
+    }
+
+    #[tokio::test]
+    async fn trace_transaction_reads_historical_state() {
+        let chain_spec = Arc::new(ChainSpecBuilder::mainnet().cancun_activated().build());
+        let factory = create_test_provider_factory_with_chain_spec(chain_spec);
+
+        let sender_key = generate_key(&mut generators::rng());
+        let sender = public_key_to_address(sender_key.public_key());
+        let sender_info =
+            AccountInfo { balance: U256::from(10).pow(U256::from(18)), ..Default::default() };
+        // reads slot 0: PUSH1 0 SLOAD POP STOP
+        let contract = Address::repeat_byte(0x10);
+        let code = Bytecode::new_raw(bytes!("0x6000545000"));
+        let contract_info = AccountInfo {
+            code_hash: code.hash_slow(),
+            code: Some(code.clone()),
+            ..Default::default()
+        };
+
+        // the genesis block creates the contract with 1 in slot 0
+        let genesis = SealedBlock::seal_slow(Block::new(
+            test_header(),
+            BlockBody::<TransactionSigned>::default(),
+        ));
+        let genesis_state = BundleState::builder(0..=0)
+            .state_present_account_info(sender, sender_info)
+            .revert_account_info(0, sender, Some(None))
+            .state_present_account_info(contract, contract_info.clone())
+            .revert_account_info(0, contract, Some(None))
+            .state_storage(
+                contract,
+                [(U256::ZERO, (U256::ZERO, U256::from(1)))].into_iter().collect(),
+            )
+            .revert_storage(0, contract, vec![(U256::ZERO, U256::ZERO)])
+            .contract(contract_info.code_hash, code)
+            .build();
+
+        // block 1 calls the contract, its state changes are irrelevant for the trace
+        let transaction = sign_tx_with_key_pair(
+            sender_key,
+            Transaction::Legacy(TxLegacy {
+                chain_id: Some(1),
+                nonce: 0,
+                gas_price: 2_000_000_000,
+                gas_limit: 100_000,
+                to: TxKind::Call(contract),
+                value: U256::ZERO,
+                input: Default::default(),
+            }),
+        );
+        let tx_hash = *transaction.tx_hash();
+        let block1 = SealedBlock::seal_slow(Block::new(
+            Header { number: 1, parent_hash: genesis.hash(), timestamp: 12, ..test_header() },
+            BlockBody { transactions: vec![transaction], ..Default::default() },
+        ));
+
+        // block 2 overwrites slot 0 with 2
+        let block2 = SealedBlock::seal_slow(Block::new(
+            Header { number: 2, parent_hash: block1.hash(), timestamp: 24, ..test_header() },
+            BlockBody::<TransactionSigned>::default(),
+        ));
+        let block2_state = BundleState::builder(2..=2)
+            .state_present_account_info(contract, contract_info)
+            .state_storage(
+                contract,
+                [(U256::ZERO, (U256::from(1), U256::from(2)))].into_iter().collect(),
+            )
+            .revert_storage(2, contract, vec![(U256::ZERO, U256::from(1))])
+            .build();
+
+        let provider_rw = factory.provider_rw().unwrap();
+        for (block, senders, state, receipts) in [
+            (genesis, vec![], genesis_state, vec![]),
+            (block1, vec![sender], BundleState::default(), vec![Receipt::default()]),
+            (block2.clone(), vec![], block2_state, vec![]),
+        ] {
+            let number = block.number();
+            provider_rw
+                .append_blocks_with_state(
+                    vec![RecoveredBlock::new_sealed(block, senders)],
+                    &ExecutionOutcome::new(state, vec![receipts], number, Vec::new()),
+                    Default::default(),
+                )
+                .unwrap();
+        }
+        provider_rw.commit().unwrap();
+
+        let provider = BlockchainProvider::<MockNodeTypesWithDB>::with_latest(
+            factory,
+            block2.clone_sealed_header(),
+        )
+        .unwrap();
+        let evm_config = EthEvmConfig::new(provider.chain_spec());
+        let eth_api =
+            EthApi::builder(provider, testing_pool(), NoopNetwork::default(), evm_config).build();
+        let debug_api = DebugApi::new(eth_api, BlockingTaskGuard::new(1));
+
+        let opts = GethDebugTracingOptions::default()
+            .with_tracer(GethDebugBuiltInTracerType::PreStateTracer.into());
+        let GethTrace::PreStateTracer(PreStateFrame::Default(PreStateMode(prestate))) =
+            debug_api.debug_trace_transaction(tx_hash, opts).await.unwrap()
+        else {
+            panic!("expected prestate frame")
+        };
+        // the slot is read as of the parent block, reverted from the changeset of block 2
+        assert_eq!(
+            prestate[&contract].storage,
+            BTreeMap::from([(B256::ZERO, B256::with_last_byte(1))])
+        );
+    }
+
+    #[tokio::test]
+    async fn trace_call_applies_block_overrides() {
+        let provider = test_provider();
+
+        // returns the block context as five words:
+        // COINBASE PUSH1 0 MSTORE TIMESTAMP PUSH1 32 MSTORE PREVRANDAO PUSH1 64 MSTORE
+        // BASEFEE PUSH1 96 MSTORE GASLIMIT PUSH1 128 MSTORE PUSH1 160 PUSH1 0 RETURN
+        let sender = Address::repeat_byte(0x01);
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(10).pow(U256::from(18))));
+        let contract = Address::repeat_byte(0x10);
+        provider.add_account(
+            contract,
+            ExtendedAccount::new(0, U256::ZERO)
+                .with_bytecode(bytes!("0x416000524260205244604052486060524560805260a06000f3")),
+        );
+
+        add_genesis(&provider, test_header());
+
+        let debug_api = test_debug_api(provider, None);
+
+        let context = |coinbase: Address, timestamp: u64, prevrandao, base_fee: u64, gas_limit| {
+            [
+                coinbase.into_word(),
+                B256::from(U256::from(timestamp)),
+                prevrandao,
+                B256::from(U256::from(base_fee)),
+                B256::from(U256::from(gas_limit)),
+            ]
+            .concat()
+        };
+        // a call without gas price executes with a zero base fee, so set one
+        let request = || {
+            TransactionRequest::default().from(sender).to(contract).gas_limit(100_000).gas_price(7)
+        };
+        let trace = |overrides: Option<BlockOverrides>| {
+            let opts =
+                GethDebugTracingCallOptions { block_overrides: overrides, ..Default::default() };
+            debug_api.debug_trace_call(request(), Some(BlockId::latest()), opts)
+        };
+
+        let frame = trace(None).await.unwrap().try_into_default_frame().unwrap();
+        assert_eq!(
+            frame.return_value,
+            context(Address::ZERO, 0, B256::ZERO, 1_000_000_000, 30_000_000)
+        );
+
+        let coinbase = Address::repeat_byte(0xcb);
+        let prevrandao = B256::repeat_byte(0x42);
+        let overrides = BlockOverrides {
+            coinbase: Some(coinbase),
+            time: Some(1_700_000_000),
+            random: Some(prevrandao),
+            base_fee: Some(U256::from(7)),
+            gas_limit: Some(1_000_000),
+            ..Default::default()
+        };
+        let expected = context(coinbase, 1_700_000_000, prevrandao, 7, 1_000_000);
+        let frame = trace(Some(overrides.clone())).await.unwrap().try_into_default_frame().unwrap();
+        assert_eq!(frame.return_value, expected);
+
+        // eth_call applies the same overrides
+        let output = debug_api
+            .eth_api()
+            .call(
+                request(),
+                Some(BlockId::latest()),
+                EvmOverrides::new(None, Some(Box::new(overrides))),
+            )
+            .await
+            .unwrap();
+        assert_eq!(output, expected);
+    }
+
+    #[tokio::test]
+    async fn trace_call_pays_fees_to_overridden_coinbase() {
+        let provider = test_provider();
+
+        let sender = Address::repeat_byte(0x01);
+        let ether = U256::from(10).pow(U256::from(18));
+        provider.add_account(sender, ExtendedAccount::new(0, ether));
+
+        let header = Header { beneficiary: Address::repeat_byte(0xbb), ..test_header() };
+        add_genesis(&provider, header);
+
+        let debug_api = test_debug_api(provider, None);
+
+        let coinbase = Address::repeat_byte(0xcb);
+        let opts = GethDebugTracingCallOptions::default()
+            .with_tracing_options(
+                GethDebugTracingOptions::default()
+                    .with_tracer(GethDebugBuiltInTracerType::PreStateTracer.into())
+                    .with_prestate_config(PreStateConfig {
+                        diff_mode: Some(true),
+                        ..Default::default()
+                    }),
+            )
+            .with_block_overrides(BlockOverrides {
+                coinbase: Some(coinbase),
+                base_fee: Some(U256::from(1_000_000_000)),
+                ..Default::default()
+            });
+        let request = TransactionRequest::default()
+            .from(sender)
+            .to(Address::repeat_byte(0x22))
+            .gas_limit(21_000)
+            .gas_price(3_000_000_000);
+
+        let GethTrace::PreStateTracer(PreStateFrame::Diff(DiffMode { post, .. })) =
+            debug_api.debug_trace_call(request, Some(BlockId::latest()), opts).await.unwrap()
+        else {
+            panic!("expected prestate diff frame")
+        };
+        // the tip above the overridden base fee goes to the overridden coinbase
+        let tip = U256::from(21_000u64 * 2_000_000_000);
+        assert_eq!(post[&coinbase].balance, Some(tip));
+        assert!(!post.contains_key(&Address::repeat_byte(0xbb)));
+    }
+}
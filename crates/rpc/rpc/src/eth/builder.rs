@@ -14,8 +14,9 @@ use reth_rpc_eth_types::{
     receipt::EthReceiptConverter, EthStateCache, EthStateCacheConfig, FeeHistoryCache,
     FeeHistoryCacheConfig, ForwardConfig, GasCap, GasPriceOracle, GasPriceOracleConfig,
 };
-use reth_rpc_server_types::constants::{
-    DEFAULT_ETH_PROOF_WINDOW, DEFAULT_MAX_SIMULATE_BLOCKS, DEFAULT_PROOF_PERMITS,
+use reth_rpc_server_types::{
+    constants::{DEFAULT_ETH_PROOF_WINDOW, DEFAULT_MAX_SIMULATE_BLOCKS, DEFAULT_PROOF_PERMITS},
+    result::RetryConfig,
 };
 use reth_tasks::{pool::BlockingTaskPool, TaskSpawner, TokioTaskExecutor};
 use std::{sync::Arc, time::Duration};
@@ -45,6 +46,8 @@ pub struct EthApiBuilder<N: RpcNodeCore, Rpc, NextEnv = ()> {
     raw_tx_forwarder: ForwardConfig,
     send_raw_transaction_sync_timeout: Duration,
     evm_memory_limit: u64,
+    transient_retry: RetryConfig,
+    max_trace_response_size: Option<usize>,
 }
 
 impl<Provider, Pool, Network, EvmConfig, ChainSpec>
@@ -96,6 +99,8 @@ impl<N: RpcNodeCore, Rpc, NextEnv> EthApiBuilder<N, Rpc, NextEnv> {
             raw_tx_forwarder,
             send_raw_transaction_sync_timeout,
             evm_memory_limit,
+            transient_retry,
+            max_trace_response_size,
         } = self;
         EthApiBuilder {
             components,
@@ -117,6 +122,8 @@ impl<N: RpcNodeCore, Rpc, NextEnv> EthApiBuilder<N, Rpc, NextEnv> {
             raw_tx_forwarder,
             send_raw_transaction_sync_timeout,
             evm_memory_limit,
+            transient_retry,
+            max_trace_response_size,
         }
     }
 }
@@ -149,6 +156,8 @@ where
             raw_tx_forwarder: ForwardConfig::default(),
             send_raw_transaction_sync_timeout: Duration::from_secs(30),
             evm_memory_limit: (1 << 32) - 1,
+            transient_retry: RetryConfig::default(),
+            max_trace_response_size: None,
         }
     }
 }
@@ -188,6 +197,8 @@ where
             raw_tx_forwarder,
             send_raw_transaction_sync_timeout,
             evm_memory_limit,
+            transient_retry,
+            max_trace_response_size,
         } = self;
         EthApiBuilder {
             components,
@@ -209,6 +220,8 @@ where
             raw_tx_forwarder,
             send_raw_transaction_sync_timeout,
             evm_memory_limit,
+            transient_retry,
+            max_trace_response_size,
         }
     }
 
@@ -237,6 +250,8 @@ where
             raw_tx_forwarder,
             send_raw_transaction_sync_timeout,
             evm_memory_limit,
+            transient_retry,
+            max_trace_response_size,
         } = self;
         EthApiBuilder {
             components,
@@ -258,6 +273,8 @@ where
             raw_tx_forwarder,
             send_raw_transaction_sync_timeout,
             evm_memory_limit,
+            transient_retry,
+            max_trace_response_size,
         }
     }
 
@@ -486,6 +503,8 @@ where
             raw_tx_forwarder,
             send_raw_transaction_sync_timeout,
             evm_memory_limit,
+            transient_retry,
+            max_trace_response_size,
         } = self;
 
         let provider = components.provider().clone();
@@ -527,6 +546,8 @@ where
             raw_tx_forwarder.forwarder_client(),
             send_raw_transaction_sync_timeout,
             evm_memory_limit,
+            transient_retry,
+            max_trace_response_size,
         )
     }
 
@@ -557,4 +578,16 @@ where
         self.evm_memory_limit = memory_limit;
         self
     }
+
+    /// Sets how reads that failed with a transient provider error are retried.
+    pub const fn transient_retry(mut self, transient_retry: RetryConfig) -> Self {
+        self.transient_retry = transient_retry;
+        self
+    }
+
+    /// Sets the maximum size in bytes of the serialized output of a single trace request.
+    pub const fn max_trace_response_size(mut self, max_trace_response_size: Option<usize>) -> Self {
+        self.max_trace_response_size = max_trace_response_size;
+        self
+    }
 }
@@ -24,6 +24,7 @@ use reth_rpc_eth_types::{
     builder::config::PendingBlockKind, receipt::EthReceiptConverter, tx_forward::ForwardConfig,
     EthApiError, EthStateCache, FeeHistoryCache, GasCap, GasPriceOracle, PendingBlock,
 };
+use reth_rpc_server_types::result::RetryConfig;
 use reth_storage_api::{noop::NoopProvider, BlockReaderIdExt, ProviderHeader};
 use reth_tasks::{
     pool::{BlockingTaskGuard, BlockingTaskPool},
@@ -175,6 +176,8 @@ where
             raw_tx_forwarder.forwarder_client(),
             send_raw_transaction_sync_timeout,
             evm_memory_limit,
+            RetryConfig::default(),
+            None,
         );
 
         Self { inner: Arc::new(inner) }
@@ -323,6 +326,12 @@ pub struct EthApiInner<N: RpcNodeCore, Rpc: RpcConvert> {
 
     /// Maximum memory the EVM can allocate per RPC request.
     evm_memory_limit: u64,
+
+    /// Retries of reads that failed with a transient provider error.
+    transient_retry: RetryConfig,
+
+    /// Maximum size in bytes of the serialized output of a single trace request.
+    max_trace_response_size: Option<usize>,
 }
 
 impl<N, Rpc> EthApiInner<N, Rpc>
@@ -350,6 +359,8 @@ where
         raw_tx_forwarder: Option<RpcClient>,
         send_raw_transaction_sync_timeout: Duration,
         evm_memory_limit: u64,
+        transient_retry: RetryConfig,
+        max_trace_response_size: Option<usize>,
     ) -> Self {
         let signers = parking_lot::RwLock::new(Default::default());
         // get the block number of the latest block
@@ -393,6 +404,8 @@ where
             send_raw_transaction_sync_timeout,
             blob_sidecar_converter: BlobSidecarConverter::new(),
             evm_memory_limit,
+            transient_retry,
+            max_trace_response_size,
         }
     }
 }
@@ -576,6 +589,18 @@ where
     pub const fn evm_memory_limit(&self) -> u64 {
         self.evm_memory_limit
     }
+
+    /// Returns the config for retrying reads that failed with a transient provider error.
+    #[inline]
+    pub const fn transient_retry(&self) -> RetryConfig {
+        self.transient_retry
+    }
+
+    /// Returns the maximum size in bytes of the serialized output of a single trace request.
+    #[inline]
+    pub const fn max_trace_response_size(&self) -> Option<usize> {
+        self.max_trace_response_size
+    }
 }
 
 #[cfg(test)]
@@ -583,23 +608,29 @@ mod tests {
     use crate::{eth::helpers::types::EthRpcConverter, EthApi, EthApiBuilder};
     use alloy_consensus::{Block, BlockBody, Header};
     use alloy_eips::BlockNumberOrTag;
-    use alloy_primitives::{Signature, B256, U64};
-    use alloy_rpc_types::FeeHistory;
+    use alloy_primitives::{Signature, B256, U256, U64};
+    use alloy_rlp::Encodable;
+    use alloy_rpc_types::{BlockTransactions, FeeHistory};
     use jsonrpsee_types::error::INVALID_PARAMS_CODE;
     use rand::Rng;
     use reth_chain_state::CanonStateSubscriptions;
-    use reth_chainspec::{ChainSpec, ChainSpecProvider, EthChainSpec};
+    use reth_chainspec::{Chain, ChainSpec, ChainSpecBuilder, ChainSpecProvider, EthChainSpec};
     use reth_ethereum_primitives::TransactionSigned;
     use reth_evm_ethereum::EthEvmConfig;
     use reth_network_api::noop::NoopNetwork;
+    use reth_primitives_traits::SealedHeader;
     use reth_provider::{
-        test_utils::{MockEthProvider, NoopProvider},
+        test_utils::{ExtendedAccount, MockEthProvider, NoopProvider},
         StageCheckpointReader,
     };
-    use reth_rpc_eth_api::{node::RpcNodeCoreAdapter, EthApiServer};
+    use reth_rpc_eth_api::{node::RpcNodeCoreAdapter, EthApiServer, RpcNodeCore};
+    use reth_rpc_eth_types::TransactionStatus;
     use reth_storage_api::{BlockReader, BlockReaderIdExt, StateProviderFactory};
     use reth_testing_utils::generators;
-    use reth_transaction_pool::test_utils::{testing_pool, TestPool};
+    use reth_transaction_pool::{
+        test_utils::{testing_pool, MockTransaction, TestPool},
+        QueuedReason, TransactionOrigin, TransactionPool,
+    };
 
     type FakeEthApi<P = MockEthProvider> = EthApi<
         RpcNodeCoreAdapter<P, TestPool, NoopNetwork, EthEvmConfig>,
@@ -877,4 +908,259 @@ mod tests {
             "all: no percentiles were requested, so there should be no rewards result"
         );
     }
+
+    #[tokio::test]
+    async fn test_block_transaction_count() {
+        use reth_db_api::models::StoredBlockBodyIndices;
+
+        let transaction = |nonce| {
+            TransactionSigned::new_unhashed(
+                alloy_consensus::TxLegacy { nonce, gas_limit: 21_000, ..Default::default() }.into(),
+                Signature::test_signature(),
+            )
+        };
+
+        let mock_provider = MockEthProvider::default();
+        let header = Header { number: 1, ..Default::default() };
+        let hash = header.hash_slow();
+        let body =
+            BlockBody { transactions: (0..3).map(transaction).collect(), ..Default::default() };
+        mock_provider.add_block(hash, Block { header, body });
+        mock_provider
+            .add_block_body_indices(1, StoredBlockBodyIndices { first_tx_num: 0, tx_count: 3 });
+
+        // a non-canonical block at the same height
+        let fork_header = Header { number: 1, timestamp: 1, ..Default::default() };
+        let fork_hash = fork_header.hash_slow();
+        let fork_body = BlockBody { transactions: vec![transaction(0)], ..Default::default() };
+        mock_provider.add_block(fork_hash, Block { header: fork_header, body: fork_body });
+
+        let eth_api = build_test_eth_api(mock_provider);
+
+        let count =
+            <EthApi<_, _> as EthApiServer<_, _, _, _, _, _>>::block_transaction_count_by_number(
+                &eth_api,
+                BlockNumberOrTag::Number(1),
+            )
+            .await
+            .unwrap();
+        assert_eq!(count, Some(U256::from(3)));
+
+        let count =
+            <EthApi<_, _> as EthApiServer<_, _, _, _, _, _>>::block_transaction_count_by_hash(
+                &eth_api, hash,
+            )
+            .await
+            .unwrap();
+        assert_eq!(count, Some(U256::from(3)));
+
+        // blocks requested by hash are resolved by hash and not by their number
+        let count =
+            <EthApi<_, _> as EthApiServer<_, _, _, _, _, _>>::block_transaction_count_by_hash(
+                &eth_api, fork_hash,
+            )
+            .await
+            .unwrap();
+        assert_eq!(count, Some(U256::from(1)));
+
+        // unknown blocks
+        let count =
+            <EthApi<_, _> as EthApiServer<_, _, _, _, _, _>>::block_transaction_count_by_number(
+                &eth_api,
+                BlockNumberOrTag::Number(2),
+            )
+            .await
+            .unwrap();
+        assert_eq!(count, None);
+
+        let count =
+            <EthApi<_, _> as EthApiServer<_, _, _, _, _, _>>::block_transaction_count_by_hash(
+                &eth_api,
+                B256::with_last_byte(1),
+            )
+            .await
+            .unwrap();
+        assert_eq!(count, None);
+
+        // the pending block is built locally on top of the latest block from the (empty) pool
+        let count =
+            <EthApi<_, _> as EthApiServer<_, _, _, _, _, _>>::block_transaction_count_by_number(
+                &eth_api,
+                BlockNumberOrTag::Pending,
+            )
+            .await
+            .unwrap();
+        assert_eq!(count, Some(U256::ZERO));
+    }
+
+    #[tokio::test]
+    async fn test_block_by_number_full_transactions() {
+        let mock_provider = MockEthProvider::default();
+        let mined = TransactionSigned::new_unhashed(
+            alloy_consensus::TxLegacy { gas_limit: 21_000, ..Default::default() }.into(),
+            Signature::test_signature(),
+        );
+        let header = Header { number: 1, gas_limit: 30_000_000, ..Default::default() };
+        let block_hash = header.hash_slow();
+        mock_provider.add_block(
+            block_hash,
+            Block {
+                header,
+                body: BlockBody { transactions: vec![mined.clone()], ..Default::default() },
+            },
+        );
+        // included in the locally built pending block
+        let pending = MockTransaction::legacy().with_gas_limit(21_000).with_gas_price(7);
+        mock_provider.add_account(
+            *pending.get_sender(),
+            ExtendedAccount::new(0, U256::from(1_000_000_000_000_000_000u128)),
+        );
+        let eth_api = build_test_eth_api(mock_provider);
+
+        eth_api.pool().add_transaction(TransactionOrigin::External, pending.clone()).await.unwrap();
+
+        let block = |number, full| {
+            <EthApi<_, _> as EthApiServer<_, _, _, _, _, _>>::block_by_number(
+                &eth_api, number, full,
+            )
+        };
+
+        let latest = block(BlockNumberOrTag::Latest, false).await.unwrap().unwrap();
+        assert_eq!(latest.transactions, BlockTransactions::Hashes(vec![*mined.tx_hash()]));
+
+        let latest = block(BlockNumberOrTag::Latest, true).await.unwrap().unwrap();
+        let BlockTransactions::Full(transactions) = latest.transactions else {
+            panic!("expected full transactions")
+        };
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(*transactions[0].inner.tx_hash(), *mined.tx_hash());
+        assert_eq!(transactions[0].block_hash, Some(block_hash));
+        assert_eq!(transactions[0].block_number, Some(1));
+        assert_eq!(transactions[0].transaction_index, Some(0));
+
+        let pending_block = block(BlockNumberOrTag::Pending, false).await.unwrap().unwrap();
+        assert_eq!(pending_block.header.number, 2);
+        assert_eq!(
+            pending_block.transactions,
+            BlockTransactions::Hashes(vec![*pending.get_hash()])
+        );
+
+        let pending_block = block(BlockNumberOrTag::Pending, true).await.unwrap().unwrap();
+        let BlockTransactions::Full(transactions) = pending_block.transactions else {
+            panic!("expected full transactions")
+        };
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(*transactions[0].inner.tx_hash(), *pending.get_hash());
+        assert_eq!(transactions[0].block_hash, None);
+        assert_eq!(transactions[0].block_number, None);
+        assert_eq!(transactions[0].transaction_index, None);
+    }
+
+    #[tokio::test]
+    async fn test_header_by_number_and_hash() {
+        let mock_provider = MockEthProvider::default();
+        let header = Header {
+            number: 1,
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(7),
+            withdrawals_root: Some(B256::with_last_byte(1)),
+            blob_gas_used: Some(131_072),
+            excess_blob_gas: Some(0),
+            parent_beacon_block_root: Some(B256::with_last_byte(2)),
+            ..Default::default()
+        };
+        let sealed = SealedHeader::seal_slow(header.clone());
+        let block = Block { header, body: BlockBody::default() };
+        let block_size = block.length();
+        mock_provider.add_block(sealed.hash(), block);
+        let eth_api = build_test_eth_api(mock_provider);
+
+        let by_number = <EthApi<_, _> as EthApiServer<_, _, _, _, _, _>>::header_by_number(
+            &eth_api,
+            BlockNumberOrTag::Number(1),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        let by_hash = <EthApi<_, _> as EthApiServer<_, _, _, _, _, _>>::header_by_hash(
+            &eth_api,
+            sealed.hash(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        for rpc_header in [by_number, by_hash] {
+            assert_eq!(rpc_header.hash, sealed.hash());
+            assert_eq!(rpc_header.inner, *sealed.header());
+            assert_eq!(rpc_header.inner.hash_slow(), sealed.hash());
+            assert_eq!(rpc_header.size, Some(U256::from(block_size)));
+        }
+
+        assert!(<EthApi<_, _> as EthApiServer<_, _, _, _, _, _>>::header_by_number(
+            &eth_api,
+            BlockNumberOrTag::Number(2),
+        )
+        .await
+        .unwrap()
+        .is_none());
+        assert!(<EthApi<_, _> as EthApiServer<_, _, _, _, _, _>>::header_by_hash(
+            &eth_api,
+            B256::with_last_byte(3),
+        )
+        .await
+        .unwrap()
+        .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_transaction_status() {
+        let mock_provider = MockEthProvider::default();
+        let mined = TransactionSigned::new_unhashed(
+            alloy_consensus::TxLegacy::default().into(),
+            Signature::test_signature(),
+        );
+        let header = Header { number: 1, ..Default::default() };
+        let block_hash = header.hash_slow();
+        mock_provider.add_block(
+            block_hash,
+            Block {
+                header,
+                body: BlockBody { transactions: vec![mined.clone()], ..Default::default() },
+            },
+        );
+        let eth_api = build_test_eth_api(mock_provider);
+
+        // the mock validator reports an on chain nonce of 0
+        let pending = MockTransaction::eip1559();
+        let queued = pending.next().next();
+        for tx in [pending.clone(), queued.clone()] {
+            eth_api.pool().add_transaction(TransactionOrigin::External, tx).await.unwrap();
+        }
+
+        let status = |hash| {
+            <EthApi<_, _> as EthApiServer<_, _, _, _, _, _>>::transaction_status(&eth_api, hash)
+        };
+        assert_eq!(
+            status(*mined.tx_hash()).await.unwrap(),
+            TransactionStatus::Mined { block_hash, block_number: 1, index: 0 }
+        );
+        assert_eq!(status(*pending.get_hash()).await.unwrap(), TransactionStatus::Pending);
+        assert_eq!(
+            status(*queued.get_hash()).await.unwrap(),
+            TransactionStatus::Queued { reason: QueuedReason::NonceGap }
+        );
+        assert_eq!(status(B256::random()).await.unwrap(), TransactionStatus::Unknown);
+    }
+
+    #[tokio::test]
+    async fn test_chain_id_from_chain_spec() {
+        let chain_spec = ChainSpecBuilder::mainnet().chain(Chain::from_id(1337)).build();
+        let provider = MockEthProvider::default().with_chain_spec(chain_spec);
+        let eth_api = build_test_eth_api(provider);
+
+        let chain_id =
+            <EthApi<_, _> as EthApiServer<_, _, _, _, _, _>>::chain_id(&eth_api).await.unwrap();
+        assert_eq!(chain_id, Some(U64::from(1337)));
+    }
 }
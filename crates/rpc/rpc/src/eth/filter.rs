@@ -23,7 +23,9 @@ use reth_rpc_eth_api::{
     RpcNodeCoreExt, RpcTransaction,
 };
 use reth_rpc_eth_types::{
-    logs_utils::{self, append_matching_block_logs, ProviderOrBlock},
+    logs_utils::{
+        self, append_matching_block_logs, BlockLogsBloom, LogsWithBlooms, ProviderOrBlock,
+    },
     EthApiError, EthFilterConfig, EthStateCache, EthSubscriptionIdProvider,
 };
 use reth_rpc_server_types::{result::rpc_error_with_code, ToRpcResult};
@@ -313,6 +315,29 @@ where
         self.logs_for_filter(filter, self.inner.query_limits).await
     }
 
+    /// Returns logs matching given filter object, together with the `logsBloom` of every block
+    /// that contains a matching log.
+    ///
+    /// The blooms are read from the block headers as is.
+    pub async fn logs_with_blooms(&self, filter: Filter) -> Result<LogsWithBlooms, EthFilterError> {
+        let logs = self.logs_for_filter(filter, self.inner.query_limits).await?;
+
+        // logs are ordered by block, so each block only needs to be looked up once
+        let block_hashes: Vec<_> = logs.iter().filter_map(|log| log.block_hash).dedup().collect();
+
+        let mut blooms = Vec::with_capacity(block_hashes.len());
+        for block_hash in block_hashes {
+            let header = self.inner.eth_cache().get_header(block_hash).await?;
+            blooms.push(BlockLogsBloom {
+                block_number: header.number(),
+                block_hash,
+                logs_bloom: header.logs_bloom(),
+            });
+        }
+
+        Ok(LogsWithBlooms { logs, blooms })
+    }
+
     /// Returns logs matching given filter object.
     async fn logs_for_filter(
         &self,
@@ -409,6 +434,15 @@ where
         trace!(target: "rpc::eth", "Serving eth_getLogs");
         Ok(self.logs_for_filter(filter, self.inner.query_limits).await?)
     }
+
+    /// Returns logs matching given filter object, together with the blooms of the blocks that
+    /// contain them.
+    ///
+    /// Handler for `eth_getLogsWithBlooms`
+    async fn logs_with_blooms(&self, filter: Filter) -> RpcResult<LogsWithBlooms> {
+        trace!(target: "rpc::eth", "Serving eth_getLogsWithBlooms");
+        Ok(Self::logs_with_blooms(self, filter).await?)
+    }
 }
 
 impl<Eth> std::fmt::Debug for EthFilter<Eth>
@@ -604,10 +638,10 @@ where
             return Err(EthFilterError::InvalidBlockRangeParams)
         }
 
-        if let Some(max_blocks_per_filter) =
-            limits.max_blocks_per_filter.filter(|limit| to_block - from_block > *limit)
-        {
-            return Err(EthFilterError::QueryExceedsMaxBlocks(max_blocks_per_filter))
+        // the range is inclusive, both the limit and the error refer to the number of blocks
+        let requested = to_block - from_block + 1;
+        if let Some(max) = limits.max_blocks_per_filter.filter(|limit| requested > *limit) {
+            return Err(EthFilterError::QueryExceedsMaxBlocks(max, requested))
         }
 
         let (tx, rx) = oneshot::channel();
@@ -894,8 +928,10 @@ pub enum EthFilterError {
     #[error("invalid block range params")]
     InvalidBlockRangeParams,
     /// Query scope is too broad.
-    #[error("query exceeds max block range {0}")]
-    QueryExceedsMaxBlocks(u64),
+    ///
+    /// Contains the maximum number of blocks per query and the number of blocks requested.
+    #[error("query exceeds max block range {0}, requested range of {1} blocks")]
+    QueryExceedsMaxBlocks(u64, u64),
     /// Query result is too large.
     #[error("query exceeds max results {max_logs}, retry with the range {from_block}-{to_block}")]
     QueryExceedsMaxResults {
@@ -926,7 +962,7 @@ impl From<EthFilterError> for jsonrpsee::types::error::ErrorObject<'static> {
             }
             EthFilterError::EthAPIError(err) => err.into(),
             err @ (EthFilterError::InvalidBlockRangeParams |
-            EthFilterError::QueryExceedsMaxBlocks(_) |
+            EthFilterError::QueryExceedsMaxBlocks(..) |
             EthFilterError::QueryExceedsMaxResults { .. }) => {
                 rpc_error_with_code(jsonrpsee::types::error::INVALID_PARAMS_CODE, err.to_string())
             }
@@ -1274,8 +1310,9 @@ impl<
 mod tests {
     use super::*;
     use crate::{eth::EthApi, EthApiBuilder};
+    use alloy_eips::BlockId;
     use alloy_network::Ethereum;
-    use alloy_primitives::FixedBytes;
+    use alloy_primitives::{FixedBytes, B256};
     use rand::Rng;
     use reth_chainspec::{ChainSpec, ChainSpecProvider};
     use reth_ethereum_primitives::TxType;
@@ -1874,4 +1911,89 @@ mod tests {
         assert_eq!(logs[0].block_hash, Some(expected_hashes[0])); // block 100
         assert_eq!(logs[1].block_hash, Some(expected_hashes[2])); // block 102
     }
+
+    #[tokio::test]
+    async fn test_get_logs_max_blocks_per_filter() {
+        let provider = MockEthProvider::default();
+        let tip = alloy_consensus::Header { number: 200, ..Default::default() };
+        provider.add_block(
+            tip.hash_slow(),
+            reth_ethereum_primitives::Block { header: tip, body: Default::default() },
+        );
+        let eth_api = build_test_eth_api(provider);
+        let eth_filter = EthFilter::new(
+            eth_api,
+            EthFilterConfig::default(),
+            Box::new(TokioTaskExecutor::default()),
+        );
+        let limits = QueryLimits { max_blocks_per_filter: Some(10), max_logs_per_response: None };
+
+        // a range of exactly the limit is allowed
+        let logs = eth_filter
+            .inner
+            .clone()
+            .get_logs_in_block_range(Filter::default(), 100, 109, limits)
+            .await
+            .expect("should succeed");
+        assert!(logs.is_empty());
+
+        // one block over the limit is rejected
+        let err = eth_filter
+            .inner
+            .clone()
+            .get_logs_in_block_range(Filter::default(), 100, 110, limits)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, EthFilterError::QueryExceedsMaxBlocks(10, 11)));
+        assert_eq!(
+            err.to_string(),
+            "query exceeds max block range 10, requested range of 11 blocks"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_logs_safe_and_finalized_tags() {
+        let provider = MockEthProvider::default();
+        let tip = alloy_consensus::Header { number: 200, ..Default::default() };
+        provider.add_block(
+            tip.hash_slow(),
+            reth_ethereum_primitives::Block { header: tip, body: Default::default() },
+        );
+        let eth_api = build_test_eth_api(provider.clone());
+        let eth_filter = EthFilter::new(
+            eth_api,
+            EthFilterConfig::default(),
+            Box::new(TokioTaskExecutor::default()),
+        );
+        let limits = QueryLimits { max_blocks_per_filter: Some(10), max_logs_per_response: None };
+        let logs = |from, to| {
+            eth_filter
+                .inner
+                .clone()
+                .logs_for_filter(Filter::new().from_block(from).to_block(to), limits)
+        };
+
+        // tags can't be resolved until the chain has a safe and finalized block
+        let err = logs(BlockNumberOrTag::Finalized, BlockNumberOrTag::Latest).await.unwrap_err();
+        assert!(matches!(
+            err,
+            EthFilterError::EthAPIError(EthApiError::HeaderNotFound(id)) if id == BlockId::finalized()
+        ));
+        let err = logs(BlockNumberOrTag::Earliest, BlockNumberOrTag::Safe).await.unwrap_err();
+        assert!(matches!(
+            err,
+            EthFilterError::EthAPIError(EthApiError::HeaderNotFound(id)) if id == BlockId::safe()
+        ));
+
+        provider.set_finalized_block(BlockNumHash::new(150, B256::random()));
+        provider.set_safe_block(BlockNumHash::new(155, B256::random()));
+
+        // finalized..=latest resolves to 150..=200
+        let err = logs(BlockNumberOrTag::Finalized, BlockNumberOrTag::Latest).await.unwrap_err();
+        assert!(matches!(err, EthFilterError::QueryExceedsMaxBlocks(10, 51)));
+
+        // finalized..=safe resolves to 150..=155
+        let logs = logs(BlockNumberOrTag::Finalized, BlockNumberOrTag::Safe).await.unwrap();
+        assert!(logs.is_empty());
+    }
 }
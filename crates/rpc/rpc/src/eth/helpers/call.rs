@@ -45,3 +45,149 @@ where
     Rpc: RpcConvert<Primitives = N::Primitives, Error = EthApiError, Evm = N::Evm>,
 {
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth::helpers::types::EthRpcConverter;
+    use alloy_consensus::{Block, Header};
+    use alloy_eips::eip2930::{AccessList, AccessListItem};
+    use alloy_primitives::{address, bytes, Address, B256, U256};
+    use alloy_rpc_types_eth::{request::TransactionRequest, BlockId};
+    use reth_chainspec::{ChainSpec, ChainSpecBuilder};
+    use reth_evm_ethereum::EthEvmConfig;
+    use reth_network_api::noop::NoopNetwork;
+    use reth_provider::{
+        test_utils::{ExtendedAccount, MockEthProvider},
+        ChainSpecProvider,
+    };
+    use reth_rpc_eth_api::node::RpcNodeCoreAdapter;
+    use reth_transaction_pool::test_utils::{testing_pool, TestPool};
+    use std::collections::HashMap;
+
+    const STORAGE_CONTRACT: Address = address!("0x1000000000000000000000000000000000000001");
+    const REVERT_CONTRACT: Address = address!("0x1000000000000000000000000000000000000002");
+    const BALANCE_CONTRACT: Address = address!("0x1000000000000000000000000000000000000003");
+
+    fn mock_eth_api() -> EthApi<
+        RpcNodeCoreAdapter<MockEthProvider, TestPool, NoopNetwork, EthEvmConfig>,
+        EthRpcConverter<ChainSpec>,
+    > {
+        let mock_provider = MockEthProvider::default()
+            .with_chain_spec(ChainSpecBuilder::mainnet().cancun_activated().build());
+
+        // returns the value of storage slot 0 and then increments it
+        let storage_code = bytes!("0x6000548060005260010160005560206000f3");
+        // always reverts
+        let revert_code = bytes!("0x60006000fd");
+        // reads the balance of the storage contract
+        let balance_code = bytes!("0x73100000000000000000000000000000000000000131");
+        mock_provider.extend_accounts(HashMap::from([
+            (
+                STORAGE_CONTRACT,
+                ExtendedAccount::new(0, U256::ZERO)
+                    .with_bytecode(storage_code)
+                    .extend_storage([(B256::ZERO, U256::from(7))]),
+            ),
+            (REVERT_CONTRACT, ExtendedAccount::new(0, U256::ZERO).with_bytecode(revert_code)),
+            (BALANCE_CONTRACT, ExtendedAccount::new(0, U256::ZERO).with_bytecode(balance_code)),
+        ]));
+
+        let evm_config = EthEvmConfig::new(mock_provider.chain_spec());
+        let genesis_header = Header {
+            number: 0,
+            gas_limit: 30_000_000,
+            timestamp: 1,
+            excess_blob_gas: Some(0),
+            base_fee_per_gas: Some(1_000_000_000),
+            blob_gas_used: Some(0),
+            ..Default::default()
+        };
+        mock_provider.add_block(B256::ZERO, Block::new(genesis_header, Default::default()));
+
+        EthApi::builder(mock_provider, testing_pool(), NoopNetwork::default(), evm_config).build()
+    }
+
+    #[tokio::test]
+    async fn call_batch_matches_individual_calls() {
+        let eth_api = mock_eth_api();
+        let requests = vec![
+            TransactionRequest::default().to(STORAGE_CONTRACT),
+            TransactionRequest::default().to(REVERT_CONTRACT),
+            TransactionRequest::default().to(STORAGE_CONTRACT),
+        ];
+        let at = Some(BlockId::latest());
+
+        let batch = EthCall::call_batch(&eth_api, requests.clone(), at).await.unwrap();
+        assert_eq!(batch.len(), requests.len());
+
+        for (request, res) in requests.into_iter().zip(batch) {
+            match EthCall::call(&eth_api, request, at, Default::default()).await {
+                Ok(output) => {
+                    assert_eq!(res.value, Some(output));
+                    assert!(res.error.is_none());
+                }
+                Err(err) => {
+                    assert!(res.value.is_none());
+                    assert_eq!(res.error, Some(err.to_string()));
+                }
+            }
+        }
+
+        // state changes of the first call are not visible to the last one
+        let batch = EthCall::call_batch(
+            &eth_api,
+            vec![
+                TransactionRequest::default().to(STORAGE_CONTRACT),
+                TransactionRequest::default().to(STORAGE_CONTRACT),
+            ],
+            at,
+        )
+        .await
+        .unwrap();
+        assert_eq!(batch[0].value, Some(B256::from(U256::from(7)).into()));
+        assert_eq!(batch[0].value, batch[1].value);
+    }
+
+    #[tokio::test]
+    async fn call_batch_rejects_empty_batch() {
+        let eth_api = mock_eth_api();
+        assert!(EthCall::call_batch(&eth_api, vec![], None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn call_with_access_list_reports_accesses() {
+        let eth_api = mock_eth_api();
+        let at = Some(BlockId::latest());
+
+        let request = TransactionRequest::default().to(STORAGE_CONTRACT);
+        let res = EthCall::call_with_access_list(&eth_api, request.clone(), at, Default::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            res.output,
+            EthCall::call(&eth_api, request, at, Default::default()).await.unwrap()
+        );
+        assert_eq!(
+            res.access_list,
+            AccessList(vec![AccessListItem {
+                address: STORAGE_CONTRACT,
+                storage_keys: vec![B256::ZERO]
+            }])
+        );
+
+        let request = TransactionRequest::default().to(BALANCE_CONTRACT);
+        let res = EthCall::call_with_access_list(&eth_api, request, at, Default::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            res.access_list,
+            AccessList(vec![AccessListItem { address: STORAGE_CONTRACT, storage_keys: vec![] }])
+        );
+
+        let request = TransactionRequest::default().to(REVERT_CONTRACT);
+        assert!(EthCall::call_with_access_list(&eth_api, request, at, Default::default())
+            .await
+            .is_err());
+    }
+}
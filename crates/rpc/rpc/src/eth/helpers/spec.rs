@@ -2,6 +2,7 @@ use alloy_primitives::U256;
 use reth_rpc_convert::RpcConvert;
 use reth_rpc_eth_api::{helpers::EthApiSpec, RpcNodeCore};
 use reth_rpc_eth_types::EthApiError;
+use reth_rpc_server_types::result::RetryConfig;
 
 use crate::EthApi;
 
@@ -13,4 +14,8 @@ where
     fn starting_block(&self) -> U256 {
         self.inner.starting_block()
     }
+
+    fn transient_retry(&self) -> RetryConfig {
+        self.inner.transient_retry()
+    }
 }
@@ -32,13 +32,18 @@ mod tests {
     use crate::eth::helpers::types::EthRpcConverter;
 
     use super::*;
-    use alloy_primitives::{Address, StorageKey, StorageValue, U256};
+    use alloy_eips::BlockId;
+    use alloy_primitives::{Address, StorageKey, StorageValue, B256, U256};
     use reth_chainspec::ChainSpec;
     use reth_evm_ethereum::EthEvmConfig;
     use reth_network_api::noop::NoopNetwork;
     use reth_provider::{
-        test_utils::{ExtendedAccount, MockEthProvider, NoopProvider},
-        ChainSpecProvider,
+        providers::BlockchainProvider,
+        test_utils::{
+            blocks::BlockchainTestData, create_test_provider_factory, ExtendedAccount,
+            MockEthProvider, MockNodeTypesWithDB, NoopProvider,
+        },
+        BlockWriter, ChainSpecProvider, ExecutionOutcome,
     };
     use reth_rpc_eth_api::{helpers::EthState, node::RpcNodeCoreAdapter};
     use reth_transaction_pool::test_utils::{testing_pool, TestPool};
@@ -92,6 +97,80 @@ mod tests {
         assert_eq!(storage, storage_value.to_be_bytes());
     }
 
+    #[tokio::test]
+    async fn test_storage_at_historical_block() {
+        // the slot is set to 10 in the first block and to 15 in the second block
+        let data = BlockchainTestData::default();
+        let address = Address::repeat_byte(0x60);
+        let slot = U256::from(5);
+
+        let factory = create_test_provider_factory();
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw
+            .append_blocks_with_state(
+                vec![data.genesis.clone().try_recover().unwrap()],
+                &ExecutionOutcome { receipts: vec![vec![]], ..Default::default() },
+                Default::default(),
+            )
+            .unwrap();
+        for (block, execution_outcome) in data.blocks.iter().take(2) {
+            provider_rw
+                .append_blocks_with_state(
+                    vec![block.clone()],
+                    execution_outcome,
+                    Default::default(),
+                )
+                .unwrap();
+        }
+        provider_rw.commit().unwrap();
+
+        let (first, _) = &data.blocks[0];
+        let (second, _) = &data.blocks[1];
+        let provider = BlockchainProvider::<MockNodeTypesWithDB>::with_latest(
+            factory,
+            second.clone_sealed_header(),
+        )
+        .unwrap();
+        let evm_config = EthEvmConfig::new(provider.chain_spec());
+        let eth_api =
+            EthApi::builder(provider, testing_pool(), NoopNetwork::default(), evm_config).build();
+
+        let storage_at = async |block_id| {
+            eth_api.storage_at(address, slot.into(), Some(block_id)).await.unwrap()
+        };
+        let ten = B256::from(U256::from(10));
+        let fifteen = B256::from(U256::from(15));
+        assert_eq!(storage_at(BlockId::number(0)).await, B256::ZERO);
+        assert_eq!(storage_at(BlockId::number(1)).await, ten);
+        assert_eq!(storage_at(BlockId::hash(first.hash())).await, ten);
+        assert_eq!(storage_at(BlockId::number(2)).await, fifteen);
+        assert_eq!(storage_at(BlockId::latest()).await, fifteen);
+
+        // all values are read from the same historical state, unset slots and missing accounts are
+        // zero
+        let missing = Address::repeat_byte(0x99);
+        let values = eth_api
+            .storage_values(
+                HashMap::from([
+                    (address, vec![slot.into(), U256::from(6).into(), slot.into()]),
+                    (missing, vec![slot.into()]),
+                ]),
+                Some(BlockId::number(1)),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            values,
+            HashMap::from([(address, vec![ten, B256::ZERO, ten]), (missing, vec![B256::ZERO])])
+        );
+
+        let values = eth_api
+            .storage_values(HashMap::from([(address, vec![slot.into()])]), None)
+            .await
+            .unwrap();
+        assert_eq!(values, HashMap::from([(address, vec![fifteen])]));
+    }
+
     #[tokio::test]
     async fn test_get_account_missing() {
         let eth_api = noop_eth_api();
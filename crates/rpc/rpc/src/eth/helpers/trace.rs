@@ -12,4 +12,7 @@ where
     EthApiError: FromEvmError<N::Evm>,
     Rpc: RpcConvert<Primitives = N::Primitives, Error = EthApiError, Evm = N::Evm>,
 {
+    fn max_trace_response_size(&self) -> Option<usize> {
+        self.inner.max_trace_response_size()
+    }
 }
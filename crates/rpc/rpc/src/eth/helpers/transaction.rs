@@ -134,17 +134,20 @@ where
 mod tests {
     use super::*;
     use crate::eth::helpers::types::EthRpcConverter;
-    use alloy_consensus::{Block, Header, SidecarBuilder, SimpleCoder, Transaction};
+    use alloy_consensus::{Block, BlockBody, Header, SidecarBuilder, SimpleCoder, Transaction};
+    use alloy_eips::Decodable2718;
     use alloy_primitives::{Address, U256};
     use alloy_rpc_types_eth::request::TransactionRequest;
     use reth_chainspec::{ChainSpec, ChainSpecBuilder};
+    use reth_ethereum_primitives::TransactionSigned;
     use reth_evm_ethereum::EthEvmConfig;
     use reth_network_api::noop::NoopNetwork;
     use reth_provider::{
         test_utils::{ExtendedAccount, MockEthProvider},
         ChainSpecProvider,
     };
-    use reth_rpc_eth_api::node::RpcNodeCoreAdapter;
+    use reth_primitives_traits::SignerRecoverable;
+    use reth_rpc_eth_api::{node::RpcNodeCoreAdapter, EthApiServer};
     use reth_transaction_pool::test_utils::{testing_pool, TestPool};
     use revm_primitives::Bytes;
     use std::collections::HashMap;
@@ -213,6 +216,41 @@ mod tests {
         assert!(pool.get(&tx_2_result).is_some(), "tx2 not found in the pool");
     }
 
+    #[tokio::test]
+    async fn transaction_by_hash_returns_stored_sender() {
+        let eth_api = mock_eth_api(Default::default());
+
+        // https://etherscan.io/tx/0xa694b71e6c128a2ed8e2e0f6770bddbe52e3bb8f10e8472f9a79ab81497a8b5d
+        let tx = TransactionSigned::decode_2718(&mut &hex!(
+            "02f871018303579880850555633d1b82520894eee27662c2b8eba3cd936a23f039f3189633e4c887ad591c62bdaeb180c080a07ea72c68abfb8fca1bd964f0f99132ed9280261bdca3e549546c0205e800f7d0a05b4ef3039e9c9b9babc179a1878fb825b5aaf5aed2fa8744854150157b08d6f3"
+        )[..])
+        .unwrap();
+        let tx_hash = *tx.tx_hash();
+        let signer = tx.recover_signer().unwrap();
+
+        let header = Header { number: 1, base_fee_per_gas: Some(1000000000), ..Default::default() };
+        let body = BlockBody { transactions: vec![tx], ..Default::default() };
+        let block = Block::new(header, body);
+        let block_hash = block.header.hash_slow();
+        eth_api.provider().add_block(block_hash, block);
+
+        // a stored sender that differs from the signer, so a recovered `from` would not match
+        let stored_sender = Address::with_last_byte(0x42);
+        assert_ne!(stored_sender, signer);
+        eth_api.provider().add_sender(0, stored_sender);
+
+        let tx = EthApiServer::transaction_by_hash(&eth_api, tx_hash).await.unwrap().unwrap();
+        assert_eq!(tx.inner.signer(), stored_sender);
+        assert_eq!(tx.block_number, Some(1));
+        assert_eq!(tx.block_hash, Some(block_hash));
+        assert_eq!(tx.transaction_index, Some(0));
+
+        // without a stored sender the signer is recovered
+        eth_api.provider().senders.lock().clear();
+        let tx = EthApiServer::transaction_by_hash(&eth_api, tx_hash).await.unwrap().unwrap();
+        assert_eq!(tx.inner.signer(), signer);
+    }
+
     #[tokio::test]
     async fn test_fill_transaction_fills_chain_id() {
         let address = Address::random();
@@ -363,20 +363,11 @@ where
 
     /// Returns a stream that yields all logs that match the given filter.
     fn log_stream(&self, filter: Filter) -> impl Stream<Item = Log> {
-        BroadcastStream::new(self.eth_api.provider().subscribe_to_canonical_state())
-            .map(move |canon_state| {
-                canon_state.expect("new block subscription never ends").block_receipts()
-            })
-            .flat_map(futures::stream::iter)
-            .flat_map(move |(block_receipts, removed)| {
-                let all_logs = logs_utils::matching_block_logs_with_tx_hashes(
-                    &filter,
-                    block_receipts.block,
-                    block_receipts.timestamp,
-                    block_receipts.tx_receipts.iter().map(|(tx, receipt)| (*tx, receipt)),
-                    removed,
-                );
-                futures::stream::iter(all_logs)
-            })
+        BroadcastStream::new(self.eth_api.provider().subscribe_to_canonical_state()).flat_map(
+            move |canon_state| {
+                let canon_state = canon_state.expect("new block subscription never ends");
+                futures::stream::iter(logs_utils::matching_canon_state_logs(&filter, &canon_state))
+            },
+        )
     }
 }
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, future::Future, sync::Arc};
 
-use alloy_eips::BlockId;
+use alloy_eips::{BlockId, BlockNumHash};
 use alloy_primitives::{Address, U256};
 use async_trait::async_trait;
 use futures::StreamExt;
@@ -10,7 +10,7 @@ use reth_chain_state::{CanonStateNotificationStream, CanonStateSubscriptions};
 use reth_errors::RethResult;
 use reth_primitives_traits::NodePrimitives;
 use reth_rpc_api::RethApiServer;
-use reth_rpc_eth_types::{EthApiError, EthResult};
+use reth_rpc_eth_types::{EthApiError, EthResult, ForkchoiceStateInfo};
 use reth_rpc_server_types::result::internal_rpc_err;
 use reth_storage_api::{BlockReaderIdExt, ChangeSetReader, StateProviderFactory};
 use reth_tasks::TaskSpawner;
@@ -88,6 +88,16 @@ where
         )?;
         Ok(hash_map)
     }
+
+    /// Returns the current fork choice state, as last updated by the consensus layer.
+    pub fn forkchoice_state(&self) -> EthResult<ForkchoiceStateInfo> {
+        let head = self.provider().chain_info()?;
+        Ok(ForkchoiceStateInfo::new(
+            BlockNumHash::new(head.best_number, head.best_hash),
+            self.provider().safe_block_num_hash()?,
+            self.provider().finalized_block_num_hash()?,
+        ))
+    }
 }
 
 #[async_trait]
@@ -107,6 +117,11 @@ where
         Ok(Self::balance_changes_in_block(self, block_id).await?)
     }
 
+    /// Handler for `reth_getForkchoiceState`
+    async fn reth_get_forkchoice_state(&self) -> RpcResult<ForkchoiceStateInfo> {
+        Ok(Self::forkchoice_state(self)?)
+    }
+
     /// Handler for `reth_subscribeChainNotifications`
     async fn reth_subscribe_chain_notifications(
         &self,
@@ -170,3 +185,49 @@ struct RethApiInner<Provider> {
     /// The type that can spawn tasks which would otherwise block.
     task_spawner: Box<dyn TaskSpawner>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::Header;
+    use reth_primitives_traits::SealedHeader;
+    use reth_provider::{
+        providers::BlockchainProvider, test_utils::create_test_provider_factory, CanonChainTracker,
+    };
+    use reth_tasks::TokioTaskExecutor;
+
+    fn sealed_header(number: u64) -> SealedHeader {
+        SealedHeader::seal_slow(Header { number, ..Default::default() })
+    }
+
+    #[tokio::test]
+    async fn forkchoice_state_follows_forkchoice_update() {
+        let genesis = sealed_header(0);
+        let provider =
+            BlockchainProvider::with_latest(create_test_provider_factory(), genesis.clone())
+                .unwrap();
+        let api = RethApi::new(provider.clone(), TokioTaskExecutor::default().boxed());
+
+        let state = api.reth_get_forkchoice_state().await.unwrap();
+        assert_eq!(state, ForkchoiceStateInfo::new(genesis.num_hash(), None, None));
+
+        // blocks of a forkchoice update, as applied by the engine
+        let (head, safe, finalized) = (sealed_header(3), sealed_header(2), sealed_header(1));
+        provider.set_canonical_head(head.clone());
+        provider.set_safe(safe.clone());
+        provider.set_finalized(finalized.clone());
+
+        let state = api.reth_get_forkchoice_state().await.unwrap();
+        assert_eq!(
+            state,
+            ForkchoiceStateInfo {
+                head_block_hash: head.hash(),
+                head_block_number: 3,
+                safe_block_hash: safe.hash(),
+                safe_block_number: 2,
+                finalized_block_hash: finalized.hash(),
+                finalized_block_number: 1,
+            }
+        );
+    }
+}
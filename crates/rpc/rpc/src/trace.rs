@@ -26,7 +26,12 @@ use reth_rpc_eth_api::{
     helpers::{Call, LoadPendingBlock, LoadTransaction, Trace, TraceExt},
     FromEthApiError, RpcNodeCore,
 };
-use reth_rpc_eth_types::{error::EthApiError, utils::recover_raw_transaction, EthConfig};
+use reth_rpc_eth_types::{
+    error::EthApiError,
+    trace::{self, CallKind, ExtendedTransactionTrace, TraceSizeBudget},
+    utils::recover_raw_transaction,
+    EthConfig,
+};
 use reth_storage_api::{BlockNumReader, BlockReader};
 use reth_tasks::pool::BlockingTaskGuard;
 use reth_transaction_pool::{PoolPooledTx, PoolTransaction, TransactionPool};
@@ -88,6 +93,11 @@ where
     // bound
     Eth: Trace + Call + LoadPendingBlock + LoadTransaction + 'static,
 {
+    /// Returns a fresh budget for the serialized output of a single trace request.
+    fn trace_size_budget(&self) -> TraceSizeBudget {
+        TraceSizeBudget::new(self.eth_api().max_trace_response_size())
+    }
+
     /// Executes the given call and returns a number of possible traces for it.
     pub async fn trace_call(
         &self,
@@ -98,6 +108,7 @@ where
         let overrides =
             EvmOverrides::new(trace_request.state_overrides, trace_request.block_overrides);
         let mut inspector = TracingInspector::new(config);
+        let budget = self.trace_size_budget();
         let this = self.clone();
         self.eth_api()
             .spawn_with_call_at(trace_request.call, at, overrides, move |db, evm_env, tx_env| {
@@ -106,6 +117,7 @@ where
                     .into_parity_builder()
                     .into_trace_results_with_state(&res, &trace_request.trace_types, &db)
                     .map_err(Eth::Error::from_eth_err)?;
+                budget.charge(&trace_res).map_err(Eth::Error::from_eth_err)?;
                 Ok(trace_res)
             })
             .await
@@ -125,13 +137,16 @@ where
         let tx_env = self.eth_api().evm_config().tx_env(tx);
 
         let config = TracingInspectorConfig::from_parity_config(&trace_types);
+        let budget = self.trace_size_budget();
 
         self.eth_api()
             .spawn_trace_at_with_state(evm_env, tx_env, config, at, move |inspector, res, db| {
-                inspector
+                let trace_res = inspector
                     .into_parity_builder()
                     .into_trace_results_with_state(&res, &trace_types, &db)
-                    .map_err(Eth::Error::from_eth_err)
+                    .map_err(Eth::Error::from_eth_err)?;
+                budget.charge(&trace_res).map_err(Eth::Error::from_eth_err)?;
+                Ok(trace_res)
             })
             .await
     }
@@ -147,6 +162,7 @@ where
     ) -> Result<Vec<TraceResults>, Eth::Error> {
         let at = block_id.unwrap_or(BlockId::pending());
         let (evm_env, at) = self.eth_api().evm_env_at(at).await?;
+        let budget = self.trace_size_budget();
 
         // execute all transactions on top of each other and record the traces
         self.eth_api()
@@ -169,6 +185,7 @@ where
                         .into_parity_builder()
                         .into_trace_results_with_state(&res, &trace_types, &db)
                         .map_err(Eth::Error::from_eth_err)?;
+                    budget.charge(&trace_res).map_err(Eth::Error::from_eth_err)?;
 
                     results.push(trace_res);
 
@@ -187,18 +204,24 @@ where
     }
 
     /// Replays a transaction, returning the traces.
+    ///
+    /// If [`TraceType::VmTrace`] is requested, the recorded steps are converted into parity's
+    /// `VmTrace` format by [`ParityTraceBuilder`](revm_inspectors::tracing::ParityTraceBuilder),
+    /// including the nested sub-traces of call-like opcodes.
     pub async fn replay_transaction(
         &self,
         hash: B256,
         trace_types: HashSet<TraceType>,
     ) -> Result<TraceResults, Eth::Error> {
         let config = TracingInspectorConfig::from_parity_config(&trace_types);
+        let budget = self.trace_size_budget();
         self.eth_api()
             .spawn_trace_transaction_in_block(hash, config, move |_, inspector, res, db| {
                 let trace_res = inspector
                     .into_parity_builder()
                     .into_trace_results_with_state(&res, &trace_types, &db)
                     .map_err(Eth::Error::from_eth_err)?;
+                budget.charge(&trace_res).map_err(Eth::Error::from_eth_err)?;
                 Ok(trace_res)
             })
             .await
@@ -240,6 +263,7 @@ where
         &self,
         hash: B256,
     ) -> Result<Option<Vec<LocalizedTransactionTrace>>, Eth::Error> {
+        let budget = self.trace_size_budget();
         self.eth_api()
             .spawn_trace_transaction_in_block(
                 hash,
@@ -247,12 +271,43 @@ where
                 move |tx_info, inspector, _, _| {
                     let traces =
                         inspector.into_parity_builder().into_localized_transaction_traces(tx_info);
+                    budget.charge(&traces).map_err(Eth::Error::from_eth_err)?;
                     Ok(traces)
                 },
             )
             .await
     }
 
+    /// Returns all traces for the given transaction hash, optionally extended with the calls into
+    /// precompiles aggregated per precompile.
+    pub async fn trace_transaction_extended(
+        &self,
+        hash: B256,
+        include_precompile_calls: bool,
+    ) -> Result<Option<ExtendedTransactionTrace>, Eth::Error> {
+        let budget = self.trace_size_budget();
+        self.eth_api()
+            .spawn_trace_transaction_in_block(
+                hash,
+                // excluding precompile calls from the parity traces is what marks them as
+                // precompile calls in the recorded call traces
+                TracingInspectorConfig::default_parity().set_exclude_precompile_calls(true),
+                move |tx_info, inspector, _, _| {
+                    let precompile_calls = include_precompile_calls
+                        .then(|| trace::precompile_calls(inspector.traces()));
+                    let trace = ExtendedTransactionTrace {
+                        traces: inspector
+                            .into_parity_builder()
+                            .into_localized_transaction_traces(tx_info),
+                        precompile_calls,
+                    };
+                    budget.charge(&trace).map_err(Eth::Error::from_eth_err)?;
+                    Ok(trace)
+                },
+            )
+            .await
+    }
+
     /// Returns all opcodes with their count and combined gas usage for the given transaction in no
     /// particular order.
     pub async fn trace_transaction_opcode_gas(
@@ -347,13 +402,19 @@ where
     /// Returns all transaction traces that match the given filter.
     ///
     /// This is similar to [`Self::trace_block`] but only returns traces for transactions that match
-    /// the filter.
+    /// the filter. If a [`CallKind`] is given, only call and create traces of that kind are
+    /// returned.
     pub async fn trace_filter(
         &self,
         filter: TraceFilter,
+        call_kind: Option<CallKind>,
     ) -> Result<Vec<LocalizedTransactionTrace>, Eth::Error> {
         // We'll reuse the matcher across multiple blocks that are traced in parallel
         let matcher = Arc::new(filter.matcher());
+        let matches = move |trace: &TransactionTrace| {
+            matcher.matches(trace) &&
+                call_kind.is_none_or(|kind| trace::matches_call_kind(&trace.action, kind))
+        };
         let TraceFilter { from_block, to_block, mut after, count, .. } = filter;
         let start = from_block.unwrap_or(0);
 
@@ -380,6 +441,8 @@ where
             .into())
         }
 
+        // the budget is shared by all blocks that are traced in parallel
+        let budget = Arc::new(self.trace_size_budget());
         let mut all_traces = Vec::new();
         let mut block_traces = Vec::with_capacity(self.inner.eth_config.max_tracing_requests);
         for chunk_start in (start..end).step_by(self.inner.eth_config.max_tracing_requests) {
@@ -402,7 +465,8 @@ where
 
             // trace all blocks
             for block in &blocks {
-                let matcher = matcher.clone();
+                let matches = matches.clone();
+                let budget = budget.clone();
                 let traces = self.eth_api().trace_block_until(
                     block.hash().into(),
                     Some(block.clone()),
@@ -413,7 +477,8 @@ where
                             .take_inspector()
                             .into_parity_builder()
                             .into_localized_transaction_traces(tx_info);
-                        traces.retain(|trace| matcher.matches(&trace.trace));
+                        traces.retain(|trace| matches(&trace.trace));
+                        budget.charge(&traces).map_err(Eth::Error::from_eth_err)?;
                         Ok(Some(traces))
                     },
                 );
@@ -436,7 +501,7 @@ where
                             base_block_reward,
                         )
                         .into_iter()
-                        .filter(|trace| matcher.matches(&trace.trace)),
+                        .filter(|trace| matches(&trace.trace)),
                     );
                 } else {
                     // no block reward, means we're past the Paris hardfork and don't expect any
@@ -480,15 +545,17 @@ where
         &self,
         block_id: BlockId,
     ) -> Result<Option<Vec<LocalizedTransactionTrace>>, Eth::Error> {
+        let budget = self.trace_size_budget();
         let traces = self.eth_api().trace_block_with(
             block_id,
             None,
             TracingInspectorConfig::default_parity(),
-            |tx_info, mut ctx| {
+            move |tx_info, mut ctx| {
                 let traces = ctx
                     .take_inspector()
                     .into_parity_builder()
                     .into_localized_transaction_traces(tx_info);
+                budget.charge(&traces).map_err(Eth::Error::from_eth_err)?;
                 Ok(traces)
             },
         );
@@ -518,6 +585,7 @@ where
         block_id: BlockId,
         trace_types: HashSet<TraceType>,
     ) -> Result<Option<Vec<TraceResultsWithTransactionHash>>, Eth::Error> {
+        let budget = self.trace_size_budget();
         self.eth_api()
             .trace_block_with(
                 block_id,
@@ -540,6 +608,7 @@ where
                         transaction_hash: tx_info.hash.expect("tx hash is set"),
                         full_trace,
                     };
+                    budget.charge(&trace).map_err(Eth::Error::from_eth_err)?;
                     Ok(trace)
                 },
             )
@@ -699,9 +768,13 @@ where
     ///
     /// # Limitations
     /// This currently requires block filter fields, since reth does not have address indices yet.
-    async fn trace_filter(&self, filter: TraceFilter) -> RpcResult<Vec<LocalizedTransactionTrace>> {
+    async fn trace_filter(
+        &self,
+        filter: TraceFilter,
+        call_kind: Option<CallKind>,
+    ) -> RpcResult<Vec<LocalizedTransactionTrace>> {
         let _permit = self.inner.blocking_task_guard.clone().acquire_many_owned(2).await;
-        Ok(Self::trace_filter(self, filter).await.map_err(Into::into)?)
+        Ok(Self::trace_filter(self, filter, call_kind).await.map_err(Into::into)?)
     }
 
     /// Returns transaction trace at given index.
@@ -721,9 +794,32 @@ where
     async fn trace_transaction(
         &self,
         hash: B256,
+        only_failed: Option<bool>,
     ) -> RpcResult<Option<Vec<LocalizedTransactionTrace>>> {
         let _permit = self.acquire_trace_permit().await;
-        Ok(Self::trace_transaction(self, hash).await.map_err(Into::into)?)
+        let mut traces = Self::trace_transaction(self, hash).await.map_err(Into::into)?;
+        if only_failed.unwrap_or_default() &&
+            let Some(traces) = &mut traces
+        {
+            trace::retain_failed_traces(traces);
+        }
+        Ok(traces)
+    }
+
+    /// Handler for `trace_transactionExtended`
+    async fn trace_transaction_extended(
+        &self,
+        hash: B256,
+        include_precompile_calls: Option<bool>,
+    ) -> RpcResult<Option<ExtendedTransactionTrace>> {
+        let _permit = self.acquire_trace_permit().await;
+        Ok(Self::trace_transaction_extended(
+            self,
+            hash,
+            include_precompile_calls.unwrap_or_default(),
+        )
+        .await
+        .map_err(Into::into)?)
     }
 
     /// Handler for `trace_transactionOpcodeGas`
@@ -806,3 +902,161 @@ fn reward_trace<H: BlockHeader>(header: &H, reward: RewardAction) -> LocalizedTr
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{eth::helpers::types::EthRpcConverter, EthApi};
+    use alloy_consensus::{Block, BlockBody, Header, TxLegacy};
+    use alloy_primitives::{bytes, TxKind, B256};
+    use reth_chainspec::{ChainSpec, ChainSpecBuilder};
+    use reth_ethereum_primitives::{Transaction, TransactionSigned};
+    use reth_evm_ethereum::EthEvmConfig;
+    use reth_network_api::noop::NoopNetwork;
+    use reth_primitives_traits::SignerRecoverable;
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+    use reth_rpc_eth_api::node::RpcNodeCoreAdapter;
+    use reth_testing_utils::generators::{self, generate_key, sign_tx_with_key_pair};
+    use reth_transaction_pool::test_utils::{testing_pool, TestPool};
+
+    type TestEthApi = EthApi<
+        RpcNodeCoreAdapter<MockEthProvider, TestPool, NoopNetwork, EthEvmConfig>,
+        EthRpcConverter<ChainSpec>,
+    >;
+
+    /// Funds the sender of the transactions, adds a genesis block and block 1 with the given
+    /// transactions to the provider and returns a [`TraceApi`] on top of it with the hash of
+    /// block 1.
+    fn test_trace_api(
+        provider: MockEthProvider,
+        transactions: Vec<TransactionSigned>,
+    ) -> (TraceApi<TestEthApi>, B256) {
+        let sender = transactions[0].recover_signer().unwrap();
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(10).pow(U256::from(18))));
+
+        let header = Header {
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(1_000_000_000),
+            excess_blob_gas: Some(0),
+            blob_gas_used: Some(0),
+            parent_beacon_block_root: Some(B256::ZERO),
+            ..Default::default()
+        };
+        let genesis = Block::new(header.clone(), BlockBody::default());
+        let genesis_hash = genesis.header.hash_slow();
+        provider.add_block(genesis_hash, genesis);
+
+        let header = Header { number: 1, parent_hash: genesis_hash, timestamp: 12, ..header };
+        let block = Block::new(header, BlockBody { transactions, ..Default::default() });
+        let block_hash = block.header.hash_slow();
+        provider.add_block(block_hash, block);
+
+        let evm_config = EthEvmConfig::new(provider.chain_spec());
+        let eth_api: TestEthApi =
+            EthApi::builder(provider, testing_pool(), NoopNetwork::default(), evm_config).build();
+        (TraceApi::new(eth_api, BlockingTaskGuard::new(1), EthConfig::default()), block_hash)
+    }
+
+    #[tokio::test]
+    async fn traces_report_transaction_position() {
+        let provider = MockEthProvider::default()
+            .with_chain_spec(ChainSpecBuilder::mainnet().cancun_activated().build());
+
+        let key = generate_key(&mut generators::rng());
+        let transactions = (0..2)
+            .map(|nonce| {
+                sign_tx_with_key_pair(
+                    key,
+                    Transaction::Legacy(TxLegacy {
+                        chain_id: Some(1),
+                        nonce,
+                        gas_price: 2_000_000_000,
+                        gas_limit: 21_000,
+                        to: TxKind::Call(Address::with_last_byte(1)),
+                        value: U256::from(1),
+                        input: Default::default(),
+                    }),
+                )
+            })
+            .collect::<Vec<_>>();
+        let (trace_api, block_hash) = test_trace_api(provider, transactions.clone());
+
+        let traces =
+            trace_api.trace_transaction(*transactions[1].tx_hash()).await.unwrap().unwrap();
+        assert!(!traces.is_empty());
+        for trace in traces {
+            assert_eq!(trace.transaction_hash, Some(*transactions[1].tx_hash()));
+            assert_eq!(trace.transaction_position, Some(1));
+            assert_eq!(trace.block_number, Some(1));
+            assert_eq!(trace.block_hash, Some(block_hash));
+        }
+
+        // the block traces carry the same position context
+        let traces = trace_api.trace_block(BlockId::number(1)).await.unwrap().unwrap();
+        assert!(traces
+            .iter()
+            .filter(|trace| trace.transaction_hash == Some(*transactions[1].tx_hash()))
+            .all(|trace| trace.transaction_position == Some(1) && trace.block_number == Some(1)));
+        assert!(traces.iter().any(|trace| trace.transaction_position == Some(1)));
+    }
+
+    #[tokio::test]
+    async fn replay_transaction_vm_trace_nests_calls() {
+        let provider = MockEthProvider::default()
+            .with_chain_spec(ChainSpecBuilder::mainnet().cancun_activated().build());
+
+        // stores 1 at slot 0
+        let callee = Address::with_last_byte(0x20);
+        let callee_code = bytes!("600160005500");
+        provider.add_account(
+            callee,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(callee_code.clone()),
+        );
+        // calls the callee with all remaining gas
+        let caller = Address::with_last_byte(0x10);
+        let caller_code =
+            bytes!("600060006000600060007300000000000000000000000000000000000000205af100");
+        provider.add_account(
+            caller,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(caller_code.clone()),
+        );
+
+        let key = generate_key(&mut generators::rng());
+        let transaction = sign_tx_with_key_pair(
+            key,
+            Transaction::Legacy(TxLegacy {
+                chain_id: Some(1),
+                nonce: 0,
+                gas_price: 2_000_000_000,
+                gas_limit: 100_000,
+                to: TxKind::Call(caller),
+                value: U256::ZERO,
+                input: Default::default(),
+            }),
+        );
+        let hash = *transaction.tx_hash();
+        let (trace_api, _) = test_trace_api(provider, vec![transaction]);
+
+        let results = trace_api
+            .replay_transaction(hash, HashSet::from_iter([TraceType::VmTrace]))
+            .await
+            .unwrap();
+        let vm_trace = results.vm_trace.unwrap();
+        assert_eq!(vm_trace.code, caller_code);
+
+        // only the CALL carries a sub trace, which holds the steps of the callee
+        let mut calls = vm_trace.ops.iter().filter(|op| op.sub.is_some());
+        let call = calls.next().unwrap();
+        assert!(calls.next().is_none());
+        assert_eq!(call.op.as_deref(), Some("CALL"));
+        let sub = call.sub.as_ref().unwrap();
+        assert_eq!(sub.code, callee_code);
+        assert_eq!(
+            sub.ops.iter().map(|op| op.op.as_deref().unwrap()).collect::<Vec<_>>(),
+            ["PUSH1", "PUSH1", "SSTORE", "STOP"]
+        );
+
+        let sstore = sub.ops[2].ex.as_ref().unwrap().store.as_ref().unwrap();
+        assert_eq!((sstore.key, sstore.val), (U256::ZERO, U256::from(1)));
+    }
+}
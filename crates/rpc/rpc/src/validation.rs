@@ -15,7 +15,7 @@ use async_trait::async_trait;
 use core::fmt;
 use jsonrpsee::core::RpcResult;
 use jsonrpsee_types::error::ErrorObject;
-use reth_chainspec::{ChainSpecProvider, EthereumHardforks};
+use reth_chainspec::{ChainSpecProvider, EthChainSpec, EthereumHardforks};
 use reth_consensus::{Consensus, FullConsensus};
 use reth_consensus_common::validation::MAX_RLP_BLOCK_SIZE;
 use reth_engine_primitives::PayloadValidator;
@@ -30,13 +30,14 @@ use reth_metrics::{
 use reth_node_api::{NewPayloadError, PayloadTypes};
 use reth_primitives_traits::{
     constants::GAS_LIMIT_BOUND_DIVISOR, BlockBody, GotExpected, NodePrimitives, RecoveredBlock,
-    SealedBlock, SealedHeaderFor,
+    SealedBlock, SealedHeaderFor, SignedTransaction,
 };
 use reth_revm::{cached::CachedReads, database::StateProviderDatabase};
 use reth_rpc_api::BlockSubmissionValidationApiServer;
 use reth_rpc_server_types::result::{internal_rpc_err, invalid_params_rpc_err};
 use reth_storage_api::{BlockReaderIdExt, StateProviderFactory};
 use reth_tasks::TaskSpawner;
+use reth_transaction_pool::{error::Eip4844PoolTransactionError, validate::ensure_blob_count};
 use revm_primitives::{Address, B256, U256};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
@@ -67,7 +68,7 @@ where
             dyn PayloadValidator<T, Block = <E::Primitives as NodePrimitives>::Block>,
         >,
     ) -> Self {
-        let ValidationApiConfig { disallow, validation_window } = config;
+        let ValidationApiConfig { disallow, validation_window, kzg_settings } = config;
 
         let inner = Arc::new(ValidationApiInner {
             provider,
@@ -76,6 +77,7 @@ where
             evm_config,
             disallow,
             validation_window,
+            kzg_settings,
             cached_state: Default::default(),
             task_spawner,
             metrics: Default::default(),
@@ -121,17 +123,103 @@ where
     T: PayloadTypes<ExecutionData = ExecutionData>,
 {
     /// Validates the given block and a [`BidTrace`] against it.
+    ///
+    /// The block's parent must be within the configured validation window of the latest block.
     pub async fn validate_message_against_block(
         &self,
         block: RecoveredBlock<<E::Primitives as NodePrimitives>::Block>,
         message: BidTrace,
         registered_gas_limit: u64,
+    ) -> Result<(), ValidationApiError> {
+        let latest_header =
+            self.provider.latest_header()?.ok_or_else(|| ValidationApiError::MissingLatestBlock)?;
+
+        let parent_header = if block.parent_hash() == latest_header.hash() {
+            latest_header
+        } else {
+            // parent is not the latest header so we need to fetch it and ensure it's not too old
+            let parent_header = self
+                .provider
+                .sealed_header_by_hash(block.parent_hash())?
+                .ok_or_else(|| ValidationApiError::MissingParentBlock)?;
+
+            if latest_header.number().saturating_sub(parent_header.number()) >
+                self.validation_window
+            {
+                return Err(ValidationApiError::BlockTooOld)
+            }
+            parent_header
+        };
+
+        self.validate_message_against_block_with_parent(
+            block,
+            message,
+            registered_gas_limit,
+            parent_header,
+        )
+        .await
+    }
+
+    /// Validates the given block and a [`BidTrace`] against it, executing on top of the state of
+    /// the given parent block.
+    ///
+    /// Unlike [`Self::validate_message_against_block`], the parent does not need to be the latest
+    /// block or within the validation window, which allows validating submissions that build on a
+    /// parent that is not yet the canonical head. Returns
+    /// [`ValidationApiError::ParentStateUnavailable`] if the state of the parent can't be
+    /// reconstructed by the provider.
+    pub async fn validate_message_against_parent(
+        &self,
+        block: RecoveredBlock<<E::Primitives as NodePrimitives>::Block>,
+        message: BidTrace,
+        registered_gas_limit: u64,
+        parent_hash: B256,
+    ) -> Result<(), ValidationApiError> {
+        if block.parent_hash() != parent_hash {
+            return Err(ValidationApiError::ParentHashMismatch(GotExpected {
+                got: block.parent_hash(),
+                expected: parent_hash,
+            }))
+        }
+
+        let parent_header = self
+            .provider
+            .sealed_header_by_hash(parent_hash)?
+            .ok_or_else(|| ValidationApiError::MissingParentBlock)?;
+
+        self.validate_message_against_block_with_parent(
+            block,
+            message,
+            registered_gas_limit,
+            parent_header,
+        )
+        .await
+    }
+
+    /// Validates the given block and a [`BidTrace`] against it on top of the given parent.
+    async fn validate_message_against_block_with_parent(
+        &self,
+        block: RecoveredBlock<<E::Primitives as NodePrimitives>::Block>,
+        message: BidTrace,
+        registered_gas_limit: u64,
+        parent_header: SealedHeaderFor<E::Primitives>,
     ) -> Result<(), ValidationApiError> {
         self.validate_message_against_header(block.sealed_header(), &message)?;
+        ensure_withdrawals_root(block.header(), block.body())?;
 
         self.consensus.validate_header(block.sealed_header())?;
         self.consensus.validate_block_pre_execution(block.sealed_block())?;
 
+        if let Some(blob_params) =
+            self.provider.chain_spec().blob_params_at_timestamp(block.timestamp())
+        {
+            for tx in block.body().transactions() {
+                ensure_blob_count(tx, blob_params.max_blobs_per_tx)?;
+            }
+        }
+
+        ensure_gas_price_above_base_fee(block.body().transactions(), block.base_fee_per_gas())?;
+
         if !self.disallow.is_empty() {
             if self.disallow.contains(&block.beneficiary()) {
                 return Err(ValidationApiError::Blacklist(block.beneficiary()))
@@ -151,30 +239,16 @@ where
             }
         }
 
-        let latest_header =
-            self.provider.latest_header()?.ok_or_else(|| ValidationApiError::MissingLatestBlock)?;
-
-        let parent_header = if block.parent_hash() == latest_header.hash() {
-            latest_header
-        } else {
-            // parent is not the latest header so we need to fetch it and ensure it's not too old
-            let parent_header = self
-                .provider
-                .sealed_header_by_hash(block.parent_hash())?
-                .ok_or_else(|| ValidationApiError::MissingParentBlock)?;
-
-            if latest_header.number().saturating_sub(parent_header.number()) >
-                self.validation_window
-            {
-                return Err(ValidationApiError::BlockTooOld)
-            }
-            parent_header
-        };
-
         self.consensus.validate_header_against_parent(block.sealed_header(), &parent_header)?;
         self.validate_gas_limit(registered_gas_limit, &parent_header, block.sealed_header())?;
         let parent_header_hash = parent_header.hash();
-        let state_provider = self.provider.state_by_block_hash(parent_header_hash)?;
+        let state_provider =
+            self.provider.state_by_block_hash(parent_header_hash).map_err(|err| match err {
+                ProviderError::BlockHashNotFound(_) | ProviderError::StateAtBlockPruned(_) => {
+                    ValidationApiError::ParentStateUnavailable(parent_header_hash)
+                }
+                err => err.into(),
+            })?;
 
         let mut request_cache = self.cached_reads(parent_header_hash).await;
 
@@ -205,8 +279,7 @@ where
 
         self.ensure_payment(&block, &output, &message)?;
 
-        let state_root =
-            state_provider.state_root(state_provider.hashed_post_state(&output.state))?;
+        let state_root = state_provider.bundle_state_root(&output.state)?;
 
         if state_root != block.header().state_root() {
             return Err(ConsensusError::BodyStateRootDiff(
@@ -277,38 +350,49 @@ where
         Ok(())
     }
 
-    /// Ensures that the proposer has received [`BidTrace::value`] for this block.
+    /// Returns the amount paid to the given fee recipient by this block.
     ///
-    /// Firstly attempts to verify the payment by checking the state changes, otherwise falls back
-    /// to checking the latest block transaction.
-    fn ensure_payment(
+    /// This is derived from the recipient's balance before and after the block in the executed
+    /// state, without re-executing the block or querying the balance separately. Withdrawals to
+    /// the recipient are not counted as payment.
+    ///
+    /// Returns `None` if the recipient's balance decreased over the block.
+    pub fn proposer_payment(
         &self,
         block: &SealedBlock<<E::Primitives as NodePrimitives>::Block>,
         output: &BlockExecutionOutput<<E::Primitives as NodePrimitives>::Receipt>,
-        message: &BidTrace,
-    ) -> Result<(), ValidationApiError> {
-        let (mut balance_before, balance_after) = if let Some(acc) =
-            output.state.state.get(&message.proposer_fee_recipient)
-        {
-            let balance_before = acc.original_info.as_ref().map(|i| i.balance).unwrap_or_default();
-            let balance_after = acc.info.as_ref().map(|i| i.balance).unwrap_or_default();
-
-            (balance_before, balance_after)
-        } else {
-            // account might have balance but considering it zero is fine as long as we know
-            // that balance have not changed
-            (U256::ZERO, U256::ZERO)
-        };
+        fee_recipient: Address,
+    ) -> Option<U256> {
+        // account might have balance but considering it zero is fine as long as we know that
+        // balance have not changed
+        let (mut balance_before, balance_after) =
+            output.balance_change(&fee_recipient).unwrap_or_default();
 
         if let Some(withdrawals) = block.body().withdrawals() {
             for withdrawal in withdrawals {
-                if withdrawal.address == message.proposer_fee_recipient {
+                if withdrawal.address == fee_recipient {
                     balance_before += withdrawal.amount_wei();
                 }
             }
         }
 
-        if balance_after >= balance_before.saturating_add(message.value) {
+        balance_after.checked_sub(balance_before)
+    }
+
+    /// Ensures that the proposer has received [`BidTrace::value`] for this block.
+    ///
+    /// Firstly attempts to verify the payment by checking the state changes, otherwise falls back
+    /// to checking the latest block transaction.
+    fn ensure_payment(
+        &self,
+        block: &SealedBlock<<E::Primitives as NodePrimitives>::Block>,
+        output: &BlockExecutionOutput<<E::Primitives as NodePrimitives>::Receipt>,
+        message: &BidTrace,
+    ) -> Result<(), ValidationApiError> {
+        if self
+            .proposer_payment(block, output, message.proposer_fee_recipient)
+            .is_some_and(|payment| payment >= message.value)
+        {
             return Ok(())
         }
 
@@ -362,7 +446,7 @@ where
 
         let sidecar = blobs_bundle.pop_sidecar(blobs_bundle.blobs.len());
 
-        sidecar.validate(&versioned_hashes, EnvKzgSettings::default().get())?;
+        sidecar.validate(&versioned_hashes, self.kzg_settings.get())?;
 
         Ok(versioned_hashes)
     }
@@ -380,11 +464,35 @@ where
         blobs_bundle
             .try_into_sidecar()
             .map_err(|_| ValidationApiError::InvalidBlobsBundle)?
-            .validate(&versioned_hashes, EnvKzgSettings::default().get())?;
+            .validate(&versioned_hashes, self.kzg_settings.get())?;
 
         Ok(versioned_hashes)
     }
 
+    /// Validates the given block and a [`BidTrace`] against it, on top of the given parent if any.
+    ///
+    /// See [`Self::validate_message_against_parent`] and [`Self::validate_message_against_block`].
+    async fn validate_message(
+        &self,
+        block: RecoveredBlock<<E::Primitives as NodePrimitives>::Block>,
+        message: BidTrace,
+        registered_gas_limit: u64,
+        parent_hash: Option<B256>,
+    ) -> Result<(), ValidationApiError> {
+        match parent_hash {
+            Some(parent_hash) => {
+                self.validate_message_against_parent(
+                    block,
+                    message,
+                    registered_gas_limit,
+                    parent_hash,
+                )
+                .await
+            }
+            None => self.validate_message_against_block(block, message, registered_gas_limit).await,
+        }
+    }
+
     /// Core logic for validating the builder submission v3
     async fn validate_builder_submission_v3(
         &self,
@@ -406,10 +514,11 @@ where
         .await
     }
 
-    /// Core logic for validating the builder submission v4
+    /// Core logic for validating the builder submission v4, optionally on top of the given parent
     async fn validate_builder_submission_v4(
         &self,
         request: BuilderBlockValidationRequestV4,
+        parent_hash: Option<B256>,
     ) -> Result<(), ValidationApiError> {
         let block = self.payload_validator.ensure_well_formed_payload(ExecutionData {
             payload: ExecutionPayload::V3(request.request.execution_payload),
@@ -426,18 +535,20 @@ where
             ),
         })?;
 
-        self.validate_message_against_block(
+        self.validate_message(
             block,
             request.request.message,
             request.registered_gas_limit,
+            parent_hash,
         )
         .await
     }
 
-    /// Core logic for validating the builder submission v5
+    /// Core logic for validating the builder submission v5, optionally on top of the given parent
     async fn validate_builder_submission_v5(
         &self,
         request: BuilderBlockValidationRequestV5,
+        parent_hash: Option<B256>,
     ) -> Result<(), ValidationApiError> {
         let block = self.payload_validator.ensure_well_formed_payload(ExecutionData {
             payload: ExecutionPayload::V3(request.request.execution_payload),
@@ -466,10 +577,11 @@ where
             }));
         }
 
-        self.validate_message_against_block(
+        self.validate_message(
             block,
             request.request.message,
             request.registered_gas_limit,
+            parent_hash,
         )
         .await
     }
@@ -529,7 +641,7 @@ where
         let (tx, rx) = oneshot::channel();
 
         self.task_spawner.spawn_blocking(Box::pin(async move {
-            let result = Self::validate_builder_submission_v4(&this, request)
+            let result = Self::validate_builder_submission_v4(&this, request, None)
                 .await
                 .map_err(ErrorObject::from);
             let _ = tx.send(result);
@@ -547,7 +659,7 @@ where
         let (tx, rx) = oneshot::channel();
 
         self.task_spawner.spawn_blocking(Box::pin(async move {
-            let result = Self::validate_builder_submission_v5(&this, request)
+            let result = Self::validate_builder_submission_v5(&this, request, None)
                 .await
                 .map_err(ErrorObject::from);
             let _ = tx.send(result);
@@ -555,6 +667,46 @@ where
 
         rx.await.map_err(|_| internal_rpc_err("Internal blocking task error"))?
     }
+
+    /// Validates a block submitted to the relay on top of the given parent
+    async fn validate_builder_submission_v4_with_parent(
+        &self,
+        request: BuilderBlockValidationRequestV4,
+        parent_block_hash: B256,
+    ) -> RpcResult<()> {
+        let this = self.clone();
+        let (tx, rx) = oneshot::channel();
+
+        self.task_spawner.spawn_blocking(Box::pin(async move {
+            let result =
+                Self::validate_builder_submission_v4(&this, request, Some(parent_block_hash))
+                    .await
+                    .map_err(ErrorObject::from);
+            let _ = tx.send(result);
+        }));
+
+        rx.await.map_err(|_| internal_rpc_err("Internal blocking task error"))?
+    }
+
+    /// Validates a block submitted to the relay on top of the given parent
+    async fn validate_builder_submission_v5_with_parent(
+        &self,
+        request: BuilderBlockValidationRequestV5,
+        parent_block_hash: B256,
+    ) -> RpcResult<()> {
+        let this = self.clone();
+        let (tx, rx) = oneshot::channel();
+
+        self.task_spawner.spawn_blocking(Box::pin(async move {
+            let result =
+                Self::validate_builder_submission_v5(&this, request, Some(parent_block_hash))
+                    .await
+                    .map_err(ErrorObject::from);
+            let _ = tx.send(result);
+        }));
+
+        rx.await.map_err(|_| internal_rpc_err("Internal blocking task error"))?
+    }
 }
 
 pub struct ValidationApiInner<Provider, E: ConfigureEvm, T: PayloadTypes> {
@@ -571,6 +723,8 @@ pub struct ValidationApiInner<Provider, E: ConfigureEvm, T: PayloadTypes> {
     disallow: HashSet<Address>,
     /// The maximum block distance - parent to latest - allowed for validation
     validation_window: u64,
+    /// KZG settings used to verify blob sidecars
+    kzg_settings: EnvKzgSettings,
     /// Cached state reads to avoid redundant disk I/O across multiple validation attempts
     /// targeting the same state. Stores a tuple of (`block_hash`, `cached_reads`) for the
     /// latest head block state. Uses async `RwLock` to safely handle concurrent validation
@@ -598,6 +752,50 @@ fn hash_disallow_list(disallow: &HashSet<Address>) -> String {
     format!("{:x}", hasher.finalize())
 }
 
+/// Ensures that the effective gas price of every transaction is at least the block's base fee.
+///
+/// Such transactions can't be included in the block. Deposit transactions, see
+/// [`SignedTransaction::is_deposit_transaction`], don't pay the base fee and are exempt.
+fn ensure_gas_price_above_base_fee<'a, Tx: SignedTransaction + 'a>(
+    transactions: impl IntoIterator<Item = &'a Tx>,
+    base_fee: Option<u64>,
+) -> Result<(), ValidationApiError> {
+    let Some(base_fee) = base_fee else { return Ok(()) };
+    for (index, tx) in transactions.into_iter().enumerate() {
+        if tx.is_deposit_transaction() {
+            continue
+        }
+        let effective_gas_price = tx.effective_gas_price(Some(base_fee));
+        if effective_gas_price < base_fee as u128 {
+            return Err(ValidationApiError::GasPriceBelowBaseFee {
+                index,
+                effective_gas_price,
+                base_fee,
+            })
+        }
+    }
+    Ok(())
+}
+
+/// Ensures that the header's withdrawals root commits to the withdrawals of the body.
+///
+/// A root without withdrawals is reported as [`ConsensusError::BodyWithdrawalsMissing`] and
+/// withdrawals without a root as [`ConsensusError::WithdrawalsRootUnexpected`].
+fn ensure_withdrawals_root<H: BlockHeader, B: BlockBody>(
+    header: &H,
+    body: &B,
+) -> Result<(), ValidationApiError> {
+    match (header.withdrawals_root(), body.calculate_withdrawals_root()) {
+        (Some(expected), Some(got)) if got != expected => {
+            Err(ConsensusError::BodyWithdrawalsRootDiff(GotExpected { got, expected }.into())
+                .into())
+        }
+        (Some(_), None) => Err(ConsensusError::BodyWithdrawalsMissing.into()),
+        (None, Some(_)) => Err(ConsensusError::WithdrawalsRootUnexpected.into()),
+        _ => Ok(()),
+    }
+}
+
 impl<Provider, E: ConfigureEvm, T: PayloadTypes> fmt::Debug for ValidationApiInner<Provider, E, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ValidationApiInner").finish_non_exhaustive()
@@ -611,6 +809,9 @@ pub struct ValidationApiConfig {
     pub disallow: HashSet<Address>,
     /// The maximum block distance - parent to latest - allowed for validation
     pub validation_window: u64,
+    /// KZG settings used to verify blob sidecars.
+    #[serde(skip)]
+    pub kzg_settings: EnvKzgSettings,
 }
 
 impl ValidationApiConfig {
@@ -620,7 +821,11 @@ impl ValidationApiConfig {
 
 impl Default for ValidationApiConfig {
     fn default() -> Self {
-        Self { disallow: Default::default(), validation_window: Self::DEFAULT_VALIDATION_WINDOW }
+        Self {
+            disallow: Default::default(),
+            validation_window: Self::DEFAULT_VALIDATION_WINDOW,
+            kzg_settings: Default::default(),
+        }
     }
 }
 
@@ -639,6 +844,8 @@ pub enum ValidationApiError {
     MissingLatestBlock,
     #[error("parent block not found")]
     MissingParentBlock,
+    #[error("state of parent block {_0} is not available")]
+    ParentStateUnavailable(B256),
     #[error("block is too old, outside validation window")]
     BlockTooOld,
     #[error("could not verify proposer payment")]
@@ -647,9 +854,22 @@ pub enum ValidationApiError {
     InvalidBlobsBundle,
     #[error("block accesses blacklisted address: {_0}")]
     Blacklist(Address),
+    #[error(
+        "transaction {index} has effective gas price {effective_gas_price} below base fee {base_fee}"
+    )]
+    GasPriceBelowBaseFee {
+        /// Index of the transaction in the block.
+        index: usize,
+        /// Effective gas price of the transaction at the block's base fee.
+        effective_gas_price: u128,
+        /// Base fee of the block.
+        base_fee: u64,
+    },
     #[error(transparent)]
     Blob(#[from] BlobTransactionValidationError),
     #[error(transparent)]
+    BlobCount(#[from] Eip4844PoolTransactionError),
+    #[error(transparent)]
     Consensus(#[from] ConsensusError),
     #[error(transparent)]
     Provider(#[from] ProviderError),
@@ -667,15 +887,26 @@ impl From<ValidationApiError> for ErrorObject<'static> {
             ValidationApiError::ParentHashMismatch(_) |
             ValidationApiError::BlockHashMismatch(_) |
             ValidationApiError::Blacklist(_) |
+            ValidationApiError::GasPriceBelowBaseFee { .. } |
             ValidationApiError::ProposerPayment |
             ValidationApiError::InvalidBlobsBundle |
-            ValidationApiError::Blob(_) => invalid_params_rpc_err(error.to_string()),
+            ValidationApiError::Blob(_) |
+            ValidationApiError::BlobCount(_) => invalid_params_rpc_err(error.to_string()),
 
             ValidationApiError::MissingLatestBlock |
             ValidationApiError::MissingParentBlock |
+            ValidationApiError::ParentStateUnavailable(_) |
             ValidationApiError::BlockTooOld |
-            ValidationApiError::Consensus(_) |
             ValidationApiError::Provider(_) => internal_rpc_err(error.to_string()),
+            // invalid blocks are reported as invalid params, so that relays can tell them apart
+            // from failures caused by the local environment
+            ValidationApiError::Consensus(ref err) => {
+                if err.is_builder_fault() {
+                    invalid_params_rpc_err(error.to_string())
+                } else {
+                    internal_rpc_err(error.to_string())
+                }
+            }
             ValidationApiError::Execution(err) => match err {
                 error @ BlockExecutionError::Validation(_) => {
                     invalid_params_rpc_err(error.to_string())
@@ -700,9 +931,32 @@ pub(crate) struct ValidationMetrics {
 
 #[cfg(test)]
 mod tests {
-    use super::hash_disallow_list;
+    use super::{
+        ensure_withdrawals_root, hash_disallow_list, ValidationApi, ValidationApiConfig,
+        ValidationApiError,
+    };
+    use alloy_consensus::{proofs, BlockBody, BlockHeader, Header, Signed, TxEip1559, TxLegacy};
+    use alloy_eips::eip4895::{Withdrawal, Withdrawals};
+    use alloy_primitives::{Signature, TxKind, B256};
+    use alloy_rpc_types_beacon::relay::BidTrace;
+    use alloy_rpc_types_engine::ExecutionData;
+    use jsonrpsee_types::{
+        error::{INTERNAL_ERROR_CODE, INVALID_PARAMS_CODE},
+        ErrorObject,
+    };
+    use reth_chainspec::ChainSpecProvider;
+    use reth_consensus::noop::NoopConsensus;
+    use reth_engine_primitives::PayloadValidator;
+    use reth_errors::ConsensusError;
+    use reth_ethereum_engine_primitives::EthEngineTypes;
+    use reth_ethereum_primitives::{Block, TransactionSigned};
+    use reth_evm_ethereum::EthEvmConfig;
+    use reth_node_api::NewPayloadError;
+    use reth_primitives_traits::{Block as _, GotExpected, RecoveredBlock, SealedBlock};
+    use reth_provider::test_utils::MockEthProvider;
+    use reth_tasks::TokioTaskExecutor;
     use revm_primitives::Address;
-    use std::collections::HashSet;
+    use std::{collections::HashSet, sync::Arc};
 
     #[test]
     fn test_hash_disallow_list_deterministic() {
@@ -756,4 +1010,175 @@ mod tests {
         let hash = hash_disallow_list(&blocklist);
         assert_eq!(expected_hash, hash);
     }
+
+    #[test]
+    fn test_consensus_error_code_by_category() {
+        let builder_fault = ValidationApiError::Consensus(ConsensusError::BodyStateRootDiff(
+            GotExpected { got: B256::ZERO, expected: B256::with_last_byte(1) }.into(),
+        ));
+        assert_eq!(ErrorObject::from(builder_fault).code(), INVALID_PARAMS_CODE);
+
+        let environmental =
+            ValidationApiError::Consensus(ConsensusError::ParentUnknown { hash: B256::ZERO });
+        assert_eq!(ErrorObject::from(environmental).code(), INTERNAL_ERROR_CODE);
+
+        let indeterminate =
+            ValidationApiError::Consensus(ConsensusError::Other("l2 error".to_string()));
+        assert_eq!(ErrorObject::from(indeterminate).code(), INTERNAL_ERROR_CODE);
+    }
+
+    #[test]
+    fn test_parent_state_unavailable_error() {
+        let parent = B256::with_last_byte(1);
+        let error = ValidationApiError::ParentStateUnavailable(parent);
+        assert_eq!(error.to_string(), format!("state of parent block {parent} is not available"));
+        assert_eq!(ErrorObject::from(error).code(), INTERNAL_ERROR_CODE);
+    }
+
+    #[tokio::test]
+    async fn test_underpriced_transaction_rejected() {
+        struct MockPayloadValidator;
+
+        impl PayloadValidator<EthEngineTypes> for MockPayloadValidator {
+            type Block = Block;
+
+            fn convert_payload_to_block(
+                &self,
+                payload: ExecutionData,
+            ) -> Result<SealedBlock<Block>, NewPayloadError> {
+                let block = Block::try_from(payload.payload)
+                    .map_err(|e| NewPayloadError::Other(format!("{e:?}").into()))?;
+                Ok(block.seal_slow())
+            }
+        }
+
+        // a disallowed beneficiary fails the checks that follow the base fee check
+        let beneficiary = Address::with_last_byte(0xcb);
+        let provider = MockEthProvider::default();
+        let api = ValidationApi::<_, _, EthEngineTypes>::new(
+            provider.clone(),
+            NoopConsensus::arc(),
+            EthEvmConfig::new(provider.chain_spec()),
+            ValidationApiConfig {
+                disallow: HashSet::from([beneficiary]),
+                ..ValidationApiConfig::default()
+            },
+            Box::new(TokioTaskExecutor::default()),
+            Arc::new(MockPayloadValidator),
+        );
+
+        let base_fee = 1_000_000_000u64;
+        let parent = Header {
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(base_fee),
+            ..Default::default()
+        };
+        let parent_hash = parent.hash_slow();
+        provider.add_header(parent_hash, parent.clone());
+
+        let validate = |transactions: Vec<TransactionSigned>| {
+            let header = Header {
+                parent_hash,
+                number: 1,
+                beneficiary,
+                gas_limit: parent.gas_limit,
+                base_fee_per_gas: Some(base_fee),
+                ..Default::default()
+            };
+            let senders = vec![Address::ZERO; transactions.len()];
+            let block = RecoveredBlock::new_unhashed(
+                Block { header, body: BlockBody { transactions, ..Default::default() } },
+                senders,
+            );
+            let message = BidTrace {
+                block_hash: block.hash(),
+                parent_hash,
+                gas_limit: block.gas_limit(),
+                gas_used: block.gas_used(),
+                ..Default::default()
+            };
+            api.validate_message_against_parent(block, message, parent.gas_limit, parent_hash)
+        };
+
+        let legacy = |gas_price| {
+            TransactionSigned::from(Signed::new_unhashed(
+                TxLegacy { gas_price, to: TxKind::Call(Address::ZERO), ..Default::default() },
+                Signature::test_signature(),
+            ))
+        };
+        let dynamic = |max_fee_per_gas, max_priority_fee_per_gas| {
+            TransactionSigned::from(Signed::new_unhashed(
+                TxEip1559 {
+                    max_fee_per_gas,
+                    max_priority_fee_per_gas,
+                    to: TxKind::Call(Address::ZERO),
+                    ..Default::default()
+                },
+                Signature::test_signature(),
+            ))
+        };
+
+        let mut transactions = vec![
+            legacy(base_fee as u128),
+            dynamic(2 * base_fee as u128, 0),
+            legacy(2 * base_fee as u128),
+        ];
+        // a correctly priced block gets past the base fee check
+        let err = validate(transactions.clone()).await.unwrap_err();
+        assert!(matches!(err, ValidationApiError::Blacklist(address) if address == beneficiary));
+
+        // the priority fee can't make up for a max fee below the base fee
+        transactions.insert(2, dynamic(base_fee as u128 - 1, base_fee as u128));
+        let err = validate(transactions).await.unwrap_err();
+        assert!(matches!(
+            err,
+            ValidationApiError::GasPriceBelowBaseFee { index: 2, effective_gas_price, base_fee: b }
+                if effective_gas_price == base_fee as u128 - 1 && b == base_fee
+        ));
+        assert_eq!(ErrorObject::from(err).code(), INVALID_PARAMS_CODE);
+    }
+
+    #[test]
+    fn test_tampered_withdrawal_rejected() {
+        let withdrawal = |index, amount| Withdrawal {
+            index,
+            validator_index: index,
+            address: Address::with_last_byte(index as u8),
+            amount,
+        };
+        let withdrawals = Withdrawals::new(vec![withdrawal(0, 32), withdrawal(1, 64)]);
+        let header = Header {
+            withdrawals_root: Some(proofs::calculate_withdrawals_root(&withdrawals)),
+            ..Default::default()
+        };
+        let body =
+            |withdrawals| BlockBody::<TransactionSigned> { withdrawals, ..Default::default() };
+
+        assert!(ensure_withdrawals_root(&header, &body(Some(withdrawals.clone()))).is_ok());
+        // pre-shanghai blocks have neither
+        assert!(ensure_withdrawals_root(&Header::default(), &body(None)).is_ok());
+
+        let mut tampered = withdrawals.clone();
+        tampered[1].amount += 1;
+        let err = ensure_withdrawals_root(&header, &body(Some(tampered.clone()))).unwrap_err();
+        assert!(matches!(
+            &err,
+            ValidationApiError::Consensus(ConsensusError::BodyWithdrawalsRootDiff(diff))
+                if diff.got == proofs::calculate_withdrawals_root(&tampered) &&
+                    Some(diff.expected) == header.withdrawals_root
+        ));
+        assert_eq!(ErrorObject::from(err).code(), INVALID_PARAMS_CODE);
+
+        let err = ensure_withdrawals_root(&header, &body(None)).unwrap_err();
+        assert!(matches!(
+            err,
+            ValidationApiError::Consensus(ConsensusError::BodyWithdrawalsMissing)
+        ));
+        let err =
+            ensure_withdrawals_root(&Header::default(), &body(Some(withdrawals))).unwrap_err();
+        assert!(matches!(
+            err,
+            ValidationApiError::Consensus(ConsensusError::WithdrawalsRootUnexpected)
+        ));
+    }
 }
@@ -35,6 +35,9 @@ const WORKER_CHUNK_SIZE: usize = 100;
 /// Type alias for a sender that transmits the result of sender recovery.
 type RecoveryResultSender = mpsc::Sender<Result<(u64, Address), Box<SenderRecoveryStageError>>>;
 
+/// Type alias for a sender that transmits chunks of transactions to recover senders for.
+type RecoveryBatchSender = mpsc::Sender<Vec<(Range<u64>, RecoveryResultSender)>>;
+
 /// The sender recovery stage iterates over existing transactions,
 /// recovers the transaction signer and stores them
 /// in [`TransactionSenders`][reth_db_api::tables::TransactionSenders] table.
@@ -43,18 +46,22 @@ pub struct SenderRecoveryStage {
     /// The size of inserted items after which the control
     /// flow will be returned to the pipeline for commit
     pub commit_threshold: u64,
+    /// The number of threads used to recover senders.
+    ///
+    /// If not set, the global rayon pool is used.
+    pub concurrency: Option<usize>,
 }
 
 impl SenderRecoveryStage {
     /// Create new instance of [`SenderRecoveryStage`].
     pub const fn new(config: SenderRecoveryConfig) -> Self {
-        Self { commit_threshold: config.commit_threshold }
+        Self { commit_threshold: config.commit_threshold, concurrency: config.concurrency }
     }
 }
 
 impl Default for SenderRecoveryStage {
     fn default() -> Self {
-        Self { commit_threshold: 5_000_000 }
+        Self { commit_threshold: 5_000_000, concurrency: None }
     }
 }
 
@@ -114,7 +121,7 @@ where
             .map(|start| start..std::cmp::min(start + BATCH_SIZE as u64, range_output.tx_range.end))
             .collect::<Vec<Range<u64>>>();
 
-        let tx_batch_sender = setup_range_recovery(provider);
+        let tx_batch_sender = setup_range_recovery(provider, self.concurrency)?;
 
         let start = Instant::now();
         let block_body_indices =
@@ -173,7 +180,7 @@ fn recover_range<Provider, CURSOR>(
     tx_range: Range<TxNumber>,
     block_numbers: Vec<BlockNumber>,
     provider: &Provider,
-    tx_batch_sender: mpsc::Sender<Vec<(Range<u64>, RecoveryResultSender)>>,
+    tx_batch_sender: RecoveryBatchSender,
     writer: &mut EitherWriter<'_, CURSOR, Provider::Primitives>,
 ) -> Result<(), StageError>
 where
@@ -272,10 +279,12 @@ where
 
 /// Spawns a thread to handle the recovery of transaction senders for
 /// specified chunks of a given batch. It processes incoming ranges, fetching and recovering
-/// transactions in parallel using global rayon pool
+/// transactions in parallel using a dedicated rayon pool with `concurrency` threads, or the global
+/// rayon pool if not set.
 fn setup_range_recovery<Provider>(
     provider: &Provider,
-) -> mpsc::Sender<Vec<(Range<u64>, RecoveryResultSender)>>
+    concurrency: Option<usize>,
+) -> Result<RecoveryBatchSender, StageError>
 where
     Provider: DBProvider
         + HeaderProvider
@@ -283,6 +292,15 @@ where
 {
     let (tx_sender, tx_receiver) = mpsc::channel::<Vec<(Range<u64>, RecoveryResultSender)>>();
     let static_file_provider = provider.static_file_provider();
+    let thread_pool = concurrency
+        .map(|num_threads| {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .thread_name(|i| format!("sender-recovery-{i}"))
+                .build()
+        })
+        .transpose()
+        .map_err(|err| StageError::Fatal(err.into()))?;
 
     // We do not use `tokio::task::spawn_blocking` because, during a shutdown,
     // there will be a timeout grace period in which Tokio does not allow spawning
@@ -316,10 +334,10 @@ where
                     }
                 };
 
-                // Spawn the task onto the global rayon pool
+                // Spawn the task onto the rayon pool
                 // This task will send the results through the channel after it has read the
                 // transaction and calculated the sender.
-                rayon::spawn(move || {
+                let task = move || {
                     let mut rlp_buf = Vec::with_capacity(128);
                     for (number, tx) in chunk {
                         let res = tx
@@ -338,11 +356,15 @@ where
                             break
                         }
                     }
-                });
+                };
+                match &thread_pool {
+                    Some(thread_pool) => thread_pool.spawn(task),
+                    None => rayon::spawn(task),
+                }
             }
         }
     });
-    tx_sender
+    Ok(tx_sender)
 }
 
 #[inline]
@@ -561,6 +583,52 @@ mod tests {
         assert!(runner.validate_execution(first_input, result.ok()).is_ok(), "validation failed");
     }
 
+    /// Execute the stage with different thread counts and compare the recovered senders
+    #[tokio::test]
+    async fn execute_with_concurrency() {
+        let mut rng = generators::rng();
+        let (stage_progress, previous_stage) = (0, 100);
+        let blocks = random_block_range(
+            &mut rng,
+            stage_progress..=previous_stage,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 0..4, ..Default::default() },
+        );
+        // senders of the block at the checkpoint are already recovered
+        let expected = blocks
+            .iter()
+            .flat_map(|block| block.body().transactions.iter().map(move |tx| (block.number, tx)))
+            .enumerate()
+            .filter(|(_, (number, _))| *number > stage_progress)
+            .map(|(tx_number, (_, tx))| (tx_number as u64, tx.recover_signer().unwrap()))
+            .collect::<Vec<_>>();
+
+        for concurrency in [None, Some(1), Some(3)] {
+            let runner = SenderRecoveryTestRunner { concurrency, ..Default::default() };
+            runner
+                .db
+                .insert_blocks(blocks.iter(), StorageKind::Static)
+                .expect("failed to insert blocks");
+
+            let input = ExecInput {
+                target: Some(previous_stage),
+                checkpoint: Some(StageCheckpoint::new(stage_progress)),
+            };
+            let result = runner.execute(input).await.unwrap();
+            assert_matches!(result, Ok(ExecOutput { done: true, .. }));
+
+            let senders = runner
+                .db
+                .query(|tx| {
+                    Ok(tx
+                        .cursor_read::<tables::TransactionSenders>()?
+                        .walk(None)?
+                        .collect::<Result<Vec<_>, _>>()?)
+                })
+                .unwrap();
+            assert_eq!(senders, expected, "concurrency: {concurrency:?}");
+        }
+    }
+
     #[test]
     fn stage_checkpoint_pruned() {
         let db = TestStageDB::default();
@@ -623,11 +691,12 @@ mod tests {
     struct SenderRecoveryTestRunner {
         db: TestStageDB,
         threshold: u64,
+        concurrency: Option<usize>,
     }
 
     impl Default for SenderRecoveryTestRunner {
         fn default() -> Self {
-            Self { threshold: 1000, db: TestStageDB::default() }
+            Self { threshold: 1000, concurrency: None, db: TestStageDB::default() }
         }
     }
 
@@ -671,7 +740,7 @@ mod tests {
         }
 
         fn stage(&self) -> Self::S {
-            SenderRecoveryStage { commit_threshold: self.threshold }
+            SenderRecoveryStage { commit_threshold: self.threshold, concurrency: self.concurrency }
         }
     }
 
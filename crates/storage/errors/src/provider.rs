@@ -70,6 +70,12 @@ pub enum ProviderError {
     /// Unable to find the best block.
     #[error("best block does not exist")]
     BestBlockNotFound,
+    /// The block is not an ancestor of the requested descendant block.
+    #[error("block {ancestor} is not an ancestor of the requested block")]
+    NotAnAncestor {
+        /// Hash of the presumed ancestor.
+        ancestor: BlockHash,
+    },
     /// Unable to find the finalized block.
     #[error("finalized block does not exist")]
     FinalizedBlockNotFound,
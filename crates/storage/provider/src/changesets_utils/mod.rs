@@ -3,5 +3,8 @@
 mod state_reverts;
 pub use state_reverts::StorageRevertsIter;
 
+mod storage_page;
+pub(crate) use storage_page::overlay_storage_page;
+
 mod trie;
 pub use trie::*;
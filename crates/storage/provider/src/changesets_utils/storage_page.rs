@@ -0,0 +1,26 @@
+use alloy_primitives::{B256, U256};
+use itertools::{merge_join_by, EitherOrBoth};
+use reth_primitives_traits::StorageEntry;
+
+/// Applies slot values on top of a page of storage slots and returns at most `limit` non-zero
+/// slots, ordered by slot.
+///
+/// Both the page and the overrides must be sorted by slot. Since every override can remove at most
+/// one slot from the page, the page must contain `limit` plus the number of overrides slots, or all
+/// remaining slots, for the result to be complete.
+pub(crate) fn overlay_storage_page<'a>(
+    page: Vec<StorageEntry>,
+    overrides: impl IntoIterator<Item = (&'a B256, &'a U256)>,
+    limit: usize,
+) -> Vec<StorageEntry> {
+    merge_join_by(page, overrides, |entry, (key, _)| entry.key.cmp(key))
+        .map(|entry| match entry {
+            EitherOrBoth::Left(entry) => entry,
+            EitherOrBoth::Right((&key, &value)) | EitherOrBoth::Both(_, (&key, &value)) => {
+                StorageEntry { key, value }
+            }
+        })
+        .filter(|entry| !entry.value.is_zero())
+        .take(limit)
+        .collect()
+}
@@ -26,7 +26,9 @@ use reth_primitives_traits::{Account, RecoveredBlock, SealedHeader, StorageEntry
 use reth_prune_types::{PruneCheckpoint, PruneSegment};
 use reth_stages_types::{StageCheckpoint, StageId};
 use reth_static_file_types::StaticFileSegment;
-use reth_storage_api::{BlockBodyIndicesProvider, NodePrimitivesProvider, StorageChangeSetReader};
+use reth_storage_api::{
+    BlockBodyIndicesProvider, NodePrimitivesProvider, SealedHeadersIter, StorageChangeSetReader,
+};
 use reth_storage_errors::provider::ProviderResult;
 use reth_trie::{updates::TrieUpdatesSorted, HashedPostState, KeccakKeyHasher};
 use revm_database::BundleState;
@@ -142,6 +144,20 @@ impl<N: ProviderNodeTypes> BlockchainProvider<N> {
     ) -> ProviderResult<Option<ExecutionOutcome<ReceiptTy<N>>>> {
         self.consistent_provider()?.get_state(range)
     }
+
+    /// Returns at most `limit` non-zero storage slots of the account at the given block, starting
+    /// at `start_key` and ordered by slot.
+    ///
+    /// See [`ConsistentProvider::account_storage_at`].
+    pub fn account_storage_at(
+        &self,
+        address: Address,
+        block_id: BlockId,
+        start_key: B256,
+        limit: usize,
+    ) -> ProviderResult<Vec<StorageEntry>> {
+        self.consistent_provider()?.account_storage_at(address, block_id, start_key, limit)
+    }
 }
 
 impl<N: NodeTypesWithDB> NodePrimitivesProvider for BlockchainProvider<N> {
@@ -215,6 +231,24 @@ impl<N: ProviderNodeTypes> HeaderProvider for BlockchainProvider<N> {
     ) -> ProviderResult<Vec<SealedHeader<Self::Header>>> {
         self.consistent_provider()?.sealed_headers_while(range, predicate)
     }
+
+    fn canonical_sealed_headers_iter(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<SealedHeadersIter<'_, Self::Header>> {
+        let provider = self.consistent_provider()?;
+        if range.is_empty() || *range.end() > provider.best_block_number()? {
+            return Err(ProviderError::HeaderNotFound((*range.end()).into()))
+        }
+
+        // The iterator can't borrow a consistent provider created here, so it owns one and reads
+        // the headers one by one.
+        Ok(Box::new(range.map(move |number| {
+            provider
+                .sealed_header(number)?
+                .ok_or_else(|| ProviderError::HeaderNotFound(number.into()))
+        })))
+    }
 }
 
 impl<N: ProviderNodeTypes> BlockHashReader for BlockchainProvider<N> {
@@ -430,6 +464,13 @@ impl<N: ProviderNodeTypes> ReceiptProvider for BlockchainProvider<N> {
     ) -> ProviderResult<Vec<Vec<Self::Receipt>>> {
         self.consistent_provider()?.receipts_by_block_range(block_range)
     }
+
+    fn receipts_by_tx_hashes(
+        &self,
+        hashes: Vec<TxHash>,
+    ) -> ProviderResult<Vec<Option<(Self::Receipt, BlockNumber, u64)>>> {
+        self.consistent_provider()?.receipts_by_tx_hashes(hashes)
+    }
 }
 
 impl<N: ProviderNodeTypes> ReceiptProviderIdExt for BlockchainProvider<N> {
@@ -761,8 +802,9 @@ mod tests {
         },
         BlockWriter, CanonChainTracker, ProviderFactory,
     };
-    use alloy_eips::{BlockHashOrNumber, BlockNumHash, BlockNumberOrTag};
+    use alloy_eips::{BlockHashOrNumber, BlockId, BlockNumHash, BlockNumberOrTag, RpcBlockHash};
     use alloy_primitives::{BlockNumber, TxNumber, B256};
+    use assert_matches::assert_matches;
     use itertools::Itertools;
     use rand::Rng;
     use reth_chain_state::{
@@ -1269,6 +1311,84 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_transaction_block() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (provider, database_blocks, in_memory_blocks, _) = provider_with_random_blocks(
+            &mut rng,
+            TEST_BLOCKS_COUNT,
+            TEST_BLOCKS_COUNT,
+            BlockRangeParams {
+                tx_count: TEST_TRANSACTIONS_COUNT..TEST_TRANSACTIONS_COUNT,
+                ..Default::default()
+            },
+        )?;
+
+        // transactions are found in both persisted and in-memory blocks
+        for block in [database_blocks.last().unwrap(), in_memory_blocks.first().unwrap()] {
+            for tx in block.body().transactions() {
+                assert_eq!(provider.transaction_block(*tx.tx_hash())?, Some(block.num_hash()));
+            }
+        }
+
+        // an unknown transaction isn't found
+        assert_eq!(provider.transaction_block(B256::random())?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_canonical_hash_across_reorg() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (provider, database_blocks, in_memory_blocks, _) = provider_with_random_blocks(
+            &mut rng,
+            TEST_BLOCKS_COUNT,
+            TEST_BLOCKS_COUNT,
+            BlockRangeParams { tx_count: 0..1, ..Default::default() },
+        )?;
+
+        let database_block = database_blocks.last().unwrap();
+        let old_block = in_memory_blocks.last().unwrap();
+        assert_eq!(provider.canonical_hash(database_block.number)?, Some(database_block.hash()));
+        assert_eq!(provider.canonical_hash(old_block.number)?, Some(old_block.hash()));
+
+        // replace the tip with a sibling block
+        let new_block = random_block(
+            &mut rng,
+            old_block.number,
+            BlockParams {
+                parent: Some(old_block.parent_hash),
+                tx_count: Some(0),
+                ..Default::default()
+            },
+        );
+        let executed = |block: &SealedBlock<Block>| ExecutedBlock {
+            recovered_block: Arc::new(RecoveredBlock::new_sealed(block.clone(), vec![])),
+            ..Default::default()
+        };
+        provider.canonical_in_memory_state.update_chain(NewCanonicalChain::Reorg {
+            new: vec![executed(&new_block)],
+            old: vec![executed(old_block)],
+        });
+
+        assert_eq!(provider.canonical_hash(old_block.number)?, Some(new_block.hash()));
+        assert_eq!(provider.canonical_hash(database_block.number)?, Some(database_block.hash()));
+
+        let canonical_id = |hash| BlockId::Hash(RpcBlockHash::from_hash(hash, Some(true)));
+        assert_eq!(provider.block_by_id(canonical_id(old_block.hash()))?, None);
+        assert_eq!(provider.receipts_by_block_id(canonical_id(old_block.hash()))?, None);
+        assert_eq!(
+            provider.block_by_id(canonical_id(new_block.hash()))?,
+            Some(new_block.into_block())
+        );
+        assert_eq!(
+            provider.block_by_id(canonical_id(database_block.hash()))?,
+            Some(database_block.clone().into_block())
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_header_provider() -> eyre::Result<()> {
         let mut rng = generators::rng();
@@ -1297,6 +1417,46 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_header_provider_canonical_sealed_headers_iter() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (provider, database_blocks, in_memory_blocks, _) = provider_with_random_blocks(
+            &mut rng,
+            TEST_BLOCKS_COUNT,
+            TEST_BLOCKS_COUNT,
+            BlockRangeParams::default(),
+        )?;
+
+        // range spans both database and in-memory blocks
+        let expected = [database_blocks, in_memory_blocks]
+            .concat()
+            .iter()
+            .filter(|block| (3..=7).contains(&block.number))
+            .map(|block| block.clone_sealed_header())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            provider.canonical_sealed_headers_iter(3..=7)?.collect::<Result<Vec<_>, _>>()?,
+            expected
+        );
+        assert_eq!(
+            provider
+                .consistent_provider()?
+                .canonical_sealed_headers_iter(3..=7)?
+                .collect::<Result<Vec<_>, _>>()?,
+            expected
+        );
+
+        // range extends beyond the canonical tip
+        assert_matches!(
+            provider.canonical_sealed_headers_iter(8..=10).err(),
+            Some(ProviderError::HeaderNotFound(block_number))
+                if block_number.as_number() == Some(10)
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_canon_state_subscriptions() -> eyre::Result<()> {
         let factory = create_test_provider_factory();
@@ -1605,6 +1765,48 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_receipt_provider_receipts_by_tx_hashes() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (provider, database_blocks, in_memory_blocks, receipts) = provider_with_random_blocks(
+            &mut rng,
+            TEST_BLOCKS_COUNT,
+            TEST_BLOCKS_COUNT,
+            BlockRangeParams { tx_count: 1..3, ..Default::default() },
+        )?;
+
+        let database_block = database_blocks.first().unwrap().clone();
+        let in_memory_block = in_memory_blocks.last().unwrap().clone();
+        let database_tx_index = database_block.body().transactions.len() - 1;
+        let in_memory_tx_index = in_memory_block.body().transactions.len() - 1;
+
+        // Mix in-memory, unknown and database transactions to check the result order
+        let hashes = vec![
+            *in_memory_block.body().transactions[in_memory_tx_index].tx_hash(),
+            B256::with_last_byte(0xff),
+            *database_block.body().transactions[database_tx_index].tx_hash(),
+        ];
+
+        assert_eq!(
+            provider.receipts_by_tx_hashes(hashes)?,
+            vec![
+                Some((
+                    receipts[in_memory_block.number as usize][in_memory_tx_index].clone(),
+                    in_memory_block.number,
+                    in_memory_tx_index as u64
+                )),
+                None,
+                Some((
+                    receipts[database_block.number as usize][database_tx_index].clone(),
+                    database_block.number,
+                    database_tx_index as u64
+                )),
+            ]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_changeset_reader() -> eyre::Result<()> {
         let mut rng = generators::rng();
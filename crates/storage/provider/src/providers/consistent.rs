@@ -1,10 +1,12 @@
 use super::{DatabaseProviderRO, ProviderFactory, ProviderNodeTypes};
 use crate::{
+    changesets_utils::overlay_storage_page,
     providers::{StaticFileProvider, StaticFileProviderRWRefMut},
     AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
     BlockSource, ChainSpecProvider, ChangeSetReader, HeaderProvider, ProviderError,
     PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt, StageCheckpointReader,
-    StateReader, StaticFileProviderFactory, TransactionVariant, TransactionsProvider, TrieReader,
+    StateReader, StaticFileProviderFactory, StorageReader, TransactionVariant,
+    TransactionsProvider, TrieReader,
 };
 use alloy_consensus::{transaction::TransactionMeta, BlockHeader};
 use alloy_eips::{
@@ -25,13 +27,14 @@ use reth_prune_types::{PruneCheckpoint, PruneSegment};
 use reth_stages_types::{StageCheckpoint, StageId};
 use reth_static_file_types::StaticFileSegment;
 use reth_storage_api::{
-    BlockBodyIndicesProvider, DatabaseProviderFactory, NodePrimitivesProvider, StateProvider,
-    StorageChangeSetReader, TryIntoHistoricalStateProvider,
+    BlockBodyIndicesProvider, DatabaseProviderFactory, NodePrimitivesProvider, SealedHeadersIter,
+    StateProvider, StorageChangeSetReader, TryIntoHistoricalStateProvider,
 };
 use reth_storage_errors::provider::ProviderResult;
 use reth_trie::updates::TrieUpdatesSorted;
 use revm_database::states::PlainStorageRevert;
 use std::{
+    collections::BTreeMap,
     ops::{Add, Bound, RangeBounds, RangeInclusive, Sub},
     sync::Arc,
 };
@@ -214,6 +217,66 @@ impl<N: ProviderNodeTypes> ConsistentProvider<N> {
         )))
     }
 
+    /// Returns the non-zero storage slots of the account at the given block, ordered by slot.
+    ///
+    /// See [`StorageReader::account_storage_at`], this additionally covers blocks that are not
+    /// persisted yet by applying the storage changes of the in-memory blocks on top of the
+    /// persisted state.
+    pub fn account_storage_at(
+        &self,
+        address: Address,
+        block_id: BlockId,
+        start_key: B256,
+        limit: usize,
+    ) -> ProviderResult<Vec<StorageEntry>> {
+        let block_number = self.block_number_for_id(block_id)?.ok_or(match block_id {
+            BlockId::Hash(hash) => ProviderError::BlockHashNotFound(hash.block_hash),
+            BlockId::Number(BlockNumberOrTag::Safe) => ProviderError::SafeBlockNotFound,
+            BlockId::Number(BlockNumberOrTag::Finalized) => ProviderError::FinalizedBlockNotFound,
+            BlockId::Number(_) => ProviderError::BestBlockNotFound,
+        })?;
+
+        let Some(block_state) =
+            self.head_block.as_ref().and_then(|b| b.block_on_chain(block_number.into()))
+        else {
+            return self.storage_provider.account_storage_at(address, block_number, start_key, limit)
+        };
+
+        // the storage changes of the in-memory blocks up to the requested one, oldest first
+        let mut changes = BTreeMap::new();
+        let mut wiped = false;
+        let chain = block_state.chain().collect::<Vec<_>>();
+        for state in chain.into_iter().rev() {
+            let Some(account) = state.block_ref().execution_output.bundle.account(&address) else {
+                continue
+            };
+            if account.was_destroyed() {
+                changes.clear();
+                wiped = true;
+            }
+            changes.extend(
+                account
+                    .storage
+                    .iter()
+                    .map(|(slot, value)| (B256::from(*slot), value.present_value)),
+            );
+        }
+        let changes = changes.range(start_key..);
+
+        let persisted = if wiped {
+            Vec::new()
+        } else {
+            self.storage_provider.account_storage_at(
+                address,
+                block_state.anchor().number,
+                start_key,
+                limit.saturating_add(changes.clone().count()),
+            )?
+        };
+
+        Ok(overlay_storage_page(persisted, changes, limit))
+    }
+
     /// Populate a [`BundleStateInit`] and [`RevertsInit`] using cursors over the
     /// [`reth_db::PlainAccountState`] and [`reth_db::PlainStorageState`] tables, based on the given
     /// storage and account changesets.
@@ -633,6 +696,15 @@ impl<N: ProviderNodeTypes> ConsistentProvider<N> {
             Ok(())
         }
     }
+
+    /// Returns the number of the block with the given hash if it is canonical.
+    ///
+    /// This only looks up the block number and the canonical hash at that height, so that
+    /// `requireCanonical` checks don't need to load full headers or blocks.
+    fn canonical_block_number(&self, hash: BlockHash) -> ProviderResult<Option<BlockNumber>> {
+        let Some(number) = self.block_number(hash)? else { return Ok(None) };
+        Ok((self.canonical_hash(number)? == Some(hash)).then_some(number))
+    }
 }
 
 impl<N: ProviderNodeTypes> NodePrimitivesProvider for ConsistentProvider<N> {
@@ -722,6 +794,36 @@ impl<N: ProviderNodeTypes> HeaderProvider for ConsistentProvider<N> {
             predicate,
         )
     }
+
+    fn canonical_sealed_headers_iter(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<SealedHeadersIter<'_, Self::Header>> {
+        if range.is_empty() || *range.end() > self.best_block_number()? {
+            return Err(ProviderError::HeaderNotFound((*range.end()).into()))
+        }
+
+        // Database will for a time overlap with in-memory-chain blocks, so the in-memory blocks
+        // take priority from the lowest one onwards.
+        //
+        // The last block of the in-memory chain is the lowest block number.
+        let lowest_memory_block =
+            self.head_block.as_ref().and_then(|b| b.chain().last()).map(|b| b.number());
+        let in_memory_start = lowest_memory_block
+            .map_or(*range.end() + 1, |lowest| lowest.clamp(*range.start(), *range.end() + 1));
+
+        let storage_headers = (*range.start() < in_memory_start)
+            .then(|| {
+                self.storage_provider
+                    .canonical_sealed_headers_iter(*range.start()..=in_memory_start - 1)
+            })
+            .transpose()?;
+        let in_memory_headers = (in_memory_start..=*range.end()).map(move |number| {
+            self.sealed_header(number)?.ok_or_else(|| ProviderError::HeaderNotFound(number.into()))
+        });
+
+        Ok(Box::new(storage_headers.into_iter().flatten().chain(in_memory_headers)))
+    }
 }
 
 impl<N: ProviderNodeTypes> BlockHashReader for ConsistentProvider<N> {
@@ -1105,15 +1207,58 @@ impl<N: ProviderNodeTypes> ReceiptProvider for ConsistentProvider<N> {
     ) -> ProviderResult<Vec<Vec<Self::Receipt>>> {
         self.storage_provider.receipts_by_block_range(block_range)
     }
+
+    fn receipts_by_tx_hashes(
+        &self,
+        hashes: Vec<TxHash>,
+    ) -> ProviderResult<Vec<Option<(Self::Receipt, BlockNumber, u64)>>> {
+        let mut receipts = Vec::with_capacity(hashes.len());
+        let mut missing = Vec::new();
+
+        'hashes: for hash in hashes {
+            for block_state in self.head_block.iter().flat_map(|b| b.chain()) {
+                let block = block_state.block_ref().recovered_block();
+                if let Some(tx_index) =
+                    block.body().transactions_iter().position(|tx| tx.trie_hash() == hash)
+                {
+                    // safe to use tx_index for receipts due to 1:1 correspondence
+                    let receipt = block_state.executed_block_receipts().get(tx_index).cloned();
+                    receipts.push(
+                        receipt.map(|receipt| (receipt, block.number(), tx_index as u64)),
+                    );
+                    continue 'hashes
+                }
+            }
+
+            missing.push((receipts.len(), hash));
+            receipts.push(None);
+        }
+
+        if !missing.is_empty() {
+            let (indices, missing_hashes): (Vec<_>, Vec<_>) = missing.into_iter().unzip();
+            let stored = self.storage_provider.receipts_by_tx_hashes(missing_hashes)?;
+            for (index, receipt) in indices.into_iter().zip(stored) {
+                receipts[index] = receipt;
+            }
+        }
+
+        Ok(receipts)
+    }
 }
 
 impl<N: ProviderNodeTypes> ReceiptProviderIdExt for ConsistentProvider<N> {
     fn receipts_by_block_id(&self, block: BlockId) -> ProviderResult<Option<Vec<Self::Receipt>>> {
         match block {
             BlockId::Hash(rpc_block_hash) => {
+                if rpc_block_hash.require_canonical.unwrap_or(false) {
+                    return match self.canonical_block_number(rpc_block_hash.block_hash)? {
+                        Some(number) => self.receipts_by_block(number.into()),
+                        None => Ok(None),
+                    }
+                }
+
                 let mut receipts = self.receipts_by_block(rpc_block_hash.block_hash.into())?;
                 if receipts.is_none() &&
-                    !rpc_block_hash.require_canonical.unwrap_or(false) &&
                     let Some(state) = self
                         .head_block
                         .as_ref()
@@ -1229,8 +1374,11 @@ impl<N: ProviderNodeTypes> BlockReaderIdExt for ConsistentProvider<N> {
                 // if we decide to do this at a higher level, then we can make this an automatic
                 // trait impl
                 if Some(true) == hash.require_canonical {
-                    // check the database, canonical blocks are only stored in the database
-                    self.find_block_by_hash(hash.block_hash, BlockSource::Canonical)
+                    // only load the block if the hash is canonical
+                    match self.canonical_block_number(hash.block_hash)? {
+                        Some(number) => self.block(number.into()),
+                        None => Ok(None),
+                    }
                 } else {
                     self.block_by_hash(hash.block_hash)
                 }
@@ -1494,16 +1642,19 @@ mod tests {
         providers::blockchain_provider::BlockchainProvider,
         test_utils::create_test_provider_factory, BlockWriter,
     };
-    use alloy_eips::BlockHashOrNumber;
-    use alloy_primitives::B256;
+    use alloy_eips::{BlockHashOrNumber, BlockId};
+    use alloy_primitives::{Address, B256, U256};
     use itertools::Itertools;
     use rand::Rng;
     use reth_chain_state::{ExecutedBlock, NewCanonicalChain};
-    use reth_db_api::models::AccountBeforeTx;
+    use reth_db_api::{models::AccountBeforeTx, tables, transaction::DbTxMut};
     use reth_ethereum_primitives::Block;
     use reth_execution_types::ExecutionOutcome;
-    use reth_primitives_traits::{RecoveredBlock, SealedBlock};
-    use reth_storage_api::{BlockReader, BlockSource, ChangeSetReader};
+    use reth_primitives_traits::{RecoveredBlock, SealedBlock, StorageEntry};
+    use reth_stages_types::{StageCheckpoint, StageId};
+    use reth_storage_api::{
+        BlockReader, BlockSource, ChangeSetReader, DBProvider, StageCheckpointWriter,
+    };
     use reth_testing_utils::generators::{
         self, random_block_range, random_changeset_range, random_eoa_accounts, BlockRangeParams,
     };
@@ -1857,4 +2008,102 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_account_storage_at() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (database_blocks, in_memory_blocks) = random_blocks(&mut rng, 2, 2, None, None, 0..1);
+
+        let address = Address::with_last_byte(1);
+        let slot = |n: u64| B256::from(U256::from(n));
+        let entry = |n, value: u64| StorageEntry { key: slot(n), value: U256::from(value) };
+
+        // the persisted state has slot 1 set to 1 and slot 2 set to 2
+        let factory = create_test_provider_factory();
+        let provider_rw = factory.provider_rw()?;
+        for block in &database_blocks {
+            provider_rw.insert_block(
+                block.clone().try_recover().expect("failed to seal block with senders"),
+            )?;
+        }
+        for entry in [entry(1, 1), entry(2, 2)] {
+            provider_rw.tx_ref().put::<tables::PlainStorageState>(address, entry)?;
+        }
+        let last_database_block = database_blocks.last().unwrap().number;
+        provider_rw
+            .save_stage_checkpoint(StageId::Finish, StageCheckpoint::new(last_database_block))?;
+        provider_rw.commit()?;
+
+        let provider = BlockchainProvider::new(factory)?;
+
+        // the first in-memory block sets slot 1 to 10 and slot 3 to 30, the second one clears
+        // slot 2
+        let change = |n: u64, original: u64, present: u64| {
+            (U256::from(n), (U256::from(original), U256::from(present)))
+        };
+        let storage_changes = [vec![change(1, 1, 10), change(3, 0, 30)], vec![change(2, 2, 0)]];
+        let chain = NewCanonicalChain::Commit {
+            new: in_memory_blocks
+                .iter()
+                .zip(storage_changes)
+                .map(|(block, storage)| {
+                    let senders = block.senders().expect("failed to recover senders");
+                    ExecutedBlock {
+                        recovered_block: Arc::new(RecoveredBlock::new_sealed(
+                            block.clone(),
+                            senders,
+                        )),
+                        execution_output: Arc::new(ExecutionOutcome {
+                            bundle: BundleState::new(
+                                [(
+                                    address,
+                                    None,
+                                    Some(Default::default()),
+                                    storage.into_iter().collect(),
+                                )],
+                                [],
+                                [],
+                            ),
+                            first_block: block.number,
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }
+                })
+                .collect(),
+        };
+        provider.canonical_in_memory_state.update_chain(chain);
+
+        let consistent_provider = provider.consistent_provider()?;
+        let storage_at = |block: u64, start_key, limit| {
+            consistent_provider.account_storage_at(
+                address,
+                BlockId::number(block),
+                start_key,
+                limit,
+            )
+        };
+
+        let first_in_memory_block = in_memory_blocks[0].number;
+        let last_in_memory_block = in_memory_blocks[1].number;
+        assert_eq!(
+            storage_at(last_database_block, B256::ZERO, 10)?,
+            vec![entry(1, 1), entry(2, 2)]
+        );
+        assert_eq!(
+            storage_at(first_in_memory_block, B256::ZERO, 10)?,
+            vec![entry(1, 10), entry(2, 2), entry(3, 30)]
+        );
+        assert_eq!(
+            storage_at(last_in_memory_block, B256::ZERO, 10)?,
+            vec![entry(1, 10), entry(3, 30)]
+        );
+
+        // paginated
+        assert_eq!(storage_at(last_in_memory_block, B256::ZERO, 1)?, vec![entry(1, 10)]);
+        assert_eq!(storage_at(last_in_memory_block, slot(2), 1)?, vec![entry(3, 30)]);
+        assert_eq!(storage_at(first_in_memory_block, slot(2), 1)?, vec![entry(2, 2)]);
+
+        Ok(())
+    }
 }
@@ -28,8 +28,8 @@ use reth_prune_types::{PruneCheckpoint, PruneModes, PruneSegment};
 use reth_stages_types::{StageCheckpoint, StageId};
 use reth_static_file_types::StaticFileSegment;
 use reth_storage_api::{
-    BlockBodyIndicesProvider, NodePrimitivesProvider, StorageSettings, StorageSettingsCache,
-    TryIntoHistoricalStateProvider,
+    BlockBodyIndicesProvider, NodePrimitivesProvider, SealedHeadersIter, StorageSettings,
+    StorageSettingsCache, TryIntoHistoricalStateProvider,
 };
 use reth_storage_errors::provider::ProviderResult;
 use reth_trie::HashedPostState;
@@ -310,6 +310,17 @@ impl<N: ProviderNodeTypes> HeaderProvider for ProviderFactory<N> {
     ) -> ProviderResult<Vec<SealedHeader<Self::Header>>> {
         self.static_file_provider.sealed_headers_while(range, predicate)
     }
+
+    fn canonical_sealed_headers_iter(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<SealedHeadersIter<'_, Self::Header>> {
+        if range.is_empty() || *range.end() > self.last_block_number()? {
+            return Err(ProviderError::HeaderNotFound((*range.end()).into()))
+        }
+
+        Ok(Box::new(self.static_file_provider.sealed_headers_iter(range)?))
+    }
 }
 
 impl<N: ProviderNodeTypes> BlockHashReader for ProviderFactory<N> {
@@ -526,6 +537,13 @@ impl<N: ProviderNodeTypes> ReceiptProvider for ProviderFactory<N> {
     ) -> ProviderResult<Vec<Vec<Self::Receipt>>> {
         self.provider()?.receipts_by_block_range(block_range)
     }
+
+    fn receipts_by_tx_hashes(
+        &self,
+        hashes: Vec<TxHash>,
+    ) -> ProviderResult<Vec<Option<(Self::Receipt, BlockNumber, u64)>>> {
+        self.provider()?.receipts_by_tx_hashes(hashes)
+    }
 }
 
 impl<N: ProviderNodeTypes> BlockBodyIndicesProvider for ProviderFactory<N> {
@@ -641,7 +659,9 @@ mod tests {
     use reth_primitives_traits::SignerRecoverable;
     use reth_prune_types::{PruneMode, PruneModes};
     use reth_storage_errors::provider::ProviderError;
-    use reth_testing_utils::generators::{self, random_block, random_header, BlockParams};
+    use reth_testing_utils::generators::{
+        self, random_block, random_header, random_header_range, BlockParams,
+    };
     use std::{ops::RangeInclusive, sync::Arc};
 
     #[test]
@@ -783,4 +803,35 @@ mod tests {
 
         assert_eq!(local_head, head);
     }
+
+    #[test]
+    fn canonical_sealed_headers_iter() {
+        let factory = create_test_provider_factory();
+        let mut rng = generators::rng();
+        let headers = random_header_range(&mut rng, 0..5, B256::ZERO);
+
+        let static_file_provider = factory.static_file_provider();
+        let mut static_file_writer =
+            static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+        for header in &headers {
+            static_file_writer.append_header(header.header(), &header.hash()).unwrap();
+        }
+        static_file_writer.commit().unwrap();
+        drop(static_file_writer);
+
+        let provider = factory.provider().unwrap();
+        let iter_headers = provider
+            .canonical_sealed_headers_iter(1..=3)
+            .unwrap()
+            .collect::<ProviderResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(iter_headers, headers[1..=3]);
+
+        // range extends beyond the canonical tip
+        assert_matches!(
+            provider.canonical_sealed_headers_iter(3..=5).err(),
+            Some(ProviderError::HeaderNotFound(block_number))
+                if block_number.as_number() == Some(5)
+        );
+    }
 }
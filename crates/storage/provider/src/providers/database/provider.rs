@@ -1,6 +1,7 @@
 use crate::{
     changesets_utils::{
-        storage_trie_wiped_changeset_iter, StorageRevertsIter, StorageTrieCurrentValuesIter,
+        overlay_storage_page, storage_trie_wiped_changeset_iter, StorageRevertsIter,
+        StorageTrieCurrentValuesIter,
     },
     providers::{
         database::{chain::ChainStorage, metrics},
@@ -29,7 +30,7 @@ use alloy_eips::BlockHashOrNumber;
 use alloy_primitives::{
     keccak256,
     map::{hash_map, B256Map, HashMap, HashSet},
-    Address, BlockHash, BlockNumber, TxHash, TxNumber, B256,
+    Address, BlockHash, BlockNumber, TxHash, TxNumber, B256, U256,
 };
 use itertools::Itertools;
 use parking_lot::RwLock;
@@ -60,8 +61,8 @@ use reth_stages_types::{StageCheckpoint, StageId};
 use reth_static_file_types::StaticFileSegment;
 use reth_storage_api::{
     BlockBodyIndicesProvider, BlockBodyReader, MetadataProvider, MetadataWriter,
-    NodePrimitivesProvider, StateProvider, StorageChangeSetReader, StorageSettingsCache,
-    TryIntoHistoricalStateProvider,
+    NodePrimitivesProvider, SealedHeadersIter, StateProvider, StorageChangeSetReader,
+    StorageSettingsCache, TryIntoHistoricalStateProvider,
 };
 use reth_storage_errors::provider::ProviderResult;
 use reth_trie::{
@@ -1024,6 +1025,17 @@ impl<TX: DbTx + 'static, N: NodeTypesForProvider> HeaderProvider for DatabasePro
     ) -> ProviderResult<Vec<SealedHeader<Self::Header>>> {
         self.static_file_provider.sealed_headers_while(range, predicate)
     }
+
+    fn canonical_sealed_headers_iter(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<SealedHeadersIter<'_, Self::Header>> {
+        if range.is_empty() || *range.end() > self.last_block_number()? {
+            return Err(ProviderError::HeaderNotFound((*range.end()).into()))
+        }
+
+        Ok(Box::new(self.static_file_provider.sealed_headers_iter(range)?))
+    }
 }
 
 impl<TX: DbTx + 'static, N: NodeTypes> BlockHashReader for DatabaseProvider<TX, N> {
@@ -1458,6 +1470,37 @@ impl<TX: DbTx + 'static, N: NodeTypesForProvider> ReceiptProvider for DatabasePr
 
         Ok(result)
     }
+
+    fn receipts_by_tx_hashes(
+        &self,
+        hashes: Vec<TxHash>,
+    ) -> ProviderResult<Vec<Option<(Self::Receipt, BlockNumber, u64)>>> {
+        let mut hash_cursor = self.tx.cursor_read::<tables::TransactionHashNumbers>()?;
+        let mut block_cursor = self.tx.cursor_read::<tables::TransactionBlocks>()?;
+
+        let mut receipts = Vec::with_capacity(hashes.len());
+        for hash in hashes {
+            let Some((_, tx_num)) = hash_cursor.seek_exact(hash)? else {
+                receipts.push(None);
+                continue
+            };
+            let Some((_, block_number)) = block_cursor.seek(tx_num)? else {
+                receipts.push(None);
+                continue
+            };
+            let Some(body) = self.block_body_indices(block_number)? else {
+                receipts.push(None);
+                continue
+            };
+            let receipt = self.receipt(tx_num)?;
+            receipts.push(receipt.map(|receipt| {
+                // `tx_num` is always `>=` the block's first transaction number
+                (receipt, block_number, tx_num - body.first_tx_num())
+            }));
+        }
+
+        Ok(receipts)
+    }
 }
 
 impl<TX: DbTx + 'static, N: NodeTypesForProvider> BlockBodyIndicesProvider
@@ -1600,6 +1643,73 @@ impl<TX: DbTx + 'static, N: NodeTypes> StorageReader for DatabaseProvider<TX, N>
 
         Ok(storage_changeset_lists)
     }
+
+    fn account_storage_at(
+        &self,
+        address: Address,
+        block_number: BlockNumber,
+        start_key: B256,
+        limit: usize,
+    ) -> ProviderResult<Vec<StorageEntry>> {
+        let best_block = self.best_block_number()?;
+        if block_number > best_block {
+            return Err(ProviderError::StateForNumberNotFound(block_number))
+        }
+        // the changesets of all blocks after the requested one are required
+        if self
+            .get_prune_checkpoint(PruneSegment::StorageHistory)?
+            .and_then(|checkpoint| checkpoint.block_number)
+            .is_some_and(|pruned| block_number < pruned)
+        {
+            return Err(ProviderError::StateAtBlockPruned(block_number))
+        }
+
+        // values of the slots changed after the block, as of the block: the changeset of the
+        // first block after it that changed a slot holds the value of the slot at the block
+        let mut reverted = BTreeMap::new();
+        let mut history = self.tx.cursor_read::<tables::StoragesHistory>()?;
+        let mut changesets = self.tx.cursor_dup_read::<tables::StorageChangeSets>()?;
+        let mut slot = start_key;
+        while let Some((key, chunk)) =
+            history.seek(StorageShardedKey::new(address, slot, block_number + 1))?
+        {
+            if key.address != address {
+                break
+            }
+            if key.sharded_key.key != slot {
+                // the next changed slot, seek to its first chunk with changes after the block
+                slot = key.sharded_key.key;
+                if key.sharded_key.highest_block_number <= block_number {
+                    continue
+                }
+            }
+
+            if let Some(changed) = chunk.select(chunk.rank(block_number)) {
+                let value = changesets
+                    .seek_by_key_subkey(BlockNumberAddress((changed, address)), slot)?
+                    .filter(|entry| entry.key == slot)
+                    .ok_or_else(|| ProviderError::StorageChangesetNotFound {
+                        block_number: changed,
+                        address,
+                        storage_key: Box::new(slot),
+                    })?
+                    .value;
+                reverted.insert(slot, value);
+            }
+
+            let Some(next) = U256::from_be_bytes(slot.0).checked_add(U256::from(1)) else { break };
+            slot = next.into();
+        }
+
+        let mut plain_storage = self.tx.cursor_dup_read::<tables::PlainStorageState>()?;
+        let plain = plain_storage
+            .walk_dup(Some(address), Some(start_key))?
+            .take(limit.saturating_add(reverted.len()))
+            .map(|entry| entry.map(|(_, entry)| entry))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(overlay_storage_page(plain, &reverted, limit))
+    }
 }
 
 impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> StateWriter
@@ -3212,7 +3322,7 @@ mod tests {
         BlockWriter,
     };
     use reth_ethereum_primitives::Receipt;
-    use reth_testing_utils::generators::{self, random_block, BlockParams};
+    use reth_testing_utils::generators::{self, random_block, random_receipt, BlockParams};
     use reth_trie::Nibbles;
 
     #[test]
@@ -3422,6 +3532,69 @@ mod tests {
         assert_eq!(range_result, individual_results);
     }
 
+    #[test]
+    fn test_receipts_by_tx_hashes() {
+        let factory = create_test_provider_factory();
+        let mut rng = generators::rng();
+
+        let blocks = (0..3)
+            .map(|i| {
+                random_block(&mut rng, i, BlockParams { tx_count: Some(2), ..Default::default() })
+            })
+            .collect::<Vec<_>>();
+        let receipts = blocks
+            .iter()
+            .map(|block| {
+                block
+                    .body()
+                    .transactions
+                    .iter()
+                    .map(|tx| random_receipt(&mut rng, tx, Some(1), None))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let provider_rw = factory.provider_rw().unwrap();
+        for block in &blocks {
+            provider_rw.insert_block(block.clone().try_recover().unwrap()).unwrap();
+        }
+        provider_rw
+            .write_state(
+                &ExecutionOutcome {
+                    first_block: 0,
+                    receipts: receipts.clone(),
+                    ..Default::default()
+                },
+                crate::OriginalValuesKnown::No,
+            )
+            .unwrap();
+        provider_rw.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+
+        let tx_hash =
+            |block: usize, index: usize| *blocks[block].body().transactions[index].tx_hash();
+        let result = provider
+            .receipts_by_tx_hashes(vec![
+                tx_hash(2, 1),
+                B256::random(),
+                tx_hash(0, 0),
+                tx_hash(1, 1),
+            ])
+            .unwrap();
+
+        // results are returned in request order, missing hashes yield `None`
+        assert_eq!(
+            result,
+            vec![
+                Some((receipts[2][1].clone(), 2, 1)),
+                None,
+                Some((receipts[0][0].clone(), 0, 0)),
+                Some((receipts[1][1].clone(), 1, 1)),
+            ]
+        );
+    }
+
     #[test]
     fn test_write_trie_changesets() {
         use reth_db_api::models::BlockNumberHashedAddress;
@@ -4827,4 +5000,72 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_account_storage_at() {
+        let factory = create_test_provider_factory();
+        let provider_rw = factory.provider_rw().unwrap();
+
+        let contract = Address::with_last_byte(1);
+        let other = Address::with_last_byte(2);
+        let slot = |n: u64| B256::from(U256::from(n));
+        let entry = |n, value: u64| StorageEntry { key: slot(n), value: U256::from(value) };
+
+        // block 1 sets slot 1 to 10 and slot 2 to 20
+        // block 2 sets slot 1 to 11, clears slot 2 and sets slot 3 to 30
+        let tx = provider_rw.tx_ref();
+        for (address, entry) in
+            [(contract, entry(1, 11)), (contract, entry(3, 30)), (other, entry(1, 1))]
+        {
+            tx.put::<tables::PlainStorageState>(address, entry).unwrap();
+        }
+        for (block, entry) in [
+            (1, entry(1, 0)),
+            (1, entry(2, 0)),
+            (2, entry(1, 10)),
+            (2, entry(2, 20)),
+            (2, entry(3, 0)),
+        ] {
+            tx.put::<tables::StorageChangeSets>(BlockNumberAddress((block, contract)), entry)
+                .unwrap();
+        }
+        tx.put::<tables::StorageChangeSets>(BlockNumberAddress((2, other)), entry(1, 0)).unwrap();
+        // the changes of slot 1 are split into two chunks
+        for (address, n, highest_block_number, blocks) in [
+            (contract, 1, 1, vec![1]),
+            (contract, 1, u64::MAX, vec![2]),
+            (contract, 2, u64::MAX, vec![1, 2]),
+            (contract, 3, u64::MAX, vec![2]),
+            (other, 1, u64::MAX, vec![2]),
+        ] {
+            tx.put::<tables::StoragesHistory>(
+                StorageShardedKey::new(address, slot(n), highest_block_number),
+                BlockNumberList::new_pre_sorted(blocks),
+            )
+            .unwrap();
+        }
+        provider_rw.save_stage_checkpoint(StageId::Finish, StageCheckpoint::new(2)).unwrap();
+        provider_rw.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        let storage_at = |block, start_key, limit| {
+            provider.account_storage_at(contract, block, start_key, limit).unwrap()
+        };
+
+        assert_eq!(storage_at(2, B256::ZERO, 10), vec![entry(1, 11), entry(3, 30)]);
+        assert_eq!(storage_at(1, B256::ZERO, 10), vec![entry(1, 10), entry(2, 20)]);
+        assert_eq!(storage_at(0, B256::ZERO, 10), vec![]);
+
+        // paginated
+        assert_eq!(storage_at(1, B256::ZERO, 1), vec![entry(1, 10)]);
+        assert_eq!(storage_at(1, slot(2), 1), vec![entry(2, 20)]);
+        assert_eq!(storage_at(1, slot(3), 1), vec![]);
+        assert_eq!(storage_at(2, slot(2), 10), vec![entry(3, 30)]);
+        assert_eq!(storage_at(0, slot(2), 10), vec![]);
+
+        assert!(matches!(
+            provider.account_storage_at(contract, 3, B256::ZERO, 10),
+            Err(ProviderError::StateForNumberNotFound(3))
+        ));
+    }
 }
@@ -0,0 +1,135 @@
+//! Cumulative issuance backed by periodic checkpoints.
+
+use alloy_primitives::BlockNumber;
+use parking_lot::RwLock;
+use reth_chainspec::{ChainSpecProvider, EthChainSpec, EthereumHardforks};
+use reth_storage_api::{range_issuance, BlockReader, Issuance, IssuanceProvider};
+use reth_storage_errors::provider::ProviderResult;
+use std::collections::BTreeMap;
+
+/// Default number of blocks between two issuance checkpoints.
+pub const ISSUANCE_CHECKPOINT_INTERVAL: u64 = 100_000;
+
+/// An [`IssuanceProvider`] that remembers the cumulative issuance at periodic checkpoints.
+///
+/// The cumulative issuance is only summed up from the closest checkpoint below the requested
+/// block, so once the checkpoints are populated a query reads at most one interval of headers.
+/// Checkpoints are only recorded for blocks at least one interval below the best block, which are
+/// not expected to be reorged.
+#[derive(Debug)]
+pub struct IssuanceCheckpoints<P> {
+    /// The provider the headers are read from.
+    provider: P,
+    /// Number of blocks between two checkpoints.
+    interval: u64,
+    /// Cumulative issuance of all blocks up to and including the checkpoint block.
+    checkpoints: RwLock<BTreeMap<BlockNumber, Issuance>>,
+}
+
+impl<P> IssuanceCheckpoints<P> {
+    /// Creates a new instance with the default [`ISSUANCE_CHECKPOINT_INTERVAL`].
+    pub fn new(provider: P) -> Self {
+        Self::with_interval(provider, ISSUANCE_CHECKPOINT_INTERVAL)
+    }
+
+    /// Creates a new instance that records a checkpoint every `interval` blocks.
+    ///
+    /// # Panics
+    ///
+    /// If the interval is zero.
+    pub fn with_interval(provider: P, interval: u64) -> Self {
+        assert!(interval > 0, "issuance checkpoint interval must be non-zero");
+        Self { provider, interval, checkpoints: Default::default() }
+    }
+
+    /// Returns the wrapped provider.
+    pub const fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    /// Returns the number of recorded checkpoints.
+    pub fn checkpoints_len(&self) -> usize {
+        self.checkpoints.read().len()
+    }
+}
+
+impl<P> IssuanceProvider for IssuanceCheckpoints<P>
+where
+    P: BlockReader + ChainSpecProvider<ChainSpec: EthChainSpec + EthereumHardforks>,
+{
+    fn cumulative_issuance(&self, block: BlockNumber) -> ProviderResult<Issuance> {
+        let (mut issuance, mut start) = self
+            .checkpoints
+            .read()
+            .range(..=block)
+            .next_back()
+            .map(|(number, issuance)| (*issuance, number + 1))
+            .unwrap_or_default();
+
+        let stable = self.provider.best_block_number()?.saturating_sub(self.interval);
+        while start <= block {
+            // checkpoints are recorded at the last block of every interval
+            let checkpoint = (start / self.interval + 1) * self.interval - 1;
+            let end = block.min(checkpoint);
+            issuance += range_issuance(&self.provider, start..=end)?;
+
+            if end == checkpoint && end <= stable {
+                self.checkpoints.write().insert(end, issuance);
+            }
+
+            let Some(next) = end.checked_add(1) else { break };
+            start = next;
+        }
+
+        Ok(issuance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockEthProvider;
+    use alloy_consensus::{constants::ETH_TO_WEI, Header};
+    use alloy_primitives::{Bytes, U256};
+    use reth_ethereum_primitives::{Block, BlockBody, EthPrimitives};
+
+    #[test]
+    fn cumulative_issuance_from_checkpoints() {
+        let provider = MockEthProvider::<EthPrimitives>::new();
+        let ommer =
+            Header { number: 1, extra_data: Bytes::from_static(b"ommer"), ..Default::default() };
+        let body = BlockBody { ommers: vec![ommer], ..Default::default() };
+        for number in 0..=5 {
+            let block = if number == 2 {
+                let ommers_hash = body.calculate_ommers_root();
+                Block::new(Header { number, ommers_hash, ..Default::default() }, body.clone())
+            } else {
+                Block::new(Header { number, ..Default::default() }, Default::default())
+            };
+            provider.add_block(block.header.hash_slow(), block);
+        }
+        let issuance = IssuanceCheckpoints::with_interval(provider, 2);
+
+        let base = 5 * ETH_TO_WEI;
+        let ommer_rewards = base / 32 + base * 7 / 8;
+        assert_eq!(issuance.cumulative_issuance(1).unwrap().block_rewards, U256::from(base));
+        assert_eq!(
+            issuance.cumulative_issuance(2).unwrap().block_rewards,
+            U256::from(2 * base + ommer_rewards)
+        );
+
+        let at_tip = issuance.cumulative_issuance(5).unwrap();
+        assert_eq!(at_tip.block_rewards, U256::from(5 * base + ommer_rewards));
+        assert_eq!(at_tip.burnt_fees, U256::ZERO);
+        // blocks 1 and 3 are checkpointed, block 5 is too close to the tip
+        assert_eq!(issuance.checkpoints_len(), 2);
+
+        // summed up from the checkpoint at block 3
+        assert_eq!(
+            issuance.cumulative_issuance(4).unwrap().block_rewards,
+            U256::from(4 * base + ommer_rewards)
+        );
+
+        assert!(issuance.cumulative_issuance(6).is_err());
+    }
+}
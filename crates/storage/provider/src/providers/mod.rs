@@ -29,6 +29,9 @@ pub use blockchain_provider::BlockchainProvider;
 mod consistent;
 pub use consistent::ConsistentProvider;
 
+mod issuance;
+pub use issuance::{IssuanceCheckpoints, ISSUANCE_CHECKPOINT_INTERVAL};
+
 /// Helper trait to bound [`NodeTypes`] so that combined with database they satisfy
 /// [`ProviderNodeTypes`].
 pub trait NodeTypesForProvider
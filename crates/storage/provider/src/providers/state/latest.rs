@@ -201,10 +201,73 @@ delegate_provider_impls!(LatestStateProvider<Provider> where [Provider: DBProvid
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{test_utils::create_test_provider_factory, TrieWriter};
+    use alloy_primitives::{keccak256, map::HashMap, U256};
+    use reth_db_api::transaction::DbTxMut;
+    use reth_storage_api::DatabaseProviderFactory;
+    use reth_trie::test_utils::state_root;
+    use revm_database::{states::bundle_state::BundleRetention, State};
+    use revm_database_interface::DatabaseCommit;
+    use revm_state::{Account as RevmAccount, AccountStatus};
+    use std::collections::BTreeMap;
 
     const fn assert_state_provider<T: StateProvider>() {}
     #[expect(dead_code)]
     const fn assert_latest_state_provider<T: DBProvider + BlockHashReader>() {
         assert_state_provider::<LatestStateProvider<T>>();
     }
+
+    #[test]
+    fn bundle_state_root_is_not_persisted() {
+        let mut prestate = (0..3)
+            .map(|key| {
+                let account = Account { nonce: 1, balance: U256::from(key), bytecode_hash: None };
+                (Address::with_last_byte(key), account)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let provider_factory = create_test_provider_factory();
+        let provider_rw = provider_factory.database_provider_rw().unwrap();
+
+        let tx = provider_rw.tx_ref();
+        for (address, account) in &prestate {
+            tx.put::<tables::HashedAccounts>(keccak256(address), *account).unwrap();
+        }
+        let (prestate_root, updates) = StateRoot::from_tx(tx).root_with_updates().unwrap();
+        provider_rw.write_trie_updates(updates).unwrap();
+
+        // change the balance of account 1
+        let address1 = Address::with_last_byte(1);
+        let account1 = prestate.get_mut(&address1).unwrap();
+        let mut state = State::builder().with_bundle_update().build();
+        state.insert_account(address1, (*account1).into());
+        account1.balance = U256::from(100);
+        state.commit(HashMap::from_iter([(
+            address1,
+            RevmAccount {
+                status: AccountStatus::Touched,
+                info: (*account1).into(),
+                storage: Default::default(),
+                transaction_id: 0,
+            },
+        )]));
+        state.merge_transitions(BundleRetention::PlainState);
+
+        let state_provider = LatestStateProviderRef::new(&provider_rw);
+        assert_eq!(
+            state_provider.bundle_state_root(&state.bundle_state).unwrap(),
+            state_root(
+                prestate
+                    .into_iter()
+                    .map(|(address, account)| (address, (account, std::iter::empty())))
+            )
+        );
+
+        // the database still contains the state before the bundle was applied
+        assert_eq!(StateRoot::from_tx(tx).root().unwrap(), prestate_root);
+        assert_eq!(
+            tx.get::<tables::HashedAccounts>(keccak256(address1)).unwrap().map(|a| a.balance),
+            Some(U256::from(1))
+        );
+    }
 }
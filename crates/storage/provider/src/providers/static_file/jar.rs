@@ -337,4 +337,13 @@ impl<N: NodePrimitives<SignedTx: Decompress + SignedTransaction, Receipt: Decomp
         // provider with `receipt()` instead for each
         Err(ProviderError::UnsupportedProvider)
     }
+
+    fn receipts_by_tx_hashes(
+        &self,
+        _hashes: Vec<TxHash>,
+    ) -> ProviderResult<Vec<Option<(Self::Receipt, BlockNumber, u64)>>> {
+        // Related to indexing tables. Live database should get the tx_num and call static file
+        // provider with `receipt()` instead for each
+        Err(ProviderError::UnsupportedProvider)
+    }
 }
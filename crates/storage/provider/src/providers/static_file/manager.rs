@@ -1738,6 +1738,35 @@ impl<N: NodePrimitives> StaticFileWriter for StaticFileProvider<N> {
     }
 }
 
+impl<N: NodePrimitives<BlockHeader: Value>> StaticFileProvider<N> {
+    /// Returns an iterator over the sealed headers in the given block range.
+    ///
+    /// Unlike [`HeaderProvider::sealed_headers_range`], headers are yielded lazily and read through
+    /// the same static file provider until the range crosses into the next static file.
+    ///
+    /// Yields [`ProviderError::HeaderNotFound`] for headers that don't exist.
+    pub fn sealed_headers_iter(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<impl Iterator<Item = ProviderResult<SealedHeader<N::BlockHeader>>> + '_>
+    {
+        let numbers = range.clone();
+        let headers = self.fetch_range_iter(
+            StaticFileSegment::Headers,
+            to_range(range),
+            |cursor, number| {
+                Ok(cursor
+                    .get_two::<HeaderWithHashMask<N::BlockHeader>>(number.into())?
+                    .map(|(header, hash)| SealedHeader::new(header, hash)))
+            },
+        )?;
+
+        Ok(headers.zip(numbers).map(|(header, number)| {
+            header?.ok_or_else(|| ProviderError::HeaderNotFound(number.into()))
+        }))
+    }
+}
+
 impl<N: NodePrimitives<BlockHeader: Value>> HeaderProvider for StaticFileProvider<N> {
     type Header = N::BlockHeader;
 
@@ -1888,6 +1917,13 @@ impl<N: NodePrimitives<SignedTx: Value + SignedTransaction, Receipt: Value>> Rec
     ) -> ProviderResult<Vec<Vec<Self::Receipt>>> {
         Err(ProviderError::UnsupportedProvider)
     }
+
+    fn receipts_by_tx_hashes(
+        &self,
+        _hashes: Vec<TxHash>,
+    ) -> ProviderResult<Vec<Option<(Self::Receipt, BlockNumber, u64)>>> {
+        Err(ProviderError::UnsupportedProvider)
+    }
 }
 
 impl<N: NodePrimitives<SignedTx: Value, Receipt: Value, BlockHeader: Value>> TransactionsProviderExt
@@ -68,6 +68,12 @@ pub struct MockEthProvider<T: NodePrimitives = EthPrimitives, ChainSpec = reth_c
     pub state_roots: Arc<Mutex<Vec<B256>>>,
     /// Local block body indices store
     pub block_body_indices: Arc<Mutex<HashMap<BlockNumber, StoredBlockBodyIndices>>>,
+    /// Local transaction sender store indexed by transaction number
+    pub senders: Arc<Mutex<HashMap<TxNumber, Address>>>,
+    /// Local safe block
+    pub safe_block: Arc<Mutex<Option<alloy_eips::BlockNumHash>>>,
+    /// Local finalized block
+    pub finalized_block: Arc<Mutex<Option<alloy_eips::BlockNumHash>>>,
     tx: TxMock,
     prune_modes: Arc<PruneModes>,
 }
@@ -85,6 +91,9 @@ where
             chain_spec: self.chain_spec.clone(),
             state_roots: self.state_roots.clone(),
             block_body_indices: self.block_body_indices.clone(),
+            senders: self.senders.clone(),
+            safe_block: self.safe_block.clone(),
+            finalized_block: self.finalized_block.clone(),
             tx: self.tx.clone(),
             prune_modes: self.prune_modes.clone(),
         }
@@ -102,6 +111,9 @@ impl<T: NodePrimitives> MockEthProvider<T, reth_chainspec::ChainSpec> {
             chain_spec: Arc::new(reth_chainspec::ChainSpecBuilder::mainnet().build()),
             state_roots: Default::default(),
             block_body_indices: Default::default(),
+            senders: Default::default(),
+            safe_block: Default::default(),
+            finalized_block: Default::default(),
             tx: Default::default(),
             prune_modes: Default::default(),
         }
@@ -122,6 +134,16 @@ impl<T: NodePrimitives, ChainSpec> MockEthProvider<T, ChainSpec> {
         }
     }
 
+    /// Set the safe block
+    pub fn set_safe_block(&self, num_hash: alloy_eips::BlockNumHash) {
+        *self.safe_block.lock() = Some(num_hash);
+    }
+
+    /// Set the finalized block
+    pub fn set_finalized_block(&self, num_hash: alloy_eips::BlockNumHash) {
+        *self.finalized_block.lock() = Some(num_hash);
+    }
+
     /// Add header to local header store
     pub fn add_header(&self, hash: B256, header: <T::Block as Block>::Header) {
         self.headers.lock().insert(hash, header);
@@ -170,6 +192,11 @@ impl<T: NodePrimitives, ChainSpec> MockEthProvider<T, ChainSpec> {
         self.block_body_indices.lock().insert(block_number, indices);
     }
 
+    /// Add the stored sender of a transaction to local store
+    pub fn add_sender(&self, id: TxNumber, sender: Address) {
+        self.senders.lock().insert(id, sender);
+    }
+
     /// Add state root to local state root store
     pub fn add_state_root(&self, state_root: B256) {
         self.state_roots.lock().push(state_root);
@@ -185,6 +212,9 @@ impl<T: NodePrimitives, ChainSpec> MockEthProvider<T, ChainSpec> {
             chain_spec: Arc::new(chain_spec),
             state_roots: self.state_roots,
             block_body_indices: self.block_body_indices,
+            senders: self.senders,
+            safe_block: self.safe_block,
+            finalized_block: self.finalized_block,
             tx: self.tx,
             prune_modes: self.prune_modes,
         }
@@ -462,6 +492,9 @@ impl<T: NodePrimitives, ChainSpec: EthChainSpec + 'static> TransactionsProvider
     }
 
     fn transaction_sender(&self, id: TxNumber) -> ProviderResult<Option<Address>> {
+        if let Some(sender) = self.senders.lock().get(&id) {
+            return Ok(Some(*sender))
+        }
         self.transaction_by_id(id).map(|tx_option| tx_option.map(|tx| tx.recover_signer().unwrap()))
     }
 }
@@ -530,6 +563,13 @@ where
 
         Ok(result)
     }
+
+    fn receipts_by_tx_hashes(
+        &self,
+        hashes: Vec<TxHash>,
+    ) -> ProviderResult<Vec<Option<(Self::Receipt, BlockNumber, u64)>>> {
+        Ok(hashes.iter().map(|_| None).collect())
+    }
 }
 
 impl<T, ChainSpec> ReceiptProviderIdExt for MockEthProvider<T, ChainSpec>
@@ -604,11 +644,11 @@ impl<T: NodePrimitives, ChainSpec: EthChainSpec + Send + Sync + 'static> BlockId
     }
 
     fn safe_block_num_hash(&self) -> ProviderResult<Option<alloy_eips::BlockNumHash>> {
-        Ok(None)
+        Ok(*self.safe_block.lock())
     }
 
     fn finalized_block_num_hash(&self) -> ProviderResult<Option<alloy_eips::BlockNumHash>> {
-        Ok(None)
+        Ok(*self.finalized_block.lock())
     }
 }
 
@@ -648,18 +688,28 @@ impl<T: NodePrimitives, ChainSpec: EthChainSpec + Send + Sync + 'static> BlockRe
 
     fn recovered_block(
         &self,
-        _id: BlockHashOrNumber,
+        id: BlockHashOrNumber,
         _transaction_kind: TransactionVariant,
     ) -> ProviderResult<Option<RecoveredBlock<Self::Block>>> {
-        Ok(None)
+        let lock = self.blocks.lock();
+        let block = match id {
+            BlockHashOrNumber::Hash(hash) => lock.get_key_value(&hash),
+            BlockHashOrNumber::Number(num) => lock.iter().find(|(_, b)| b.header().number() == num),
+        };
+        let Some((hash, block)) = block else { return Ok(None) };
+
+        let senders =
+            block.body().recover_signers().map_err(|_| ProviderError::SenderRecoveryError)?;
+        // blocks are keyed by the hash they were added with
+        Ok(Some(RecoveredBlock::new(block.clone(), senders, *hash)))
     }
 
     fn sealed_block_with_senders(
         &self,
-        _id: BlockHashOrNumber,
-        _transaction_kind: TransactionVariant,
+        id: BlockHashOrNumber,
+        transaction_kind: TransactionVariant,
     ) -> ProviderResult<Option<RecoveredBlock<Self::Block>>> {
-        Ok(None)
+        self.recovered_block(id, transaction_kind)
     }
 
     fn block_range(&self, range: RangeInclusive<BlockNumber>) -> ProviderResult<Vec<Self::Block>> {
@@ -1021,6 +1071,7 @@ impl<T: NodePrimitives, ChainSpec: Send + Sync> NodePrimitivesProvider
 mod tests {
     use super::*;
     use alloy_consensus::Header;
+    use alloy_eips::BlockNumHash;
     use alloy_primitives::BlockHash;
     use reth_ethereum_primitives::Receipt;
 
@@ -1055,6 +1106,41 @@ mod tests {
         assert_eq!(empty_range, Vec::<Vec<Receipt>>::new());
     }
 
+    #[test]
+    fn test_mock_provider_chain_segment() {
+        let provider = MockEthProvider::<EthPrimitives>::new();
+        let mut chain = Vec::new();
+        let mut parent_hash = B256::ZERO;
+        for number in 0..5 {
+            let header = Header { number, parent_hash, ..Default::default() };
+            parent_hash = header.hash_slow();
+            provider.add_header(parent_hash, header);
+            chain.push(BlockNumHash::new(number, parent_hash));
+        }
+        // a sibling of block 2
+        let fork = Header {
+            number: 2,
+            parent_hash: chain[1].hash,
+            extra_data: Bytes::from_static(b"fork"),
+            ..Default::default()
+        };
+        let fork_hash = fork.hash_slow();
+        provider.add_header(fork_hash, fork);
+
+        assert_eq!(provider.chain_segment(chain[1].hash, chain[4].hash).unwrap(), chain[2..]);
+        assert_eq!(provider.chain_segment(chain[4].hash, chain[4].hash).unwrap(), []);
+
+        // unrelated blocks
+        assert!(matches!(
+            provider.chain_segment(fork_hash, chain[4].hash),
+            Err(ProviderError::NotAnAncestor { ancestor }) if ancestor == fork_hash
+        ));
+        assert!(matches!(
+            provider.chain_segment(chain[4].hash, chain[1].hash),
+            Err(ProviderError::NotAnAncestor { .. })
+        ));
+    }
+
     #[test]
     fn test_mock_provider_receipts_multiple_blocks() {
         let provider = MockEthProvider::<EthPrimitives>::new();
@@ -613,6 +613,12 @@ where
     ) -> ProviderResult<Vec<Vec<Self::Receipt>>> {
         Err(ProviderError::UnsupportedProvider)
     }
+    fn receipts_by_tx_hashes(
+        &self,
+        _hashes: Vec<TxHash>,
+    ) -> ProviderResult<Vec<Option<(Self::Receipt, BlockNumber, u64)>>> {
+        Err(ProviderError::UnsupportedProvider)
+    }
 }
 
 impl<P, Node, N> ReceiptProviderIdExt for RpcBlockchainProvider<P, Node, N>
@@ -1232,6 +1238,16 @@ where
     ) -> Result<BTreeMap<(Address, StorageKey), Vec<u64>>, ProviderError> {
         Ok(BTreeMap::new())
     }
+
+    fn account_storage_at(
+        &self,
+        _address: Address,
+        _block_number: BlockNumber,
+        _start_key: StorageKey,
+        _limit: usize,
+    ) -> Result<Vec<reth_primitives::StorageEntry>, ProviderError> {
+        Err(ProviderError::UnsupportedProvider)
+    }
 }
 
 impl<P, Node, N> reth_storage_api::StorageRootProvider for RpcBlockchainStateProvider<P, Node, N>
@@ -1636,6 +1652,12 @@ where
     ) -> Result<Vec<Vec<Self::Receipt>>, ProviderError> {
         Err(ProviderError::UnsupportedProvider)
     }
+    fn receipts_by_tx_hashes(
+        &self,
+        _hashes: Vec<TxHash>,
+    ) -> Result<Vec<Option<(Self::Receipt, BlockNumber, u64)>>, ProviderError> {
+        Err(ProviderError::UnsupportedProvider)
+    }
 }
 
 impl<P, Node, N> HeaderProvider for RpcBlockchainStateProvider<P, Node, N>
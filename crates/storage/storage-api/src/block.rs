@@ -3,8 +3,8 @@ use crate::{
     ReceiptProviderIdExt, TransactionVariant, TransactionsProvider,
 };
 use alloc::{sync::Arc, vec::Vec};
-use alloy_eips::{BlockHashOrNumber, BlockId, BlockNumberOrTag};
-use alloy_primitives::{BlockNumber, TxNumber, B256};
+use alloy_eips::{BlockHashOrNumber, BlockId, BlockNumHash, BlockNumberOrTag};
+use alloy_primitives::{BlockNumber, TxHash, TxNumber, B256};
 use core::ops::RangeInclusive;
 use reth_primitives_traits::{RecoveredBlock, SealedHeader};
 use reth_storage_errors::provider::ProviderResult;
@@ -147,6 +147,18 @@ pub trait BlockReader:
 
     /// Returns the block number that contains the given transaction.
     fn block_by_transaction_id(&self, id: TxNumber) -> ProviderResult<Option<BlockNumber>>;
+
+    /// Returns the number and hash of the block that contains the transaction with the given hash.
+    ///
+    /// This only reads the transaction lookup index and block indices, which is cheaper than
+    /// loading the transaction or its receipt.
+    ///
+    /// Returns `None` if the transaction is not found.
+    fn transaction_block(&self, tx_hash: TxHash) -> ProviderResult<Option<BlockNumHash>> {
+        let Some(id) = self.transaction_id(tx_hash)? else { return Ok(None) };
+        let Some(number) = self.block_by_transaction_id(id)? else { return Ok(None) };
+        Ok(self.block_hash(number)?.map(|hash| BlockNumHash::new(number, hash)))
+    }
 }
 
 impl<T: BlockReader> BlockReader for Arc<T> {
@@ -208,6 +220,9 @@ impl<T: BlockReader> BlockReader for Arc<T> {
     fn block_by_transaction_id(&self, id: TxNumber) -> ProviderResult<Option<BlockNumber>> {
         T::block_by_transaction_id(self, id)
     }
+    fn transaction_block(&self, tx_hash: TxHash) -> ProviderResult<Option<BlockNumHash>> {
+        T::transaction_block(self, tx_hash)
+    }
 }
 
 impl<T: BlockReader> BlockReader for &T {
@@ -269,6 +284,9 @@ impl<T: BlockReader> BlockReader for &T {
     fn block_by_transaction_id(&self, id: TxNumber) -> ProviderResult<Option<BlockNumber>> {
         T::block_by_transaction_id(self, id)
     }
+    fn transaction_block(&self, tx_hash: TxHash) -> ProviderResult<Option<BlockNumHash>> {
+        T::transaction_block(self, tx_hash)
+    }
 }
 
 /// Trait extension for `BlockReader`, for types that implement `BlockId` conversion.
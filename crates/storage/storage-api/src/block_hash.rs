@@ -10,6 +10,14 @@ pub trait BlockHashReader: Send + Sync {
     /// exists.
     fn block_hash(&self, number: BlockNumber) -> ProviderResult<Option<B256>>;
 
+    /// Get the canonical hash of the block with the given number. Returns `None` if there's no
+    /// canonical block with this number.
+    ///
+    /// This only reads the canonical hashes index, which is cheaper than loading the header.
+    fn canonical_hash(&self, number: BlockNumber) -> ProviderResult<Option<B256>> {
+        self.block_hash(number)
+    }
+
     /// Get the hash of the block with the given number. Returns `None` if no block with this number
     /// exists.
     fn convert_block_hash(
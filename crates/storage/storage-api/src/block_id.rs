@@ -78,6 +78,19 @@ pub trait BlockIdReader: BlockNumReader + Send + Sync {
         Ok(Some(num))
     }
 
+    /// Converts the given id to a [`BlockHashOrNumber`], resolving block tags to a block number.
+    ///
+    /// Returns `None` if the tag can't be resolved, see [`Self::convert_block_number`].
+    fn block_hash_or_number_for_id(
+        &self,
+        block_id: BlockId,
+    ) -> ProviderResult<Option<BlockHashOrNumber>> {
+        match block_id_to_hash_or_number(block_id) {
+            Ok(id) => Ok(Some(id)),
+            Err(tag) => Ok(self.convert_block_number(tag)?.map(BlockHashOrNumber::Number)),
+        }
+    }
+
     /// Get the hash of the block by matching the given id.
     fn block_hash_for_id(&self, block_id: BlockId) -> ProviderResult<Option<B256>> {
         match block_id {
@@ -133,5 +146,44 @@ pub trait BlockIdReader: BlockNumReader + Send + Sync {
     }
 }
 
+/// Converts a [`BlockId`] that refers to a concrete block hash or number to a
+/// [`BlockHashOrNumber`].
+///
+/// Block tags can't be converted without resolving them first, for example with
+/// [`BlockIdReader::convert_block_number`], and are returned as error. Note that the
+/// `requireCanonical` flag of a block hash is not retained.
+pub const fn block_id_to_hash_or_number(
+    block_id: BlockId,
+) -> Result<BlockHashOrNumber, BlockNumberOrTag> {
+    match block_id {
+        BlockId::Hash(hash) => Ok(BlockHashOrNumber::Hash(hash.block_hash)),
+        BlockId::Number(BlockNumberOrTag::Number(num)) => Ok(BlockHashOrNumber::Number(num)),
+        BlockId::Number(tag) => Err(tag),
+    }
+}
+
 #[cfg(test)]
 fn _object_safe(_: Box<dyn BlockIdReader>) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_id_to_hash_or_number_conversion() {
+        let hash = B256::with_last_byte(1);
+        assert_eq!(block_id_to_hash_or_number(BlockId::hash(hash)), Ok(hash.into()));
+        assert_eq!(block_id_to_hash_or_number(BlockId::hash_canonical(hash)), Ok(hash.into()));
+        assert_eq!(block_id_to_hash_or_number(BlockId::number(7)), Ok(7.into()));
+
+        for tag in [
+            BlockNumberOrTag::Latest,
+            BlockNumberOrTag::Finalized,
+            BlockNumberOrTag::Safe,
+            BlockNumberOrTag::Earliest,
+            BlockNumberOrTag::Pending,
+        ] {
+            assert_eq!(block_id_to_hash_or_number(tag.into()), Err(tag));
+        }
+    }
+}
@@ -1,13 +1,17 @@
-use alloc::vec::Vec;
-use alloy_eips::BlockHashOrNumber;
+use alloc::{boxed::Box, vec::Vec};
+use alloy_consensus::BlockHeader as _;
+use alloy_eips::{BlockHashOrNumber, BlockNumHash};
 use alloy_primitives::{BlockHash, BlockNumber};
-use core::ops::RangeBounds;
+use core::ops::{RangeBounds, RangeInclusive};
 use reth_primitives_traits::{BlockHeader, SealedHeader};
-use reth_storage_errors::provider::ProviderResult;
+use reth_storage_errors::provider::{ProviderError, ProviderResult};
 
 /// A helper type alias to access [`HeaderProvider::Header`].
 pub type ProviderHeader<P> = <P as HeaderProvider>::Header;
 
+/// Iterator over sealed headers, returned by [`HeaderProvider::canonical_sealed_headers_iter`].
+pub type SealedHeadersIter<'a, H> = Box<dyn Iterator<Item = ProviderResult<SealedHeader<H>>> + 'a>;
+
 /// Client trait for fetching `Header` related data.
 #[auto_impl::auto_impl(&, Arc)]
 pub trait HeaderProvider: Send + Sync {
@@ -70,4 +74,50 @@ pub trait HeaderProvider: Send + Sync {
         range: impl RangeBounds<BlockNumber>,
         predicate: impl FnMut(&SealedHeader<Self::Header>) -> bool,
     ) -> ProviderResult<Vec<SealedHeader<Self::Header>>>;
+
+    /// Returns an iterator over the canonical sealed headers in the given block range.
+    ///
+    /// Returns [`ProviderError::HeaderNotFound`] if the range is empty or extends beyond the last
+    /// canonical header. The default implementation reads the whole range at once, providers
+    /// backed by static files yield the headers lazily instead.
+    fn canonical_sealed_headers_iter(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<SealedHeadersIter<'_, Self::Header>> {
+        let headers = self.sealed_headers_range(range.clone())?;
+        if range.is_empty() || headers.len() as u64 != range.end() - range.start() + 1 {
+            return Err(ProviderError::HeaderNotFound((*range.end()).into()))
+        }
+
+        Ok(Box::new(headers.into_iter().map(Ok)))
+    }
+
+    /// Returns the blocks from `ancestor` (exclusive) to `descendant` (inclusive) in ascending
+    /// order, by walking the parent hashes of `descendant`.
+    ///
+    /// Returns [`ProviderError::NotAnAncestor`] if `descendant` does not descend from `ancestor`.
+    fn chain_segment(
+        &self,
+        ancestor: BlockHash,
+        descendant: BlockHash,
+    ) -> ProviderResult<Vec<BlockNumHash>> {
+        let ancestor_number = self
+            .header(ancestor)?
+            .ok_or_else(|| ProviderError::HeaderNotFound(ancestor.into()))?
+            .number();
+
+        let mut segment = Vec::new();
+        let mut hash = descendant;
+        while hash != ancestor {
+            let header =
+                self.header(hash)?.ok_or_else(|| ProviderError::HeaderNotFound(hash.into()))?;
+            if header.number() <= ancestor_number {
+                return Err(ProviderError::NotAnAncestor { ancestor })
+            }
+            segment.push(BlockNumHash::new(header.number(), hash));
+            hash = header.parent_hash();
+        }
+        segment.reverse();
+        Ok(segment)
+    }
 }
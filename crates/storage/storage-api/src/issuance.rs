@@ -0,0 +1,268 @@
+use crate::BlockReader;
+use alloc::vec::Vec;
+use alloy_consensus::{constants::ETH_TO_WEI, BlockHeader, EMPTY_OMMER_ROOT_HASH};
+use alloy_primitives::{BlockNumber, I256, U256};
+use core::ops::{Add, AddAssign, RangeInclusive};
+use reth_chainspec::{ChainSpecProvider, EthChainSpec, EthereumHardforks};
+use reth_primitives_traits::{Block, BlockBody};
+use reth_storage_errors::provider::{ProviderError, ProviderResult};
+
+/// Number of headers that are loaded at once when summing up the issuance of a block range.
+const ISSUANCE_HEADERS_BATCH_SIZE: u64 = 10_000;
+
+/// Issuance and burn of ether on the execution layer.
+///
+/// Withdrawals and the genesis allocation aren't included.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Issuance {
+    /// Block and ommer rewards, zero since the merge.
+    pub block_rewards: U256,
+    /// Base fees (EIP-1559) and blob fees (EIP-4844) burnt by the transactions.
+    pub burnt_fees: U256,
+}
+
+impl Issuance {
+    /// Returns the issuance of a single block, given the numbers of its ommers.
+    pub fn of_block<ChainSpec, H>(
+        chain_spec: &ChainSpec,
+        header: &H,
+        ommers: impl IntoIterator<Item = BlockNumber>,
+    ) -> Self
+    where
+        ChainSpec: EthChainSpec + EthereumHardforks,
+        H: BlockHeader,
+    {
+        let number = header.number();
+
+        // the genesis block doesn't have a reward
+        let mut block_rewards = 0;
+        if let Some(base_reward) = base_block_reward(chain_spec, number).filter(|_| number > 0) {
+            let mut ommers_count = 0;
+            for ommer in ommers {
+                ommers_count += 1;
+                block_rewards += ommer_reward(base_reward, number, ommer);
+            }
+            block_rewards += block_reward(base_reward, ommers_count);
+        }
+
+        let mut burnt_fees = U256::from(header.base_fee_per_gas().unwrap_or_default()) *
+            U256::from(header.gas_used());
+        if let Some(blob_fee) = chain_spec
+            .blob_params_at_timestamp(header.timestamp())
+            .and_then(|params| header.blob_fee(params))
+        {
+            burnt_fees +=
+                U256::from(blob_fee) * U256::from(header.blob_gas_used().unwrap_or_default());
+        }
+
+        Self { block_rewards: U256::from(block_rewards), burnt_fees }
+    }
+
+    /// Returns the net issuance, the block rewards minus the burnt fees.
+    pub fn net(&self) -> I256 {
+        I256::from_raw(self.block_rewards) - I256::from_raw(self.burnt_fees)
+    }
+}
+
+impl Add for Issuance {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl AddAssign for Issuance {
+    fn add_assign(&mut self, rhs: Self) {
+        self.block_rewards += rhs.block_rewards;
+        self.burnt_fees += rhs.burnt_fees;
+    }
+}
+
+/// Provider for the cumulative issuance of ether up to a block.
+pub trait IssuanceProvider: Send + Sync {
+    /// Returns the cumulative [`Issuance`] of all blocks up to and including the given block.
+    fn cumulative_issuance(&self, block: BlockNumber) -> ProviderResult<Issuance>;
+}
+
+/// Returns the [`Issuance`] of all blocks in the given range.
+///
+/// This reads every header in the range and the bodies of blocks with ommers, so it should only be
+/// used for bounded ranges, e.g. from the last issuance checkpoint up to a block.
+pub fn range_issuance<P>(
+    provider: &P,
+    range: RangeInclusive<BlockNumber>,
+) -> ProviderResult<Issuance>
+where
+    P: BlockReader + ChainSpecProvider<ChainSpec: EthChainSpec + EthereumHardforks>,
+{
+    let chain_spec = provider.chain_spec();
+    let (mut start, last) = range.into_inner();
+    let mut issuance = Issuance::default();
+    while start <= last {
+        let end = last.min(start.saturating_add(ISSUANCE_HEADERS_BATCH_SIZE - 1));
+        let headers = provider.headers_range(start..=end)?;
+        if headers.len() as u64 != end - start + 1 {
+            return Err(ProviderError::HeaderNotFound((start + headers.len() as u64).into()))
+        }
+
+        for header in headers {
+            let ommers = if header.ommers_hash() == EMPTY_OMMER_ROOT_HASH {
+                Vec::new()
+            } else {
+                let block = provider
+                    .block_by_number(header.number())?
+                    .ok_or_else(|| ProviderError::BlockBodyIndicesNotFound(header.number()))?;
+                block
+                    .body()
+                    .ommers()
+                    .map(|ommers| ommers.iter().map(|ommer| ommer.number()).collect())
+                    .unwrap_or_default()
+            };
+            issuance += Issuance::of_block(&*chain_spec, &header, ommers);
+        }
+
+        let Some(next) = end.checked_add(1) else { break };
+        start = next;
+    }
+    Ok(issuance)
+}
+
+/// Returns the base block reward of the given block, or `None` if it has no reward since it's
+/// after the merge.
+fn base_block_reward<ChainSpec: EthereumHardforks>(
+    chain_spec: &ChainSpec,
+    number: BlockNumber,
+) -> Option<u128> {
+    if chain_spec.is_paris_active_at_block(number) {
+        None
+    } else if chain_spec.is_constantinople_active_at_block(number) {
+        Some(2 * ETH_TO_WEI)
+    } else if chain_spec.is_byzantium_active_at_block(number) {
+        Some(3 * ETH_TO_WEI)
+    } else {
+        Some(5 * ETH_TO_WEI)
+    }
+}
+
+/// Returns the reward of the block's beneficiary, which receives 1/32 of the base reward for every
+/// included ommer.
+const fn block_reward(base_block_reward: u128, ommers: usize) -> u128 {
+    base_block_reward + (base_block_reward >> 5) * ommers as u128
+}
+
+/// Returns the reward of the beneficiary of an ommer, which depends on the distance to the block
+/// that includes it.
+const fn ommer_reward(
+    base_block_reward: u128,
+    block_number: BlockNumber,
+    ommer_block_number: BlockNumber,
+) -> u128 {
+    ((8 + ommer_block_number - block_number) as u128 * base_block_reward) >> 3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{constants::ETH_TO_WEI, Header};
+    use reth_chainspec::MAINNET;
+
+    #[test]
+    fn mainnet_block_issuance_checkpoints() {
+        let issuance = |header: Header, ommers: &[BlockNumber]| {
+            Issuance::of_block(&**MAINNET, &header, ommers.iter().copied())
+        };
+        let eth = |amount| U256::from(amount * ETH_TO_WEI);
+
+        // no reward for the genesis block
+        assert_eq!(issuance(Header::default(), &[]), Issuance::default());
+
+        // frontier
+        assert_eq!(issuance(Header { number: 1, ..Default::default() }, &[]).block_rewards, eth(5));
+
+        // a frontier block with an ommer of the previous height
+        let base = 5 * ETH_TO_WEI;
+        assert_eq!(
+            issuance(Header { number: 126, ..Default::default() }, &[125]).block_rewards,
+            U256::from(base + base / 32 + base * 7 / 8)
+        );
+
+        // byzantium and constantinople
+        assert_eq!(
+            issuance(Header { number: 4_370_000, ..Default::default() }, &[]).block_rewards,
+            eth(3)
+        );
+        assert_eq!(
+            issuance(Header { number: 7_280_000, ..Default::default() }, &[]).block_rewards,
+            eth(2)
+        );
+
+        // the first london block burnt its 1 gwei base fee for 30,025,257 gas
+        let london = issuance(
+            Header {
+                number: 12_965_000,
+                base_fee_per_gas: Some(1_000_000_000),
+                gas_used: 30_025_257,
+                ..Default::default()
+            },
+            &[],
+        );
+        assert_eq!(london.block_rewards, eth(2));
+        assert_eq!(london.burnt_fees, U256::from(30_025_257u64 * 1_000_000_000));
+        assert_eq!(london.net(), I256::from_raw(eth(2)) - I256::from_raw(london.burnt_fees));
+
+        // no block rewards since the merge
+        assert_eq!(
+            issuance(Header { number: 15_537_394, ..Default::default() }, &[]).block_rewards,
+            U256::ZERO
+        );
+
+        // blob fees are burnt since cancun
+        let cancun = issuance(
+            Header {
+                number: 19_426_587,
+                timestamp: 1_710_338_135,
+                base_fee_per_gas: Some(7),
+                gas_used: 100,
+                excess_blob_gas: Some(0),
+                blob_gas_used: Some(131_072),
+                ..Default::default()
+            },
+            &[],
+        );
+        assert_eq!(cancun.block_rewards, U256::ZERO);
+        // the minimum blob base fee is 1 wei
+        assert_eq!(cancun.burnt_fees, U256::from(700 + 131_072));
+    }
+
+    #[test]
+    fn mainnet_cumulative_burn_at_london_checkpoint() {
+        // blocks before london have no base fee, so nothing is burnt regardless of their gas used
+        // and the cumulative burn at the fork block is the burn of the first london block:
+        // 30,025,257 gas at a base fee of 1 gwei, 0.030025257 ether
+        let blocks = [
+            Header { number: 12_964_998, gas_used: 15_000_000, ..Default::default() },
+            Header { number: 12_964_999, gas_used: 15_000_000, ..Default::default() },
+            Header {
+                number: 12_965_000,
+                base_fee_per_gas: Some(1_000_000_000),
+                gas_used: 30_025_257,
+                ..Default::default()
+            },
+        ];
+        let cumulative = blocks
+            .iter()
+            .map(|header| Issuance::of_block(&**MAINNET, header, []))
+            .fold(Issuance::default(), Add::add);
+
+        assert_eq!(cumulative.burnt_fees, U256::from(30_025_257_000_000_000u64));
+        // constantinople rewards of 2 ether per block, without ommers
+        assert_eq!(cumulative.block_rewards, U256::from(6 * ETH_TO_WEI));
+        assert_eq!(
+            cumulative.net(),
+            I256::from_raw(U256::from(6 * ETH_TO_WEI)) -
+                I256::from_raw(U256::from(30_025_257_000_000_000u64))
+        );
+    }
+}
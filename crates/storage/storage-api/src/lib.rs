@@ -33,6 +33,9 @@ pub use chain::*;
 mod header;
 pub use header::*;
 
+mod issuance;
+pub use issuance::*;
+
 mod prune_checkpoint;
 pub use prune_checkpoint::*;
 
@@ -337,6 +337,13 @@ impl<C: Send + Sync, N: NodePrimitives> ReceiptProvider for NoopProvider<C, N> {
     ) -> ProviderResult<Vec<Vec<Self::Receipt>>> {
         Ok(Vec::new())
     }
+
+    fn receipts_by_tx_hashes(
+        &self,
+        hashes: Vec<TxHash>,
+    ) -> ProviderResult<Vec<Option<(Self::Receipt, BlockNumber, u64)>>> {
+        Ok(hashes.iter().map(|_| None).collect())
+    }
 }
 
 impl<C: Send + Sync, N: NodePrimitives> ReceiptProviderIdExt for NoopProvider<C, N> {}
@@ -52,6 +52,18 @@ pub trait ReceiptProvider: Send + Sync {
         &self,
         block_range: RangeInclusive<BlockNumber>,
     ) -> ProviderResult<Vec<Vec<Self::Receipt>>>;
+
+    /// Get the receipts of the given transactions, each with the number of the block that includes
+    /// the transaction and the index of the transaction in that block.
+    ///
+    /// The result is in the order of the requested hashes, with `None` for transactions that are
+    /// not found. This is more efficient than calling `receipt_by_hash` for every transaction,
+    /// because the transaction lookup index is read once for the whole batch.
+    #[expect(clippy::type_complexity)]
+    fn receipts_by_tx_hashes(
+        &self,
+        hashes: Vec<TxHash>,
+    ) -> ProviderResult<Vec<Option<(Self::Receipt, BlockNumber, u64)>>>;
 }
 
 /// Trait extension for `ReceiptProvider`, for types that implement `BlockId` conversion.
@@ -67,16 +79,7 @@ pub trait ReceiptProvider: Send + Sync {
 pub trait ReceiptProviderIdExt: ReceiptProvider + BlockIdReader {
     /// Get receipt by block id
     fn receipts_by_block_id(&self, block: BlockId) -> ProviderResult<Option<Vec<Self::Receipt>>> {
-        let id = match block {
-            BlockId::Hash(hash) => BlockHashOrNumber::Hash(hash.block_hash),
-            BlockId::Number(num_tag) => {
-                if let Some(num) = self.convert_block_number(num_tag)? {
-                    BlockHashOrNumber::Number(num)
-                } else {
-                    return Ok(None)
-                }
-            }
-        };
+        let Some(id) = self.block_hash_or_number_for_id(block)? else { return Ok(None) };
 
         self.receipts_by_block(id)
     }
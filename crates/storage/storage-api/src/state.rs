@@ -90,6 +90,14 @@ pub trait StateProvider:
         // Returns None if acc doesn't exist
         self.basic_account(addr)?.map_or_else(|| Ok(None), |acc| Ok(Some(acc.nonce)))
     }
+
+    /// Returns the state root that results from applying the given [`BundleState`] on top of the
+    /// current state.
+    ///
+    /// The root is computed in memory, nothing is written to the database.
+    fn bundle_state_root(&self, bundle_state: &BundleState) -> ProviderResult<B256> {
+        self.state_root(self.hashed_post_state(bundle_state))
+    }
 }
 
 /// Minimal requirements to read a full account, for example, to validate its new transactions
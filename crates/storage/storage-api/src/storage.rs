@@ -30,6 +30,20 @@ pub trait StorageReader: Send + Sync {
         &self,
         range: RangeInclusive<BlockNumber>,
     ) -> ProviderResult<BTreeMap<(Address, B256), Vec<u64>>>;
+
+    /// Returns the non-zero storage slots of the account at the given block, ordered by slot.
+    ///
+    /// Returns at most `limit` slots starting at `start_key` (inclusive). To fetch the next page,
+    /// call again with the slot following the last returned one.
+    ///
+    /// Storage of historical blocks is reconstructed from the storage changesets.
+    fn account_storage_at(
+        &self,
+        address: Address,
+        block_number: BlockNumber,
+        start_key: B256,
+        limit: usize,
+    ) -> ProviderResult<Vec<StorageEntry>>;
 }
 
 /// Storage `ChangeSet` reader
@@ -10,7 +10,12 @@ use alloy_eips::{
 use alloy_primitives::{TxHash, B256};
 use parking_lot::{Mutex, RwLock};
 use schnellru::{ByLength, LruMap};
-use std::{collections::HashSet, fmt, fs, io, path::PathBuf, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt, fs, io,
+    path::PathBuf,
+    sync::Arc,
+};
 use tracing::{debug, trace};
 
 /// How many [`BlobTransactionSidecarVariant`] to cache in memory.
@@ -527,7 +532,8 @@ impl DiskFileBlobStoreInner {
     /// Retrieves blobs for the given transaction hashes from the blob cache or disk.
     ///
     /// This will not return an error if there are missing blobs. Therefore, the result may be a
-    /// subset of the request or an empty vector if none of the blobs were found.
+    /// subset of the request or an empty vector if none of the blobs were found. Found blobs are
+    /// returned in the order they were requested.
     #[inline]
     fn get_all(
         &self,
@@ -539,34 +545,34 @@ impl DiskFileBlobStoreInner {
             let mut cache = self.blob_cache.lock();
             for tx in txs {
                 if let Some(blob) = cache.get(&tx) {
-                    res.push((tx, blob.clone()));
+                    res.push((tx, Some(blob.clone())));
                 } else {
-                    cache_miss.push(tx)
+                    cache_miss.push(tx);
+                    res.push((tx, None));
                 }
             }
         }
         if cache_miss.is_empty() {
-            return Ok(res)
-        }
-        let from_disk = self.read_many_decoded(cache_miss);
-        if from_disk.is_empty() {
-            return Ok(res)
+            return Ok(res.into_iter().filter_map(|(tx, blob)| Some((tx, blob?))).collect())
         }
-        let from_disk = from_disk
+        let from_disk = self
+            .read_many_decoded(cache_miss)
             .into_iter()
-            .map(|(tx, data)| {
-                let data = Arc::new(data);
-                res.push((tx, data.clone()));
-                (tx, data)
-            })
-            .collect::<Vec<_>>();
+            .map(|(tx, data)| (tx, Arc::new(data)))
+            .collect::<HashMap<_, _>>();
 
-        let mut cache = self.blob_cache.lock();
-        for (tx, data) in from_disk {
-            cache.insert(tx, data);
+        if !from_disk.is_empty() {
+            let mut cache = self.blob_cache.lock();
+            for (tx, data) in &from_disk {
+                cache.insert(*tx, data.clone());
+            }
         }
 
-        Ok(res)
+        // fill the cache misses in place to preserve the requested order
+        Ok(res
+            .into_iter()
+            .filter_map(|(tx, blob)| Some((tx, blob.or_else(|| from_disk.get(&tx).cloned())?)))
+            .collect())
     }
 
     /// Retrieves blobs for the given transaction hashes from the blob cache or disk.
@@ -800,6 +806,27 @@ mod tests {
         store.cleanup();
     }
 
+    #[test]
+    fn disk_get_all_preserves_request_order() {
+        let (store, _dir) = tmp_store();
+
+        let blobs = rng_blobs(4);
+        let txs = blobs.iter().map(|(tx, _)| *tx).collect::<Vec<_>>();
+        store.insert_all(blobs).unwrap();
+
+        // serve some of the blobs from the cache and the others from disk
+        store.clear_cache();
+        store.get(txs[2]).unwrap().unwrap();
+        store.get(txs[0]).unwrap().unwrap();
+
+        let missing = rng_blobs(2).into_iter().map(|(tx, _)| tx).collect::<Vec<_>>();
+        let request = vec![txs[3], missing[0], txs[0], txs[1], missing[1], txs[2]];
+
+        let retrieved = store.get_all(request).unwrap();
+        let retrieved = retrieved.into_iter().map(|(tx, _)| tx).collect::<Vec<_>>();
+        assert_eq!(retrieved, vec![txs[3], txs[0], txs[1], txs[2]]);
+    }
+
     #[test]
     fn disk_get_exact_blobs_success() {
         let (store, _dir) = tmp_store();
@@ -178,3 +178,30 @@ fn insert_size(
     store.insert(tx, Arc::new(blob));
     add
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::eip4844::BlobTransactionSidecar;
+
+    #[test]
+    fn mem_get_all_preserves_request_order() {
+        let store = InMemoryBlobStore::default();
+
+        let txs = (0..4u8).map(B256::with_last_byte).collect::<Vec<_>>();
+        let blobs = txs
+            .iter()
+            .map(|tx| {
+                (*tx, BlobTransactionSidecarVariant::Eip4844(BlobTransactionSidecar::default()))
+            })
+            .collect::<Vec<_>>();
+        store.insert_all(blobs).unwrap();
+
+        let missing = [B256::with_last_byte(0xaa), B256::with_last_byte(0xbb)];
+        let request = vec![txs[3], missing[0], txs[0], txs[1], missing[1], txs[2]];
+
+        let retrieved = store.get_all(request).unwrap();
+        let retrieved = retrieved.into_iter().map(|(tx, _)| tx).collect::<Vec<_>>();
+        assert_eq!(retrieved, vec![txs[3], txs[0], txs[1], txs[2]]);
+    }
+}
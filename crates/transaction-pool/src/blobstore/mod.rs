@@ -64,7 +64,7 @@ pub trait BlobStore: fmt::Debug + Send + Sync + 'static {
     /// This only returns the blobs that were found in the store.
     /// If there's no blob it will not be returned.
     ///
-    /// Note: this is not guaranteed to return the blobs in the same order as the input.
+    /// The found blobs are returned in the same order as the requested hashes.
     fn get_all(
         &self,
         txs: Vec<B256>,
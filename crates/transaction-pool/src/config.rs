@@ -5,8 +5,12 @@ use crate::{
 };
 use alloy_consensus::constants::EIP4844_TX_TYPE_ID;
 use alloy_eips::eip1559::{ETHEREUM_BLOCK_GAS_LIMIT_30M, MIN_PROTOCOL_BASE_FEE};
-use alloy_primitives::Address;
-use std::{collections::HashSet, ops::Mul, time::Duration};
+use alloy_primitives::{Address, U256};
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Mul,
+    time::Duration,
+};
 
 /// Guarantees max transactions for one sender, compatible with geth/erigon
 pub const TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER: usize = 16;
@@ -17,6 +21,12 @@ pub const TXPOOL_SUBPOOL_MAX_TXS_DEFAULT: usize = 10_000;
 /// The default maximum allowed size of the given subpool.
 pub const TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT: usize = 20;
 
+/// The default maximum allowed size of the blob subpool, including the blob sidecars.
+///
+/// This is much larger than the limit of the other subpools, because a single blob is already
+/// 128KB, compatible with geth's default blobpool datacap.
+pub const TXPOOL_BLOBPOOL_MAX_SIZE_MB_DEFAULT: usize = 2560;
+
 /// The default additional validation tasks size.
 pub const DEFAULT_TXPOOL_ADDITIONAL_VALIDATION_TASKS: usize = 1;
 
@@ -34,6 +44,10 @@ pub const MAX_NEW_PENDING_TXS_NOTIFICATIONS: usize = 200;
 /// Default maximum allowed in flight delegated transactions per account.
 pub const DEFAULT_MAX_INFLIGHT_DELEGATED_SLOTS: usize = 1;
 
+/// Number of transactions rechecked for sub-pool changes while holding the pool lock, if no
+/// [`PoolConfig::max_subpool_updates_per_block`] is configured.
+pub const SUBPOOL_UPDATE_BATCH_SIZE: usize = 1_000;
+
 /// Configuration options for the Transaction pool.
 #[derive(Debug, Clone)]
 pub struct PoolConfig {
@@ -43,8 +57,20 @@ pub struct PoolConfig {
     pub basefee_limit: SubPoolLimit,
     /// Max number of transaction in the queued sub-pool
     pub queued_limit: SubPoolLimit,
-    /// Max number of transactions in the blob sub-pool
+    /// Max number of transactions in the blob sub-pool.
+    ///
+    /// The size of blob transactions includes their blob sidecars, which are kept in the blob
+    /// store.
     pub blob_limit: SubPoolLimit,
+    /// Max combined size (in bytes) of the blob sidecars of the transactions in the pending
+    /// sub-pool.
+    ///
+    /// The sidecars are kept in the blob store and are not part of the size of the pending
+    /// sub-pool, see [`Self::pending_limit`]. If this is exceeded, the pending sub-pool is
+    /// truncated and evicted blob transactions free their sidecar as well.
+    ///
+    /// Unlimited if `None`.
+    pub pending_blob_sidecar_limit: Option<usize>,
     /// Blob cache size
     pub blob_cache_size: Option<u32>,
     /// Max number of executable transaction slots guaranteed per account
@@ -68,10 +94,31 @@ pub struct PoolConfig {
     pub max_new_pending_txs_notifications: usize,
     /// Maximum lifetime for transactions in the pool
     pub max_queued_lifetime: Duration,
+    /// Maximum lifetime for any transaction in the pool, regardless of its subpool.
+    ///
+    /// Disabled if `None`.
+    pub max_tx_lifetime: Option<Duration>,
     /// The maximum allowed inflight transactions a delegated sender can have.
     ///
     /// This restricts how many executable transaction a delegated sender can stack.
     pub max_inflight_delegated_slot_limit: usize,
+    /// Max number of transactions rechecked for sub-pool changes on a new block.
+    ///
+    /// On a new block all transactions in the pool are rechecked against the changed accounts and
+    /// fees. If this is set, the recheck stops once the limit is exceeded and the remaining
+    /// transactions are rechecked later on, in steps of at most this many transactions. This
+    /// bounds the time it takes to process a new block, at the cost of the pool being
+    /// temporarily out of sync for the remaining transactions.
+    ///
+    /// Unlimited by default, all transactions are then rechecked on the new block in batches of
+    /// [`SUBPOOL_UPDATE_BATCH_SIZE`] transactions, releasing the pool lock between batches.
+    pub max_subpool_updates_per_block: Option<usize>,
+    /// How long the hash of a replaced transaction is mapped to the hash of its replacement.
+    ///
+    /// This only records the replacement so it can be looked up, it doesn't affect the pool.
+    ///
+    /// Disabled if `None`.
+    pub replaced_transactions_grace_period: Option<Duration>,
 }
 
 impl PoolConfig {
@@ -100,6 +147,33 @@ impl PoolConfig {
         self
     }
 
+    /// Configures the max number of transactions rechecked for sub-pool changes on a new block.
+    ///
+    /// See [`PoolConfig::max_subpool_updates_per_block`].
+    pub const fn with_max_subpool_updates_per_block(
+        mut self,
+        max_subpool_updates_per_block: usize,
+    ) -> Self {
+        self.max_subpool_updates_per_block = Some(max_subpool_updates_per_block);
+        self
+    }
+
+    /// Configures the max combined size of the blob sidecars of pending transactions.
+    ///
+    /// See [`PoolConfig::pending_blob_sidecar_limit`].
+    pub const fn with_pending_blob_sidecar_limit(mut self, limit: usize) -> Self {
+        self.pending_blob_sidecar_limit = Some(limit);
+        self
+    }
+
+    /// Configures how long replaced transactions are mapped to their replacement.
+    ///
+    /// See [`PoolConfig::replaced_transactions_grace_period`].
+    pub const fn with_replaced_transactions_grace_period(mut self, grace_period: Duration) -> Self {
+        self.replaced_transactions_grace_period = Some(grace_period);
+        self
+    }
+
     /// Returns whether the size and amount constraints in any sub-pools are exceeded.
     #[inline]
     pub const fn is_exceeded(&self, pool_size: PoolSize) -> bool {
@@ -116,7 +190,11 @@ impl Default for PoolConfig {
             pending_limit: Default::default(),
             basefee_limit: Default::default(),
             queued_limit: Default::default(),
-            blob_limit: Default::default(),
+            blob_limit: SubPoolLimit::new(
+                TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
+                TXPOOL_BLOBPOOL_MAX_SIZE_MB_DEFAULT * 1024 * 1024,
+            ),
+            pending_blob_sidecar_limit: None,
             blob_cache_size: None,
             max_account_slots: TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
             price_bumps: Default::default(),
@@ -128,7 +206,10 @@ impl Default for PoolConfig {
             new_tx_listener_buffer_size: NEW_TX_LISTENER_BUFFER_SIZE,
             max_new_pending_txs_notifications: MAX_NEW_PENDING_TXS_NOTIFICATIONS,
             max_queued_lifetime: MAX_QUEUED_TRANSACTION_LIFETIME,
+            max_tx_lifetime: None,
             max_inflight_delegated_slot_limit: DEFAULT_MAX_INFLIGHT_DELEGATED_SLOTS,
+            max_subpool_updates_per_block: None,
+            replaced_transactions_grace_period: None,
         }
     }
 }
@@ -223,6 +304,11 @@ pub struct LocalTransactionConfig {
     pub local_addresses: HashSet<Address>,
     /// Flag indicating whether local transactions should be propagated.
     pub propagate_local_transactions: bool,
+    /// Balance kept in reserve for the given addresses.
+    ///
+    /// Transactions of these senders only have enough balance if their cumulative cost doesn't
+    /// dip into the reserve, otherwise they are parked.
+    pub balance_reserves: HashMap<Address, U256>,
 }
 
 impl Default for LocalTransactionConfig {
@@ -231,6 +317,7 @@ impl Default for LocalTransactionConfig {
             no_exemptions: false,
             local_addresses: HashSet::default(),
             propagate_local_transactions: true,
+            balance_reserves: HashMap::default(),
         }
     }
 }
@@ -259,6 +346,21 @@ impl LocalTransactionConfig {
         origin.is_local() || self.contains_local_address(sender)
     }
 
+    /// Returns the part of the sender's balance its transactions can spend, which is the balance
+    /// minus the reserve configured for the sender.
+    #[inline]
+    pub fn usable_balance(&self, sender: &Address, balance: U256) -> U256 {
+        self.balance_reserves
+            .get(sender)
+            .map_or(balance, |reserve| balance.saturating_sub(*reserve))
+    }
+
+    /// Sets the balance that is kept in reserve for the given address.
+    pub fn with_balance_reserve(mut self, address: Address, reserve: U256) -> Self {
+        self.balance_reserves.insert(address, reserve);
+        self
+    }
+
     /// Sets toggle to propagate transactions received locally by this client (e.g
     /// transactions from `eth_sendTransaction` to this nodes' RPC server)
     ///
@@ -1,6 +1,6 @@
 //! Transaction pool errors
 
-use std::any::Any;
+use std::{any::Any, fmt};
 
 use alloy_eips::eip4844::BlobTransactionValidationError;
 use alloy_primitives::{Address, TxHash, U256};
@@ -145,6 +145,91 @@ impl PoolError {
             }
         }
     }
+
+    /// Returns the reason why the transaction was rejected.
+    pub const fn rejection_reason(&self) -> PoolRejectionReason {
+        match &self.kind {
+            PoolErrorKind::AlreadyImported => PoolRejectionReason::AlreadyKnown,
+            PoolErrorKind::ReplacementUnderpriced => PoolRejectionReason::ReplacementUnderpriced,
+            PoolErrorKind::FeeCapBelowMinimumProtocolFeeCap(_) => PoolRejectionReason::Underpriced,
+            PoolErrorKind::SpammerExceededCapacity(_) => {
+                PoolRejectionReason::SenderCapacityExceeded
+            }
+            PoolErrorKind::DiscardedOnInsert => PoolRejectionReason::PoolFull,
+            PoolErrorKind::InvalidTransaction(err) => err.rejection_reason(),
+            PoolErrorKind::ExistingConflictingTransactionType(_, _) => PoolRejectionReason::Invalid,
+            PoolErrorKind::Other(_) => PoolRejectionReason::Other,
+        }
+    }
+}
+
+/// The reason why a transaction was rejected by the pool.
+///
+/// This groups the [`PoolErrorKind`]s, for example to label metrics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PoolRejectionReason {
+    /// The transaction is already in the pool.
+    AlreadyKnown,
+    /// The transaction doesn't pay enough to replace an existing transaction.
+    ReplacementUnderpriced,
+    /// The fee of the transaction is below the required minimum.
+    Underpriced,
+    /// The nonce of the transaction is too low or, for blob transactions, has a gap.
+    InvalidNonce,
+    /// The sender can't pay for the transaction.
+    InsufficientFunds,
+    /// The gas limit of the transaction exceeds the block or transaction gas limit.
+    GasLimitTooHigh,
+    /// The gas limit of the transaction is below the intrinsic gas.
+    IntrinsicGasTooLow,
+    /// The sender exceeded its transaction slots in the pool.
+    SenderCapacityExceeded,
+    /// The transaction was discarded because the pool is full.
+    PoolFull,
+    /// The transaction is invalid for any other reason.
+    Invalid,
+    /// The transaction couldn't be added due to an internal error.
+    Other,
+}
+
+impl PoolRejectionReason {
+    /// All rejection reasons.
+    pub const ALL: [Self; 11] = [
+        Self::AlreadyKnown,
+        Self::ReplacementUnderpriced,
+        Self::Underpriced,
+        Self::InvalidNonce,
+        Self::InsufficientFunds,
+        Self::GasLimitTooHigh,
+        Self::IntrinsicGasTooLow,
+        Self::SenderCapacityExceeded,
+        Self::PoolFull,
+        Self::Invalid,
+        Self::Other,
+    ];
+
+    /// Returns the name of the reason in snake case.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::AlreadyKnown => "already_known",
+            Self::ReplacementUnderpriced => "replacement_underpriced",
+            Self::Underpriced => "underpriced",
+            Self::InvalidNonce => "invalid_nonce",
+            Self::InsufficientFunds => "insufficient_funds",
+            Self::GasLimitTooHigh => "gas_limit_too_high",
+            Self::IntrinsicGasTooLow => "intrinsic_gas_too_low",
+            Self::SenderCapacityExceeded => "sender_capacity_exceeded",
+            Self::PoolFull => "pool_full",
+            Self::Invalid => "invalid",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl fmt::Display for PoolRejectionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// Represents all errors that can happen when validating transactions for the pool for EIP-4844
@@ -221,6 +306,20 @@ pub enum InvalidPoolTransactionError {
     /// Thrown when a transaction's gas limit exceeds the configured maximum per-transaction limit.
     #[error("transaction's gas limit {0} exceeds maximum per-transaction gas limit {1}")]
     MaxTxGasLimitExceeded(u64, u64),
+    /// Thrown when a transaction's access list has more entries than the configured maximum.
+    #[error(
+        "access list entries {entries} exceed maximum per-transaction access list entries {limit}"
+    )]
+    AccessListTooLarge {
+        /// Number of addresses and storage keys in the transaction's access list.
+        entries: usize,
+        /// Configured limit that was exceeded.
+        limit: usize,
+    },
+    /// Thrown when a legacy transaction without [EIP-155](https://eips.ethereum.org/EIPS/eip-155)
+    /// replay protection is submitted while such transactions are rejected.
+    #[error("only replay-protected (EIP-155) transactions are allowed")]
+    UnprotectedTransaction,
     /// Thrown when a new transaction is added to the pool, but then immediately discarded to
     /// respect the tx fee exceeds the configured cap
     #[error("tx fee ({max_tx_fee_wei} wei) exceeds the configured cap ({tx_fee_cap_wei} wei)")]
@@ -338,6 +437,14 @@ impl InvalidPoolTransactionError {
                 // local setting
                 false
             }
+            Self::AccessListTooLarge { .. } => {
+                // local setting
+                false
+            }
+            Self::UnprotectedTransaction => {
+                // local setting
+                false
+            }
             Self::ExceedsFeeCap { max_tx_fee_wei: _, tx_fee_cap_wei: _ } => true,
             Self::ExceedsMaxInitCodeSize(_, _) => true,
             Self::OversizedData { .. } => true,
@@ -424,6 +531,27 @@ impl InvalidPoolTransactionError {
             matches!(self, Self::Eip4844(Eip4844PoolTransactionError::Eip4844NonceGap))
     }
 
+    /// Returns the reason why the transaction was rejected, see [`PoolError::rejection_reason`].
+    pub const fn rejection_reason(&self) -> PoolRejectionReason {
+        match self {
+            _ if self.is_nonce_gap() => PoolRejectionReason::InvalidNonce,
+            Self::Consensus(InvalidTransactionError::InsufficientFunds(_)) |
+            Self::Overdraft { .. } => PoolRejectionReason::InsufficientFunds,
+            Self::Consensus(
+                InvalidTransactionError::GasTooHigh | InvalidTransactionError::GasLimitTooHigh,
+            ) |
+            Self::ExceedsGasLimit(..) |
+            Self::MaxTxGasLimitExceeded(..) => PoolRejectionReason::GasLimitTooHigh,
+            Self::Consensus(InvalidTransactionError::GasTooLow) | Self::IntrinsicGasTooLow => {
+                PoolRejectionReason::IntrinsicGasTooLow
+            }
+            Self::Consensus(InvalidTransactionError::FeeCapTooLow) |
+            Self::Underpriced |
+            Self::PriorityFeeBelowMinimum { .. } => PoolRejectionReason::Underpriced,
+            _ => PoolRejectionReason::Invalid,
+        }
+    }
+
     /// Returns the arbitrary error if it is [`InvalidPoolTransactionError::Other`]
     pub fn as_other(&self) -> Option<&dyn PoolTransactionError> {
         match self {
@@ -471,4 +599,43 @@ mod tests {
 
         assert!(err.downcast_other_ref::<E>().is_some());
     }
+
+    #[test]
+    fn rejection_reasons() {
+        let hash = TxHash::ZERO;
+        assert_eq!(
+            PoolError::new(hash, PoolErrorKind::AlreadyImported).rejection_reason(),
+            PoolRejectionReason::AlreadyKnown
+        );
+        assert_eq!(
+            PoolError::new(hash, PoolErrorKind::DiscardedOnInsert).rejection_reason(),
+            PoolRejectionReason::PoolFull
+        );
+        assert_eq!(
+            PoolError::new(
+                hash,
+                PoolErrorKind::InvalidTransaction(InvalidPoolTransactionError::ExceedsGasLimit(
+                    2, 1
+                ))
+            )
+            .rejection_reason(),
+            PoolRejectionReason::GasLimitTooHigh
+        );
+        assert_eq!(
+            InvalidPoolTransactionError::Consensus(InvalidTransactionError::NonceNotConsistent {
+                tx: 1,
+                state: 2
+            })
+            .rejection_reason(),
+            PoolRejectionReason::InvalidNonce
+        );
+        assert_eq!(
+            InvalidPoolTransactionError::Other(Box::new(E)).rejection_reason(),
+            PoolRejectionReason::Invalid
+        );
+        assert_eq!(
+            PoolRejectionReason::SenderCapacityExceeded.as_str(),
+            "sender_capacity_exceeded"
+        );
+    }
 }
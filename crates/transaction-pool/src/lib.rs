@@ -261,6 +261,7 @@
 //!
 //! - `serde` (default): Enable serde support
 //! - `test-utils`: Export utilities for testing
+//! - `pending-spill`: Enable spilling low priority pending transactions to disk
 
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/paradigmxyz/reth/main/assets/reth-docs.png",
@@ -277,14 +278,16 @@ pub use crate::{
         LocalTransactionConfig, PoolConfig, PriceBumpConfig, SubPoolLimit,
         DEFAULT_MAX_INFLIGHT_DELEGATED_SLOTS, DEFAULT_PRICE_BUMP,
         DEFAULT_TXPOOL_ADDITIONAL_VALIDATION_TASKS, MAX_NEW_PENDING_TXS_NOTIFICATIONS,
-        REPLACE_BLOB_PRICE_BUMP, TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
-        TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT, TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
+        REPLACE_BLOB_PRICE_BUMP, SUBPOOL_UPDATE_BATCH_SIZE, TXPOOL_BLOBPOOL_MAX_SIZE_MB_DEFAULT,
+        TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER, TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT,
+        TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
     },
     error::PoolResult,
     ordering::{CoinbaseTipOrdering, Priority, TransactionOrdering},
     pool::{
         blob_tx_priority, fee_delta, state::SubPool, AddedTransactionOutcome,
-        AllTransactionsEvents, FullTransactionEvent, NewTransactionEvent, TransactionEvent,
+        AddedTransactionState, AllTransactionsEvents, EvictedTransactionEvent, EvictionReason,
+        FullTransactionEvent, NewTransactionEvent, QueuedReason, TransactionEvent,
         TransactionEvents, TransactionListenerKind,
     },
     traits::*,
@@ -314,6 +317,7 @@ pub mod maintain;
 pub mod metrics;
 pub mod noop;
 pub mod pool;
+pub mod snapshot;
 pub mod validate;
 
 pub mod batcher;
@@ -364,6 +368,12 @@ where
         self.inner().config()
     }
 
+    /// Returns the hash of the transaction that replaced the given transaction, if the
+    /// replacement happened within [`PoolConfig::replaced_transactions_grace_period`].
+    pub fn replacement_of(&self, replaced: &TxHash) -> Option<TxHash> {
+        self.inner().replacement_of(replaced)
+    }
+
     /// Get the validator reference.
     pub fn validator(&self) -> &V {
         self.inner().validator()
@@ -520,6 +530,10 @@ where
         self.pool.add_blob_sidecar_listener()
     }
 
+    fn evicted_transactions_listener(&self) -> Receiver<EvictedTransactionEvent> {
+        self.pool.add_eviction_listener()
+    }
+
     fn new_transactions_listener_for(
         &self,
         kind: TransactionListenerKind,
@@ -575,6 +589,13 @@ where
         self.pool.best_transactions_with_attributes(best_transactions_attributes)
     }
 
+    fn best_transactions_count_with_attributes(
+        &self,
+        best_transactions_attributes: BestTransactionsAttributes,
+    ) -> usize {
+        self.pool.best_transactions_count_with_attributes(best_transactions_attributes)
+    }
+
     fn pending_transactions(&self) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
         self.pool.pending_transactions()
     }
@@ -619,6 +640,20 @@ where
         self.pool.remove_transactions_and_descendants(hashes)
     }
 
+    fn remove_transactions_by(
+        &self,
+        predicate: impl FnMut(&ValidPoolTransaction<Self::Transaction>) -> bool,
+    ) -> Vec<TxHash> {
+        self.pool.remove_transactions_by(predicate).iter().map(|tx| *tx.hash()).collect()
+    }
+
+    fn remove_expired_transactions(
+        &self,
+        predicate: impl FnMut(&ValidPoolTransaction<Self::Transaction>) -> bool,
+    ) -> Vec<TxHash> {
+        self.pool.remove_expired_transactions(predicate).iter().map(|tx| *tx.hash()).collect()
+    }
+
     fn remove_transactions_by_sender(
         &self,
         sender: Address,
@@ -633,10 +668,18 @@ where
         self.pool.retain_unknown(announcement)
     }
 
+    fn contains(&self, tx_hash: &TxHash) -> bool {
+        self.inner().contains(tx_hash)
+    }
+
     fn get(&self, tx_hash: &TxHash) -> Option<Arc<ValidPoolTransaction<Self::Transaction>>> {
         self.inner().get(tx_hash)
     }
 
+    fn transaction_state(&self, tx_hash: &TxHash) -> Option<AddedTransactionState> {
+        self.inner().transaction_state(tx_hash)
+    }
+
     fn get_all(&self, txs: Vec<TxHash>) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
         self.inner().get_all(txs)
     }
@@ -766,17 +809,28 @@ where
         self.pool.set_block_info(info)
     }
 
-    fn on_canonical_state_change<B>(&self, update: CanonicalStateUpdate<'_, B>)
+    fn on_canonical_state_change<B>(
+        &self,
+        update: CanonicalStateUpdate<'_, B>,
+    ) -> CanonicalStateUpdateSummary
     where
         B: Block,
     {
-        self.pool.on_canonical_state_change(update);
+        self.pool.on_canonical_state_change(update)
     }
 
     fn update_accounts(&self, accounts: Vec<ChangedAccount>) {
         self.pool.update_accounts(accounts);
     }
 
+    fn process_deferred_updates(&self) {
+        self.pool.process_deferred_updates();
+    }
+
+    fn has_deferred_updates(&self) -> bool {
+        self.pool.has_deferred_updates()
+    }
+
     fn delete_blob(&self, tx: TxHash) {
         self.pool.delete_blob(tx)
     }
@@ -10,7 +10,7 @@ use crate::{
 };
 use alloy_consensus::{transaction::TxHashRef, BlockHeader, Typed2718};
 use alloy_eips::{BlockNumberOrTag, Decodable2718, Encodable2718};
-use alloy_primitives::{Address, BlockHash, BlockNumber, Bytes};
+use alloy_primitives::{Address, BlockHash, BlockNumber, Bytes, TxHash};
 use alloy_rlp::Encodable;
 use futures_util::{
     future::{BoxFuture, Fuse, FusedFuture},
@@ -32,6 +32,7 @@ use std::{
     hash::{Hash, Hasher},
     path::{Path, PathBuf},
     sync::Arc,
+    time::Instant,
 };
 use tokio::{
     sync::oneshot,
@@ -59,6 +60,11 @@ pub struct MaintainPoolConfig {
     /// Default: 3 hours
     pub max_tx_lifetime: Duration,
 
+    /// Maximum amount of time any non local transaction is kept in the pool, regardless of its
+    /// subpool.
+    /// Default: disabled
+    pub max_pooled_tx_lifetime: Option<Duration>,
+
     /// Apply no exemptions to the locally received transactions.
     ///
     /// This includes:
@@ -73,6 +79,7 @@ impl Default for MaintainPoolConfig {
             max_update_depth: 64,
             max_reload_accounts: 100,
             max_tx_lifetime: MAX_QUEUED_TRANSACTION_LIFETIME,
+            max_pooled_tx_lifetime: None,
             no_local_exemptions: false,
         }
     }
@@ -147,9 +154,7 @@ pub async fn maintain_transaction_pool<N, Client, P, St, Tasks>(
             block_gas_limit: latest.gas_limit(),
             last_seen_block_hash: latest.hash(),
             last_seen_block_number: latest.number(),
-            pending_basefee: chain_spec
-                .next_block_base_fee(latest.header(), latest.timestamp())
-                .unwrap_or_default(),
+            pending_basefee: pending_block_base_fee(&*chain_spec, latest.header()),
             pending_blob_fee: latest
                 .maybe_next_block_blob_fee(chain_spec.blob_params_at_timestamp(latest.timestamp())),
         };
@@ -175,6 +180,9 @@ pub async fn maintain_transaction_pool<N, Client, P, St, Tasks>(
     // eviction interval for stale non local txs
     let mut stale_eviction_interval = time::interval(config.max_tx_lifetime);
 
+    // eviction interval for expired non local txs in any subpool, if enabled
+    let mut expiry_interval = config.max_pooled_tx_lifetime.map(time::interval);
+
     // toggle for the first notification
     let mut first_event = true;
 
@@ -249,6 +257,9 @@ pub async fn maintain_transaction_pool<N, Client, P, St, Tasks>(
         let mut event = None;
         let mut reloaded = None;
 
+        // sub-pool updates that exceeded the per block budget are processed in between events
+        let has_deferred_updates = pool.has_deferred_updates();
+
         // select of account reloads and new canonical state updates which should arrive at the rate
         // of the block time
         tokio::select! {
@@ -281,6 +292,20 @@ pub async fn maintain_transaction_pool<N, Client, P, St, Tasks>(
                 debug!(target: "txpool", count=%stale_txs.len(), "removing stale transactions");
                 pool.remove_transactions(stale_txs);
             }
+            _ = async { expiry_interval.as_mut().expect("is some").tick().await }, if expiry_interval.is_some() => {
+                if let Some(max_lifetime) = config.max_pooled_tx_lifetime {
+                    let expired = evict_expired_transactions(
+                        &pool,
+                        max_lifetime,
+                        config.no_local_exemptions,
+                        Instant::now(),
+                    );
+                    debug!(target: "txpool", count=%expired.len(), "removed expired transactions");
+                }
+            }
+            _ = tokio::task::yield_now(), if has_deferred_updates => {
+                pool.process_deferred_updates();
+            }
         }
         // handle the result of the account reload
         match reloaded {
@@ -325,9 +350,7 @@ pub async fn maintain_transaction_pool<N, Client, P, St, Tasks>(
                 let chain_spec = client.chain_spec();
 
                 // fees for the next block: `new_tip+1`
-                let pending_block_base_fee = chain_spec
-                    .next_block_base_fee(new_tip.header(), new_tip.timestamp())
-                    .unwrap_or_default();
+                let pending_block_base_fee = pending_block_base_fee(&*chain_spec, new_tip.header());
                 let pending_block_blob_fee = new_tip.header().maybe_next_block_blob_fee(
                     chain_spec.blob_params_at_timestamp(new_tip.timestamp()),
                 );
@@ -408,7 +431,8 @@ pub async fn maintain_transaction_pool<N, Client, P, St, Tasks>(
                     mined_transactions: new_blocks.transaction_hashes().collect(),
                     update_kind: PoolUpdateKind::Reorg,
                 };
-                pool.on_canonical_state_change(update);
+                let summary = pool.on_canonical_state_change(update);
+                debug!(target: "txpool", ?summary, "applied reorg to pool");
 
                 // all transactions that were mined in the old chain but not in the new chain need
                 // to be re-injected
@@ -428,9 +452,7 @@ pub async fn maintain_transaction_pool<N, Client, P, St, Tasks>(
                 let chain_spec = client.chain_spec();
 
                 // fees for the next block: `tip+1`
-                let pending_block_base_fee = chain_spec
-                    .next_block_base_fee(tip.header(), tip.timestamp())
-                    .unwrap_or_default();
+                let pending_block_base_fee = pending_block_base_fee(&*chain_spec, tip.header());
                 let pending_block_blob_fee = tip.header().maybe_next_block_blob_fee(
                     chain_spec.blob_params_at_timestamp(tip.timestamp()),
                 );
@@ -491,7 +513,8 @@ pub async fn maintain_transaction_pool<N, Client, P, St, Tasks>(
                     mined_transactions,
                     update_kind: PoolUpdateKind::Commit,
                 };
-                pool.on_canonical_state_change(update);
+                let summary = pool.on_canonical_state_change(update);
+                debug!(target: "txpool", ?summary, "applied canonical state update");
 
                 // keep track of mined blob transactions
                 blob_store_tracker.add_new_chain_blocks(&blocks);
@@ -642,6 +665,14 @@ impl Borrow<Address> for ChangedAccountEntry {
     }
 }
 
+/// Returns the projected base fee of the block following the given tip.
+///
+/// This uses the EIP-1559 parameters (elasticity multiplier and max change denominator) of the
+/// chain spec, so chains with non-default parameters park transactions in the correct subpool.
+fn pending_block_base_fee<C: EthChainSpec>(chain_spec: &C, tip: &C::Header) -> u64 {
+    chain_spec.next_block_base_fee_after(tip).unwrap_or_default()
+}
+
 #[derive(Default)]
 struct LoadedAccounts {
     /// All accounts that were loaded
@@ -650,6 +681,26 @@ struct LoadedAccounts {
     failed_to_load: Vec<Address>,
 }
 
+/// Evicts all transactions that have been in the pool for longer than `max_lifetime` at `now`,
+/// regardless of their subpool.
+///
+/// Local transactions are exempt unless `no_local_exemptions` is set. The blob sidecars of evicted
+/// transactions are deleted and their descendants are parked because of the introduced nonce gap.
+fn evict_expired_transactions<P>(
+    pool: &P,
+    max_lifetime: Duration,
+    no_local_exemptions: bool,
+    now: Instant,
+) -> Vec<TxHash>
+where
+    P: TransactionPool,
+{
+    pool.remove_expired_transactions(|tx| {
+        (tx.origin.is_external() || no_local_exemptions) &&
+            now.saturating_duration_since(tx.timestamp) > max_lifetime
+    })
+}
+
 /// Loads all accounts at the given state
 ///
 /// Returns an error with all given addresses if the state is not available.
@@ -715,6 +766,8 @@ where
                             backup.rlp.as_ref(),
                         )
                         .ok()?;
+                    // reject malformed signatures before the more expensive recovery
+                    tx_signed.verify_signature().ok()?;
                     let recovered = tx_signed.try_into_recovered().ok()?;
                     let pool_tx =
                         <P::Transaction as PoolTransaction>::try_from_consensus(recovered).ok()?;
@@ -728,6 +781,7 @@ where
 
             txs_signed
                 .into_iter()
+                .filter(|tx| tx.verify_signature().is_ok())
                 .filter_map(|tx| tx.try_into_recovered().ok())
                 .filter_map(|tx| {
                     <P::Transaction as PoolTransaction>::try_from_consensus(tx)
@@ -863,6 +917,56 @@ mod tests {
         assert!(changed_acc.eq(&ChangedAccountEntry(copy)));
     }
 
+    #[tokio::test]
+    async fn pending_base_fee_uses_chain_spec_params() {
+        use crate::test_utils::{testing_pool, MockTransaction};
+        use alloy_consensus::Header;
+        use alloy_eips::eip1559::BaseFeeParams;
+        use reth_chainspec::{ChainSpec, MAINNET};
+
+        let base_fee = 1_000_000_000u64;
+        // a full parent block
+        let parent = Header {
+            gas_limit: 30_000_000,
+            gas_used: 30_000_000,
+            base_fee_per_gas: Some(base_fee),
+            ..Default::default()
+        };
+
+        // mainnet params (elasticity 2): +12.5%
+        let mainnet = MAINNET.as_ref().clone();
+        let mainnet_base_fee = pending_block_base_fee(&mainnet, &parent);
+        assert_eq!(mainnet_base_fee, 1_125_000_000);
+
+        // custom params (elasticity 4): +37.5%
+        let custom =
+            ChainSpec { base_fee_params: BaseFeeParams::new(8, 4).into(), ..mainnet.clone() };
+        let custom_base_fee = pending_block_base_fee(&custom, &parent);
+        assert_eq!(custom_base_fee, 1_375_000_000);
+
+        let pool = testing_pool();
+        let info = pool.block_info();
+        // fee cap that only satisfies the mainnet projection
+        let tx = MockTransaction::eip1559().with_max_fee(1_200_000_000).with_priority_fee(1);
+        pool.add_transaction(TransactionOrigin::External, tx).await.unwrap();
+
+        pool.set_block_info(BlockInfo { pending_basefee: mainnet_base_fee, ..info });
+        assert_eq!(pool.pool_size().pending, 1);
+
+        // the correct projection demotes the transaction to the basefee subpool
+        pool.set_block_info(BlockInfo { pending_basefee: custom_base_fee, ..info });
+        assert_eq!(pool.pool_size().pending, 0);
+        assert_eq!(pool.pool_size().basefee, 1);
+
+        // a parent at the custom gas target keeps the base fee unchanged, which promotes it again
+        let parent = Header { gas_used: 7_500_000, ..parent };
+        let custom_base_fee = pending_block_base_fee(&custom, &parent);
+        assert_eq!(custom_base_fee, base_fee);
+        pool.set_block_info(BlockInfo { pending_basefee: custom_base_fee, ..info });
+        assert_eq!(pool.pool_size().pending, 1);
+        assert_eq!(pool.pool_size().basefee, 0);
+    }
+
     const EXTENSION: &str = "json";
     const FILENAME: &str = "test_transactions_backup";
 
@@ -955,4 +1059,34 @@ mod tests {
         assert_eq!(tracker.update(None), None);
         assert_eq!(tracker.last_finalized_block, None);
     }
+
+    #[tokio::test]
+    async fn evicts_expired_transactions_from_any_subpool() {
+        use crate::test_utils::{testing_pool, MockTransaction};
+
+        let pool = testing_pool();
+        let tx = MockTransaction::eip1559();
+        let descendant = tx.next();
+
+        pool.add_transaction(TransactionOrigin::External, tx.clone()).await.unwrap();
+        pool.add_transaction(TransactionOrigin::External, descendant.clone()).await.unwrap();
+        assert_eq!(pool.pending_transactions().len(), 2);
+
+        let added_at = pool.get(tx.get_hash()).unwrap().timestamp;
+        let descendant_added_at = pool.get(descendant.get_hash()).unwrap().timestamp;
+        assert!(added_at < descendant_added_at);
+
+        // at this instant the descendant has been pooled for exactly the max lifetime
+        let max_lifetime = Duration::from_secs(30);
+        let now = descendant_added_at + max_lifetime;
+        let expired = evict_expired_transactions(&pool, max_lifetime, false, now);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired, vec![*tx.get_hash()]);
+
+        // the descendant is parked because of the nonce gap
+        assert!(!pool.contains(tx.get_hash()));
+        assert!(pool.pending_transactions().is_empty());
+        assert_eq!(pool.queued_transactions().len(), 1);
+        assert_eq!(pool.queued_transactions()[0].hash(), descendant.get_hash());
+    }
 }
@@ -1,5 +1,7 @@
 //! Transaction pool metrics.
 
+use crate::error::{PoolError, PoolRejectionReason};
+use alloy_primitives::map::HashMap;
 use reth_metrics::{
     metrics::{Counter, Gauge, Histogram},
     Metrics,
@@ -64,6 +66,50 @@ pub struct TxPoolMetrics {
     pub(crate) queued_transactions_evicted: Counter,
 }
 
+/// Transaction pool metrics of transactions rejected for a specific reason.
+#[derive(Metrics)]
+#[metrics(scope = "transaction_pool")]
+pub struct RejectedTransactionsMetrics {
+    /// Number of transactions rejected by the pool
+    pub(crate) rejected_transactions: Counter,
+}
+
+/// Transaction pool metrics of rejected transactions, labeled by the rejection reason.
+#[derive(Debug)]
+pub struct TxPoolRejectionMetrics {
+    reasons: HashMap<PoolRejectionReason, RejectedTransactionsMetrics>,
+}
+
+impl Default for TxPoolRejectionMetrics {
+    fn default() -> Self {
+        Self {
+            reasons: PoolRejectionReason::ALL
+                .into_iter()
+                .map(|reason| {
+                    (
+                        reason,
+                        RejectedTransactionsMetrics::new_with_labels(&[(
+                            "reason",
+                            reason.as_str(),
+                        )]),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TxPoolRejectionMetrics {
+    /// Records a transaction rejected with the given error.
+    pub(crate) fn record(&self, err: &PoolError) {
+        self.reasons
+            .get(&err.rejection_reason())
+            .expect("rejection reason metrics should exist")
+            .rejected_transactions
+            .increment(1);
+    }
+}
+
 /// Transaction pool blobstore metrics
 #[derive(Metrics)]
 #[metrics(scope = "transaction_pool")]
@@ -9,10 +9,11 @@ use crate::{
     pool::TransactionListenerKind,
     traits::{BestTransactionsAttributes, GetPooledTransactionLimit, NewBlobSidecar},
     validate::ValidTransaction,
-    AddedTransactionOutcome, AllPoolTransactions, AllTransactionsEvents, BestTransactions,
-    BlockInfo, EthPoolTransaction, EthPooledTransaction, NewTransactionEvent, PoolResult, PoolSize,
-    PoolTransaction, PropagatedTransactions, TransactionEvents, TransactionOrigin, TransactionPool,
-    TransactionValidationOutcome, TransactionValidator, ValidPoolTransaction,
+    AddedTransactionOutcome, AddedTransactionState, AllPoolTransactions, AllTransactionsEvents,
+    BestTransactions, BlockInfo, EthPoolTransaction, EthPooledTransaction, EvictedTransactionEvent,
+    NewTransactionEvent, PoolResult, PoolSize, PoolTransaction, PropagatedTransactions,
+    TransactionEvents, TransactionOrigin, TransactionPool, TransactionValidationOutcome,
+    TransactionValidator, ValidPoolTransaction,
 };
 use alloy_eips::{
     eip1559::ETHEREUM_BLOCK_GAS_LIMIT_30M,
@@ -121,6 +122,10 @@ impl<T: EthPoolTransaction> TransactionPool for NoopTransactionPool<T> {
         mpsc::channel(1).1
     }
 
+    fn evicted_transactions_listener(&self) -> Receiver<EvictedTransactionEvent> {
+        mpsc::channel(1).1
+    }
+
     fn new_transactions_listener_for(
         &self,
         _kind: TransactionListenerKind,
@@ -175,6 +180,10 @@ impl<T: EthPoolTransaction> TransactionPool for NoopTransactionPool<T> {
         Box::new(std::iter::empty())
     }
 
+    fn best_transactions_count_with_attributes(&self, _: BestTransactionsAttributes) -> usize {
+        0
+    }
+
     fn pending_transactions(&self) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
         vec![]
     }
@@ -216,6 +225,13 @@ impl<T: EthPoolTransaction> TransactionPool for NoopTransactionPool<T> {
         vec![]
     }
 
+    fn remove_transactions_by(
+        &self,
+        _predicate: impl FnMut(&ValidPoolTransaction<Self::Transaction>) -> bool,
+    ) -> Vec<TxHash> {
+        vec![]
+    }
+
     fn remove_transactions_by_sender(
         &self,
         _sender: Address,
@@ -233,6 +249,10 @@ impl<T: EthPoolTransaction> TransactionPool for NoopTransactionPool<T> {
         None
     }
 
+    fn transaction_state(&self, _tx_hash: &TxHash) -> Option<AddedTransactionState> {
+        None
+    }
+
     fn get_all(&self, _txs: Vec<TxHash>) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
         vec![]
     }
@@ -28,9 +28,9 @@ pub struct BlobTransactions<T: PoolTransaction> {
     all: BTreeSet<BlobTransaction<T>>,
     /// Keeps track of the current fees, so transaction priority can be calculated on insertion.
     pending_fees: PendingFees,
-    /// Keeps track of the size of this pool.
+    /// Keeps track of the size of this pool, including the blob sidecars kept in the blob store.
     ///
-    /// See also [`reth_primitives_traits::InMemorySize::size`].
+    /// See also [`ValidPoolTransaction::size_with_blob_sidecar`].
     size_of: SizeTracker,
 }
 
@@ -50,7 +50,7 @@ impl<T: PoolTransaction> BlobTransactions<T> {
         let submission_id = self.next_id();
 
         // keep track of size
-        self.size_of += tx.size();
+        self.size_of += tx.size_with_blob_sidecar();
 
         // set transaction, which will also calculate priority based on current pending fees
         let transaction = BlobTransaction::new(tx, submission_id, &self.pending_fees);
@@ -76,7 +76,7 @@ impl<T: PoolTransaction> BlobTransactions<T> {
         self.all.remove(&tx);
 
         // keep track of size
-        self.size_of -= tx.transaction.size();
+        self.size_of -= tx.transaction.size_with_blob_sidecar();
 
         Some(tx.transaction)
     }
@@ -108,3 +108,31 @@ impl<T: PoolTransaction> Clone for NewTransactionEvent<T> {
         Self { subpool: self.subpool, transaction: self.transaction.clone() }
     }
 }
+
+/// The reason a transaction was evicted from the pool.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum EvictionReason {
+    /// The subpool exceeded its configured size limit in bytes.
+    SizeLimit,
+    /// The subpool exceeded its configured limit on the number of transactions.
+    CountLimit,
+    /// The subpool exceeded one of its limits and the transaction's sender held more transactions
+    /// than its configured account slots allow.
+    AccountSlots,
+    /// The transaction was in the pool for longer than the configured lifetime.
+    Expired,
+}
+
+/// A transaction that was evicted from the pool.
+///
+/// Unlike [`TransactionEvent::Discarded`], this is only emitted for transactions the pool removed
+/// on its own and is tagged with the reason for the eviction.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct EvictedTransactionEvent {
+    /// Hash of the evicted transaction.
+    pub tx_hash: TxHash,
+    /// The subpool the transaction was evicted from.
+    pub subpool: SubPool,
+    /// Why the transaction was evicted.
+    pub reason: EvictionReason,
+}
@@ -69,14 +69,14 @@ use crate::{
     blobstore::BlobStore,
     error::{PoolError, PoolErrorKind, PoolResult},
     identifier::{SenderId, SenderIdentifiers, TransactionId},
-    metrics::BlobStoreMetrics,
+    metrics::{BlobStoreMetrics, TxPoolRejectionMetrics},
     pool::{
         listener::{
             BlobTransactionSidecarListener, PendingTransactionHashListener, PoolEventBroadcast,
             TransactionListener,
         },
         state::SubPool,
-        txpool::{SenderInfo, TxPool},
+        txpool::{EvictedTransaction, SenderInfo, TxPool},
         update::UpdateOutcome,
     },
     traits::{
@@ -84,8 +84,8 @@ use crate::{
         NewBlobSidecar, PoolSize, PoolTransaction, PropagatedTransactions, TransactionOrigin,
     },
     validate::{TransactionValidationOutcome, ValidPoolTransaction, ValidTransaction},
-    CanonicalStateUpdate, EthPoolTransaction, PoolConfig, TransactionOrdering,
-    TransactionValidator,
+    CanonicalStateUpdate, CanonicalStateUpdateSummary, EthPoolTransaction, PoolConfig,
+    TransactionOrdering, TransactionValidator,
 };
 
 use alloy_primitives::{Address, TxHash, B256};
@@ -94,15 +94,20 @@ use reth_eth_wire_types::HandleMempoolData;
 use reth_execution_types::ChangedAccount;
 
 use alloy_eips::{eip7594::BlobTransactionSidecarVariant, Typed2718};
+use replaced::{ReplacedTransactions, MAX_REPLACED_TRANSACTIONS};
 use reth_primitives_traits::Recovered;
 use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
 use std::{collections::HashSet, fmt, sync::Arc, time::Instant};
 use tokio::sync::mpsc;
 use tracing::{debug, trace, warn};
 mod events;
 pub use best::{BestTransactionFilter, BestTransactionsWithPrioritizedSenders};
 pub use blob::{blob_tx_priority, fee_delta, BlobOrd, BlobTransactions};
-pub use events::{FullTransactionEvent, NewTransactionEvent, TransactionEvent};
+pub use events::{
+    EvictedTransactionEvent, EvictionReason, FullTransactionEvent, NewTransactionEvent,
+    TransactionEvent,
+};
 pub use listener::{AllTransactionsEvents, TransactionEvents, TransactionListenerKind};
 pub use parked::{BasefeeOrd, ParkedOrd, ParkedPool, QueuedOrd};
 pub use pending::PendingPool;
@@ -115,7 +120,10 @@ mod blob;
 mod listener;
 mod parked;
 pub mod pending;
+mod replaced;
 pub(crate) mod size;
+#[cfg(feature = "pending-spill")]
+pub mod spill;
 pub(crate) mod state;
 pub mod txpool;
 mod update;
@@ -127,6 +135,8 @@ pub const NEW_TX_LISTENER_BUFFER_SIZE: usize = 1024;
 
 const BLOB_SIDECAR_LISTENER_BUFFER_SIZE: usize = 512;
 
+const EVICTED_TX_LISTENER_BUFFER_SIZE: usize = 1024;
+
 /// Transaction pool internals.
 pub struct PoolInner<V, T, S>
 where
@@ -150,8 +160,15 @@ where
     transaction_listener: Mutex<Vec<TransactionListener<T::Transaction>>>,
     /// Listener for new blob transaction sidecars added to the pool.
     blob_transaction_sidecar_listener: Mutex<Vec<BlobTransactionSidecarListener>>,
+    /// Listeners for transactions evicted from the pool.
+    eviction_listener: Mutex<Vec<mpsc::Sender<EvictedTransactionEvent>>>,
     /// Metrics for the blob store
     blob_store_metrics: BlobStoreMetrics,
+    /// Metrics for rejected transactions
+    rejection_metrics: TxPoolRejectionMetrics,
+    /// Recently replaced transactions, if enabled via
+    /// [`PoolConfig::replaced_transactions_grace_period`].
+    replaced_transactions: Option<Mutex<ReplacedTransactions>>,
 }
 
 // === impl PoolInner ===
@@ -172,9 +189,16 @@ where
             pending_transaction_listener: Default::default(),
             transaction_listener: Default::default(),
             blob_transaction_sidecar_listener: Default::default(),
+            eviction_listener: Default::default(),
+            replaced_transactions: config
+                .replaced_transactions_grace_period
+                .map(|grace_period| {
+                    Mutex::new(ReplacedTransactions::new(grace_period, MAX_REPLACED_TRANSACTIONS))
+                }),
             config,
             blob_store,
             blob_store_metrics: Default::default(),
+            rejection_metrics: Default::default(),
         }
     }
 
@@ -233,6 +257,15 @@ where
         &self.config
     }
 
+    /// Returns the hash of the transaction that replaced the given transaction.
+    ///
+    /// Replacements are only remembered for
+    /// [`PoolConfig::replaced_transactions_grace_period`], this always returns `None` if that is
+    /// not configured.
+    pub fn replacement_of(&self, replaced: &TxHash) -> Option<TxHash> {
+        self.replaced_transactions.as_ref()?.lock().get(replaced, Instant::now())
+    }
+
     /// Get the validator reference.
     pub const fn validator(&self) -> &V {
         &self.validator
@@ -266,6 +299,14 @@ where
         rx
     }
 
+    /// Adds a new listener to the pool that gets notified about every transaction the pool evicts
+    /// because of its limits or because the transaction expired.
+    pub fn add_eviction_listener(&self) -> mpsc::Receiver<EvictedTransactionEvent> {
+        let (sender, rx) = mpsc::channel(EVICTED_TX_LISTENER_BUFFER_SIZE);
+        self.eviction_listener.lock().push(sender);
+        rx
+    }
+
     /// If the pool contains the transaction, this adds a new listener that gets notified about
     /// transaction events.
     pub fn add_transaction_event_listener(&self, tx_hash: TxHash) -> Option<TransactionEvents> {
@@ -431,7 +472,14 @@ where
     }
 
     /// Updates the entire pool after a new block was executed.
-    pub fn on_canonical_state_change<B>(&self, update: CanonicalStateUpdate<'_, B>)
+    ///
+    /// If no [`PoolConfig::max_subpool_updates_per_block`] is configured, all transactions are
+    /// rechecked before this returns, but the pool lock is released between the batches of
+    /// rechecked transactions.
+    pub fn on_canonical_state_change<B>(
+        &self,
+        update: CanonicalStateUpdate<'_, B>,
+    ) -> CanonicalStateUpdateSummary
     where
         B: Block,
     {
@@ -446,18 +494,40 @@ where
         let changed_senders = self.changed_senders(changed_accounts.into_iter());
 
         // update the pool
-        let outcome = self.pool.write().on_canonical_state_change(
+        let mut outcome = self.pool.write().on_canonical_state_change(
             block_info,
             mined_transactions,
             changed_senders,
             update_kind,
         );
 
+        // recheck the remaining transactions unless they're deferred to the next blocks, each
+        // batch takes the lock separately so that the pool isn't blocked for the entire update
+        if self.config.max_subpool_updates_per_block.is_none() {
+            loop {
+                let mut pool = self.pool.write();
+                if !pool.has_deferred_updates() {
+                    break
+                }
+                let UpdateOutcome { promoted, demoted, discarded } =
+                    pool.process_deferred_updates();
+                drop(pool);
+
+                outcome.promoted.extend(promoted);
+                outcome.demoted.extend(demoted);
+                outcome.discarded.extend(discarded);
+            }
+        }
+
         // This will discard outdated transactions based on the account's nonce
         self.delete_discarded_blobs(outcome.discarded.iter());
 
+        let summary = outcome.summary();
+
         // notify listeners about updates
         self.notify_on_new_state(outcome);
+
+        summary
     }
 
     /// Performs account updates on the pool.
@@ -467,12 +537,29 @@ where
     /// This should be invoked when the pool drifted and accounts are updated manually
     pub fn update_accounts(&self, accounts: Vec<ChangedAccount>) {
         let changed_senders = self.changed_senders(accounts.into_iter());
-        let UpdateOutcome { promoted, discarded } =
+        let UpdateOutcome { promoted, discarded, .. } =
             self.pool.write().update_accounts(changed_senders);
 
         self.notify_on_transaction_updates(promoted, discarded);
     }
 
+    /// Processes sub-pool updates that were deferred because they exceeded the configured per
+    /// block budget, see [`PoolConfig::max_subpool_updates_per_block`].
+    pub fn process_deferred_updates(&self) {
+        let UpdateOutcome { promoted, discarded, .. } =
+            self.pool.write().process_deferred_updates();
+
+        // This will discard outdated transactions based on the account's nonce
+        self.delete_discarded_blobs(discarded.iter());
+
+        self.notify_on_transaction_updates(promoted, discarded);
+    }
+
+    /// Returns `true` if there are deferred sub-pool updates that are yet to be processed.
+    pub fn has_deferred_updates(&self) -> bool {
+        self.pool.read().has_deferred_updates()
+    }
+
     /// Add a single validated transaction into the pool.
     ///
     /// Note: this is only used internally by [`Self::add_transactions()`], all new transaction(s)
@@ -528,6 +615,12 @@ where
                     self.insert_blob(hash, sidecar);
                 }
 
+                if let Some(replaced_transactions) = &self.replaced_transactions &&
+                    let Some(replaced) = added.replaced()
+                {
+                    replaced_transactions.lock().insert(*replaced.hash(), hash, Instant::now());
+                }
+
                 if let Some(replaced) = added.replaced_blob_transaction() {
                     debug!(target: "txpool", "[{:?}] delete replaced blob sidecar", replaced);
                     // delete the replaced transaction from the blob store
@@ -590,7 +683,7 @@ where
         transactions: impl IntoIterator<Item = TransactionValidationOutcome<T::Transaction>>,
     ) -> Vec<PoolResult<AddedTransactionOutcome>> {
         // Process all transactions in one write lock, maintaining individual origins
-        let (mut added, discarded) = {
+        let (mut added, evicted) = {
             let mut pool = self.pool.write();
             let added = transactions
                 .into_iter()
@@ -598,16 +691,20 @@ where
                 .collect::<Vec<_>>();
 
             // Enforce the pool size limits if at least one transaction was added successfully
-            let discarded = if added.iter().any(Result::is_ok) {
+            let evicted = if added.iter().any(Result::is_ok) {
                 pool.discard_worst()
             } else {
                 Default::default()
             };
 
-            (added, discarded)
+            (added, evicted)
         };
 
-        if !discarded.is_empty() {
+        if !evicted.is_empty() {
+            self.on_evicted_transactions(&evicted);
+            let discarded =
+                evicted.into_iter().map(|evicted| evicted.transaction).collect::<Vec<_>>();
+
             // Delete any blobs associated with discarded blob transactions
             self.delete_discarded_blobs(discarded.iter());
             self.event_listener.write().discarded_many(&discarded);
@@ -626,6 +723,10 @@ where
             }
         };
 
+        for err in added.iter().filter_map(|res| res.as_ref().err()) {
+            self.rejection_metrics.record(err);
+        }
+
         added
     }
 
@@ -693,6 +794,26 @@ where
         })
     }
 
+    /// Notify all listeners about transactions evicted from the pool.
+    fn on_evicted_transactions(&self, evicted: &[EvictedTransaction<T::Transaction>]) {
+        let mut listeners = self.eviction_listener.lock();
+        if listeners.is_empty() {
+            return
+        }
+        listeners.retain(|listener| {
+            for event in evicted.iter().map(EvictedTransaction::event) {
+                match listener.try_send(event) {
+                    Ok(()) => {}
+                    Err(mpsc::error::TrySendError::Full(_)) => {
+                        debug!(target: "txpool", ?event, "failed to send evicted transaction; channel full");
+                    }
+                    Err(mpsc::error::TrySendError::Closed(_)) => return false,
+                }
+            }
+            true
+        });
+    }
+
     /// Notifies transaction listeners about changes once a block was processed.
     fn notify_on_new_state(&self, outcome: OnNewCanonicalStateOutcome<T::Transaction>) {
         trace!(target: "txpool", promoted=outcome.promoted.len(), discarded= outcome.discarded.len() ,"notifying listeners on state change");
@@ -708,7 +829,7 @@ where
             listener.send_all(outcome.full_pending_transactions(listener.kind))
         });
 
-        let OnNewCanonicalStateOutcome { mined, promoted, discarded, block_hash } = outcome;
+        let OnNewCanonicalStateOutcome { mined, promoted, discarded, block_hash, .. } = outcome;
 
         // broadcast specific transaction events
         let mut listener = self.event_listener.write();
@@ -836,6 +957,14 @@ where
         self.get_pool_data().best_transactions_with_attributes(best_transactions_attributes)
     }
 
+    /// Returns the number of transactions [`Self::best_transactions_with_attributes`] would yield.
+    pub fn best_transactions_count_with_attributes(
+        &self,
+        best_transactions_attributes: BestTransactionsAttributes,
+    ) -> usize {
+        self.get_pool_data().best_transactions_count_with_attributes(best_transactions_attributes)
+    }
+
     /// Returns only the first `max` transactions in the pending pool.
     pub fn pending_transactions_max(
         &self,
@@ -906,6 +1035,41 @@ where
         removed
     }
 
+    /// Removes all transactions that match the given predicate from the pool and deletes the blob
+    /// sidecars of removed blob transactions.
+    ///
+    /// This behaves as if the transactions got discarded (_not_ mined), effectively introducing a
+    /// nonce gap for the descendants of removed transactions.
+    pub fn remove_transactions_by(
+        &self,
+        predicate: impl FnMut(&ValidPoolTransaction<T::Transaction>) -> bool,
+    ) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
+        let removed = self.pool.write().remove_transactions_by(predicate);
+
+        self.event_listener.write().discarded_many(&removed);
+        self.delete_discarded_blobs(removed.iter());
+
+        removed
+    }
+
+    /// Removes all transactions that match the given predicate because they expired.
+    ///
+    /// This behaves like [`Self::remove_transactions_by`] and additionally notifies the eviction
+    /// listeners with [`EvictionReason::Expired`].
+    pub fn remove_expired_transactions(
+        &self,
+        predicate: impl FnMut(&ValidPoolTransaction<T::Transaction>) -> bool,
+    ) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
+        let evicted = self.pool.write().remove_expired_transactions(predicate);
+        self.on_evicted_transactions(&evicted);
+
+        let removed = evicted.into_iter().map(|evicted| evicted.transaction).collect::<Vec<_>>();
+        self.event_listener.write().discarded_many(&removed);
+        self.delete_discarded_blobs(removed.iter());
+
+        removed
+    }
+
     /// Removes and returns all transactions by the specified sender from the pool.
     pub fn remove_transactions_by_sender(
         &self,
@@ -936,6 +1100,17 @@ where
         self.get_pool_data().get(tx_hash)
     }
 
+    /// Returns `true` if the transaction with the given hash is in the pool, regardless of its
+    /// subpool.
+    pub fn contains(&self, tx_hash: &TxHash) -> bool {
+        self.get_pool_data().contains(tx_hash)
+    }
+
+    /// Returns the current state of the transaction with the given hash, if it is in the pool.
+    pub fn transaction_state(&self, tx_hash: &TxHash) -> Option<AddedTransactionState> {
+        self.get_pool_data().transaction_state(tx_hash)
+    }
+
     /// Returns all transactions of the address
     pub fn get_transactions_by_sender(
         &self,
@@ -1316,7 +1491,8 @@ impl<T: PoolTransaction> AddedTransaction<T> {
 }
 
 /// The specific reason why a transaction is queued (not ready for execution)
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum QueuedReason {
     /// Transaction has a nonce gap - missing prior transactions
     NonceGap,
@@ -1391,11 +1567,24 @@ pub(crate) struct OnNewCanonicalStateOutcome<T: PoolTransaction> {
     pub(crate) mined: Vec<TxHash>,
     /// Transactions promoted to the pending pool.
     pub(crate) promoted: Vec<Arc<ValidPoolTransaction<T>>>,
+    /// Transactions moved out of the pending pool.
+    pub(crate) demoted: Vec<Arc<ValidPoolTransaction<T>>>,
     /// transaction that were discarded during the update
     pub(crate) discarded: Vec<Arc<ValidPoolTransaction<T>>>,
 }
 
 impl<T: PoolTransaction> OnNewCanonicalStateOutcome<T> {
+    /// Returns the number of transactions affected by the update.
+    pub(crate) fn summary(&self) -> CanonicalStateUpdateSummary {
+        CanonicalStateUpdateSummary {
+            block_hash: self.block_hash,
+            mined: self.mined.len(),
+            promoted: self.promoted.len(),
+            demoted: self.demoted.len(),
+            discarded: self.discarded.len(),
+        }
+    }
+
     /// Returns all transactions that were promoted to the pending pool and adhere to the given
     /// [`TransactionListenerKind`].
     ///
@@ -1430,11 +1619,18 @@ mod tests {
         identifier::SenderId,
         test_utils::{MockTransaction, TestPoolBuilder},
         validate::ValidTransaction,
-        BlockInfo, PoolConfig, SubPoolLimit, TransactionOrigin, TransactionValidationOutcome, U256,
+        BlockInfo, CanonicalStateUpdate, CanonicalStateUpdateSummary, PoolConfig,
+        PoolTransaction, PoolUpdateKind, SubPoolLimit, TransactionOrigin,
+        TransactionValidationOutcome, SUBPOOL_UPDATE_BATCH_SIZE, U256,
+    };
+    use alloy_eips::{
+        eip4844::{BlobTransactionSidecar, BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_PROOF},
+        eip7594::BlobTransactionSidecarVariant,
     };
-    use alloy_eips::{eip4844::BlobTransactionSidecar, eip7594::BlobTransactionSidecarVariant};
     use alloy_primitives::Address;
-    use std::{fs, path::PathBuf};
+    use reth_execution_types::ChangedAccount;
+    use reth_primitives_traits::SealedBlock;
+    use std::{fs, path::PathBuf, time::Duration};
 
     #[test]
     fn test_discard_blobs_on_blob_tx_eviction() {
@@ -1514,13 +1710,98 @@ mod tests {
         // Assert that the size of the pool's blob component is equal to the maximum blob limit.
         assert_eq!(test_pool.size().blob, blob_limit.max_txs);
 
-        // Assert that the size of the pool's blob_size component matches the expected value.
-        assert_eq!(test_pool.size().blob_size, 1844674407370951000);
+        // Assert that the size of the pool's blob_size component matches the expected value, which
+        // includes the single blob sidecar of each transaction.
+        let sidecar_size = BYTES_PER_BLOB + BYTES_PER_COMMITMENT + BYTES_PER_PROOF;
+        assert_eq!(
+            test_pool.size().blob_size,
+            blob_limit.max_txs * (1844674407370951 + sidecar_size)
+        );
 
         // Assert that the pool's blob store matches the expected blob store.
         assert_eq!(*test_pool.blob_store(), blob_store);
     }
 
+    #[test]
+    fn test_replaced_transaction_maps_to_replacement() {
+        let config =
+            PoolConfig::default().with_replaced_transactions_grace_period(Duration::from_secs(60));
+        let test_pool = &TestPoolBuilder::default().with_config(config).pool;
+
+        let tx = MockTransaction::legacy().with_gas_price(10_000_000_000);
+        let replacement = tx.inc_price_by(tx.get_gas_price()).rng_hash();
+        let (tx_hash, replacement_hash) = (*tx.get_hash(), *replacement.get_hash());
+        let valid = |transaction| TransactionValidationOutcome::Valid {
+            balance: U256::MAX,
+            state_nonce: 0,
+            bytecode_hash: None,
+            transaction: ValidTransaction::Valid(transaction),
+            propagate: true,
+            authorities: None,
+        };
+
+        test_pool.add_transactions(TransactionOrigin::External, [valid(tx.clone())]);
+        assert_eq!(test_pool.replacement_of(&tx_hash), None);
+
+        test_pool.add_transactions(TransactionOrigin::External, [valid(replacement.clone())]);
+        // the replaced transaction is still dropped
+        assert_eq!(test_pool.len(), 1);
+        assert!(!test_pool.contains(&tx_hash));
+        assert_eq!(test_pool.replacement_of(&tx_hash), Some(replacement_hash));
+        assert_eq!(test_pool.replacement_of(&replacement_hash), None);
+
+        // replacements are not recorded by default
+        let test_pool = &TestPoolBuilder::default().pool;
+        test_pool.add_transactions(TransactionOrigin::External, [valid(tx), valid(replacement)]);
+        assert_eq!(test_pool.len(), 1);
+        assert_eq!(test_pool.replacement_of(&tx_hash), None);
+    }
+
+    #[test]
+    fn test_remove_transactions_by_deletes_blobs() {
+        let test_pool = &TestPoolBuilder::default().with_config(Default::default()).pool;
+        test_pool
+            .set_block_info(BlockInfo { pending_blob_fee: Some(10_000_000), ..Default::default() });
+
+        let sidecar = BlobTransactionSidecarVariant::Eip4844(BlobTransactionSidecar::default());
+        let blob_tx = MockTransaction::eip4844_with_sidecar(sidecar.clone());
+        let blob_tx_hash = *blob_tx.get_hash();
+        let tx = MockTransaction::eip1559();
+
+        test_pool.add_transactions(
+            TransactionOrigin::External,
+            [
+                TransactionValidationOutcome::Valid {
+                    balance: U256::from(1_000),
+                    state_nonce: 0,
+                    bytecode_hash: None,
+                    transaction: ValidTransaction::ValidWithSidecar {
+                        transaction: blob_tx,
+                        sidecar,
+                    },
+                    propagate: true,
+                    authorities: None,
+                },
+                TransactionValidationOutcome::Valid {
+                    balance: U256::from(1_000),
+                    state_nonce: 0,
+                    bytecode_hash: None,
+                    transaction: ValidTransaction::Valid(tx),
+                    propagate: true,
+                    authorities: None,
+                },
+            ],
+        );
+        assert_eq!(test_pool.len(), 2);
+        assert!(test_pool.blob_store().contains(blob_tx_hash).unwrap());
+
+        let removed = test_pool.remove_transactions_by(|tx| tx.is_eip4844());
+        assert_eq!(removed.len(), 1);
+        assert_eq!(*removed[0].hash(), blob_tx_hash);
+        assert_eq!(test_pool.len(), 1);
+        assert!(!test_pool.blob_store().contains(blob_tx_hash).unwrap());
+    }
+
     #[test]
     fn test_auths_stored_in_identifiers() {
         // Create a test pool with default configuration.
@@ -1544,4 +1825,97 @@ mod tests {
         let identifiers = test_pool.identifiers.read();
         assert_eq!(identifiers.sender_id(&auth), Some(SenderId::from(1)));
     }
+
+    #[test]
+    fn test_contains_after_removal_and_mining() {
+        let test_pool = &TestPoolBuilder::default().with_config(Default::default()).pool;
+        let valid = |tx| TransactionValidationOutcome::Valid {
+            balance: U256::from(1_000),
+            state_nonce: 0,
+            bytecode_hash: None,
+            transaction: ValidTransaction::Valid(tx),
+            propagate: true,
+            authorities: None,
+        };
+
+        let removed_tx = MockTransaction::eip1559();
+        let mined_tx = MockTransaction::eip1559();
+        let removed_hash = *removed_tx.get_hash();
+        let mined_hash = *mined_tx.get_hash();
+        assert!(!test_pool.contains(&removed_hash));
+
+        test_pool
+            .add_transactions(TransactionOrigin::External, [valid(removed_tx), valid(mined_tx)]);
+        assert!(test_pool.contains(&removed_hash));
+        assert!(test_pool.contains(&mined_hash));
+
+        test_pool.remove_transactions(vec![removed_hash]);
+        assert!(!test_pool.contains(&removed_hash));
+        assert!(test_pool.contains(&mined_hash));
+
+        let tip = SealedBlock::<reth_ethereum_primitives::Block>::default();
+        test_pool.on_canonical_state_change(CanonicalStateUpdate {
+            new_tip: &tip,
+            pending_block_base_fee: 0,
+            pending_block_blob_fee: None,
+            changed_accounts: vec![],
+            mined_transactions: vec![mined_hash],
+            update_kind: PoolUpdateKind::Commit,
+        });
+        assert!(!test_pool.contains(&mined_hash));
+    }
+
+    #[test]
+    fn test_canonical_state_change_applies_all_batches() {
+        let test_pool = &TestPoolBuilder::default().with_config(Default::default()).pool;
+
+        // more senders than rechecked in a single batch, all queued because of a nonce gap
+        let num_senders = SUBPOOL_UPDATE_BATCH_SIZE + 10;
+        let txs = (0..num_senders)
+            .map(|_| MockTransaction::eip1559().inc_nonce())
+            .collect::<Vec<_>>();
+        let changed_accounts = txs
+            .iter()
+            .map(|tx| ChangedAccount {
+                address: tx.sender(),
+                nonce: 1,
+                balance: U256::from(1_000),
+            })
+            .collect();
+        test_pool.add_transactions(
+            TransactionOrigin::External,
+            txs.into_iter().map(|tx| TransactionValidationOutcome::Valid {
+                balance: U256::from(1_000),
+                state_nonce: 0,
+                bytecode_hash: None,
+                transaction: ValidTransaction::Valid(tx),
+                propagate: true,
+                authorities: None,
+            }),
+        );
+        assert_eq!(test_pool.pool.read().queued().len(), num_senders);
+
+        let tip = SealedBlock::<reth_ethereum_primitives::Block>::default();
+        let summary = test_pool.on_canonical_state_change(CanonicalStateUpdate {
+            new_tip: &tip,
+            pending_block_base_fee: 0,
+            pending_block_blob_fee: None,
+            changed_accounts,
+            mined_transactions: vec![],
+            update_kind: PoolUpdateKind::Commit,
+        });
+
+        assert_eq!(
+            summary,
+            CanonicalStateUpdateSummary {
+                block_hash: tip.hash(),
+                mined: 0,
+                promoted: num_senders,
+                demoted: 0,
+                discarded: 0,
+            }
+        );
+        assert!(!test_pool.has_deferred_updates());
+        assert_eq!(test_pool.pool.read().pending().len(), num_senders);
+    }
 }
@@ -1,12 +1,14 @@
 //! Pending transactions
 
+#[cfg(feature = "pending-spill")]
+use crate::pool::spill::{PendingSpill, PendingSpillError};
 use crate::{
     identifier::{SenderId, TransactionId},
     pool::{
         best::{BestTransactions, BestTransactionsWithFees},
         size::SizeTracker,
     },
-    Priority, SubPoolLimit, TransactionOrdering, ValidPoolTransaction,
+    LocalTransactionConfig, Priority, SubPoolLimit, TransactionOrdering, ValidPoolTransaction,
 };
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::{
@@ -47,9 +49,20 @@ pub struct PendingPool<T: TransactionOrdering> {
     ///
     /// See also [`reth_primitives_traits::InMemorySize::size`].
     size_of: SizeTracker,
+    /// Keeps track of the estimated size of the blob sidecars of the blob transactions in this
+    /// pool, which are kept in the blob store.
+    ///
+    /// See also [`ValidPoolTransaction::blob_sidecar_size`].
+    blob_sidecar_size_of: SizeTracker,
+    /// The max combined size of the blob sidecars of the transactions in this pool.
+    ///
+    /// Unlimited if `None`.
+    max_blob_sidecar_size: Option<usize>,
     /// Used to broadcast new transactions that have been added to the `PendingPool` to existing
     /// `static_files` of this pool.
     new_transaction_notifier: broadcast::Sender<PendingTransaction<T>>,
+    /// Determines which transactions are local and therefore exempt from eviction.
+    local_transactions_config: LocalTransactionConfig,
 }
 
 // === impl PendingPool ===
@@ -70,10 +83,35 @@ impl<T: TransactionOrdering> PendingPool<T> {
             independent_transactions: Default::default(),
             highest_nonces: Default::default(),
             size_of: Default::default(),
+            blob_sidecar_size_of: Default::default(),
+            max_blob_sidecar_size: None,
             new_transaction_notifier,
+            local_transactions_config: Default::default(),
         }
     }
 
+    /// Sets the [`LocalTransactionConfig`] that determines which transactions are exempt from
+    /// eviction when the pool is truncated.
+    pub fn with_local_transactions_config(
+        mut self,
+        local_transactions_config: LocalTransactionConfig,
+    ) -> Self {
+        self.local_transactions_config = local_transactions_config;
+        self
+    }
+
+    /// Sets the max combined size of the blob sidecars of the transactions in this pool.
+    ///
+    /// The sidecars are not part of the size of this pool, if this limit is exceeded the pool is
+    /// truncated until it is met, see [`Self::truncate_pool`].
+    pub const fn with_max_blob_sidecar_size(
+        mut self,
+        max_blob_sidecar_size: Option<usize>,
+    ) -> Self {
+        self.max_blob_sidecar_size = max_blob_sidecar_size;
+        self
+    }
+
     /// Clear all transactions from the pool without resetting other values.
     /// Used for atomic reordering during basefee update.
     ///
@@ -84,6 +122,7 @@ impl<T: TransactionOrdering> PendingPool<T> {
         self.independent_transactions.clear();
         self.highest_nonces.clear();
         self.size_of.reset();
+        self.blob_sidecar_size_of.reset();
         std::mem::take(&mut self.by_id)
     }
 
@@ -158,6 +197,41 @@ impl<T: TransactionOrdering> PendingPool<T> {
         BestTransactionsWithFees { best, base_fee, base_fee_per_blob_gas }
     }
 
+    /// Returns the number of transactions [`Self::best_with_unlocked_and_attributes`] yields if
+    /// it is drained, without creating the iterator.
+    ///
+    /// The transactions of a sender are yielded in nonce order until the first transaction that
+    /// does not satisfy the given fees, which also invalidates all of its descendants.
+    pub(crate) fn best_with_unlocked_and_attributes_count(
+        &self,
+        unlocked: &[Arc<ValidPoolTransaction<T::Transaction>>],
+        base_fee: u64,
+        base_fee_per_blob_gas: u64,
+    ) -> usize {
+        let all = self
+            .by_id
+            .iter()
+            .map(|(id, tx)| (id, &tx.transaction))
+            .chain(unlocked.iter().map(|tx| (tx.id(), tx)))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut count = 0;
+        let mut invalid_sender = None;
+        for (id, tx) in all {
+            if invalid_sender == Some(id.sender) {
+                continue
+            }
+            if tx.max_fee_per_gas() >= base_fee as u128 &&
+                tx.max_fee_per_blob_gas().is_none_or(|fee| fee >= base_fee_per_blob_gas as u128)
+            {
+                count += 1;
+            } else {
+                invalid_sender = Some(id.sender);
+            }
+        }
+        count
+    }
+
     /// Returns an iterator over all transactions in the pool
     pub(crate) fn all(
         &self,
@@ -200,6 +274,7 @@ impl<T: TransactionOrdering> PendingPool<T> {
                 }
             } else {
                 self.size_of += tx.transaction.size();
+                self.blob_sidecar_size_of += tx.transaction.blob_sidecar_size();
                 self.update_independents_and_highest_nonces(&tx);
                 self.by_id.insert(id, tx);
             }
@@ -245,6 +320,7 @@ impl<T: TransactionOrdering> PendingPool<T> {
                 tx.priority = self.ordering.priority(&tx.transaction.transaction, base_fee);
 
                 self.size_of += tx.transaction.size();
+                self.blob_sidecar_size_of += tx.transaction.blob_sidecar_size();
                 self.update_independents_and_highest_nonces(&tx);
                 self.by_id.insert(id, tx);
             }
@@ -296,6 +372,7 @@ impl<T: TransactionOrdering> PendingPool<T> {
 
         // keep track of size
         self.size_of += tx.size();
+        self.blob_sidecar_size_of += tx.blob_sidecar_size();
 
         let tx_id = *tx.id();
 
@@ -333,6 +410,7 @@ impl<T: TransactionOrdering> PendingPool<T> {
 
         let tx = self.by_id.remove(id)?;
         self.size_of -= tx.transaction.size();
+        self.blob_sidecar_size_of -= tx.transaction.blob_sidecar_size();
 
         match self.highest_nonces.entry(id.sender) {
             Entry::Occupied(mut entry) => {
@@ -418,6 +496,10 @@ impl<T: TransactionOrdering> PendingPool<T> {
         let original_size = self.size();
         let mut total_size = 0;
 
+        // track total size of the blob sidecars of transactions to remove
+        let original_blob_sidecar_size = self.blob_sidecar_size();
+        let mut total_blob_sidecar_size = 0;
+
         loop {
             // check how many unique senders were removed last iteration
             let unique_removed = unique_senders - self.highest_nonces.len();
@@ -436,7 +518,10 @@ impl<T: TransactionOrdering> PendingPool<T> {
             // loop through the highest nonces set, removing transactions until we reach the limit
             for tx in worst_transactions {
                 // return early if the pool is under limits
-                if !limit.is_exceeded(original_length - total_removed, original_size - total_size) ||
+                let size = original_size - total_size;
+                let blob_sidecar_size = original_blob_sidecar_size - total_blob_sidecar_size;
+                if (!limit.is_exceeded(original_length - total_removed, size) &&
+                    !self.exceeds_blob_sidecar_limit(blob_sidecar_size)) ||
                     non_local_senders == 0
                 {
                     // need to remove remaining transactions before exiting
@@ -449,7 +534,10 @@ impl<T: TransactionOrdering> PendingPool<T> {
                     return
                 }
 
-                if !remove_locals && tx.transaction.is_local() {
+                if !remove_locals &&
+                    self.local_transactions_config
+                        .is_local(tx.transaction.origin, tx.transaction.sender_ref())
+                {
                     let sender_id = tx.transaction.sender_id();
                     if local_senders.insert(sender_id) {
                         non_local_senders -= 1;
@@ -458,6 +546,7 @@ impl<T: TransactionOrdering> PendingPool<T> {
                 }
 
                 total_size += tx.transaction.size();
+                total_blob_sidecar_size += tx.transaction.blob_sidecar_size();
                 total_removed += 1;
                 removed.push(*tx.transaction.id());
             }
@@ -510,10 +599,18 @@ impl<T: TransactionOrdering> PendingPool<T> {
         removed
     }
 
-    /// Returns true if the pool exceeds the given limit
+    /// Returns true if the pool exceeds the given limit or the limit of the blob sidecars
     #[inline]
     pub(crate) fn exceeds(&self, limit: &SubPoolLimit) -> bool {
-        limit.is_exceeded(self.len(), self.size())
+        limit.is_exceeded(self.len(), self.size()) ||
+            self.exceeds_blob_sidecar_limit(self.blob_sidecar_size())
+    }
+
+    /// Returns true if the given size of blob sidecars exceeds the configured max blob sidecar
+    /// size.
+    #[inline]
+    pub(crate) fn exceeds_blob_sidecar_limit(&self, blob_sidecar_size: usize) -> bool {
+        self.max_blob_sidecar_size.is_some_and(|max| blob_sidecar_size > max)
     }
 
     /// The reported size of all transactions in this pool.
@@ -521,6 +618,11 @@ impl<T: TransactionOrdering> PendingPool<T> {
         self.size_of.into()
     }
 
+    /// The estimated size of the blob sidecars of all blob transactions in this pool.
+    pub(crate) fn blob_sidecar_size(&self) -> usize {
+        self.blob_sidecar_size_of.into()
+    }
+
     /// Number of transactions in the entire pool
     pub(crate) fn len(&self) -> usize {
         self.by_id.len()
@@ -598,6 +700,94 @@ impl<T: TransactionOrdering> PendingPool<T> {
     }
 }
 
+#[cfg(feature = "pending-spill")]
+impl<T: TransactionOrdering> PendingPool<T> {
+    /// Moves the lowest priority transactions to disk until at most `max_in_memory` transactions
+    /// remain in memory, and returns the number of spilled transactions.
+    ///
+    /// Only the highest nonce transaction of a sender is spilled, and never its independent
+    /// transaction, so the spilled transactions of a sender are always preceded by an in-memory
+    /// one. Blob transactions are not spilled.
+    ///
+    /// Spilled transactions are no longer part of this pool, they are only returned by
+    /// [`Self::best_with_spilled`] and must be put back with [`Self::restore_spilled`] before
+    /// the pool is updated for a new block.
+    pub fn spill_to(
+        &mut self,
+        spill: &mut PendingSpill<T>,
+        max_in_memory: usize,
+    ) -> Result<usize, PendingSpillError> {
+        let mut spilled = 0;
+        while self.len() > max_in_memory {
+            let Some(worst) = self
+                .highest_nonces
+                .values()
+                .filter(|tx| {
+                    !tx.transaction.is_eip4844() &&
+                        self.independent_transactions[&tx.transaction.sender_id()]
+                            .transaction
+                            .nonce() !=
+                            tx.transaction.nonce()
+                })
+                .min()
+                .cloned()
+            else {
+                break
+            };
+
+            let id = *worst.transaction.id();
+            self.remove_transaction(&id);
+            if let Err(err) = spill.insert(worst.clone()) {
+                self.restore_transaction(worst);
+                return Err(err)
+            }
+            spilled += 1;
+        }
+        Ok(spilled)
+    }
+
+    /// Same as [`Self::best`] but also returns the transactions that were spilled to disk.
+    ///
+    /// Spilled transactions keep the priority and submission id they had in this pool, so the
+    /// iterator yields them in the same order as if they were never spilled.
+    pub fn best_with_spilled(
+        &self,
+        spill: &PendingSpill<T>,
+    ) -> Result<BestTransactions<T>, PendingSpillError> {
+        let mut best = self.best();
+        for tx in spill.all()? {
+            best.all.insert(*tx.transaction.id(), tx);
+        }
+        Ok(best)
+    }
+
+    /// Moves all spilled transactions back into this pool.
+    ///
+    /// The transactions keep their priority and submission id, so this should be called before
+    /// the base fee is updated.
+    pub fn restore_spilled(
+        &mut self,
+        spill: &mut PendingSpill<T>,
+    ) -> Result<(), PendingSpillError> {
+        let ids = spill.all_ids().copied().collect::<Vec<_>>();
+        for id in ids {
+            if let Some(tx) = spill.remove(&id)? {
+                self.restore_transaction(tx);
+            }
+        }
+        Ok(())
+    }
+
+    /// Inserts a transaction that was previously part of this pool, keeping its priority and
+    /// submission id.
+    fn restore_transaction(&mut self, tx: PendingTransaction<T>) {
+        self.size_of += tx.transaction.size();
+        self.blob_sidecar_size_of += tx.transaction.blob_sidecar_size();
+        self.update_independents_and_highest_nonces(&tx);
+        self.by_id.insert(*tx.transaction.id(), tx);
+    }
+}
+
 /// A transaction that is ready to be included in a block.
 #[derive(Debug)]
 pub struct PendingTransaction<T: TransactionOrdering> {
@@ -659,6 +849,7 @@ mod tests {
         PoolTransaction,
     };
     use alloy_consensus::{Transaction, TxType};
+    use alloy_eips::{eip4844::BlobTransactionSidecar, eip7594::BlobTransactionSidecarVariant};
     use alloy_primitives::address;
     use std::collections::HashSet;
 
@@ -877,6 +1068,61 @@ mod tests {
         assert_eq!(pending, expected_pending);
     }
 
+    #[test]
+    fn truncate_accounts_blob_sidecar_size() {
+        let mut f = MockTransactionFactory::default();
+
+        // a single blob transaction that pays the lowest tip
+        let sidecar = BlobTransactionSidecar {
+            blobs: vec![Default::default()],
+            commitments: vec![Default::default()],
+            proofs: vec![Default::default()],
+        };
+        let blob_tx =
+            MockTransaction::eip4844_with_sidecar(BlobTransactionSidecarVariant::Eip4844(sidecar))
+                .with_max_fee(200)
+                .with_priority_fee(1)
+                .with_size(1_000);
+        let blob_tx = f.validated_arc(blob_tx);
+        let sidecar_size = blob_tx.blob_sidecar_size();
+        assert!(sidecar_size > 0);
+
+        let mut pool = PendingPool::new(MockOrdering::default())
+            .with_max_blob_sidecar_size(Some(sidecar_size - 1));
+
+        // four small transactions from different senders that pay a high tip
+        let small_txs = (0..4)
+            .map(|_| {
+                MockTransaction::eip1559().with_max_fee(200).with_priority_fee(100).with_size(1_000)
+            })
+            .collect::<Vec<_>>();
+        for tx in &small_txs {
+            pool.add_transaction(f.validated_arc(tx.clone()), 0);
+        }
+        pool.add_transaction(blob_tx.clone(), 0);
+        pool.assert_invariants();
+
+        // the sidecar is tracked separately from the size of the pool
+        assert_eq!(pool.size(), 5 * 1_000);
+        assert_eq!(pool.blob_sidecar_size(), sidecar_size);
+
+        // the pool is within the size limit but exceeds the blob sidecar limit
+        let limit = SubPoolLimit { max_txs: usize::MAX, max_size: 5_000 };
+        assert!(pool.exceeds(&limit));
+
+        // evicting the blob transaction alone frees its sidecar, evicting the small
+        // transactions wouldn't help
+        let removed = pool.truncate_pool(limit);
+        pool.assert_invariants();
+
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].hash(), blob_tx.hash());
+        assert_eq!(pool.len(), small_txs.len());
+        assert_eq!(pool.size(), 4 * 1_000);
+        assert_eq!(pool.blob_sidecar_size(), 0);
+        assert!(!pool.exceeds(&limit));
+    }
+
     // <https://github.com/paradigmxyz/reth/issues/12340>
     #[test]
     fn test_eligible_updates_promoted() {
@@ -1068,6 +1314,38 @@ mod tests {
         assert!(pool.get_txs_by_sender(sender_c).is_empty());
     }
 
+    #[test]
+    fn local_addresses_exempt_from_eviction() {
+        let mut f = MockTransactionFactory::default();
+
+        // Addresses for simulated senders A, B
+        let a = address!("0x000000000000000000000000000000000000000a");
+        let b = address!("0x000000000000000000000000000000000000000b");
+
+        // sender A is configured as a local address, but submits external transactions
+        let local_config =
+            LocalTransactionConfig { local_addresses: HashSet::from([a]), ..Default::default() };
+        let mut pool =
+            PendingPool::new(MockOrdering::default()).with_local_transactions_config(local_config);
+
+        let a_txs = MockTransactionSet::sequential_transactions_by_sender(a, 3, TxType::Eip1559);
+        let b_txs = MockTransactionSet::sequential_transactions_by_sender(b, 3, TxType::Eip1559);
+        for tx in [a_txs.into_vec(), b_txs.into_vec()].concat() {
+            pool.add_transaction(f.validated_arc(tx), 0);
+        }
+        pool.assert_invariants();
+
+        let pool_limit = SubPoolLimit { max_txs: 3, max_size: usize::MAX };
+        pool.truncate_pool(pool_limit);
+        pool.assert_invariants();
+
+        let sender_a = f.ids.sender_id(&a).unwrap();
+        let sender_b = f.ids.sender_id(&b).unwrap();
+
+        assert_eq!(pool.get_txs_by_sender(sender_a).len(), 3);
+        assert!(pool.get_txs_by_sender(sender_b).is_empty());
+    }
+
     #[test]
     fn test_remove_non_highest_keeps_highest() {
         let mut f = MockTransactionFactory::default();
@@ -0,0 +1,126 @@
+//! Short-lived record of replaced transactions.
+
+use alloy_primitives::TxHash;
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+/// The maximum number of replacements that are remembered at the same time.
+pub(crate) const MAX_REPLACED_TRANSACTIONS: usize = 10_000;
+
+/// Remembers which transaction replaced which for a grace period after the replacement.
+///
+/// This is purely observational: the replaced transaction is removed from the pool as usual and
+/// only the relationship of the two hashes is kept. At most `max_replacements` are remembered,
+/// the oldest replacements are forgotten first even if they are within the grace period.
+#[derive(Debug)]
+pub(crate) struct ReplacedTransactions {
+    /// How long a replacement is remembered.
+    grace_period: Duration,
+    /// The maximum number of recorded replacements.
+    max_replacements: usize,
+    /// Maps the hash of a replaced transaction to the hash of its replacement and the time of the
+    /// replacement.
+    replacements: HashMap<TxHash, (TxHash, Instant)>,
+    /// Replaced hashes in the order they were recorded, used to evict expired entries.
+    order: VecDeque<(TxHash, Instant)>,
+}
+
+impl ReplacedTransactions {
+    /// Creates an empty record that remembers up to `max_replacements` replacements for the given
+    /// grace period.
+    pub(crate) fn new(grace_period: Duration, max_replacements: usize) -> Self {
+        Self {
+            grace_period,
+            max_replacements,
+            replacements: Default::default(),
+            order: Default::default(),
+        }
+    }
+
+    /// Records that `replaced` was replaced by `replacement` at `now`.
+    pub(crate) fn insert(&mut self, replaced: TxHash, replacement: TxHash, now: Instant) {
+        self.evict_expired(now);
+        self.replacements.insert(replaced, (replacement, now));
+        self.order.push_back((replaced, now));
+        while self.order.len() > self.max_replacements {
+            self.evict_oldest();
+        }
+    }
+
+    /// Returns the hash of the transaction that replaced the given transaction, if the
+    /// replacement happened within the grace period before `now`.
+    pub(crate) fn get(&self, replaced: &TxHash, now: Instant) -> Option<TxHash> {
+        self.replacements
+            .get(replaced)
+            .filter(|(_, at)| now.saturating_duration_since(*at) <= self.grace_period)
+            .map(|(replacement, _)| *replacement)
+    }
+
+    /// Removes all replacements that are older than the grace period.
+    fn evict_expired(&mut self, now: Instant) {
+        while let Some((_, at)) = self.order.front() {
+            if now.saturating_duration_since(*at) <= self.grace_period {
+                break
+            }
+            self.evict_oldest();
+        }
+    }
+
+    /// Removes the oldest recorded replacement.
+    fn evict_oldest(&mut self) {
+        let Some((hash, at)) = self.order.pop_front() else { return };
+        // the hash may have been recorded again since
+        if self.replacements.get(&hash).is_some_and(|(_, recorded)| *recorded == at) {
+            self.replacements.remove(&hash);
+        }
+    }
+
+    /// Returns the number of recorded replacements, including expired ones that weren't evicted
+    /// yet.
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.replacements.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replacements_expire_after_grace_period() {
+        let grace_period = Duration::from_secs(10);
+        let mut replaced = ReplacedTransactions::new(grace_period, MAX_REPLACED_TRANSACTIONS);
+        let start = Instant::now();
+        let (old, new) = (TxHash::with_last_byte(1), TxHash::with_last_byte(2));
+
+        replaced.insert(old, new, start);
+        assert_eq!(replaced.get(&old, start), Some(new));
+        assert_eq!(replaced.get(&old, start + grace_period), Some(new));
+        assert_eq!(replaced.get(&new, start), None);
+        assert_eq!(replaced.get(&old, start + grace_period + Duration::from_secs(1)), None);
+
+        // expired entries are evicted on the next insert
+        let later = start + 2 * grace_period;
+        replaced.insert(new, TxHash::with_last_byte(3), later);
+        assert_eq!(replaced.len(), 1);
+        assert_eq!(replaced.get(&new, later), Some(TxHash::with_last_byte(3)));
+    }
+
+    #[test]
+    fn oldest_replacements_evicted_when_full() {
+        let mut replaced = ReplacedTransactions::new(Duration::from_secs(10), 2);
+        let now = Instant::now();
+        let hash = TxHash::with_last_byte;
+
+        replaced.insert(hash(1), hash(2), now);
+        replaced.insert(hash(3), hash(4), now);
+        replaced.insert(hash(5), hash(6), now);
+        assert_eq!(replaced.len(), 2);
+        assert_eq!(replaced.get(&hash(1), now), None);
+        assert_eq!(replaced.get(&hash(3), now), Some(hash(4)));
+        assert_eq!(replaced.get(&hash(5), now), Some(hash(6)));
+    }
+}
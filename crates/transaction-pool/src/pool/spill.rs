@@ -0,0 +1,326 @@
+//! Disk-backed overflow for the pending pool.
+
+use crate::{
+    identifier::{SenderId, TransactionId},
+    pool::pending::PendingTransaction,
+    PoolTransaction, Priority, TransactionOrdering, TransactionOrigin, ValidPoolTransaction,
+};
+use alloy_eips::eip2718::{Decodable2718, Encodable2718};
+use alloy_primitives::{Address, TxHash};
+use reth_primitives_traits::Recovered;
+use std::{
+    collections::BTreeMap, fs, io, ops::Bound::Unbounded, path::PathBuf, sync::Arc, time::Instant,
+};
+use tracing::debug;
+
+/// Pending transactions that were moved out of memory and into a directory on disk.
+///
+/// Only the transaction itself is written to disk, one file per transaction, containing the
+/// sender followed by the EIP-2718 encoding of the consensus transaction. The priority and
+/// submission id assigned by the [`PendingPool`](crate::pool::PendingPool) as well as the pool
+/// metadata are kept in memory, so a reloaded transaction is identical to the spilled one and
+/// ordered the same.
+///
+/// Blob transactions can't be spilled, because their consensus encoding doesn't include the
+/// sidecar.
+#[derive(Debug)]
+pub struct PendingSpill<T: TransactionOrdering> {
+    /// Directory the transactions are written to.
+    dir: PathBuf,
+    /// The spilled transactions, without the transaction itself.
+    spilled: BTreeMap<TransactionId, SpilledTransaction<T>>,
+    /// Combined size of all spill files in bytes.
+    size_on_disk: usize,
+}
+
+impl<T: TransactionOrdering> PendingSpill<T> {
+    /// Opens the spill directory at the given path.
+    ///
+    /// Spilled transactions don't outlive the pool, so any existing content of the directory is
+    /// deleted.
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self, PendingSpillError> {
+        let dir = dir.into();
+        match fs::remove_dir_all(&dir) {
+            Ok(_) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(PendingSpillError::Open(dir, err)),
+        }
+        fs::create_dir_all(&dir).map_err(|err| PendingSpillError::Open(dir.clone(), err))?;
+        Ok(Self { dir, spilled: Default::default(), size_on_disk: 0 })
+    }
+
+    /// Returns the number of spilled transactions.
+    pub fn len(&self) -> usize {
+        self.spilled.len()
+    }
+
+    /// Returns `true` if no transactions are spilled.
+    pub fn is_empty(&self) -> bool {
+        self.spilled.is_empty()
+    }
+
+    /// Returns the combined size of all spilled transactions on disk in bytes.
+    pub const fn size_on_disk(&self) -> usize {
+        self.size_on_disk
+    }
+
+    /// Returns `true` if the transaction with the given id is spilled.
+    pub fn contains(&self, id: &TransactionId) -> bool {
+        self.spilled.contains_key(id)
+    }
+
+    /// Returns the ids of all spilled transactions.
+    pub fn all_ids(&self) -> impl Iterator<Item = &TransactionId> + '_ {
+        self.spilled.keys()
+    }
+
+    /// Returns the ids of all spilled transactions of the given sender.
+    pub fn ids_by_sender(&self, sender: SenderId) -> impl Iterator<Item = &TransactionId> + '_ {
+        self.spilled
+            .range((sender.start_bound(), Unbounded))
+            .take_while(move |(other, _)| sender == other.sender)
+            .map(|(id, _)| id)
+    }
+
+    /// Writes the transaction to disk and keeps track of it.
+    pub fn insert(&mut self, tx: PendingTransaction<T>) -> Result<(), PendingSpillError> {
+        let hash = *tx.transaction.hash();
+        if tx.transaction.is_eip4844() {
+            return Err(PendingSpillError::BlobTransaction(hash))
+        }
+
+        let consensus = tx.transaction.transaction.clone_into_consensus();
+        let mut data =
+            Vec::with_capacity(Address::len_bytes() + consensus.inner().encode_2718_len());
+        data.extend_from_slice(consensus.signer().as_slice());
+        consensus.inner().encode_2718(&mut data);
+
+        let path = self.spill_file(hash);
+        fs::write(&path, &data).map_err(|err| PendingSpillError::WriteFile(hash, path, err))?;
+        self.size_on_disk += data.len();
+
+        let PendingTransaction { submission_id, transaction, priority } = tx;
+        self.spilled.insert(
+            transaction.transaction_id,
+            SpilledTransaction {
+                submission_id,
+                priority,
+                hash,
+                size: data.len(),
+                propagate: transaction.propagate,
+                timestamp: transaction.timestamp,
+                origin: transaction.origin,
+                authority_ids: transaction.authority_ids.clone(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Reads the spilled transaction with the given id from disk.
+    pub fn get(
+        &self,
+        id: &TransactionId,
+    ) -> Result<Option<PendingTransaction<T>>, PendingSpillError> {
+        let Some(spilled) = self.spilled.get(id) else { return Ok(None) };
+        self.read(*id, spilled).map(Some)
+    }
+
+    /// Reads all spilled transactions from disk, ordered by id.
+    pub fn all(&self) -> Result<Vec<PendingTransaction<T>>, PendingSpillError> {
+        self.spilled.iter().map(|(id, spilled)| self.read(*id, spilled)).collect()
+    }
+
+    /// Removes the spilled transaction with the given id and returns it.
+    pub fn remove(
+        &mut self,
+        id: &TransactionId,
+    ) -> Result<Option<PendingTransaction<T>>, PendingSpillError> {
+        let Some(spilled) = self.spilled.get(id) else { return Ok(None) };
+        let tx = self.read(*id, spilled)?;
+        self.delete(id);
+        Ok(Some(tx))
+    }
+
+    /// Removes the spilled transaction with the given id without reading it.
+    ///
+    /// Returns `true` if the transaction was spilled.
+    pub fn delete(&mut self, id: &TransactionId) -> bool {
+        let Some(spilled) = self.spilled.remove(id) else { return false };
+        self.size_on_disk -= spilled.size;
+
+        let path = self.spill_file(spilled.hash);
+        if let Err(err) = fs::remove_file(&path) {
+            debug!(target: "txpool", %err, ?path, "Failed to delete spilled transaction file");
+        }
+        true
+    }
+
+    /// Reads and decodes the file of a spilled transaction.
+    fn read(
+        &self,
+        id: TransactionId,
+        spilled: &SpilledTransaction<T>,
+    ) -> Result<PendingTransaction<T>, PendingSpillError> {
+        let hash = spilled.hash;
+        let path = self.spill_file(hash);
+        let data = fs::read(&path).map_err(|err| PendingSpillError::ReadFile(hash, path, err))?;
+
+        let decode_err = |err: String| PendingSpillError::Decode(hash, err);
+        if data.len() < Address::len_bytes() {
+            return Err(decode_err("file too short".to_string()))
+        }
+        let (signer, mut encoded) = data.split_at(Address::len_bytes());
+        let consensus =
+            <<T::Transaction as PoolTransaction>::Consensus as Decodable2718>::decode_2718(
+                &mut encoded,
+            )
+            .map_err(|err| decode_err(err.to_string()))?;
+        let transaction = T::Transaction::try_from_consensus(Recovered::new_unchecked(
+            consensus,
+            Address::from_slice(signer),
+        ))
+        .map_err(|err| decode_err(err.to_string()))?;
+
+        Ok(PendingTransaction {
+            submission_id: spilled.submission_id,
+            transaction: Arc::new(ValidPoolTransaction {
+                transaction,
+                transaction_id: id,
+                propagate: spilled.propagate,
+                timestamp: spilled.timestamp,
+                origin: spilled.origin,
+                authority_ids: spilled.authority_ids.clone(),
+            }),
+            priority: spilled.priority.clone(),
+        })
+    }
+
+    /// Returns the path to the spill file of the given transaction hash.
+    fn spill_file(&self, hash: TxHash) -> PathBuf {
+        self.dir.join(format!("{hash:x}"))
+    }
+}
+
+/// The in-memory part of a spilled transaction.
+#[derive(Debug)]
+struct SpilledTransaction<T: TransactionOrdering> {
+    /// Identifier that tags when the transaction was submitted in the pool.
+    submission_id: u64,
+    /// The priority value assigned by the used `Ordering` function.
+    priority: Priority<T::PriorityValue>,
+    /// Hash of the transaction, names the spill file.
+    hash: TxHash,
+    /// Size of the spill file in bytes.
+    size: usize,
+    /// Whether it is allowed to propagate the transaction.
+    propagate: bool,
+    /// Timestamp when this was added to the pool.
+    timestamp: Instant,
+    /// Where this transaction originated from.
+    origin: TransactionOrigin,
+    /// The sender ids of the 7702 transaction authorities.
+    authority_ids: Option<Vec<SenderId>>,
+}
+
+/// Errors that can occur while spilling pending transactions to disk.
+#[derive(Debug, thiserror::Error)]
+pub enum PendingSpillError {
+    /// The spill directory cannot be opened.
+    #[error("failed to open pending spill directory at {0}: {1}")]
+    Open(PathBuf, io::Error),
+    /// Failure while writing a spill file.
+    #[error("[{0}] failed to write spill file at {1}: {2}")]
+    WriteFile(TxHash, PathBuf, io::Error),
+    /// Failure while reading a spill file.
+    #[error("[{0}] failed to read spill file at {1}: {2}")]
+    ReadFile(TxHash, PathBuf, io::Error),
+    /// A spill file doesn't contain a valid transaction.
+    #[error("[{0}] failed to decode spilled transaction: {1}")]
+    Decode(TxHash, String),
+    /// Blob transactions can't be spilled.
+    #[error("[{0}] blob transactions can't be spilled")]
+    BlobTransaction(TxHash),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        pool::PendingPool, test_utils::TransactionBuilder, CoinbaseTipOrdering,
+        EthPooledTransaction,
+    };
+    use alloy_primitives::B256;
+    use reth_primitives_traits::SignedTransaction;
+
+    fn assert_same_transaction(
+        a: &ValidPoolTransaction<EthPooledTransaction>,
+        b: &ValidPoolTransaction<EthPooledTransaction>,
+    ) {
+        assert_eq!(a.transaction, b.transaction);
+        assert_eq!(a.transaction_id, b.transaction_id);
+        assert_eq!(a.propagate, b.propagate);
+        assert_eq!(a.timestamp, b.timestamp);
+        assert_eq!(a.origin, b.origin);
+        assert_eq!(a.authority_ids, b.authority_ids);
+    }
+
+    #[test]
+    fn spilled_transactions_are_returned_identically() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut spill = PendingSpill::open(dir.path().join("spill")).unwrap();
+        let mut pool = PendingPool::new(CoinbaseTipOrdering::<EthPooledTransaction>::default());
+
+        // 3 senders with 3 transactions each, all with different tips
+        for sender in 0..3u64 {
+            let signer = B256::with_last_byte(sender as u8 + 1);
+            for nonce in 0..3u64 {
+                let tx = TransactionBuilder::default()
+                    .signer(signer)
+                    .nonce(nonce)
+                    .gas_limit(21_000)
+                    .max_fee_per_gas(100)
+                    .max_priority_fee_per_gas(((sender * 7 + nonce * 3) % 10 + 1) as u128)
+                    .into_eip1559()
+                    .try_into_recovered()
+                    .unwrap();
+                let encoded_length = tx.encode_2718_len();
+                let tx = Arc::new(ValidPoolTransaction {
+                    transaction: EthPooledTransaction::new(tx, encoded_length),
+                    transaction_id: TransactionId::new(sender.into(), nonce),
+                    propagate: nonce != 1,
+                    timestamp: Instant::now(),
+                    origin: TransactionOrigin::External,
+                    authority_ids: None,
+                });
+                pool.add_transaction(tx, 0);
+            }
+        }
+        let expected = pool.best().collect::<Vec<_>>();
+        assert_eq!(expected.len(), 9);
+
+        assert_eq!(pool.spill_to(&mut spill, 4).unwrap(), 5);
+        assert_eq!(pool.len(), 4);
+        assert_eq!(spill.len(), 5);
+        assert_eq!(std::fs::read_dir(dir.path().join("spill")).unwrap().count(), 5);
+        // independent transactions stay in memory
+        assert!(pool.best().all(|tx| tx.nonce() == 0 || !spill.contains(tx.id())));
+
+        let best = pool.best_with_spilled(&spill).unwrap().collect::<Vec<_>>();
+        assert_eq!(best.len(), expected.len());
+        for (tx, expected) in best.iter().zip(&expected) {
+            assert_same_transaction(tx, expected);
+        }
+
+        pool.restore_spilled(&mut spill).unwrap();
+        assert!(spill.is_empty());
+        assert_eq!(spill.size_on_disk(), 0);
+        assert_eq!(std::fs::read_dir(dir.path().join("spill")).unwrap().count(), 0);
+        pool.assert_invariants();
+
+        let best = pool.best().collect::<Vec<_>>();
+        assert_eq!(best.len(), expected.len());
+        for (tx, expected) in best.iter().zip(&expected) {
+            assert_same_transaction(tx, expected);
+        }
+    }
+}
@@ -1,7 +1,9 @@
 //! The internal transaction pool implementation.
 
 use crate::{
-    config::{LocalTransactionConfig, TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER},
+    config::{
+        LocalTransactionConfig, SUBPOOL_UPDATE_BATCH_SIZE, TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
+    },
     error::{
         Eip4844PoolTransactionError, Eip7702PoolTransactionError, InvalidPoolTransactionError,
         PoolError, PoolErrorKind,
@@ -11,11 +13,13 @@ use crate::{
     pool::{
         best::BestTransactions,
         blob::BlobTransactions,
+        events::{EvictedTransactionEvent, EvictionReason},
         parked::{BasefeeOrd, ParkedPool, QueuedOrd},
         pending::PendingPool,
         state::{SubPool, TxState},
         update::{Destination, PoolUpdate, UpdateOutcome},
-        AddedPendingTransaction, AddedTransaction, OnNewCanonicalStateOutcome,
+        AddedPendingTransaction, AddedTransaction, AddedTransactionState,
+        OnNewCanonicalStateOutcome,
     },
     traits::{BestTransactionsAttributes, BlockInfo, PoolSize},
     PoolConfig, PoolResult, PoolTransaction, PoolUpdateKind, PriceBumpConfig, TransactionOrdering,
@@ -35,9 +39,9 @@ use rustc_hash::FxHashMap;
 use smallvec::SmallVec;
 use std::{
     cmp::Ordering,
-    collections::{btree_map::Entry, hash_map, BTreeMap, HashMap, HashSet},
+    collections::{btree_map::Entry, hash_map, BTreeMap, BTreeSet, HashMap, HashSet},
     fmt,
-    ops::Bound::{Excluded, Unbounded},
+    ops::Bound::{Excluded, Included, Unbounded},
     sync::Arc,
 };
 use tracing::{trace, warn};
@@ -114,6 +118,8 @@ pub struct TxPool<T: TransactionOrdering> {
     blob_pool: BlobTransactions<T::Transaction>,
     /// All transactions in the pool.
     all_transactions: AllTransactions<T::Transaction>,
+    /// Sub-pool updates that exceeded the per block budget and are yet to be processed.
+    deferred_updates: Option<DeferredUpdates>,
     /// Transaction pool metrics
     metrics: TxPoolMetrics,
 }
@@ -128,11 +134,14 @@ impl<T: TransactionOrdering> TxPool<T> {
             pending_pool: PendingPool::with_buffer(
                 ordering,
                 config.max_new_pending_txs_notifications,
-            ),
+            )
+            .with_local_transactions_config(config.local_transactions_config.clone())
+            .with_max_blob_sidecar_size(config.pending_blob_sidecar_limit),
             queued_pool: Default::default(),
             basefee_pool: Default::default(),
             blob_pool: Default::default(),
             all_transactions: AllTransactions::new(&config),
+            deferred_updates: None,
             config,
             metrics: Default::default(),
         }
@@ -452,6 +461,33 @@ impl<T: TransactionOrdering> TxPool<T> {
         }
     }
 
+    /// Returns the number of transactions the iterator returned by
+    /// [`Self::best_transactions_with_attributes`] yields if it is drained, without creating it.
+    pub(crate) fn best_transactions_count_with_attributes(
+        &self,
+        best_transactions_attributes: BestTransactionsAttributes,
+    ) -> usize {
+        let base_fee = best_transactions_attributes.basefee;
+        let blob_fee = best_transactions_attributes.blob_fee.unwrap_or_default();
+        let pending_blob_fee = self.all_transactions.pending_fees.blob_fee as u64;
+
+        // same selection of unlocked transactions as in `best_transactions_with_attributes`
+        let unlocked = match base_fee.cmp(&self.all_transactions.pending_fees.base_fee) {
+            Ordering::Equal if blob_fee == pending_blob_fee => return self.pending_pool.len(),
+            Ordering::Less => {
+                let mut unlocked = self.basefee_pool.satisfy_base_fee_transactions(base_fee);
+                unlocked.extend(self.blob_pool.satisfy_attributes(best_transactions_attributes));
+                unlocked
+            }
+            _ if blob_fee < pending_blob_fee => {
+                self.blob_pool.satisfy_attributes(best_transactions_attributes)
+            }
+            _ => Vec::new(),
+        };
+
+        self.pending_pool.best_with_unlocked_and_attributes_count(&unlocked, base_fee, blob_fee)
+    }
+
     /// Returns all transactions from the pending sub-pool
     pub(crate) fn pending_transactions(&self) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
         self.pending_pool.all().collect()
@@ -536,7 +572,8 @@ impl<T: TransactionOrdering> TxPool<T> {
     /// Returns `true` if the pool is over its configured limits.
     #[inline]
     pub(crate) fn is_exceeded(&self) -> bool {
-        self.config.is_exceeded(self.size())
+        self.config.is_exceeded(self.size()) ||
+            self.pending_pool.exceeds_blob_sidecar_limit(self.pending_pool.blob_sidecar_size())
     }
 
     /// Returns the transaction for the given hash.
@@ -547,6 +584,16 @@ impl<T: TransactionOrdering> TxPool<T> {
         self.all_transactions.by_hash.get(tx_hash).cloned()
     }
 
+    /// Returns the current state of the transaction with the given hash, if it is in the pool.
+    pub(crate) fn transaction_state(&self, tx_hash: &TxHash) -> Option<AddedTransactionState> {
+        let id = self.all_transactions.by_hash.get(tx_hash)?.id();
+        let tx = self.all_transactions.get(id)?;
+        Some(match tx.state.determine_queued_reason(tx.subpool) {
+            Some(reason) => AddedTransactionState::Queued(reason),
+            None => AddedTransactionState::Pending,
+        })
+    }
+
     /// Returns transactions for the multiple given hashes, if they exist.
     pub(crate) fn get_all(
         &self,
@@ -621,14 +668,78 @@ impl<T: TransactionOrdering> TxPool<T> {
         &mut self,
         changed_senders: FxHashMap<SenderId, SenderInfo>,
     ) -> UpdateOutcome<T::Transaction> {
-        // Apply the state changes to the total set of transactions which triggers sub-pool updates.
-        let updates = self.all_transactions.update(&changed_senders);
+        self.update_accounts_with_budget(changed_senders, Vec::new(), None)
+    }
 
+    /// Updates the transactions for the changed senders and the given additional senders,
+    /// rechecking at most `budget` transactions.
+    ///
+    /// Rechecking the transactions is deferred if it exceeds the budget, the remaining
+    /// transactions are then rechecked with [`Self::process_deferred_updates`]. An unfinished
+    /// recheck is merged with the new account changes and continues where it stopped, so that
+    /// every sender is eventually rechecked with its latest changes.
+    fn update_accounts_with_budget(
+        &mut self,
+        changed_senders: FxHashMap<SenderId, SenderInfo>,
+        senders: Vec<SenderId>,
+        budget: Option<usize>,
+    ) -> UpdateOutcome<T::Transaction> {
         // track changed accounts
-        self.sender_info.extend(changed_senders);
+        self.sender_info.extend(changed_senders.iter().map(|(id, info)| (*id, info.clone())));
+
+        let deferred = match self.deferred_updates.take() {
+            Some(mut deferred) => {
+                deferred.extend(changed_senders, senders);
+                deferred
+            }
+            None => DeferredUpdates::new(changed_senders, senders),
+        };
+
+        self.apply_updates_with_budget(deferred, budget)
+    }
+
+    /// Rechecks the transactions that were deferred because they exceeded the per block budget
+    /// configured by [`PoolConfig::max_subpool_updates_per_block`], again rechecking at most that
+    /// many transactions.
+    ///
+    /// If no per block budget is configured, at most [`SUBPOOL_UPDATE_BATCH_SIZE`] transactions
+    /// are rechecked.
+    pub(crate) fn process_deferred_updates(&mut self) -> UpdateOutcome<T::Transaction> {
+        match self.deferred_updates.take() {
+            Some(deferred) => {
+                let budget = self.subpool_update_budget();
+                self.apply_updates_with_budget(deferred, budget)
+            }
+            None => UpdateOutcome::default(),
+        }
+    }
+
+    /// Returns the number of transactions to recheck for sub-pool changes at once.
+    fn subpool_update_budget(&self) -> Option<usize> {
+        Some(self.config.max_subpool_updates_per_block.unwrap_or(SUBPOOL_UPDATE_BATCH_SIZE))
+    }
+
+    /// Returns `true` if there are sub-pool updates that were deferred and are yet to be
+    /// processed with [`Self::process_deferred_updates`].
+    pub(crate) const fn has_deferred_updates(&self) -> bool {
+        self.deferred_updates.is_some()
+    }
+
+    /// Rechecks the transactions of the remaining senders of the deferred updates and applies the
+    /// resulting sub-pool updates, keeps the rest deferred if the budget is exhausted.
+    fn apply_updates_with_budget(
+        &mut self,
+        mut deferred: DeferredUpdates,
+        budget: Option<usize>,
+    ) -> UpdateOutcome<T::Transaction> {
+        // Apply the state changes to the total set of transactions which triggers sub-pool updates.
+        let updates = deferred.update(&mut self.all_transactions, budget);
+        if !deferred.is_done() {
+            self.deferred_updates = Some(deferred);
+        }
 
         // Process the sub-pool updates
-        let update = self.process_updates(updates);
+        let update = self.process_updates_batched(updates);
         // update the metrics after the update
         self.update_size_metrics();
         update
@@ -638,6 +749,9 @@ impl<T: TransactionOrdering> TxPool<T> {
     ///
     /// This removes all mined transactions, updates according to the new base fee and blob fee and
     /// rechecks sender allowance based on the given changed sender infos.
+    ///
+    /// At most one batch of transactions is rechecked, the remaining transactions are deferred
+    /// and rechecked with [`Self::process_deferred_updates`].
     pub(crate) fn on_canonical_state_change(
         &mut self,
         block_info: BlockInfo,
@@ -666,8 +780,15 @@ impl<T: TransactionOrdering> TxPool<T> {
         let (prev_base_fee, prev_blob_fee) =
             self.update_pending_fees_only(block_info.pending_basefee, block_info.pending_blob_fee);
 
-        // Now update accounts with the new fees already set
-        let mut outcome = self.update_accounts(changed_senders);
+        // Now update accounts with the new fees already set, besides the changed accounts only the
+        // senders of transactions whose fee cap crosses the base fee change need to be rechecked
+        let fee_cap_senders = self
+            .all_transactions
+            .fee_cap_crossing_senders(prev_base_fee, block_info.pending_basefee)
+            .collect();
+        let budget = self.subpool_update_budget();
+        let mut outcome =
+            self.update_accounts_with_budget(changed_senders, fee_cap_senders, budget);
 
         // Apply subpool updates based on fee changes
         // This will record any additional promotions based on fee movements
@@ -683,6 +804,7 @@ impl<T: TransactionOrdering> TxPool<T> {
             block_hash,
             mined: mined_transactions,
             promoted: outcome.promoted,
+            demoted: outcome.demoted,
             discarded: outcome.discarded,
         }
     }
@@ -754,7 +876,7 @@ impl<T: TransactionOrdering> TxPool<T> {
                 self.add_new_transaction(transaction.clone(), replaced_tx.clone(), move_to);
                 // Update inserted transactions metric
                 self.metrics.inserted_transactions.increment(1);
-                let UpdateOutcome { promoted, discarded } = self.process_updates(updates);
+                let UpdateOutcome { promoted, discarded, .. } = self.process_updates(updates);
 
                 let replaced = replaced_tx.map(|(tx, _)| tx);
 
@@ -950,11 +1072,13 @@ impl<T: TransactionOrdering> TxPool<T> {
                 Destination::Pool(move_to) => {
                     debug_assert_ne!(&move_to, &current, "destination must be different");
                     let moved = self.move_transaction(current, move_to, &id);
-                    if matches!(move_to, SubPool::Pending) &&
-                        let Some(tx) = moved
-                    {
-                        trace!(target: "txpool", hash=%tx.transaction.hash(), "Promoted transaction to pending");
-                        outcome.promoted.push(tx);
+                    if let Some(tx) = moved {
+                        if move_to.is_pending() {
+                            trace!(target: "txpool", hash=%tx.transaction.hash(), "Promoted transaction to pending");
+                            outcome.promoted.push(tx);
+                        } else if current.is_pending() {
+                            outcome.demoted.push(tx);
+                        }
                     }
                 }
             }
@@ -963,6 +1087,60 @@ impl<T: TransactionOrdering> TxPool<T> {
         outcome
     }
 
+    /// Applies all the given sub-pool updates in a single batch.
+    ///
+    /// This yields the same result as [`Self::process_updates`], but instead of moving the
+    /// transactions one by one, all discarded transactions are pruned first, then all moved
+    /// transactions are taken out of their current sub-pools and finally inserted into their
+    /// destination sub-pools.
+    ///
+    /// This is used for account updates on new blocks, which can affect a large number of
+    /// transactions at once.
+    fn process_updates_batched(
+        &mut self,
+        updates: Vec<PoolUpdate>,
+    ) -> UpdateOutcome<T::Transaction> {
+        let mut outcome = UpdateOutcome::default();
+        let mut moves = Vec::with_capacity(updates.len());
+
+        for PoolUpdate { id, current, destination } in updates {
+            match destination {
+                Destination::Discard => {
+                    // remove the transaction from the pool and subpool
+                    if let Some(tx) = self.prune_transaction_by_id(&id) {
+                        outcome.discarded.push(tx);
+                    }
+                    self.metrics.removed_transactions.increment(1);
+                }
+                Destination::Pool(move_to) => {
+                    debug_assert_ne!(&move_to, &current, "destination must be different");
+                    moves.push((current, move_to, id));
+                }
+            }
+        }
+
+        // take all moved transactions out of their current sub-pools
+        let moved = moves
+            .into_iter()
+            .filter_map(|(current, move_to, id)| {
+                self.remove_from_subpool(current, &id).map(|tx| (current, move_to, tx))
+            })
+            .collect::<Vec<_>>();
+
+        // and insert them into their destination sub-pools
+        for (current, move_to, tx) in moved {
+            self.add_transaction_to_subpool(move_to, tx.clone());
+            if move_to.is_pending() {
+                trace!(target: "txpool", hash=%tx.transaction.hash(), "Promoted transaction to pending");
+                outcome.promoted.push(tx);
+            } else if current.is_pending() {
+                outcome.demoted.push(tx);
+            }
+        }
+
+        outcome
+    }
+
     /// Moves a transaction from one sub pool to another.
     ///
     /// This will remove the given transaction from one sub-pool and insert it into the other
@@ -1008,6 +1186,23 @@ impl<T: TransactionOrdering> TxPool<T> {
         removed
     }
 
+    /// Removes and returns all transactions that match the given predicate.
+    ///
+    /// This behaves like [`Self::remove_transactions`]: descendants of removed transactions are
+    /// parked because of the introduced nonce gap.
+    pub(crate) fn remove_transactions_by(
+        &mut self,
+        mut predicate: impl FnMut(&ValidPoolTransaction<T::Transaction>) -> bool,
+    ) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
+        let hashes = self
+            .all_transactions
+            .transactions_iter()
+            .filter(|tx| predicate(tx))
+            .map(|tx| *tx.hash())
+            .collect();
+        self.remove_transactions(hashes)
+    }
+
     /// Removes all transactions from the given sender.
     pub(crate) fn remove_transactions_by_sender(
         &mut self,
@@ -1109,6 +1304,16 @@ impl<T: TransactionOrdering> TxPool<T> {
         &mut self,
         tx: &TransactionId,
         removed: &mut Vec<Arc<ValidPoolTransaction<T::Transaction>>>,
+    ) {
+        self.remove_descendants_with(tx, |tx, _| removed.push(tx))
+    }
+
+    /// Like [`Self::remove_descendants`], but passes every removed descendant together with the
+    /// subpool it was removed from to the given closure.
+    fn remove_descendants_with(
+        &mut self,
+        tx: &TransactionId,
+        mut on_removed: impl FnMut(Arc<ValidPoolTransaction<T::Transaction>>, SubPool),
     ) {
         let mut id = *tx;
 
@@ -1117,8 +1322,10 @@ impl<T: TransactionOrdering> TxPool<T> {
             let descendant =
                 self.all_transactions.descendant_txs_exclusive(&id).map(|(id, _)| *id).next();
             if let Some(descendant) = descendant {
-                if let Some(tx) = self.remove_transaction(&descendant) {
-                    removed.push(tx)
+                if let Some((tx, pool)) = self.all_transactions.remove_transaction(&descendant) &&
+                    let Some(tx) = self.remove_from_subpool(pool, tx.id())
+                {
+                    on_removed(tx, pool)
                 }
                 id = descendant;
             } else {
@@ -1172,13 +1379,14 @@ impl<T: TransactionOrdering> TxPool<T> {
     /// If the current size exceeds the given bounds, the worst transactions are evicted from the
     /// pool and returned.
     ///
-    /// This returns all transactions that were removed from the entire pool.
-    pub(crate) fn discard_worst(&mut self) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
+    /// This returns all transactions that were removed from the entire pool, including the
+    /// descendants of evicted transactions, which are removed because of the nonce gap.
+    pub(crate) fn discard_worst(&mut self) -> Vec<EvictedTransaction<T::Transaction>> {
         let mut removed = Vec::new();
 
         // Helper macro that discards the worst transactions for the pools
         macro_rules! discard_worst {
-            ($this:ident, $removed:ident, [$($limit:ident => ($pool:ident, $metric:ident)),* $(,)*]) => {
+            ($this:ident, $removed:ident, [$($limit:ident => ($pool:ident, $subpool:expr, $metric:ident)),* $(,)*]) => {
                 $ (
                 while $this.$pool.exceeds(&$this.config.$limit)
                     {
@@ -1191,6 +1399,12 @@ impl<T: TransactionOrdering> TxPool<T> {
                             $this.$pool.len(),
                         );
 
+                        let reason = if $this.$pool.len() > $this.config.$limit.max_txs {
+                            EvictionReason::CountLimit
+                        } else {
+                            EvictionReason::SizeLimit
+                        };
+
                         // 1. first remove the worst transaction from the subpool
                         let removed_from_subpool = $this.$pool.truncate_pool($this.config.$limit.clone());
 
@@ -1207,15 +1421,29 @@ impl<T: TransactionOrdering> TxPool<T> {
 
                         // 2. remove all transactions from the total set
                         for tx in removed_from_subpool {
+                            // senders that hold more transactions than their account slots allow
+                            // (e.g. exempt local senders) are charged for the eviction
+                            let sender_txs = $this.all_transactions.tx_counter
+                                .get(&tx.sender_id())
+                                .copied()
+                                .unwrap_or_default();
+                            let reason = if sender_txs > $this.all_transactions.max_account_slots {
+                                EvictionReason::AccountSlots
+                            } else {
+                                reason
+                            };
+
                             $this.all_transactions.remove_transaction(tx.id());
 
                             let id = *tx.id();
 
                             // keep track of removed transaction
-                            removed.push(tx);
+                            $removed.push(EvictedTransaction { transaction: tx, subpool: $subpool, reason });
 
                             // 3. remove all its descendants from the entire pool
-                            $this.remove_descendants(&id, &mut $removed);
+                            $this.remove_descendants_with(&id, |transaction, subpool| {
+                                $removed.push(EvictedTransaction { transaction, subpool, reason })
+                            });
                         }
                     }
 
@@ -1225,16 +1453,45 @@ impl<T: TransactionOrdering> TxPool<T> {
 
         discard_worst!(
             self, removed, [
-                pending_limit => (pending_pool, pending_transactions_evicted),
-                basefee_limit => (basefee_pool, basefee_transactions_evicted),
-                blob_limit    => (blob_pool, blob_transactions_evicted),
-                queued_limit  => (queued_pool, queued_transactions_evicted),
+                pending_limit => (pending_pool, SubPool::Pending, pending_transactions_evicted),
+                basefee_limit => (basefee_pool, SubPool::BaseFee, basefee_transactions_evicted),
+                blob_limit    => (blob_pool, SubPool::Blob, blob_transactions_evicted),
+                queued_limit  => (queued_pool, SubPool::Queued, queued_transactions_evicted),
             ]
         );
 
         removed
     }
 
+    /// Removes all transactions that match the given predicate because they expired.
+    ///
+    /// This behaves like [`Self::remove_transactions_by`], but also returns the subpool each
+    /// transaction was removed from.
+    pub(crate) fn remove_expired_transactions(
+        &mut self,
+        mut predicate: impl FnMut(&ValidPoolTransaction<T::Transaction>) -> bool,
+    ) -> Vec<EvictedTransaction<T::Transaction>> {
+        let expired = self
+            .all_transactions
+            .transactions_iter()
+            .filter(|tx| predicate(tx))
+            .map(|tx| *tx.id())
+            .collect::<Vec<_>>();
+        let removed = expired
+            .into_iter()
+            .filter_map(|id| {
+                // removing an expired transaction parks its descendants, so the subpool is looked
+                // up right before the removal
+                let tx = self.all_transactions.txs.get(&id)?;
+                let (subpool, hash) = (tx.subpool, *tx.transaction.hash());
+                let transaction = self.remove_transaction_by_hash(&hash)?;
+                Some(EvictedTransaction { transaction, subpool, reason: EvictionReason::Expired })
+            })
+            .collect();
+        self.update_size_metrics();
+        removed
+    }
+
     /// Number of transactions in the entire pool
     pub(crate) fn len(&self) -> usize {
         self.all_transactions.len()
@@ -1324,6 +1581,11 @@ pub(crate) struct AllTransactions<T: PoolTransaction> {
     by_hash: HashMap<TxHash, Arc<ValidPoolTransaction<T>>>,
     /// _All_ transaction in the pool sorted by their sender and nonce pair.
     txs: BTreeMap<TransactionId, PoolInternalTransaction<T>>,
+    /// _All_ transactions in the pool sorted by their fee cap (`max_fee_per_gas`).
+    ///
+    /// This is used to look up the transactions whose [`TxState::ENOUGH_FEE_CAP_BLOCK`] changes
+    /// with the base fee, without visiting the entire pool.
+    by_fee_cap: BTreeSet<(u128, TransactionId)>,
     /// Tracks the number of transactions by sender that are currently in the pool.
     tx_counter: FxHashMap<SenderId, usize>,
     /// The current block number the pool keeps track of.
@@ -1429,6 +1691,43 @@ impl<T: PoolTransaction> AllTransactions<T> {
     }
 
     /// Rechecks all transactions in the pool against the changes.
+    #[cfg(test)]
+    pub(crate) fn update(
+        &mut self,
+        changed_accounts: &FxHashMap<SenderId, SenderInfo>,
+    ) -> Vec<PoolUpdate> {
+        // pre-allocate a few updates
+        let mut updates = Vec::with_capacity(64);
+        let mut budget = usize::MAX;
+        let senders = self.txs.keys().map(|id| id.sender).collect::<BTreeSet<_>>();
+        for sender in senders {
+            self.update_sender(changed_accounts, sender, &mut budget, &mut updates);
+        }
+        updates
+    }
+
+    /// Returns the senders of all transactions whose fee cap is in `[lower, upper)` for the two
+    /// given base fees.
+    ///
+    /// These are exactly the transactions whose [`TxState::ENOUGH_FEE_CAP_BLOCK`] changes if the
+    /// base fee moves from one to the other, so only these senders need to be rechecked.
+    pub(crate) fn fee_cap_crossing_senders(
+        &self,
+        base_fee: u64,
+        other_base_fee: u64,
+    ) -> impl Iterator<Item = SenderId> + '_ {
+        let lower = base_fee.min(other_base_fee) as u128;
+        let upper = base_fee.max(other_base_fee) as u128;
+        let first_id = SenderId::from(0).into_transaction_id(0);
+        self.by_fee_cap.range((lower, first_id)..(upper, first_id)).map(|(_, id)| id.sender)
+    }
+
+    /// Returns the key of the transaction in [`Self::by_fee_cap`].
+    fn fee_cap_key(tx: &ValidPoolTransaction<T>) -> (u128, TransactionId) {
+        (tx.max_fee_per_gas(), *tx.id())
+    }
+
+    /// Rechecks the transactions of the given sender against the changes.
     ///
     /// Possible changes are:
     ///
@@ -1444,31 +1743,39 @@ impl<T: PoolTransaction> AllTransactions<T> {
     ///
     /// Additionally, this will also update the `cumulative_gas_used` for transactions of a sender
     /// that got transaction included in the block.
-    pub(crate) fn update(
+    ///
+    /// Every visited transaction is deducted from the `budget`.
+    pub(crate) fn update_sender(
         &mut self,
         changed_accounts: &FxHashMap<SenderId, SenderInfo>,
-    ) -> Vec<PoolUpdate> {
-        // pre-allocate a few updates
-        let mut updates = Vec::with_capacity(64);
-
-        let mut iter = self.txs.iter_mut().peekable();
+        sender: SenderId,
+        budget: &mut usize,
+        updates: &mut Vec<PoolUpdate>,
+    ) {
+        let mut iter = self
+            .txs
+            .range_mut((sender.start_bound(), Included(sender.into_transaction_id(u64::MAX))))
+            .peekable();
 
-        // Loop over all individual senders and update all affected transactions.
-        // One sender may have up to `max_account_slots` transactions here, which means, worst case
+        // Update all affected transactions of the sender.
+        // The sender may have up to `max_account_slots` transactions here, which means, worst case
         // `max_accounts_slots` need to be updated, for example if the first transaction is blocked
         // due to too low base fee.
-        // However, we don't have to necessarily check every transaction of a sender. If no updates
-        // are possible (nonce gap) then we can skip to the next sender.
+        // However, we don't have to necessarily check every transaction of the sender. If no
+        // updates are possible (nonce gap) then we can skip the remaining transactions.
 
-        // The `unique_sender` loop will process the first transaction of all senders, update its
+        // The `transactions` loop will process the first transaction of the sender, update its
         // state and internally update all consecutive transactions
         'transactions: while let Some((id, tx)) = iter.next() {
+            *budget = budget.saturating_sub(1);
+
             macro_rules! next_sender {
                 ($iter:ident) => {
                     'this: while let Some((peek, _)) = iter.peek() {
                         if peek.sender != id.sender {
                             break 'this
                         }
+                        *budget = budget.saturating_sub(1);
                         iter.next();
                     }
                 };
@@ -1487,13 +1794,16 @@ impl<T: PoolTransaction> AllTransactions<T> {
                     continue 'transactions
                 }
 
+                let balance = self
+                    .local_transactions_config
+                    .usable_balance(&tx.transaction.sender(), info.balance);
                 let ancestor = TransactionId::ancestor(id.nonce, info.state_nonce, id.sender);
                 // If there's no ancestor then this is the next transaction.
                 if ancestor.is_none() {
                     tx.state.insert(TxState::NO_NONCE_GAPS);
                     tx.state.insert(TxState::NO_PARKED_ANCESTORS);
                     tx.cumulative_cost = U256::ZERO;
-                    if tx.transaction.cost() > &info.balance {
+                    if tx.transaction.cost() > &balance {
                         // sender lacks sufficient funds to pay for this transaction
                         tx.state.remove(TxState::ENOUGH_BALANCE);
                     } else {
@@ -1501,7 +1811,7 @@ impl<T: PoolTransaction> AllTransactions<T> {
                     }
                 }
 
-                Some(&info.balance)
+                Some(balance)
             } else {
                 None
             };
@@ -1518,7 +1828,7 @@ impl<T: PoolTransaction> AllTransactions<T> {
             // Update the first transaction of this sender.
             Self::update_tx_base_fee(self.pending_fees.base_fee, tx);
             // Track if the transaction's sub-pool changed.
-            Self::record_subpool_update(&mut updates, tx);
+            Self::record_subpool_update(updates, tx);
 
             // Track blocking transactions.
             let mut has_parked_ancestor = !tx.state.is_pending();
@@ -1554,7 +1864,7 @@ impl<T: PoolTransaction> AllTransactions<T> {
 
                 // If the account changed in the block, check the balance.
                 if let Some(changed_balance) = changed_balance {
-                    if &cumulative_cost > changed_balance {
+                    if cumulative_cost > changed_balance {
                         // sender lacks sufficient funds to pay for this transaction
                         tx.state.remove(TxState::ENOUGH_BALANCE);
                     } else {
@@ -1572,14 +1882,13 @@ impl<T: PoolTransaction> AllTransactions<T> {
 
                 // Update and record sub-pool changes.
                 Self::update_tx_base_fee(self.pending_fees.base_fee, tx);
-                Self::record_subpool_update(&mut updates, tx);
+                Self::record_subpool_update(updates, tx);
 
                 // Advance iterator
+                *budget = budget.saturating_sub(1);
                 iter.next();
             }
         }
-
-        updates
     }
 
     /// This will update the transaction's `subpool` based on its state.
@@ -1674,6 +1983,7 @@ impl<T: PoolTransaction> AllTransactions<T> {
     ) -> Option<(Arc<ValidPoolTransaction<T>>, SubPool)> {
         let tx = self.by_hash.remove(tx_hash)?;
         let internal = self.txs.remove(&tx.transaction_id)?;
+        self.by_fee_cap.remove(&Self::fee_cap_key(&internal.transaction));
         self.remove_auths(&internal);
         // decrement the counter for the sender.
         self.tx_decr(tx.sender_id());
@@ -1688,6 +1998,7 @@ impl<T: PoolTransaction> AllTransactions<T> {
         tx_id: &TransactionId,
     ) -> Option<(Arc<ValidPoolTransaction<T>>, SubPool)> {
         let internal = self.txs.remove(tx_id)?;
+        self.by_fee_cap.remove(&Self::fee_cap_key(&internal.transaction));
         let tx = self.by_hash.remove(internal.transaction.hash())?;
         self.remove_auths(&internal);
         // decrement the counter for the sender.
@@ -1733,6 +2044,7 @@ impl<T: PoolTransaction> AllTransactions<T> {
         id: &TransactionId,
     ) -> Option<(Arc<ValidPoolTransaction<T>>, SubPool)> {
         let internal = self.txs.remove(id)?;
+        self.by_fee_cap.remove(&Self::fee_cap_key(&internal.transaction));
 
         // decrement the counter for the sender.
         self.tx_decr(internal.transaction.sender_id());
@@ -1914,6 +2226,8 @@ impl<T: PoolTransaction> AllTransactions<T> {
     ) -> InsertResult<T> {
         assert!(on_chain_nonce <= transaction.nonce(), "Invalid transaction");
 
+        let on_chain_balance =
+            self.local_transactions_config.usable_balance(&transaction.sender(), on_chain_balance);
         let mut transaction = self.ensure_valid(transaction, on_chain_nonce)?;
 
         let inserted_tx_id = *transaction.id();
@@ -1979,8 +2293,9 @@ impl<T: PoolTransaction> AllTransactions<T> {
         // try to insert the transaction
         match self.txs.entry(*transaction.id()) {
             Entry::Vacant(entry) => {
-                // Insert the transaction in both maps
+                // Insert the transaction in all maps
                 self.by_hash.insert(*pool_tx.transaction.hash(), pool_tx.transaction.clone());
+                self.by_fee_cap.insert(Self::fee_cap_key(&pool_tx.transaction));
                 entry.insert(pool_tx);
             }
             Entry::Occupied(mut entry) => {
@@ -1988,8 +2303,17 @@ impl<T: PoolTransaction> AllTransactions<T> {
                 let existing_transaction = entry.get().transaction.as_ref();
                 let maybe_replacement = transaction.as_ref();
 
-                // Ensure the new transaction is not underpriced
-                if existing_transaction.is_underpriced(maybe_replacement, &self.price_bumps) {
+                // Ensure the new transaction is not underpriced, local transactions are exempt from
+                // the price bump but must not lower the fees of the transaction they replace
+                let price_bumps = if self
+                    .local_transactions_config
+                    .is_local(maybe_replacement.origin, maybe_replacement.sender_ref())
+                {
+                    PriceBumpConfig { default_price_bump: 0, replace_blob_tx_price_bump: 0 }
+                } else {
+                    self.price_bumps
+                };
+                if existing_transaction.is_underpriced(maybe_replacement, &price_bumps) {
                     return Err(InsertErr::Underpriced {
                         transaction: pool_tx.transaction,
                         existing: *entry.get().transaction.hash(),
@@ -1999,6 +2323,8 @@ impl<T: PoolTransaction> AllTransactions<T> {
                 let new_transaction = pool_tx.transaction.clone();
                 let replaced = entry.insert(pool_tx);
                 self.by_hash.remove(replaced.transaction.hash());
+                self.by_fee_cap.remove(&Self::fee_cap_key(&replaced.transaction));
+                self.by_fee_cap.insert(Self::fee_cap_key(&new_transaction));
                 self.by_hash.insert(new_hash, new_transaction);
 
                 self.remove_auths(&replaced);
@@ -2106,6 +2432,7 @@ impl<T: PoolTransaction> AllTransactions<T> {
     #[cfg(any(test, feature = "test-utils"))]
     pub(crate) fn assert_invariants(&self) {
         assert_eq!(self.by_hash.len(), self.txs.len(), "by_hash.len() != txs.len()");
+        assert_eq!(self.by_fee_cap.len(), self.txs.len(), "by_fee_cap.len() != txs.len()");
         assert!(self.auths.len() <= self.txs.len(), "auths.len() > txs.len()");
     }
 }
@@ -2128,6 +2455,7 @@ impl<T: PoolTransaction> Default for AllTransactions<T> {
             block_gas_limit: ETHEREUM_BLOCK_GAS_LIMIT_30M,
             by_hash: Default::default(),
             txs: Default::default(),
+            by_fee_cap: Default::default(),
             tx_counter: Default::default(),
             last_seen_block_number: Default::default(),
             last_seen_block_hash: Default::default(),
@@ -2190,6 +2518,28 @@ pub(crate) enum InsertErr<T: PoolTransaction> {
     TxTypeConflict { transaction: Arc<ValidPoolTransaction<T>> },
 }
 
+/// A transaction that was evicted from the pool, see [`TxPool::discard_worst`].
+#[derive(Debug)]
+pub(crate) struct EvictedTransaction<T: PoolTransaction> {
+    /// The evicted transaction.
+    pub(crate) transaction: Arc<ValidPoolTransaction<T>>,
+    /// The subpool the transaction was evicted from.
+    pub(crate) subpool: SubPool,
+    /// Why the transaction was evicted.
+    pub(crate) reason: EvictionReason,
+}
+
+impl<T: PoolTransaction> EvictedTransaction<T> {
+    /// Returns the event that is emitted for this eviction.
+    pub(crate) fn event(&self) -> EvictedTransactionEvent {
+        EvictedTransactionEvent {
+            tx_hash: *self.transaction.hash(),
+            subpool: self.subpool,
+            reason: self.reason,
+        }
+    }
+}
+
 /// Transaction was successfully inserted into the pool
 #[derive(Debug)]
 pub(crate) struct InsertOk<T: PoolTransaction> {
@@ -2231,6 +2581,84 @@ impl<T: PoolTransaction> PoolInternalTransaction<T> {
     }
 }
 
+/// Account changes and the senders whose transactions are yet to be rechecked against them.
+///
+/// Rechecking the transactions can be split into multiple steps, each rechecking a bounded number
+/// of transactions, see [`PoolConfig::max_subpool_updates_per_block`].
+#[derive(Debug)]
+struct DeferredUpdates {
+    /// The account changes to recheck the transactions against.
+    changed_senders: FxHashMap<SenderId, SenderInfo>,
+    /// The senders whose transactions are yet to be rechecked.
+    senders: BTreeSet<SenderId>,
+    /// The sender to continue the recheck with.
+    ///
+    /// The recheck wraps around instead of starting over from the first sender, so that the
+    /// senders at the end aren't starved if more senders are added repeatedly.
+    next: SenderId,
+}
+
+impl DeferredUpdates {
+    /// Creates a recheck of the changed senders and the given additional senders, for example the
+    /// senders affected by a base fee change.
+    fn new(
+        changed_senders: FxHashMap<SenderId, SenderInfo>,
+        senders: impl IntoIterator<Item = SenderId>,
+    ) -> Self {
+        let mut deferred = Self {
+            changed_senders: Default::default(),
+            senders: Default::default(),
+            next: SenderId::from(0),
+        };
+        deferred.extend(changed_senders, senders);
+        deferred
+    }
+
+    /// Adds the changed senders and the given additional senders to the recheck.
+    fn extend(
+        &mut self,
+        changed_senders: FxHashMap<SenderId, SenderInfo>,
+        senders: impl IntoIterator<Item = SenderId>,
+    ) {
+        self.senders.extend(changed_senders.keys().copied().chain(senders));
+        self.changed_senders.extend(changed_senders);
+    }
+
+    /// Returns `true` if all senders were rechecked.
+    fn is_done(&self) -> bool {
+        self.senders.is_empty()
+    }
+
+    /// Rechecks the transactions of the remaining senders, at most `budget` transactions, and
+    /// returns the resulting sub-pool updates.
+    ///
+    /// The transactions of at least one sender are rechecked so that this always makes progress.
+    fn update<T: PoolTransaction>(
+        &mut self,
+        all_transactions: &mut AllTransactions<T>,
+        budget: Option<usize>,
+    ) -> Vec<PoolUpdate> {
+        let mut budget = budget.unwrap_or(usize::MAX).max(1);
+        let mut updates = Vec::with_capacity(64);
+        while budget > 0 {
+            let Some(sender) =
+                self.senders.range(self.next..).next().or_else(|| self.senders.first()).copied()
+            else {
+                break
+            };
+            self.senders.remove(&sender);
+            self.next = sender;
+            all_transactions.update_sender(
+                &self.changed_senders,
+                sender,
+                &mut budget,
+                &mut updates,
+            );
+        }
+        updates
+    }
+}
+
 /// Stores relevant context about a sender.
 #[derive(Debug, Clone, Default)]
 pub(crate) struct SenderInfo {
@@ -2253,12 +2681,15 @@ impl SenderInfo {
 mod tests {
     use super::*;
     use crate::{
+        pool::QueuedReason,
         test_utils::{MockOrdering, MockTransaction, MockTransactionFactory, MockTransactionSet},
         traits::TransactionOrigin,
         SubPoolLimit,
     };
     use alloy_consensus::{Transaction, TxType};
+    use alloy_eips::{eip4844::BlobTransactionSidecar, eip7594::BlobTransactionSidecarVariant};
     use alloy_primitives::address;
+    use std::collections::BTreeSet;
 
     #[test]
     fn test_insert_blob() {
@@ -2636,6 +3067,41 @@ mod tests {
         assert_eq!(inserted.subpool, SubPool::Pending);
     }
 
+    #[test]
+    fn test_insert_dipping_into_balance_reserve() {
+        let mut f = MockTransactionFactory::default();
+        let tx = MockTransaction::eip1559().inc_price().inc_limit();
+        let reserve = U256::from(1_000);
+        let config = PoolConfig {
+            local_transactions_config: LocalTransactionConfig::default()
+                .with_balance_reserve(tx.sender(), reserve),
+            ..Default::default()
+        };
+        let mut pool = AllTransactions::new(&config);
+
+        // the balance covers the cost, but not the cost and the reserve
+        let on_chain_balance = *tx.cost() + reserve - U256::from(1);
+        let valid_tx = f.validated(tx);
+        let InsertOk { move_to, state, .. } =
+            pool.insert_tx(valid_tx.clone(), on_chain_balance, 0).unwrap();
+        assert!(!state.contains(TxState::ENOUGH_BALANCE));
+        assert_eq!(move_to, SubPool::Queued);
+
+        // the reserve only applies to the configured sender
+        let other = f.validated(MockTransaction::eip1559().inc_price().inc_limit());
+        let InsertOk { move_to, .. } = pool.insert_tx(other, on_chain_balance, 0).unwrap();
+        assert_eq!(move_to, SubPool::Pending);
+
+        // a balance that covers the cost and the reserve makes the transaction pending
+        let changed_accounts = FxHashMap::from_iter([(
+            valid_tx.sender_id(),
+            SenderInfo { state_nonce: 0, balance: on_chain_balance + U256::from(1) },
+        )]);
+        let updates = pool.update(&changed_accounts);
+        assert_eq!(updates.len(), 1);
+        assert!(matches!(updates[0].destination, Destination::Pool(SubPool::Pending)));
+    }
+
     #[test]
     fn test_simple_insert() {
         let on_chain_balance = U256::ZERO;
@@ -2681,6 +3147,226 @@ mod tests {
         assert!(inserted.state.intersects(expected_state));
     }
 
+    #[test]
+    fn batched_account_updates_match_per_tx_updates() {
+        let mut f = MockTransactionFactory::default();
+        let balance = U256::from(1_000_000_000);
+
+        // sender a: three pending txs, the first two are mined by another node
+        let a = MockTransaction::eip1559().with_gas_limit(21_000);
+        // sender b: two queued txs with a nonce gap that is closed
+        let b = MockTransaction::eip1559().with_gas_limit(21_000).with_nonce(1);
+        // sender c: a pending tx whose sender runs out of balance
+        let c = MockTransaction::eip1559().with_gas_limit(21_000);
+
+        let txs = [a.clone(), a.next(), a.next().next(), b.clone(), b.next(), c.clone()]
+            .into_iter()
+            .map(|tx| f.validated(tx))
+            .collect::<Vec<_>>();
+
+        let mut batched = TxPool::new(MockOrdering::default(), Default::default());
+        let mut per_tx = TxPool::new(MockOrdering::default(), Default::default());
+        for tx in txs {
+            batched.add_transaction(tx.clone(), balance, 0, None).unwrap();
+            per_tx.add_transaction(tx, balance, 0, None).unwrap();
+        }
+        assert_eq!(batched.pending_pool.len(), 4);
+        assert_eq!(batched.queued_pool.len(), 2);
+
+        let sender_id = |tx: &MockTransaction| f.ids.sender_id(&tx.sender()).unwrap();
+        let changed_senders = FxHashMap::from_iter([
+            (sender_id(&a), SenderInfo { state_nonce: 2, balance }),
+            (sender_id(&b), SenderInfo { state_nonce: 1, balance }),
+            (sender_id(&c), SenderInfo { state_nonce: 0, balance: U256::ZERO }),
+        ]);
+
+        let batched_outcome = batched.update_accounts(changed_senders.clone());
+
+        let updates = per_tx.all_transactions.update(&changed_senders);
+        per_tx.sender_info.extend(changed_senders);
+        let per_tx_outcome = per_tx.process_updates(updates);
+
+        let ids = |txs: &[Arc<ValidPoolTransaction<MockTransaction>>]| {
+            txs.iter().map(|tx| *tx.id()).collect::<BTreeSet<_>>()
+        };
+        assert_eq!(ids(&batched_outcome.promoted), ids(&per_tx_outcome.promoted));
+        assert_eq!(ids(&batched_outcome.demoted), ids(&per_tx_outcome.demoted));
+        assert_eq!(ids(&batched_outcome.discarded), ids(&per_tx_outcome.discarded));
+        assert_eq!(batched_outcome.promoted.len(), 2);
+        assert_eq!(batched_outcome.demoted.len(), 1);
+        assert_eq!(batched_outcome.discarded.len(), 2);
+
+        let subpools = |pool: &TxPool<MockOrdering>| {
+            pool.all_transactions
+                .txs
+                .iter()
+                .map(|(id, tx)| (*id, tx.subpool))
+                .collect::<BTreeMap<_, _>>()
+        };
+        assert_eq!(subpools(&batched), subpools(&per_tx));
+        assert_eq!(batched.len(), per_tx.len());
+        assert_eq!(
+            batched.pending_pool.all().map(|tx| *tx.id()).collect::<BTreeSet<_>>(),
+            per_tx.pending_pool.all().map(|tx| *tx.id()).collect::<BTreeSet<_>>()
+        );
+        batched.assert_invariants();
+        per_tx.assert_invariants();
+    }
+
+    #[test]
+    fn base_fee_change_only_rechecks_fee_cap_crossing_txs() {
+        let mut f = MockTransactionFactory::default();
+        let balance = U256::from(1_000_000_000);
+
+        // one pending transaction per sender, and a queued one with a nonce gap
+        let fee_caps = [100u128, 150, 200, 250, 300];
+        let txs = fee_caps
+            .iter()
+            .map(|fee_cap| {
+                f.validated(
+                    MockTransaction::eip1559().with_gas_limit(21_000).with_max_fee(*fee_cap),
+                )
+            })
+            .collect::<Vec<_>>();
+        let queued = f.validated(
+            MockTransaction::eip1559().with_gas_limit(21_000).with_max_fee(180).with_nonce(1),
+        );
+
+        // a budget that covers the transactions crossing the base fee change but not the pool
+        let config = PoolConfig::default().with_max_subpool_updates_per_block(3);
+        let mut pool = TxPool::new(MockOrdering::default(), config);
+        let mut block_info = pool.block_info();
+        block_info.pending_basefee = 120;
+        pool.set_block_info(block_info);
+        for tx in txs.iter().chain([&queued]) {
+            pool.add_transaction(tx.clone(), balance, 0, None).unwrap();
+        }
+        assert_eq!(pool.basefee_pool.len(), 1);
+        assert_eq!(pool.pending_pool.len(), 4);
+        assert_eq!(pool.queued_pool.len(), 1);
+
+        // the fee caps in [120, 220) cross the base fee change
+        let crossing =
+            pool.all_transactions.fee_cap_crossing_senders(220, 120).collect::<BTreeSet<_>>();
+        assert_eq!(
+            crossing,
+            BTreeSet::from([txs[1].sender_id(), queued.sender_id(), txs[2].sender_id()])
+        );
+
+        block_info.pending_basefee = 220;
+        let outcome = pool.on_canonical_state_change(
+            block_info,
+            vec![],
+            Default::default(),
+            PoolUpdateKind::Commit,
+        );
+        pool.assert_invariants();
+
+        // only the crossing transactions were rechecked, which fits into the budget
+        assert!(!pool.has_deferred_updates());
+        assert!(outcome.promoted.is_empty());
+
+        let subpool = |tx: &ValidPoolTransaction<MockTransaction>| {
+            pool.all_transactions.txs.get(tx.id()).unwrap().subpool
+        };
+        assert_eq!(subpool(&txs[0]), SubPool::BaseFee);
+        assert_eq!(subpool(&txs[1]), SubPool::BaseFee);
+        assert_eq!(subpool(&txs[2]), SubPool::BaseFee);
+        assert_eq!(subpool(&txs[3]), SubPool::Pending);
+        assert_eq!(subpool(&txs[4]), SubPool::Pending);
+        assert_eq!(subpool(&queued), SubPool::Queued);
+    }
+
+    #[test]
+    fn deferred_subpool_updates_converge() {
+        let mut f = MockTransactionFactory::default();
+        let balance = U256::from(1_000_000_000);
+
+        // ten senders with three transactions each, some of them priced below the next base fee
+        let senders = (0..10u128)
+            .map(|i| MockTransaction::eip1559().with_gas_limit(21_000).with_max_fee(90 + i * 5))
+            .collect::<Vec<_>>();
+        let txs = senders
+            .iter()
+            .flat_map(|tx| [tx.clone(), tx.next(), tx.next().next()])
+            .map(|tx| f.validated(tx))
+            .collect::<Vec<_>>();
+
+        let config = PoolConfig::default().with_max_subpool_updates_per_block(4);
+        let mut deferred = TxPool::new(MockOrdering::default(), config);
+        let mut immediate = TxPool::new(MockOrdering::default(), Default::default());
+        for tx in txs {
+            deferred.add_transaction(tx.clone(), balance, 0, None).unwrap();
+            immediate.add_transaction(tx, balance, 0, None).unwrap();
+        }
+
+        let sender_id = |i: usize| f.ids.sender_id(&senders[i].sender()).unwrap();
+        let mut block_info = immediate.block_info();
+
+        // first block: mined transactions of some senders, one runs out of balance
+        block_info.pending_basefee = 100;
+        let first = FxHashMap::from_iter([
+            (sender_id(0), SenderInfo { state_nonce: 1, balance }),
+            (sender_id(5), SenderInfo { state_nonce: 0, balance: U256::ZERO }),
+            (sender_id(9), SenderInfo { state_nonce: 2, balance }),
+        ]);
+        for pool in [&mut deferred, &mut immediate] {
+            pool.on_canonical_state_change(
+                block_info,
+                vec![],
+                first.clone(),
+                PoolUpdateKind::Commit,
+            );
+        }
+        assert!(deferred.has_deferred_updates());
+        assert!(!immediate.has_deferred_updates());
+        // the last sender was not rechecked yet
+        assert_eq!(deferred.all_transactions.txs_iter(sender_id(9)).count(), 3);
+        assert_eq!(immediate.all_transactions.txs_iter(sender_id(9)).count(), 1);
+
+        // second block before the deferred updates were processed, restarts the recheck
+        block_info.pending_basefee = 110;
+        let second = FxHashMap::from_iter([
+            (sender_id(1), SenderInfo { state_nonce: 3, balance }),
+            (sender_id(5), SenderInfo { state_nonce: 1, balance }),
+        ]);
+        for pool in [&mut deferred, &mut immediate] {
+            pool.on_canonical_state_change(
+                block_info,
+                vec![],
+                second.clone(),
+                PoolUpdateKind::Commit,
+            );
+        }
+        assert!(deferred.has_deferred_updates());
+
+        let mut steps = 0;
+        while deferred.has_deferred_updates() {
+            deferred.process_deferred_updates();
+            deferred.assert_invariants();
+            steps += 1;
+        }
+        assert!(steps > 1);
+
+        let subpools = |pool: &TxPool<MockOrdering>| {
+            pool.all_transactions
+                .txs
+                .iter()
+                .map(|(id, tx)| (*id, tx.subpool))
+                .collect::<BTreeMap<_, _>>()
+        };
+        assert_eq!(subpools(&deferred), subpools(&immediate));
+        assert_eq!(deferred.size().pending, immediate.size().pending);
+        assert_eq!(deferred.size().basefee, immediate.size().basefee);
+        assert_eq!(deferred.size().queued, immediate.size().queued);
+        assert_eq!(
+            deferred.pending_pool.all().map(|tx| *tx.id()).collect::<BTreeSet<_>>(),
+            immediate.pending_pool.all().map(|tx| *tx.id()).collect::<BTreeSet<_>>()
+        );
+        deferred.assert_invariants();
+        immediate.assert_invariants();
+    }
+
     #[test]
     // Test that on_canonical_state_change doesn't double-process transactions
     // when both fee and account updates would affect the same transaction
@@ -3034,6 +3720,48 @@ mod tests {
         assert_eq!(pool.len(), 1);
     }
 
+    #[test]
+    fn insert_replace_local_exempt_from_price_bump() {
+        let on_chain_balance = U256::ZERO;
+        let on_chain_nonce = 0;
+        let mut f = MockTransactionFactory::default();
+        let mut tx = MockTransaction::eip1559().inc_price().inc_limit();
+        tx.set_priority_fee(100);
+        tx.set_max_fee(100);
+
+        // the sender is configured as a local address
+        let config = PoolConfig {
+            local_transactions_config: LocalTransactionConfig {
+                local_addresses: std::iter::once(tx.sender()).collect(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut pool = AllTransactions::new(&config);
+
+        let first = f.validated(tx.clone());
+        pool.insert_tx(first.clone(), on_chain_balance, on_chain_nonce).unwrap();
+
+        // a replacement with lower fees is still rejected
+        let mut replacement = f.validated(tx.rng_hash());
+        replacement.transaction.set_priority_fee(99);
+        replacement.transaction.set_max_fee(99);
+        let err =
+            pool.insert_tx(replacement.clone(), on_chain_balance, on_chain_nonce).unwrap_err();
+        assert!(matches!(err, InsertErr::Underpriced { .. }));
+        assert!(pool.contains(first.hash()));
+
+        // a replacement without the default price bump of 10% is accepted
+        replacement.transaction.set_priority_fee(101);
+        replacement.transaction.set_max_fee(101);
+        let replacement_hash = *replacement.hash();
+        let res = pool.insert_tx(replacement, on_chain_balance, on_chain_nonce).unwrap();
+        assert_eq!(res.replaced_tx.unwrap().0.hash(), first.hash());
+        assert!(pool.contains(&replacement_hash));
+        assert!(!pool.contains(first.hash()));
+        assert_eq!(pool.len(), 1);
+    }
+
     #[test]
     fn insert_conflicting_type_normal_to_blob() {
         let on_chain_balance = U256::from(10_000);
@@ -3786,6 +4514,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn blob_pool_limit_does_not_evict_pending_txs() {
+        let mut f = MockTransactionFactory::default();
+        let blob_tx = || {
+            let sidecar = BlobTransactionSidecar {
+                blobs: vec![Default::default()],
+                commitments: vec![Default::default()],
+                proofs: vec![Default::default()],
+            };
+            MockTransaction::eip4844_with_sidecar(BlobTransactionSidecarVariant::Eip4844(sidecar))
+                .with_blob_fee(100)
+        };
+
+        // the blob pool only has room for two blob transactions, going by their sidecars
+        let sidecar_size = f.validated(blob_tx()).blob_sidecar_size();
+        let blob_limit = SubPoolLimit::new(usize::MAX, 2 * sidecar_size + 1_000);
+        let mut pool =
+            TxPool::new(MockOrdering::default(), PoolConfig { blob_limit, ..Default::default() });
+        // park the blob transactions in the blob pool
+        pool.all_transactions.pending_fees.blob_fee = 10_000;
+
+        let pending_txs = (0..5)
+            .map(|_| f.validated(MockTransaction::eip1559().with_size(1_000)))
+            .collect::<Vec<_>>();
+        for tx in &pending_txs {
+            pool.add_transaction(tx.clone(), U256::MAX, 0, None).unwrap();
+        }
+        assert_eq!(pool.size().pending, pending_txs.len());
+
+        for _ in 0..5 {
+            pool.add_transaction(f.validated(blob_tx()), U256::MAX, 0, None).unwrap();
+        }
+        assert_eq!(pool.size().blob, 5);
+        assert!(pool.size().blob_size >= 5 * sidecar_size);
+
+        let removed = pool.discard_worst();
+        pool.assert_invariants();
+
+        // only blob transactions were evicted, until the blob pool fits its limit
+        assert_eq!(removed.len(), 3);
+        assert!(removed.iter().all(|evicted| evicted.transaction.is_eip4844()));
+        assert_eq!(pool.size().blob, 2);
+        assert!(pool.size().blob_size <= blob_limit.max_size);
+        assert_eq!(pool.size().pending, pending_txs.len());
+        assert!(pending_txs.iter().all(|tx| pool.contains(tx.hash())));
+    }
+
     #[test]
     fn account_updates_sender_balance() {
         let mut on_chain_balance = U256::from(100);
@@ -3988,6 +4763,59 @@ mod tests {
         assert_eq!(4, pool.pending_transactions().len());
     }
 
+    #[test]
+    fn test_remove_transactions_by_predicate_parks_descendants() {
+        let on_chain_balance = U256::from(10_000);
+        let on_chain_nonce = 0;
+        let mut f = MockTransactionFactory::default();
+        let mut pool = TxPool::new(MockOrdering::default(), Default::default());
+
+        let tx_0 = MockTransaction::eip1559().set_gas_price(100).inc_limit();
+        let tx_1 = tx_0.next().with_gas_price(1_000);
+        let tx_2 = tx_1.next().with_gas_price(100);
+        let tx_3 = tx_2.next();
+        let other = MockTransaction::eip1559().with_gas_price(1_000).inc_limit();
+
+        for tx in [tx_0, tx_1, tx_2, tx_3, other.clone()] {
+            pool.add_transaction(f.validated(tx), on_chain_balance, on_chain_nonce, None).unwrap();
+        }
+        assert_eq!(5, pool.pending_transactions().len());
+
+        // removes the mid-nonce tx of the first sender and the tx of the other sender
+        let removed = pool.remove_transactions_by(|tx| tx.max_fee_per_gas() >= 1_000);
+        assert_eq!(2, removed.len());
+        assert!(removed.iter().any(|tx| tx.hash() == other.hash()));
+
+        // the descendants of the removed tx are now gapped and must be parked
+        assert_eq!(1, pool.pending_transactions().len());
+        assert_eq!(2, pool.queued_transactions().len());
+        assert!(pool.queued_transactions().iter().all(|tx| tx.nonce() > 1));
+        assert_eq!(3, pool.len());
+        pool.assert_invariants();
+    }
+
+    #[test]
+    fn test_transaction_state() {
+        let on_chain_balance = U256::from(10_000);
+        let on_chain_nonce = 0;
+        let mut f = MockTransactionFactory::default();
+        let mut pool = TxPool::new(MockOrdering::default(), Default::default());
+
+        let tx_0 = MockTransaction::eip1559().set_gas_price(100).inc_limit();
+        let tx_2 = tx_0.next().next();
+
+        for tx in [tx_0.clone(), tx_2.clone()] {
+            pool.add_transaction(f.validated(tx), on_chain_balance, on_chain_nonce, None).unwrap();
+        }
+
+        assert_eq!(pool.transaction_state(tx_0.hash()), Some(AddedTransactionState::Pending));
+        assert_eq!(
+            pool.transaction_state(tx_2.hash()),
+            Some(AddedTransactionState::Queued(QueuedReason::NonceGap))
+        );
+        assert_eq!(pool.transaction_state(&TxHash::random()), None);
+    }
+
     #[test]
     fn test_remove_transactions_and_descendants() {
         let on_chain_balance = U256::from(10_000);
@@ -4290,6 +5118,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_best_transactions_count_with_attributes() {
+        let mut f = MockTransactionFactory::default();
+        let mut pool = TxPool::new(MockOrdering::default(), Default::default());
+
+        let base_fee: u128 = 100;
+        let blob_fee: u128 = 100;
+        let mut block_info = pool.block_info();
+        block_info.pending_basefee = base_fee as u64;
+        block_info.pending_blob_fee = Some(blob_fee);
+        pool.set_block_info(block_info);
+
+        // chains of transactions with decreasing fees per sender, some of them are parked
+        let chains = [
+            (MockTransaction::eip1559(), [base_fee + 30, base_fee + 20, base_fee + 10, base_fee]),
+            (MockTransaction::eip1559(), [base_fee - 10, base_fee - 5, base_fee - 20, base_fee]),
+            (MockTransaction::eip4844(), [base_fee + 20, base_fee + 10, base_fee, base_fee - 10]),
+        ];
+        for (sender, (tx, fees)) in chains.into_iter().enumerate() {
+            let mut tx = tx.with_sender(Address::with_last_byte(sender as u8 + 1));
+            for (nonce, fee) in fees.into_iter().enumerate() {
+                tx = tx.with_nonce(nonce as u64).with_max_fee(fee).with_priority_fee(1).rng_hash();
+                if tx.is_eip4844() {
+                    tx = tx.with_blob_fee(fee);
+                }
+                pool.add_transaction(f.validated(tx.clone()), U256::MAX, 0, None).unwrap();
+            }
+        }
+
+        for base_fee in [base_fee - 20, base_fee - 10, base_fee, base_fee + 10, base_fee + 25] {
+            for blob_fee in [blob_fee - 10, blob_fee, blob_fee + 15] {
+                let attributes =
+                    BestTransactionsAttributes::new(base_fee as u64, Some(blob_fee as u64));
+                assert_eq!(
+                    pool.best_transactions_count_with_attributes(attributes),
+                    pool.best_transactions_with_attributes(attributes).count(),
+                    "base fee {base_fee}, blob fee {blob_fee}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_pending_ordering() {
         let mut f = MockTransactionFactory::default();
@@ -0,0 +1,212 @@
+//! Support for exporting the pool contents as a snapshot and re-importing them, e.g. to warm-start
+//! the pool after a restart.
+//!
+//! A snapshot contains _all_ transactions of the pool (pending, basefee, queued and blob subpool)
+//! in their network ([`PoolTransaction::Pooled`]) encoding, so blob transactions include their
+//! sidecar which is fetched from the [`BlobStore`](crate::BlobStore).
+//!
+//! Importing a snapshot does not trust its contents: every transaction is submitted to the pool
+//! again and goes through regular validation against the current state.
+
+use crate::{
+    blobstore::BlobStoreError, error::PoolResult, maintain::TxBackup, AddedTransactionOutcome,
+    EthPoolTransaction, PoolTransaction, TransactionPool,
+};
+use alloy_eips::{Decodable2718, Encodable2718};
+use reth_primitives_traits::transaction::signed::SignedTransaction;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, trace};
+
+/// The current version of the [`PoolSnapshot`] format.
+pub const POOL_SNAPSHOT_VERSION: u64 = 1;
+
+/// A versioned snapshot of the transactions in the pool.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PoolSnapshot {
+    /// The version of the snapshot format.
+    pub version: u64,
+    /// All transactions of the pool in their network encoding, together with their origin.
+    pub transactions: Vec<TxBackup>,
+}
+
+/// Only the version of a snapshot, used to reject unsupported formats before decoding the rest.
+#[derive(Deserialize)]
+struct PoolSnapshotVersion {
+    version: u64,
+}
+
+/// Errors that can occur while exporting or importing a [`PoolSnapshot`].
+#[derive(thiserror::Error, Debug)]
+pub enum PoolSnapshotError {
+    /// The snapshot was created with an unsupported format version.
+    #[error("unsupported pool snapshot version {0}, expected {POOL_SNAPSHOT_VERSION}")]
+    UnsupportedVersion(u64),
+    /// Error during json encoding or decoding of the snapshot.
+    #[error("failed to encode or decode pool snapshot: {0}")]
+    Json(#[from] serde_json::Error),
+    /// Error while fetching blob sidecars from the blob store.
+    #[error("failed to fetch blob sidecars for pool snapshot: {0}")]
+    BlobStore(#[from] BlobStoreError),
+}
+
+/// Serializes all transactions currently in the pool into a versioned [`PoolSnapshot`].
+///
+/// Blob transactions are exported with their sidecar. Blob transactions whose sidecar is missing
+/// from the blob store are skipped, since they could not be re-validated on import.
+pub fn export_pool_snapshot<P>(pool: &P) -> Result<Vec<u8>, PoolSnapshotError>
+where
+    P: TransactionPool<Transaction: EthPoolTransaction>,
+{
+    let transactions = pool.get_all(pool.all_transaction_hashes());
+
+    let mut backups = Vec::with_capacity(transactions.len());
+    for tx in transactions {
+        let pooled = if tx.is_eip4844() {
+            let Some(sidecar) = pool.get_blob(*tx.hash())? else {
+                debug!(target: "txpool", tx_hash=%tx.hash(), "missing blob sidecar; skipping transaction in pool snapshot");
+                continue
+            };
+            tx.transaction.clone().try_into_pooled_eip4844(sidecar)
+        } else {
+            tx.transaction.clone_into_pooled().ok()
+        };
+        let Some(pooled) = pooled else {
+            debug!(target: "txpool", tx_hash=%tx.hash(), "failed to convert transaction to pooled element; skipping transaction in pool snapshot");
+            continue
+        };
+
+        backups
+            .push(TxBackup { rlp: pooled.into_inner().encoded_2718().into(), origin: tx.origin });
+    }
+
+    trace!(target: "txpool", num_txs=%backups.len(), "Exporting pool snapshot");
+
+    let snapshot = PoolSnapshot { version: POOL_SNAPSHOT_VERSION, transactions: backups };
+    Ok(serde_json::to_vec(&snapshot)?)
+}
+
+/// Decodes a [`PoolSnapshot`] created by [`export_pool_snapshot`] and re-inserts its transactions
+/// into the pool.
+///
+/// All transactions are validated again, because the state may have advanced since the snapshot
+/// was created. Transactions that fail to decode are skipped, the outcome of every inserted
+/// transaction is returned.
+pub async fn import_pool_snapshot<P>(
+    pool: &P,
+    data: &[u8],
+) -> Result<Vec<PoolResult<AddedTransactionOutcome>>, PoolSnapshotError>
+where
+    P: TransactionPool,
+{
+    let PoolSnapshotVersion { version } = serde_json::from_slice(data)?;
+    if version != POOL_SNAPSHOT_VERSION {
+        return Err(PoolSnapshotError::UnsupportedVersion(version))
+    }
+
+    let snapshot: PoolSnapshot = serde_json::from_slice(data)?;
+    let transactions = snapshot.transactions.into_iter().filter_map(|backup| {
+        let pooled =
+            <P::Transaction as PoolTransaction>::Pooled::decode_2718_exact(backup.rlp.as_ref())
+                .inspect_err(|err| {
+                    debug!(target: "txpool", %err, "failed to decode transaction from pool snapshot; skipping");
+                })
+                .ok()?;
+        // reject malformed signatures before the more expensive recovery
+        pooled.verify_signature().ok()?;
+        let recovered = pooled.try_into_recovered().ok()?;
+        Some((backup.origin, <P::Transaction as PoolTransaction>::from_pooled(recovered)))
+    });
+
+    let outcomes = futures_util::future::join_all(
+        transactions.map(|(origin, tx)| pool.add_transaction(origin, tx)),
+    )
+    .await;
+
+    debug!(target: "txpool", num_txs=%outcomes.len(), "Imported pool snapshot");
+    Ok(outcomes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{testing_pool, MockTransaction},
+        TransactionOrigin,
+    };
+    use alloy_consensus::{crypto::SECP256K1N_HALF, SignableTransaction, TxLegacy};
+    use alloy_eips::eip4844::BlobTransactionSidecar;
+    use alloy_primitives::{Signature, U256};
+    use reth_ethereum_primitives::PooledTransactionVariant;
+
+    #[tokio::test]
+    async fn export_import_pool_snapshot() {
+        let pool = testing_pool();
+        pool.add_transaction(TransactionOrigin::External, MockTransaction::eip1559())
+            .await
+            .unwrap();
+        pool.add_transaction(TransactionOrigin::Local, MockTransaction::eip1559()).await.unwrap();
+        pool.add_transaction(
+            TransactionOrigin::External,
+            MockTransaction::eip4844_with_sidecar(BlobTransactionSidecar::default().into()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(pool.len(), 3);
+
+        let data = export_pool_snapshot(&pool).unwrap();
+        let snapshot: PoolSnapshot = serde_json::from_slice(&data).unwrap();
+        assert_eq!(snapshot.version, POOL_SNAPSHOT_VERSION);
+        assert_eq!(snapshot.transactions.len(), 3);
+
+        let restored = testing_pool();
+        let outcomes = import_pool_snapshot(&restored, &data).await.unwrap();
+        assert_eq!(outcomes.len(), 3);
+        assert!(outcomes.iter().all(|outcome| outcome.is_ok()));
+        assert_eq!(restored.len(), 3);
+        assert_eq!(restored.get_local_transactions().len(), 1);
+
+        // the blob sidecar is restored into the blob store of the new pool
+        let blob_tx = restored
+            .get_all(restored.all_transaction_hashes())
+            .into_iter()
+            .find(|tx| tx.is_eip4844())
+            .unwrap();
+        assert!(restored.get_blob(*blob_tx.hash()).unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn import_unsupported_pool_snapshot_version() {
+        let pool = testing_pool();
+        let snapshot =
+            PoolSnapshot { version: POOL_SNAPSHOT_VERSION + 1, transactions: Vec::new() };
+        let data = serde_json::to_vec(&snapshot).unwrap();
+
+        let err = import_pool_snapshot(&pool, &data).await.unwrap_err();
+        assert!(
+            matches!(err, PoolSnapshotError::UnsupportedVersion(v) if v == POOL_SNAPSHOT_VERSION + 1)
+        );
+        assert!(pool.is_empty());
+    }
+
+    #[tokio::test]
+    async fn import_skips_malleable_signature() {
+        let signature = Signature::test_signature();
+        let malleable = Signature::new(signature.r(), SECP256K1N_HALF + U256::from(1), false);
+        let tx = PooledTransactionVariant::Legacy(
+            TxLegacy { chain_id: Some(1), ..Default::default() }.into_signed(malleable),
+        );
+        let snapshot = PoolSnapshot {
+            version: POOL_SNAPSHOT_VERSION,
+            transactions: vec![TxBackup {
+                rlp: tx.encoded_2718().into(),
+                origin: TransactionOrigin::External,
+            }],
+        };
+        let data = serde_json::to_vec(&snapshot).unwrap();
+
+        let pool = testing_pool();
+        let outcomes = import_pool_snapshot(&pool, &data).await.unwrap();
+        assert!(outcomes.is_empty());
+        assert!(pool.is_empty());
+    }
+}
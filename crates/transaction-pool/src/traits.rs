@@ -52,26 +52,30 @@
 
 use crate::{
     blobstore::BlobStoreError,
-    error::{InvalidPoolTransactionError, PoolError, PoolResult},
+    error::{Eip4844PoolTransactionError, InvalidPoolTransactionError, PoolError, PoolResult},
     pool::{
-        state::SubPool, BestTransactionFilter, NewTransactionEvent, TransactionEvents,
-        TransactionListenerKind,
+        state::SubPool, BestTransactionFilter, EvictedTransactionEvent, NewTransactionEvent,
+        TransactionEvents, TransactionListenerKind,
     },
     validate::ValidPoolTransaction,
-    AddedTransactionOutcome, AllTransactionsEvents,
+    AddedTransactionOutcome, AddedTransactionState, AllTransactionsEvents,
+};
+use alloy_consensus::{
+    error::ValueError, transaction::TxHashRef, BlockHeader, Signed, Transaction as _, Typed2718,
 };
-use alloy_consensus::{error::ValueError, transaction::TxHashRef, BlockHeader, Signed, Typed2718};
 use alloy_eips::{
     eip2718::{Encodable2718, WithEncoded},
     eip2930::AccessList,
     eip4844::{
         env_settings::KzgSettings, BlobAndProofV1, BlobAndProofV2, BlobTransactionValidationError,
+        DATA_GAS_PER_BLOB,
     },
     eip7594::BlobTransactionSidecarVariant,
     eip7702::SignedAuthorization,
 };
 use alloy_primitives::{Address, Bytes, TxHash, TxKind, B256, U256};
 use futures_util::{ready, Stream};
+use reth_chainspec::MIN_TRANSACTION_GAS;
 use reth_eth_wire_types::HandleMempoolData;
 use reth_ethereum_primitives::{PooledTransactionVariant, TransactionSigned};
 use reth_execution_types::ChangedAccount;
@@ -245,6 +249,12 @@ pub trait TransactionPool: Clone + Debug + Send + Sync {
     /// commitments/proofs) for eip-4844 transactions inserted into the pool
     fn blob_transaction_sidecars_listener(&self) -> Receiver<NewBlobSidecar>;
 
+    /// Returns a new [Receiver] that yields every transaction the pool evicts on its own, tagged
+    /// with the [`EvictionReason`](crate::EvictionReason) and the subpool it was evicted from.
+    ///
+    /// Transactions that are mined, replaced or rejected on insertion are not reported here.
+    fn evicted_transactions_listener(&self) -> Receiver<EvictedTransactionEvent>;
+
     /// Returns a new stream that yields new valid transactions added to the pool
     /// depending on the given [`TransactionListenerKind`] argument.
     fn new_transactions_listener_for(
@@ -369,6 +379,18 @@ pub trait TransactionPool: Clone + Debug + Send + Sync {
         best_transactions_attributes: BestTransactionsAttributes,
     ) -> Box<dyn BestTransactions<Item = Arc<ValidPoolTransaction<Self::Transaction>>>>;
 
+    /// Returns the number of transactions the iterator returned by
+    /// [`Self::best_transactions_with_attributes`] would yield if it were drained, without
+    /// creating it.
+    ///
+    /// This only considers the transactions currently in the pool.
+    ///
+    /// Consumer: Block production
+    fn best_transactions_count_with_attributes(
+        &self,
+        best_transactions_attributes: BestTransactionsAttributes,
+    ) -> usize;
+
     /// Returns all transactions that can be included in the next block.
     ///
     /// This is primarily used for the `txpool_` RPC namespace:
@@ -389,6 +411,66 @@ pub trait TransactionPool: Clone + Debug + Send + Sync {
         max: usize,
     ) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>>;
 
+    /// Returns the best pending transactions, in the order of [`Self::best_transactions`], that
+    /// fit into the given gas and blob gas budget.
+    ///
+    /// Transactions that would exceed either budget are skipped together with their descendants.
+    /// Once no more blob fits, blob transactions are no longer considered, and iteration stops as
+    /// soon as the remaining gas can't fit any transaction.
+    ///
+    /// The budget is charged with the gas limit of the transactions.
+    ///
+    /// Consumer: Block production
+    fn best_transactions_with_budget(
+        &self,
+        gas_limit: u64,
+        blob_gas_limit: u64,
+    ) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        let mut best = self.best_transactions();
+        let mut transactions = Vec::new();
+        let (mut gas_used, mut blob_gas_used) = (0u64, 0u64);
+        if blob_gas_limit < DATA_GAS_PER_BLOB {
+            best.skip_blobs();
+        }
+
+        while let Some(tx) = best.next() {
+            if gas_used + tx.gas_limit() > gas_limit {
+                best.mark_invalid(
+                    &tx,
+                    &InvalidPoolTransactionError::ExceedsGasLimit(tx.gas_limit(), gas_limit),
+                );
+                continue
+            }
+
+            let blob_gas = tx.transaction.blob_gas_used().unwrap_or_default();
+            if blob_gas_used + blob_gas > blob_gas_limit {
+                best.mark_invalid(
+                    &tx,
+                    &InvalidPoolTransactionError::Eip4844(
+                        Eip4844PoolTransactionError::TooManyEip4844Blobs {
+                            have: (blob_gas_used + blob_gas) / DATA_GAS_PER_BLOB,
+                            permitted: blob_gas_limit / DATA_GAS_PER_BLOB,
+                        },
+                    ),
+                );
+                continue
+            }
+
+            gas_used += tx.gas_limit();
+            blob_gas_used += blob_gas;
+            transactions.push(tx);
+
+            if blob_gas_limit - blob_gas_used < DATA_GAS_PER_BLOB {
+                best.skip_blobs();
+            }
+            if gas_limit - gas_used < MIN_TRANSACTION_GAS {
+                break
+            }
+        }
+
+        transactions
+    }
+
     /// Returns all transactions that can be included in _future_ blocks.
     ///
     /// This and [`Self::pending_transactions`] are mutually exclusive.
@@ -453,6 +535,34 @@ pub trait TransactionPool: Clone + Debug + Send + Sync {
         hashes: Vec<TxHash>,
     ) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>>;
 
+    /// Removes all transactions that match the given predicate from all subpools and returns the
+    /// hashes of the removed transactions.
+    ///
+    /// Note: This removes the transactions as if they got discarded (_not_ mined), descendants of
+    /// removed transactions are parked because of the nonce gap. The blob sidecars of removed blob
+    /// transactions are deleted from the blob store.
+    ///
+    /// Consumer: Utility
+    fn remove_transactions_by(
+        &self,
+        predicate: impl FnMut(&ValidPoolTransaction<Self::Transaction>) -> bool,
+    ) -> Vec<TxHash>;
+
+    /// Removes all transactions that match the given predicate because they expired and returns
+    /// the hashes of the removed transactions.
+    ///
+    /// This behaves like [`Self::remove_transactions_by`], but reports the removed transactions to
+    /// the [`Self::evicted_transactions_listener`] with
+    /// [`EvictionReason::Expired`](crate::EvictionReason::Expired).
+    ///
+    /// Consumer: Utility
+    fn remove_expired_transactions(
+        &self,
+        predicate: impl FnMut(&ValidPoolTransaction<Self::Transaction>) -> bool,
+    ) -> Vec<TxHash> {
+        self.remove_transactions_by(predicate)
+    }
+
     /// Removes all transactions from the given sender
     ///
     /// Consumer: Utility
@@ -478,6 +588,13 @@ pub trait TransactionPool: Clone + Debug + Send + Sync {
     /// Returns the transaction for the given hash.
     fn get(&self, tx_hash: &TxHash) -> Option<Arc<ValidPoolTransaction<Self::Transaction>>>;
 
+    /// Returns the current state of the transaction for the given hash.
+    ///
+    /// This is either [`AddedTransactionState::Pending`] or [`AddedTransactionState::Queued`] with
+    /// the reason why the transaction is not ready for execution. Returns `None` if the
+    /// transaction is not in the pool.
+    fn transaction_state(&self, tx_hash: &TxHash) -> Option<AddedTransactionState>;
+
     /// Returns all transactions objects for the given hashes.
     ///
     /// Caution: This in case of blob transactions, this does not include the sidecar.
@@ -651,13 +768,27 @@ pub trait TransactionPoolExt: TransactionPool {
     /// sidecar must not be removed from the blob store. Only after a blob transaction is
     /// finalized, its sidecar is removed from the blob store. This ensures that in case of a reorg,
     /// the sidecar is still available.
-    fn on_canonical_state_change<B>(&self, update: CanonicalStateUpdate<'_, B>)
+    ///
+    /// Returns a summary of the transactions that were mined, moved or discarded by the update.
+    fn on_canonical_state_change<B>(
+        &self,
+        update: CanonicalStateUpdate<'_, B>,
+    ) -> CanonicalStateUpdateSummary
     where
         B: Block;
 
     /// Updates the accounts in the pool
     fn update_accounts(&self, accounts: Vec<ChangedAccount>);
 
+    /// Processes the next batch of sub-pool updates that were deferred because they exceeded the
+    /// per block budget.
+    ///
+    /// See [`PoolConfig::max_subpool_updates_per_block`](crate::PoolConfig).
+    fn process_deferred_updates(&self);
+
+    /// Returns `true` if there are deferred sub-pool updates that are yet to be processed.
+    fn has_deferred_updates(&self) -> bool;
+
     /// Deletes the blob sidecar for the given transaction from the blob store
     fn delete_blob(&self, tx: B256);
 
@@ -891,6 +1022,21 @@ where
     }
 }
 
+/// Summary of the changes the pool applied for a [`CanonicalStateUpdate`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CanonicalStateUpdateSummary {
+    /// Hash of the tip block.
+    pub block_hash: B256,
+    /// Number of transactions that were mined in the updated block range.
+    pub mined: usize,
+    /// Number of transactions that were promoted to the pending sub-pool.
+    pub promoted: usize,
+    /// Number of transactions that were moved out of the pending sub-pool.
+    pub demoted: usize,
+    /// Number of transactions that were discarded from the pool.
+    pub discarded: usize,
+}
+
 /// Alias to restrict the [`BestTransactions`] items to the pool's transaction type.
 pub type BestTransactionsFor<Pool> = Box<
     dyn BestTransactions<Item = Arc<ValidPoolTransaction<<Pool as TransactionPool>::Transaction>>>,
@@ -1228,6 +1374,13 @@ pub trait PoolTransaction:
     fn requires_nonce_check(&self) -> bool {
         true
     }
+
+    /// Returns whether the transaction is replay protected as defined in [EIP-155](https://eips.ethereum.org/EIPS/eip-155).
+    ///
+    /// See [`SignedTransaction::is_replay_protected`].
+    fn is_replay_protected(&self) -> bool {
+        self.chain_id().is_some()
+    }
 }
 
 /// Super trait for transactions that can be converted to and from Eth transactions intended for the
@@ -1695,7 +1848,6 @@ mod tests {
         EthereumTxEnvelope, SignableTransaction, TxEip1559, TxEip2930, TxEip4844, TxEip7702,
         TxEnvelope, TxLegacy,
     };
-    use alloy_eips::eip4844::DATA_GAS_PER_BLOB;
     use alloy_primitives::Signature;
 
     #[test]
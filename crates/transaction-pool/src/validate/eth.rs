@@ -32,7 +32,8 @@ use reth_primitives_traits::{
 };
 use reth_storage_api::{AccountInfoReader, BytecodeReader, StateProviderFactory};
 use reth_tasks::TaskSpawner;
-use revm_primitives::U256;
+use revm_interpreter::gas::InitialAndFloorGas;
+use revm_primitives::{hardfork::SpecId, U256};
 use std::{
     marker::PhantomData,
     sync::{
@@ -85,8 +86,16 @@ pub struct EthTransactionValidator<Client, T> {
     local_transactions_config: LocalTransactionConfig,
     /// Maximum size in bytes a single transaction can have in order to be accepted into the pool.
     max_tx_input_bytes: usize,
+    /// Maximum init code size in bytes of contract creation transactions, enforced once Shanghai
+    /// (EIP-3860) is active.
+    max_init_code_size: usize,
     /// Maximum gas limit for individual transactions
     max_tx_gas_limit: Option<u64>,
+    /// Maximum number of access list entries (addresses and storage keys) for individual
+    /// transactions
+    max_access_list_entries: Option<usize>,
+    /// Whether to reject legacy transactions without EIP-155 replay protection
+    reject_unprotected_transactions: bool,
     /// Disable balance checks during transaction validation
     disable_balance_check: bool,
     /// Marker for the transaction type
@@ -170,6 +179,23 @@ impl<Client, Tx> EthTransactionValidator<Client, Tx> {
         self.max_tx_input_bytes
     }
 
+    /// Returns the maximum init code size in bytes of contract creation transactions that is
+    /// enforced once Shanghai is active.
+    pub const fn max_init_code_size(&self) -> usize {
+        self.max_init_code_size
+    }
+
+    /// Returns the maximum number of access list entries a single transaction can have in order to
+    /// be accepted into the pool.
+    pub const fn max_access_list_entries(&self) -> Option<usize> {
+        self.max_access_list_entries
+    }
+
+    /// Returns whether legacy transactions without EIP-155 replay protection are rejected.
+    pub const fn reject_unprotected_transactions(&self) -> bool {
+        self.reject_unprotected_transactions
+    }
+
     /// Returns whether balance checks are disabled for this validator.
     pub const fn disable_balance_check(&self) -> bool {
         self.disable_balance_check
@@ -346,9 +372,28 @@ where
             }
         }
 
+        // Reject transactions with oversized access lists before doing any further work
+        if let Some(max_access_list_entries) = self.max_access_list_entries &&
+            let Some(access_list) = transaction.access_list()
+        {
+            let entries = access_list
+                .iter()
+                .map(|item| 1 + item.storage_keys.len())
+                .fold(0usize, usize::saturating_add);
+            if entries > max_access_list_entries {
+                return Err(TransactionValidationOutcome::Invalid(
+                    transaction,
+                    InvalidPoolTransactionError::AccessListTooLarge {
+                        entries,
+                        limit: max_access_list_entries,
+                    },
+                ))
+            }
+        }
+
         // Check whether the init code size has been exceeded.
         if self.fork_tracker.is_shanghai_activated() &&
-            let Err(err) = transaction.ensure_max_init_code_size(MAX_INIT_CODE_BYTE_SIZE)
+            let Err(err) = transaction.ensure_max_init_code_size(self.max_init_code_size)
         {
             return Err(TransactionValidationOutcome::Invalid(transaction, err))
         }
@@ -436,6 +481,14 @@ where
             ))
         }
 
+        // Reject legacy transactions that are not replay protected, if configured
+        if self.reject_unprotected_transactions && !transaction.is_replay_protected() {
+            return Err(TransactionValidationOutcome::Invalid(
+                transaction,
+                InvalidPoolTransactionError::UnprotectedTransaction,
+            ))
+        }
+
         if transaction.is_eip7702() {
             // Prague fork is required for 7702 txs
             if !self.fork_tracker.is_prague_activated() {
@@ -467,27 +520,10 @@ where
                 ))
             }
 
-            let blob_count = transaction.blob_count().unwrap_or(0);
-            if blob_count == 0 {
-                // no blobs
-                return Err(TransactionValidationOutcome::Invalid(
-                    transaction,
-                    InvalidPoolTransactionError::Eip4844(
-                        Eip4844PoolTransactionError::NoEip4844Blobs,
-                    ),
-                ))
-            }
-
-            let max_blob_count = self.fork_tracker.max_blob_count();
-            if blob_count > max_blob_count {
+            if let Err(err) = ensure_blob_count(&transaction, self.fork_tracker.max_blob_count()) {
                 return Err(TransactionValidationOutcome::Invalid(
                     transaction,
-                    InvalidPoolTransactionError::Eip4844(
-                        Eip4844PoolTransactionError::TooManyEip4844Blobs {
-                            have: blob_count,
-                            permitted: max_blob_count,
-                        },
-                    ),
+                    InvalidPoolTransactionError::Eip4844(err),
                 ))
             }
         }
@@ -864,8 +900,14 @@ pub struct EthTransactionValidatorBuilder<Client> {
     local_transactions_config: LocalTransactionConfig,
     /// Max size in bytes of a single transaction allowed
     max_tx_input_bytes: usize,
+    /// Max init code size in bytes of contract creation transactions allowed after Shanghai
+    max_init_code_size: usize,
     /// Maximum gas limit for individual transactions
     max_tx_gas_limit: Option<u64>,
+    /// Maximum number of access list entries for individual transactions
+    max_access_list_entries: Option<usize>,
+    /// Whether to reject legacy transactions without EIP-155 replay protection
+    reject_unprotected_transactions: bool,
     /// Disable balance checks during transaction validation
     disable_balance_check: bool,
     /// Bitmap of custom transaction types that are allowed.
@@ -891,8 +933,11 @@ impl<Client> EthTransactionValidatorBuilder<Client> {
             kzg_settings: EnvKzgSettings::Default,
             local_transactions_config: Default::default(),
             max_tx_input_bytes: DEFAULT_MAX_TX_INPUT_BYTES,
+            max_init_code_size: MAX_INIT_CODE_BYTE_SIZE,
             tx_fee_cap: Some(1e18 as u128),
             max_tx_gas_limit: None,
+            max_access_list_entries: None,
+            reject_unprotected_transactions: false,
             // by default all transaction types are allowed
             eip2718: true,
             eip1559: true,
@@ -1056,6 +1101,21 @@ impl<Client> EthTransactionValidatorBuilder<Client> {
         self
     }
 
+    /// Sets the max init code size in bytes of contract creation transactions allowed into the
+    /// pool.
+    ///
+    /// This is only enforced once Shanghai is active, defaults to the EIP-3860 limit of
+    /// [`MAX_INIT_CODE_BYTE_SIZE`]. The limit can only be lowered: larger values are clamped to
+    /// [`MAX_INIT_CODE_BYTE_SIZE`], because such transactions are invalid in a block.
+    pub const fn with_max_init_code_size(mut self, max_init_code_size: usize) -> Self {
+        self.max_init_code_size = if max_init_code_size > MAX_INIT_CODE_BYTE_SIZE {
+            MAX_INIT_CODE_BYTE_SIZE
+        } else {
+            max_init_code_size
+        };
+        self
+    }
+
     /// Sets the block gas limit
     ///
     /// Transactions with a gas limit greater than this will be rejected.
@@ -1078,6 +1138,24 @@ impl<Client> EthTransactionValidatorBuilder<Client> {
         self
     }
 
+    /// Sets the maximum number of access list entries for individual transactions.
+    ///
+    /// Every address and every storage key in the access list counts as one entry.
+    pub const fn with_max_access_list_entries(
+        mut self,
+        max_access_list_entries: Option<usize>,
+    ) -> Self {
+        self.max_access_list_entries = max_access_list_entries;
+        self
+    }
+
+    /// Sets whether legacy transactions without [EIP-155](https://eips.ethereum.org/EIPS/eip-155)
+    /// replay protection are rejected.
+    pub const fn with_reject_unprotected_transactions(mut self, reject: bool) -> Self {
+        self.reject_unprotected_transactions = reject;
+        self
+    }
+
     /// Disables balance checks during transaction validation
     pub const fn disable_balance_check(mut self) -> Self {
         self.disable_balance_check = true;
@@ -1112,7 +1190,10 @@ impl<Client> EthTransactionValidatorBuilder<Client> {
             kzg_settings,
             local_transactions_config,
             max_tx_input_bytes,
+            max_init_code_size,
             max_tx_gas_limit,
+            max_access_list_entries,
+            reject_unprotected_transactions,
             disable_balance_check,
             max_blob_count,
             additional_tasks: _,
@@ -1142,7 +1223,10 @@ impl<Client> EthTransactionValidatorBuilder<Client> {
             kzg_settings,
             local_transactions_config,
             max_tx_input_bytes,
+            max_init_code_size,
             max_tx_gas_limit,
+            max_access_list_entries,
+            reject_unprotected_transactions,
             disable_balance_check,
             _marker: Default::default(),
             validation_metrics: TxPoolValidationMetrics::default(),
@@ -1249,7 +1333,6 @@ pub fn ensure_intrinsic_gas<T: EthPoolTransaction>(
     transaction: &T,
     fork_tracker: &ForkTracker,
 ) -> Result<(), InvalidPoolTransactionError> {
-    use revm_primitives::hardfork::SpecId;
     let spec_id = if fork_tracker.is_prague_activated() {
         SpecId::PRAGUE
     } else if fork_tracker.is_shanghai_activated() {
@@ -1258,7 +1341,28 @@ pub fn ensure_intrinsic_gas<T: EthPoolTransaction>(
         SpecId::MERGE
     };
 
-    let gas = revm_interpreter::gas::calculate_initial_tx_gas(
+    let gas = intrinsic_gas(transaction, spec_id);
+
+    let gas_limit = transaction.gas_limit();
+    if gas_limit < gas.initial_gas || gas_limit < gas.floor_gas {
+        Err(InvalidPoolTransactionError::IntrinsicGasTooLow)
+    } else {
+        Ok(())
+    }
+}
+
+/// Computes the intrinsic gas of the transaction for the given spec without executing it.
+///
+/// The [`initial_gas`](InitialAndFloorGas::initial_gas) is the base cost of the transaction plus
+/// the cost of the calldata, which depends on the number of zero and non-zero bytes, the cost of
+/// the access list addresses and storage keys, the contract creation and init code cost, and the
+/// EIP-7702 authorization cost. Since Prague, the transaction must also pay at least the EIP-7623
+/// [`floor_gas`](InitialAndFloorGas::floor_gas).
+pub fn intrinsic_gas<T: alloy_consensus::Transaction>(
+    transaction: &T,
+    spec_id: SpecId,
+) -> InitialAndFloorGas {
+    revm_interpreter::gas::calculate_initial_tx_gas(
         spec_id,
         transaction.input(),
         transaction.is_create(),
@@ -1268,26 +1372,45 @@ pub fn ensure_intrinsic_gas<T: EthPoolTransaction>(
             .map(|l| l.iter().map(|i| i.storage_keys.len()).sum::<usize>())
             .unwrap_or_default() as u64,
         transaction.authorization_list().map(|l| l.len()).unwrap_or_default() as u64,
-    );
+    )
+}
 
-    let gas_limit = transaction.gas_limit();
-    if gas_limit < gas.initial_gas || gas_limit < gas.floor_gas {
-        Err(InvalidPoolTransactionError::IntrinsicGasTooLow)
-    } else {
-        Ok(())
-    }
+/// Ensures that the EIP-4844 transaction carries at least one and at most `max_blob_count` blobs.
+///
+/// The blob count is the number of
+/// [`blob_versioned_hashes`](alloy_consensus::Transaction::blob_versioned_hashes) of the
+/// transaction, transactions without blob versioned hashes are not checked.
+pub fn ensure_blob_count<T: alloy_consensus::Transaction>(
+    transaction: &T,
+    max_blob_count: u64,
+) -> Result<(), Eip4844PoolTransactionError> {
+    let Some(blob_versioned_hashes) = transaction.blob_versioned_hashes() else { return Ok(()) };
+    let blob_count = blob_versioned_hashes.len() as u64;
+    if blob_count == 0 {
+        return Err(Eip4844PoolTransactionError::NoEip4844Blobs)
+    }
+    if blob_count > max_blob_count {
+        return Err(Eip4844PoolTransactionError::TooManyEip4844Blobs {
+            have: blob_count,
+            permitted: max_blob_count,
+        })
+    }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        blobstore::InMemoryBlobStore, error::PoolErrorKind, traits::PoolTransaction,
-        CoinbaseTipOrdering, EthPooledTransaction, Pool, TransactionPool,
+        blobstore::InMemoryBlobStore, error::PoolErrorKind, test_utils::MockTransaction,
+        traits::PoolTransaction, CoinbaseTipOrdering, EthPooledTransaction, Pool, TransactionPool,
     };
     use alloy_consensus::Transaction;
-    use alloy_eips::eip2718::Decodable2718;
-    use alloy_primitives::{hex, U256};
+    use alloy_eips::{
+        eip2718::Decodable2718,
+        eip2930::{AccessList, AccessListItem},
+    };
+    use alloy_primitives::{hex, Address, TxKind, B256, U256};
     use reth_ethereum_primitives::PooledTransactionVariant;
     use reth_primitives_traits::SignedTransaction;
     use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
@@ -1518,6 +1641,113 @@ mod tests {
         assert!(outcome.is_valid());
     }
 
+    #[test]
+    fn reject_unprotected_transactions() {
+        let provider = MockEthProvider::default();
+        let mut unprotected = MockTransaction::legacy().with_gas_limit(100_000);
+        if let MockTransaction::Legacy { chain_id, .. } = &mut unprotected {
+            *chain_id = None;
+        }
+        let protected = MockTransaction::legacy().with_gas_limit(100_000);
+        for tx in [&unprotected, &protected] {
+            provider.add_account(tx.sender(), ExtendedAccount::new(tx.nonce(), U256::MAX));
+        }
+
+        // unprotected transactions are accepted by default
+        let validator = EthTransactionValidatorBuilder::new(provider.clone())
+            .build(InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::External, unprotected.clone());
+        assert!(outcome.is_valid());
+
+        let validator = EthTransactionValidatorBuilder::new(provider)
+            .with_reject_unprotected_transactions(true)
+            .build(InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::External, unprotected);
+        assert!(matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(
+                _,
+                InvalidPoolTransactionError::UnprotectedTransaction
+            )
+        ));
+        let outcome = validator.validate_one(TransactionOrigin::External, protected);
+        assert!(outcome.is_valid());
+    }
+
+    #[test]
+    fn access_list_entries_limit_boundary() {
+        let mut transaction = MockTransaction::eip2930().with_gas_limit(100_000);
+        transaction.set_accesslist(AccessList(vec![AccessListItem {
+            address: Address::random(),
+            storage_keys: vec![B256::random(), B256::random()],
+        }]));
+        let provider = MockEthProvider::default();
+        provider.add_account(
+            transaction.sender(),
+            ExtendedAccount::new(transaction.nonce(), U256::MAX),
+        );
+
+        // one address and two storage keys, exactly at the limit
+        let validator = EthTransactionValidatorBuilder::new(provider.clone())
+            .with_max_access_list_entries(Some(3))
+            .build(InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+        assert!(outcome.is_valid());
+
+        // one entry above the limit
+        let validator = EthTransactionValidatorBuilder::new(provider)
+            .with_max_access_list_entries(Some(2))
+            .build(InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction);
+        assert!(matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(
+                _,
+                InvalidPoolTransactionError::AccessListTooLarge { entries: 3, limit: 2 }
+            )
+        ));
+    }
+
+    #[test]
+    fn max_init_code_size_enforced_after_shanghai() {
+        let mut transaction = MockTransaction::eip1559().with_gas_limit(1_000_000);
+        if let MockTransaction::Eip1559 { to, input, .. } = &mut transaction {
+            *to = TxKind::Create;
+            *input = vec![0; MAX_INIT_CODE_BYTE_SIZE + 1].into();
+        }
+        let provider = MockEthProvider::default();
+        provider.add_account(
+            transaction.sender(),
+            ExtendedAccount::new(transaction.nonce(), U256::MAX),
+        );
+
+        let validator = EthTransactionValidatorBuilder::new(provider.clone())
+            .build(InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+        assert!(matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(
+                _,
+                InvalidPoolTransactionError::ExceedsMaxInitCodeSize(size, MAX_INIT_CODE_BYTE_SIZE)
+            ) if size == MAX_INIT_CODE_BYTE_SIZE + 1
+        ));
+
+        // the limit can't be raised above the EIP-3860 limit
+        let validator = EthTransactionValidatorBuilder::new(provider.clone())
+            .with_max_init_code_size(usize::MAX)
+            .build(InMemoryBlobStore::default());
+        assert_eq!(validator.max_init_code_size(), MAX_INIT_CODE_BYTE_SIZE);
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+        assert!(outcome.is_invalid());
+
+        // init code size is not limited before EIP-3860
+        let validator = EthTransactionValidatorBuilder::new(provider)
+            .no_shanghai()
+            .build(InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction);
+        assert!(outcome.is_valid());
+    }
+
     // Helper function to set up common test infrastructure for priority fee tests
     fn setup_priority_fee_test() -> (EthPooledTransaction, MockEthProvider) {
         let transaction = get_transaction();
@@ -1687,6 +1917,43 @@ mod tests {
         assert!(outcome.is_invalid()); // Still invalid because sender not in whitelist
     }
 
+    #[tokio::test]
+    async fn valid_on_local_address_below_minimum_priority_fee() {
+        let (transaction, provider) = setup_priority_fee_test();
+
+        // Set minimum priority fee to be double the transaction's priority fee
+        let minimum_priority_fee =
+            transaction.max_priority_fee_per_gas().expect("priority fee is expected") * 2;
+
+        // Sender is configured as a local address
+        let local_config = LocalTransactionConfig {
+            local_addresses: std::iter::once(transaction.sender()).collect(),
+            ..Default::default()
+        };
+        let validator = create_validator_with_minimum_fee(
+            provider.clone(),
+            Some(minimum_priority_fee),
+            Some(local_config),
+        );
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+        assert!(outcome.is_valid());
+
+        // The same transaction is rejected if the sender is not a local address
+        let validator = create_validator_with_minimum_fee(
+            provider,
+            Some(minimum_priority_fee),
+            Some(LocalTransactionConfig::default()),
+        );
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction);
+        assert!(matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(
+                _,
+                InvalidPoolTransactionError::PriorityFeeBelowMinimum { .. }
+            )
+        ));
+    }
+
     #[test]
     fn reject_oversized_tx() {
         let mut transaction = get_transaction();
@@ -1736,4 +2003,93 @@ mod tests {
         let outcome = validator.validate_one(TransactionOrigin::External, transaction);
         assert!(outcome.is_valid()); // Should be valid because balance check is disabled
     }
+
+    #[test]
+    fn blob_count_bounds() {
+        use alloy_consensus::{TxEip4844, TxLegacy};
+
+        let max_blob_count = BlobParams::prague().max_blobs_per_tx;
+        let blob_tx = |blobs: u64| TxEip4844 {
+            blob_versioned_hashes: vec![B256::ZERO; blobs as usize],
+            ..Default::default()
+        };
+
+        assert!(ensure_blob_count(&blob_tx(1), max_blob_count).is_ok());
+        assert!(ensure_blob_count(&blob_tx(max_blob_count), max_blob_count).is_ok());
+        // not a blob transaction
+        assert!(ensure_blob_count(&TxLegacy::default(), max_blob_count).is_ok());
+
+        assert!(matches!(
+            ensure_blob_count(&blob_tx(0), max_blob_count),
+            Err(Eip4844PoolTransactionError::NoEip4844Blobs)
+        ));
+        assert!(matches!(
+            ensure_blob_count(&blob_tx(max_blob_count + 1), max_blob_count),
+            Err(Eip4844PoolTransactionError::TooManyEip4844Blobs { have, permitted })
+                if have == max_blob_count + 1 && permitted == max_blob_count
+        ));
+    }
+
+    #[test]
+    fn intrinsic_gas_known_values() {
+        use alloy_consensus::{TxEip2930, TxEip7702, TxLegacy};
+        use alloy_eips::eip7702::{Authorization, SignedAuthorization};
+        use alloy_primitives::{Bytes, Signature, TxKind};
+        use reth_ethereum_primitives::{Transaction, TransactionSigned};
+
+        let signed = |tx| TransactionSigned::new_unhashed(tx, Signature::test_signature());
+
+        // simple transfer
+        let transfer = signed(Transaction::Legacy(TxLegacy {
+            to: TxKind::Call(Address::random()),
+            value: U256::from(1),
+            ..Default::default()
+        }));
+        assert_eq!(intrinsic_gas(&transfer, SpecId::CANCUN), InitialAndFloorGas::new(21_000, 0));
+
+        // contract creation with one non-zero and one zero byte of init code
+        let create = signed(Transaction::Legacy(TxLegacy {
+            to: TxKind::Create,
+            input: Bytes::from_static(&[0x60, 0x00]),
+            ..Default::default()
+        }));
+        // 53000 base + 16 + 4 calldata
+        assert_eq!(intrinsic_gas(&create, SpecId::LONDON).initial_gas, 53_020);
+        // plus 2 gas per init code word since shanghai
+        assert_eq!(intrinsic_gas(&create, SpecId::SHANGHAI).initial_gas, 53_022);
+        // creations did not cost extra before homestead
+        assert_eq!(intrinsic_gas(&create, SpecId::FRONTIER).initial_gas, 21_000 + 68 + 4);
+
+        // access list transaction with one address and two storage keys
+        let access_list = signed(Transaction::Eip2930(TxEip2930 {
+            to: TxKind::Call(Address::random()),
+            input: Bytes::from_static(&[0x01, 0x00]),
+            access_list: AccessList(vec![AccessListItem {
+                address: Address::random(),
+                storage_keys: vec![B256::ZERO, B256::with_last_byte(1)],
+            }]),
+            ..Default::default()
+        }));
+        // 21000 base + 16 + 4 calldata + 2400 address + 2 * 1900 storage keys
+        assert_eq!(intrinsic_gas(&access_list, SpecId::BERLIN), InitialAndFloorGas::new(27_220, 0));
+        // the EIP-7623 floor is 21000 + 10 per calldata token, a non-zero byte counts as 4 tokens
+        assert_eq!(
+            intrinsic_gas(&access_list, SpecId::PRAGUE),
+            InitialAndFloorGas::new(27_220, 21_050)
+        );
+
+        // set code transaction with two authorizations
+        let authorization = SignedAuthorization::new_unchecked(
+            Authorization { chain_id: U256::from(1), address: Address::random(), nonce: 0 },
+            0,
+            U256::ZERO,
+            U256::ZERO,
+        );
+        let set_code = signed(Transaction::Eip7702(TxEip7702 {
+            to: Address::random(),
+            authorization_list: vec![authorization.clone(), authorization],
+            ..Default::default()
+        }));
+        assert_eq!(intrinsic_gas(&set_code, SpecId::PRAGUE).initial_gas, 21_000 + 2 * 25_000);
+    }
 }
@@ -6,7 +6,11 @@ use crate::{
     traits::{PoolTransaction, TransactionOrigin},
     PriceBumpConfig,
 };
-use alloy_eips::{eip7594::BlobTransactionSidecarVariant, eip7702::SignedAuthorization};
+use alloy_eips::{
+    eip4844::{BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_PROOF},
+    eip7594::BlobTransactionSidecarVariant,
+    eip7702::SignedAuthorization,
+};
 use alloy_primitives::{Address, TxHash, B256, U256};
 use futures_util::future::Either;
 use reth_primitives_traits::{Recovered, SealedBlock};
@@ -420,6 +424,22 @@ impl<T: PoolTransaction> ValidPoolTransaction<T> {
         self.transaction.size()
     }
 
+    /// The estimated size of the blob sidecar of this transaction, which is kept in the blob store
+    /// rather than in the pool.
+    ///
+    /// Returns `0` for non EIP-4844 transactions.
+    pub(crate) fn blob_sidecar_size(&self) -> usize {
+        self.transaction.blob_versioned_hashes().map_or(0, |hashes| {
+            hashes.len() * (BYTES_PER_BLOB + BYTES_PER_COMMITMENT + BYTES_PER_PROOF)
+        })
+    }
+
+    /// The size of this transaction including its blob sidecar, see also
+    /// [`Self::blob_sidecar_size`].
+    pub(crate) fn size_with_blob_sidecar(&self) -> usize {
+        self.size() + self.blob_sidecar_size()
+    }
+
     /// Returns the [`SignedAuthorization`] list of the transaction.
     ///
     /// Returns `None` if this transaction is not EIP-7702.
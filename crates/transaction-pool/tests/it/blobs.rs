@@ -1,5 +1,9 @@
 //! Blob transaction tests
 
+use alloy_eips::{
+    eip4844::{BlobTransactionSidecar, DATA_GAS_PER_BLOB},
+    eip7594::BlobTransactionSidecarVariant,
+};
 use reth_transaction_pool::{
     error::PoolErrorKind,
     test_utils::{MockTransaction, MockTransactionFactory, TestPoolBuilder},
@@ -37,3 +41,43 @@ async fn blobs_exclusive() {
         _ => unreachable!(),
     }
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn best_transactions_with_budget() {
+    let txpool = TestPoolBuilder::default();
+    let blob_tx = |blobs: usize| {
+        let sidecar = BlobTransactionSidecar {
+            blobs: vec![Default::default(); blobs],
+            commitments: vec![Default::default(); blobs],
+            proofs: vec![Default::default(); blobs],
+        };
+        MockTransaction::eip4844_with_sidecar(BlobTransactionSidecarVariant::Eip4844(sidecar))
+    };
+
+    // in order of their tip
+    let transactions = [
+        MockTransaction::eip1559().with_gas_limit(100_000),
+        blob_tx(2).with_gas_limit(50_000),
+        // exceeds the blob gas budget
+        blob_tx(2).with_gas_limit(50_000),
+        // exceeds the gas budget
+        MockTransaction::eip1559().with_gas_limit(200_000),
+        MockTransaction::eip1559().with_gas_limit(50_000),
+        // fills up the blob gas budget
+        blob_tx(1).with_gas_limit(30_000),
+        // not reached, the remaining gas can't fit another transaction
+        MockTransaction::eip1559().with_gas_limit(21_000),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(idx, tx)| tx.with_max_fee(1_000_000_000_000).with_priority_fee(1_000 - idx as u128))
+    .collect::<Vec<_>>();
+    for tx in &transactions {
+        txpool.add_transaction(TransactionOrigin::External, tx.clone()).await.unwrap();
+    }
+
+    let best = txpool.best_transactions_with_budget(250_000, 3 * DATA_GAS_PER_BLOB);
+    let best = best.iter().map(|tx| *tx.hash()).collect::<Vec<_>>();
+    let expected = [0, 1, 4, 5].map(|idx| *transactions[idx].get_hash());
+    assert_eq!(best, expected);
+}
@@ -1,9 +1,10 @@
 use assert_matches::assert_matches;
+use reth_primitives_traits::InMemorySize;
 use reth_transaction_pool::{
     noop::MockTransactionValidator,
     test_utils::{MockTransactionFactory, TestPoolBuilder},
-    FullTransactionEvent, PoolTransaction, TransactionEvent, TransactionListenerKind,
-    TransactionOrigin, TransactionPool,
+    EvictionReason, FullTransactionEvent, PoolConfig, PoolTransaction, SubPool, SubPoolLimit,
+    TransactionEvent, TransactionListenerKind, TransactionOrigin, TransactionPool,
 };
 use std::{future::poll_fn, task::Poll};
 use tokio_stream::StreamExt;
@@ -238,3 +239,30 @@ async fn txpool_listener_blob_sidecar() {
     let inserted = listener_blob.recv().await.unwrap();
     assert_eq!(*inserted.tx_hash, expected);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn txpool_listener_evicted_on_pending_size_limit() {
+    let mut mock_tx_factory = MockTransactionFactory::default();
+    let transactions =
+        (0..3).map(|_| mock_tx_factory.create_eip1559().transaction).collect::<Vec<_>>();
+
+    // room for two of the three transactions
+    let max_size = transactions[0].size() + transactions[1].size();
+    let txpool = TestPoolBuilder::default().with_config(PoolConfig {
+        pending_limit: SubPoolLimit { max_txs: 100, max_size },
+        ..Default::default()
+    });
+    let mut evicted = txpool.evicted_transactions_listener();
+
+    for transaction in &transactions {
+        let _ = txpool.add_transaction(TransactionOrigin::External, transaction.clone()).await;
+    }
+
+    let event = evicted.recv().await.unwrap();
+    assert_eq!(event.reason, EvictionReason::SizeLimit);
+    assert_eq!(event.subpool, SubPool::Pending);
+    assert!(transactions.iter().any(|tx| *tx.hash() == event.tx_hash));
+    assert!(!txpool.contains(&event.tx_hash));
+    assert!(txpool.pool_size().pending_size <= max_size);
+    assert!(evicted.try_recv().is_err());
+}
@@ -14,6 +14,9 @@ pub use trie::*;
 mod traits;
 pub use traits::*;
 
+mod withdrawals;
+pub use withdrawals::WithdrawalsRootBuilder;
+
 pub mod provider;
 
 #[cfg(feature = "metrics")]
@@ -0,0 +1,102 @@
+//! Incremental computation of the withdrawals root.
+
+use crate::{provider::DefaultTrieNodeProvider, SerialSparseTrie, SparseTrieInterface};
+use alloy_eips::eip4895::Withdrawal;
+use alloy_primitives::B256;
+use reth_trie_common::Nibbles;
+
+/// Computes the withdrawals root of a block while withdrawals are added one at a time.
+///
+/// The withdrawals are kept in a fully revealed [`SerialSparseTrie`] keyed by the RLP encoded
+/// index, the same layout as the batch
+/// [`calculate_withdrawals_root`](reth_primitives_traits::proofs::calculate_withdrawals_root).
+/// Hashes of unchanged subtries are cached, so computing the root after adding a withdrawal only
+/// rehashes the `O(log n)` nodes on the path to it.
+#[derive(Debug, Default)]
+pub struct WithdrawalsRootBuilder {
+    /// The trie of the added withdrawals.
+    trie: SerialSparseTrie,
+    /// Number of added withdrawals, the index of the next withdrawal.
+    len: usize,
+}
+
+impl WithdrawalsRootBuilder {
+    /// Creates a new builder without any withdrawals.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the next withdrawal of the block.
+    pub fn push(&mut self, withdrawal: &Withdrawal) {
+        let key = Nibbles::unpack(alloy_rlp::encode_fixed_size(&self.len));
+        self.trie
+            .update_leaf(key, alloy_rlp::encode(withdrawal), DefaultTrieNodeProvider)
+            .expect("all nodes of the trie are revealed");
+
+        self.len += 1;
+    }
+
+    /// Returns the number of added withdrawals.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no withdrawals were added.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the root of the added withdrawals.
+    ///
+    /// Only the nodes changed since the last call are rehashed.
+    pub fn root(&mut self) -> B256 {
+        self.trie.root()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Address;
+    use proptest::prelude::*;
+    use reth_primitives_traits::proofs::calculate_withdrawals_root;
+    use reth_trie_common::EMPTY_ROOT_HASH;
+
+    #[test]
+    fn empty_withdrawals_root() {
+        let mut builder = WithdrawalsRootBuilder::new();
+        assert!(builder.is_empty());
+        assert_eq!(builder.root(), EMPTY_ROOT_HASH);
+        assert_eq!(builder.root(), calculate_withdrawals_root(&[]));
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(10))]
+
+        #[test]
+        fn incremental_root_matches_batch_root(
+            withdrawals in proptest::collection::vec(
+                (any::<u64>(), any::<u64>(), any::<[u8; 20]>(), any::<u64>()),
+                0..200,
+            ),
+        ) {
+            let withdrawals = withdrawals
+                .into_iter()
+                .map(|(index, validator_index, address, amount)| Withdrawal {
+                    index,
+                    validator_index,
+                    address: Address::from(address),
+                    amount,
+                })
+                .collect::<Vec<_>>();
+
+            let mut builder = WithdrawalsRootBuilder::new();
+            for (i, withdrawal) in withdrawals.iter().enumerate() {
+                builder.push(withdrawal);
+                prop_assert_eq!(builder.len(), i + 1);
+                prop_assert_eq!(builder.root(), calculate_withdrawals_root(&withdrawals[..=i]));
+            }
+            prop_assert_eq!(builder.root(), calculate_withdrawals_root(&withdrawals));
+        }
+    }
+}